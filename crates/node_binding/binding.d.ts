@@ -63,7 +63,14 @@ export class Rspack {
   constructor(options: RawOptions, builtinPlugins: Array<BuiltinPlugin>, jsHooks: JsHooks | undefined | null, outputFilesystem: ThreadsafeNodeFS, jsLoaderRunner: (...args: any[]) => any)
   unsafe_set_disabled_hooks(hooks: Array<string>): void
   /**
-   * Build with the given option passed to the constructor
+   * Build once with the given option passed to the constructor, the persistent cache is stored before the callback is called
+   *
+   * Warning:
+   * Calling this method recursively might cause a deadlock.
+   */
+  unsafe_run(callback: (err: null | Error) => void): void
+  /**
+   * Build with the given option passed to the constructor, the persistent cache is stored when the compiler is idle
    *
    * Warning:
    * Calling this method recursively might cause a deadlock.
@@ -131,6 +138,9 @@ export const enum BuiltinPluginName {
   DefinePlugin = 'DefinePlugin',
   ProvidePlugin = 'ProvidePlugin',
   BannerPlugin = 'BannerPlugin',
+  IgnorePlugin = 'IgnorePlugin',
+  NormalModuleReplacementPlugin = 'NormalModuleReplacementPlugin',
+  ContextReplacementPlugin = 'ContextReplacementPlugin',
   ProgressPlugin = 'ProgressPlugin',
   EntryPlugin = 'EntryPlugin',
   ExternalsPlugin = 'ExternalsPlugin',
@@ -154,6 +164,8 @@ export const enum BuiltinPluginName {
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
+  HttpUriPlugin = 'HttpUriPlugin',
+  SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -210,6 +222,7 @@ export interface JsAssetInfo {
 
 export interface JsAssetInfoRelated {
   sourceMap?: string
+  license?: string
 }
 
 export interface JsChunk {
@@ -463,8 +476,10 @@ export interface JsStatsModule {
   nameForCondition?: string
   reasons?: Array<JsStatsModuleReason>
   assets?: Array<string>
+  modules?: Array<JsStatsModule>
   source?: string | Buffer
   profile?: JsStatsModuleProfile
+  optimizationBailout: Array<string>
 }
 
 export interface JsStatsModuleIssuer {
@@ -497,6 +512,20 @@ export interface NodeFS {
   removeFile: (...args: any[]) => any
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
+  removeDirAll: (...args: any[]) => any
+  removeDir: (...args: any[]) => any
+  readDir: (...args: any[]) => any
+  stat: (...args: any[]) => any
+  lstat: (...args: any[]) => any
+  rename: (...args: any[]) => any
+}
+
+export interface NodeFsStats {
+  isFile: boolean
+  isDirectory: boolean
+  isSymbolicLink: boolean
+  size: number
+  mtimeMs: number
 }
 
 export interface PathData {
@@ -592,6 +621,7 @@ export interface RawCacheGroupOptions {
   automaticNameDelimiter?: string
   minChunks?: number
   minSize?: number
+  layer?: RegExp | string
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
@@ -614,12 +644,27 @@ export interface RawCacheOptions {
   cacheLocation: string
   name: string
   version: string
+  hashAlgorithm: string
+  compression: string
+  idleTimeout: number
+  idleTimeoutForInitialStore: number
+  idleTimeoutAfterLargeChanges: number
+}
+
+export interface RawCheckResourceFnCtx {
+  resource: string
+  context: string
 }
 
 export interface RawChunkOptionNameCtx {
   module: JsModule
 }
 
+export interface RawCleanOptions {
+  keep?: RawRuleSetCondition
+  dry: boolean
+}
+
 export interface RawConsumeOptions {
   key: string
   import?: string
@@ -648,6 +693,21 @@ export interface RawContainerReferencePluginOptions {
   shareScope?: string
 }
 
+/** The regexp of the context can only be replaced by `newContentRegExp` */
+export interface RawContextReplacementData {
+  request: string
+  resource?: string
+  recursive: boolean
+}
+
+export interface RawContextReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newContentResource?: string
+  newContentRecursive?: boolean
+  newContentRegExp?: RegExp
+  newContentCallback?: (data: RawContextReplacementData) => RawContextReplacementData
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
@@ -704,6 +764,7 @@ export interface RawEntryOptions {
   baseUri?: string
   filename?: string
   library?: RawLibraryOptions
+  layer?: string
 }
 
 export interface RawEntryPluginOptions {
@@ -715,9 +776,11 @@ export interface RawEntryPluginOptions {
 export interface RawExperiments {
   lazyCompilation: boolean
   incrementalRebuild: RawIncrementalRebuild
+  syncWebAssembly: boolean
   asyncWebAssembly: boolean
   newSplitChunks: boolean
   topLevelAwait: boolean
+  layers: boolean
   css: boolean
   rspackFuture: RawRspackFuture
 }
@@ -753,6 +816,12 @@ export interface RawExternalsPresets {
   electronRenderer: boolean
 }
 
+export interface RawExtractComments {
+  condition: "some" | "all" | RegExp
+  filename?: string
+  banner?: boolean | string
+}
+
 export interface RawFallbackCacheGroupOptions {
   chunks?: RegExp | 'async' | 'initial' | 'all'
   minSize?: number
@@ -804,6 +873,27 @@ export interface RawHttpExternalsRspackPluginOptions {
   webAsync: boolean
 }
 
+export interface RawHttpResponse {
+  status: number
+  headers: Record<string, string>
+  body: Buffer
+}
+
+export interface RawHttpUriPluginOptions {
+  allowedUris: (string | RegExp)[]
+  lockfileLocation: string
+  cacheLocation?: string
+  frozen: boolean
+  upgrade: boolean
+  httpClient: (url: string) => Promise<RawHttpResponse>
+}
+
+export interface RawIgnorePluginOptions {
+  resourceRegExp?: RegExp
+  contextRegExp?: RegExp
+  checkResource?: (ctx: RawCheckResourceFnCtx) => boolean
+}
+
 export interface RawIncrementalRebuild {
   make: boolean
   emitAsset: boolean
@@ -822,6 +912,19 @@ export interface RawInfo {
 
 export interface RawJavascriptParserOptions {
   dynamicImportMode: string
+  url?: "true" | "false" | "relative"
+  /** `"..."` refers to the default worker syntaxes, an empty list disables worker parsing. */
+  worker?: Array<string>
+  importMeta?: boolean
+  exprContextCritical?: boolean
+  unknownContextCritical?: boolean
+  wrappedContextCritical?: boolean
+  wrappedContextRegExp?: RawRegexMatcher
+  strictExportPresence?: boolean
+  exportsPresence?: "error" | "warn" | "auto" | "false"
+  importExportsPresence?: "error" | "warn" | "auto" | "false"
+  reexportExportsPresence?: "error" | "warn" | "auto" | "false"
+  commonjsMagicComments?: boolean
 }
 
 export interface RawLibraryAuxiliaryComment {
@@ -886,10 +989,12 @@ export interface RawModuleRule {
   sideEffects?: boolean
   use?: RawModuleRuleUses
   type?: string
+  layer?: string
   parser?: RawParserOptions
   generator?: RawGeneratorOptions
   resolve?: RawResolveOptions
   issuer?: RawRuleSetCondition
+  issuerLayer?: RawRuleSetCondition
   dependency?: RawRuleSetCondition
   scheme?: RawRuleSetCondition
   mimetype?: RawRuleSetCondition
@@ -925,6 +1030,17 @@ export interface RawNodeOption {
   global: string
 }
 
+export interface RawNormalModuleReplacementData {
+  request: string
+  context: string
+  resource?: string
+}
+
+export interface RawNormalModuleReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newResource: string | ((data: RawNormalModuleReplacementData) => RawNormalModuleReplacementData)
+}
+
 export interface RawOptimizationOptions {
   splitChunks?: RawSplitChunksOptions
   moduleIds: string
@@ -937,6 +1053,9 @@ export interface RawOptimizationOptions {
   innerGraph: boolean
   realContentHash: boolean
   mangleExports: string
+  concatenateModules: boolean
+  /** `concatenate_modules` is set by the user rather than defaulted by the mode */
+  concatenateModulesExplicit: boolean
 }
 
 export interface RawOptions {
@@ -955,13 +1074,14 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  amd?: string
   profile: boolean
   builtins: RawBuiltins
 }
 
 export interface RawOutputOptions {
   path: string
-  clean: boolean
+  clean?: RawCleanOptions
   publicPath: string
   assetModuleFilename: string
   wasmLoading: string
@@ -1116,6 +1236,14 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths: Array<RawSnapshotPathMatcher>
+  managedPaths: Array<RawSnapshotPathMatcher>
+}
+
+export interface RawSnapshotPathMatcher {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: RawRegexMatcher
 }
 
 export interface RawSnapshotStrategy {
@@ -1123,6 +1251,26 @@ export interface RawSnapshotStrategy {
   timestamp: boolean
 }
 
+export interface RawSourceMapDevToolAppendFnCtx {
+  filename: string
+  url: string
+}
+
+export interface RawSourceMapDevToolPluginOptions {
+  filename?: string
+  append?: boolean | string | ((ctx: RawSourceMapDevToolAppendFnCtx) => string)
+  namespace?: string
+  columns?: boolean
+  noSources?: boolean
+  publicPath?: string
+  moduleFilenameTemplate?: string
+  fallbackModuleFilenameTemplate?: string
+  sourceRoot?: string
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+}
+
 export interface RawSplitChunksOptions {
   fallbackCacheGroup?: RawFallbackCacheGroupOptions
   name?: string | false | Function
@@ -1152,8 +1300,17 @@ export interface RawStyleConfig {
   bool?: boolean
 }
 
+export interface RawSwcCssMinimizerRspackPluginOptions {
+  preserveComments?: RegExp
+  extractComments?: RegExp
+  targets?: Array<string>
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
-  extractComments?: string
+  extractComments?: RawExtractComments
   compress: boolean | string
   mangle: boolean | string
   format: string
@@ -1185,5 +1342,10 @@ export interface ThreadsafeNodeFS {
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
   removeDirAll: (...args: any[]) => any
+  removeDir: (...args: any[]) => any
+  readDir: (...args: any[]) => any
+  stat: (...args: any[]) => any
+  lstat: (...args: any[]) => any
+  rename: (...args: any[]) => any
 }
 
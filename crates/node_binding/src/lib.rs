@@ -114,7 +114,33 @@ impl Rspack {
     Ok(())
   }
 
-  /// Build with the given option passed to the constructor
+  /// Build once with the given option passed to the constructor, the persistent cache is stored before the callback is called
+  ///
+  /// Warning:
+  /// Calling this method recursively might cause a deadlock.
+  #[napi(
+    js_name = "unsafe_run",
+    ts_args_type = "callback: (err: null | Error) => void"
+  )]
+  pub fn run(&self, env: Env, f: JsFunction) -> Result<()> {
+    let handle_run = |compiler: &mut Pin<Box<rspack_core::Compiler<_>>>| {
+      // Safety: compiler is stored in a global hashmap, so it's guaranteed to be alive.
+      let compiler: &'static mut Pin<Box<rspack_core::Compiler<AsyncNodeWritableFileSystem>>> =
+        unsafe { std::mem::transmute::<&'_ mut _, &'static mut _>(compiler) };
+
+      callbackify(env, f, async move {
+        compiler
+          .run()
+          .await
+          .map_err(|e| Error::new(napi::Status::GenericFailure, format!("{e}")))?;
+        tracing::info!("run ok");
+        Ok(())
+      })
+    };
+    unsafe { COMPILERS.borrow_mut(&self.id, handle_run) }
+  }
+
+  /// Build with the given option passed to the constructor, the persistent cache is stored when the compiler is idle
   ///
   /// Warning:
   /// Calling this method recursively might cause a deadlock.
@@ -10,7 +10,10 @@ use rspack_binding_values::{AfterResolveData, JsChunkAssetArgs, JsModule};
 use rspack_binding_values::{BeforeResolveData, JsAssetEmittedArgs, ToJsModule};
 use rspack_binding_values::{JsResolveForSchemeInput, JsResolveForSchemeResult};
 use rspack_core::{ChunkAssetArgs, ModuleIdentifier, NormalModuleAfterResolveArgs};
-use rspack_core::{NormalModuleBeforeResolveArgs, PluginNormalModuleFactoryAfterResolveOutput};
+use rspack_core::{
+  ContextModuleBeforeResolveArgs, NormalModuleBeforeResolveArgs,
+  PluginNormalModuleFactoryAfterResolveOutput,
+};
 use rspack_core::{PluginNormalModuleFactoryBeforeResolveOutput, ResourceData};
 use rspack_core::{PluginNormalModuleFactoryResolveForSchemeOutput, PluginShouldEmitHookOutput};
 use rspack_error::internal_error;
@@ -185,14 +188,14 @@ impl rspack_core::Plugin for JsHooksAdapter {
   async fn after_resolve(
     &self,
     _ctx: rspack_core::PluginContext,
-    args: &NormalModuleAfterResolveArgs,
+    args: &mut NormalModuleAfterResolveArgs,
   ) -> PluginNormalModuleFactoryAfterResolveOutput {
     if self.is_hook_disabled(&Hook::AfterResolve) {
       return Ok(None);
     }
     self
       .after_resolve
-      .call(args.clone().into(), ThreadsafeFunctionCallMode::NonBlocking)
+      .call((&*args).into(), ThreadsafeFunctionCallMode::NonBlocking)
       .into_rspack_result()?
       .await
       .map_err(|err| internal_error!("Failed to call this_compilation: {err}"))?
@@ -200,7 +203,7 @@ impl rspack_core::Plugin for JsHooksAdapter {
   async fn context_module_before_resolve(
     &self,
     _ctx: rspack_core::PluginContext,
-    args: &mut NormalModuleBeforeResolveArgs,
+    args: &mut ContextModuleBeforeResolveArgs,
   ) -> PluginNormalModuleFactoryBeforeResolveOutput {
     self
       .context_module_before_resolve
@@ -0,0 +1 @@
+define({ value: 1 });
@@ -0,0 +1,5 @@
+if (typeof define === "function" && define.amd) {
+	define(function () {
+		return "b";
+	});
+}
@@ -0,0 +1,7 @@
+define(["./a", "require", "exports"], function (a, require, exports) {
+	var b = require("./b");
+	exports.value = a.value + b;
+	require(["./c"], function (c) {
+		console.log(c);
+	});
+});
@@ -0,0 +1,7 @@
+const url = new URL("./file.txt", import.meta.url);
+const env = import.meta.env;
+const ignored = require(/* webpackIgnore: true */ "./ignored");
+const name = "a";
+const dynamic = require("./dir/" + name + ".js");
+
+export { url, env, ignored, dynamic };
@@ -0,0 +1,12 @@
+require.ensure([], function () {
+	var a = require("./a");
+	console.log(a);
+});
+
+require.ensure(
+	["./b"],
+	() => {
+		console.log(require("./b"));
+	},
+	"named-b"
+);
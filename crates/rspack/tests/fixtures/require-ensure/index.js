@@ -0,0 +1,24 @@
+require.include("./shared");
+
+require.ensure(["./a"], function (require) {
+	var a = require("./a");
+	var shared = require("./shared");
+	console.log(a, shared);
+});
+
+require.ensure(
+	[],
+	function (require) {
+		console.log(require("./b"));
+	},
+	function (error) {
+		console.error(error);
+	},
+	"named-b"
+);
+
+if (typeof require.ensure === "function") {
+	require.ensure([], function (require) {
+		require("./c");
+	}, "named-c");
+}
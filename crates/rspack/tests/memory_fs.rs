@@ -0,0 +1,53 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rspack_core::Compiler;
+use rspack_fs::{CachedInputFileSystem, MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_to_memory_fs() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/memory_fs");
+  let (options, plugins) = apply_from_fixture(&fixture_path);
+  let output_path = options.output.path.clone();
+  let fs = MemoryFileSystem::default();
+  let mut compiler = Compiler::new(options, plugins, fs.clone());
+  compiler.build().await.expect("should build");
+
+  let files = WritableFileSystem::read_dir(&fs, &output_path).expect("should emit to memory");
+  assert!(files.contains(&"main.js".to_string()));
+  let main = ReadableFileSystem::read(&fs, output_path.join("main.js")).expect("should read");
+  assert!(String::from_utf8_lossy(&main).contains("data.txt"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn build_from_memory_fs() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/memory_fs");
+  let (mut options, plugins) = apply_from_fixture(&fixture_path);
+  options.experiments.rspack_future.new_resolver = true;
+  let output_path = options.output.path.clone();
+
+  let input_fs = MemoryFileSystem::default();
+  WritableFileSystem::create_dir_all(&input_fs, &fixture_path).expect("should create dir");
+  WritableFileSystem::write(
+    &input_fs,
+    fixture_path.join("index.js"),
+    "import data from './memory.txt';\nconsole.log(data);",
+  )
+  .expect("should write");
+  WritableFileSystem::write(&input_fs, fixture_path.join("memory.txt"), "from memory")
+    .expect("should write");
+
+  let output_fs = MemoryFileSystem::default();
+  let mut compiler = Compiler::with_input_filesystem(
+    options,
+    plugins,
+    output_fs.clone(),
+    Arc::new(CachedInputFileSystem::new(Arc::new(input_fs))),
+  );
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let main =
+    ReadableFileSystem::read(&output_fs, output_path.join("main.js")).expect("should read");
+  assert!(String::from_utf8_lossy(&main).contains("from memory"));
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+
+use rspack_core::{ConstDependency, ModuleType, ParserAndGenerator, Plugin, PluginExt, SpanExt};
+use rspack_error::Result;
+use rspack_plugin_javascript::parser_and_generator::JavaScriptParserAndGenerator;
+use rspack_plugin_javascript::parser_plugin::JavascriptParserPlugin;
+use rspack_plugin_javascript::visitors::JavascriptParser;
+use rspack_testing::test_fixture;
+use swc_core::ecma::ast::Ident;
+
+/// Replaces the free variable `__VERSION__` with a string
+#[derive(Debug)]
+struct VersionParserPlugin;
+
+impl JavascriptParserPlugin for VersionParserPlugin {
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name != "__VERSION__" {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        ident.span.real_lo(),
+        ident.span.real_hi(),
+        "\"1.0.0\"".into(),
+        None,
+      )));
+    Some(true)
+  }
+}
+
+#[derive(Debug)]
+struct VersionPlugin;
+
+impl Plugin for VersionPlugin {
+  fn name(&self) -> &'static str {
+    "VersionPlugin"
+  }
+
+  fn normal_module_factory_parser(
+    &self,
+    _module_type: &ModuleType,
+    parser: &mut dyn ParserAndGenerator,
+  ) -> Result<()> {
+    if let Some(parser) = parser.downcast_mut::<JavaScriptParserAndGenerator>() {
+      parser.add_parser_plugin(Box::new(VersionParserPlugin));
+    }
+    Ok(())
+  }
+}
+
+#[test]
+fn parser_plugin() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/parser_plugin");
+  test_fixture(
+    &fixture_path,
+    Box::new(|plugins, _| plugins.push(VersionPlugin.boxed())),
+    None,
+  );
+}
@@ -0,0 +1,3 @@
+const a = require("./a");
+
+console.log(__VERSION__, a);
@@ -0,0 +1,145 @@
+use std::{
+  path::{Path, PathBuf},
+  process::Command,
+};
+
+use rspack_core::{CacheOptions, Compiler, FileSystemCacheOptions, LogType};
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+use rspack_testing::apply_from_fixture;
+
+/// `cold` or `warm`, set when the test runs as a child process
+const STAGE: &str = "RSPACK_PERSISTENT_CACHE_STAGE";
+const CACHE_LOCATION: &str = "RSPACK_PERSISTENT_CACHE_LOCATION";
+
+/// Hit and total counts of the cache profile logs, keyed by label
+fn cache_counts(compiler: &Compiler<MemoryFileSystem>) -> Vec<(&'static str, u32, u32)> {
+  let logging = compiler.compilation.get_logging();
+  let logs = logging
+    .get("rspack.Cache")
+    .expect("should log the cache profile");
+  logs
+    .iter()
+    .filter_map(|log| match log {
+      LogType::Cache { label, hit, total } => Some((*label, *hit, *total)),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Builds the fixture and checks that each emitted file contains the expected code
+async fn build(fixture: &str, expected: &[(&str, &str)], cache_location: &Path, warm: bool) {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/persistent_cache")
+    .join(fixture);
+  let (mut options, plugins) = apply_from_fixture(&fixture_path);
+  options.cache = CacheOptions::FileSystem(FileSystemCacheOptions {
+    profile: true,
+    cache_location: cache_location.to_string_lossy().to_string(),
+    ..Default::default()
+  });
+  let output_path = options.output.path.clone();
+  let fs = MemoryFileSystem::default();
+  let mut compiler = Compiler::new(options, plugins, fs.clone());
+  compiler.run().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+  assert!(compiler.compilation.get_warnings().next().is_none());
+  for (filename, code) in expected {
+    let content = ReadableFileSystem::read(&fs, output_path.join(filename))
+      .unwrap_or_else(|_| panic!("should emit {filename}"));
+    assert!(
+      String::from_utf8_lossy(&content).contains(code),
+      "{filename} should contain {code}"
+    );
+  }
+
+  let counts = cache_counts(&compiler);
+  for label in ["build module cache", "code generation cache"] {
+    let (_, hit, total) = counts
+      .iter()
+      .find(|(l, ..)| *l == label)
+      .unwrap_or_else(|| panic!("should log {label}"));
+    assert!(*total > 0, "{label} should be used");
+    if warm {
+      assert_eq!(hit, total, "{label} should be restored from disk");
+    } else {
+      assert_eq!(*hit, 0, "{label} should be empty in a cold start");
+    }
+  }
+
+  if !warm {
+    // `run` writes the pack before the profile is logged, so the store time is included
+    let logging = compiler.compilation.get_logging();
+    let logs = logging
+      .get("rspack.Cache")
+      .expect("should log the cache profile");
+    assert!(logs.iter().any(|log| matches!(
+      log,
+      LogType::Log { message } if message.starts_with("build module cache: stored")
+    )));
+  }
+}
+
+// The cache is restored when the compiler is created, so a warm start has to run in a new process.
+async fn build_cold_and_warm(test_name: &str, fixture: &str, expected: &[(&str, &str)]) {
+  if let Ok(stage) = std::env::var(STAGE) {
+    let cache_location = std::env::var(CACHE_LOCATION).expect("should have cache location");
+    build(
+      fixture,
+      expected,
+      Path::new(&cache_location),
+      stage == "warm",
+    )
+    .await;
+    return;
+  }
+
+  let cache_location = std::env::temp_dir().join(format!(
+    "rspack-persistent-cache-{fixture}-{}",
+    std::process::id()
+  ));
+  let _ = std::fs::remove_dir_all(&cache_location);
+  for stage in ["cold", "warm"] {
+    let output = Command::new(std::env::current_exe().expect("should have test binary"))
+      .args([test_name, "--exact", "--nocapture"])
+      .env(STAGE, stage)
+      .env(CACHE_LOCATION, &cache_location)
+      .output()
+      .expect("should run the test binary");
+    assert!(
+      output.status.success(),
+      "{stage} build failed:\n{}\n{}",
+      String::from_utf8_lossy(&output.stdout),
+      String::from_utf8_lossy(&output.stderr)
+    );
+  }
+  let _ = std::fs::remove_dir_all(&cache_location);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warm_start_skips_build() {
+  build_cold_and_warm(
+    "warm_start_skips_build",
+    "commonjs",
+    &[("main.js", "__webpack_require__(/*! ./a */\"./a.js\")")],
+  )
+  .await;
+}
+
+// harmony dependencies, star exports and the async block of `import()` are restored
+#[tokio::test(flavor = "multi_thread")]
+async fn warm_start_skips_build_of_esm() {
+  build_cold_and_warm(
+    "warm_start_skips_build_of_esm",
+    "esm",
+    &[
+      ("main.js", "__webpack_require__(/*! ./a */\"./a.js\")"),
+      (
+        "main.js",
+        "__webpack_require__.es(_b__WEBPACK_IMPORTED_MODULE_1__, __webpack_exports__)",
+      ),
+      // the chunk name and prefetch order of the block
+      ("main.js", "__webpack_require__.E(\"c\")"),
+    ],
+  )
+  .await;
+}
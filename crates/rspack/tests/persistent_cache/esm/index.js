@@ -0,0 +1,5 @@
+import { a } from './a';
+
+export * from './b';
+export const value = a + 1;
+export default import(/* webpackChunkName: "c", webpackPrefetch: true */ './c');
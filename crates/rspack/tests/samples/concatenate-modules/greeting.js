@@ -0,0 +1,7 @@
+const helper = value => `Hello, ${value}`;
+
+export const name = "rspack";
+
+export default function greet(value) {
+	return helper(value);
+}
@@ -0,0 +1,8 @@
+import greet, { name } from "./greeting";
+import * as math from "./math";
+import { sum } from "./reexport";
+import legacy from "./legacy";
+
+const helper = "index";
+
+console.log(greet(name), math.double(2), sum(1, 2), legacy, helper, math);
@@ -0,0 +1,9 @@
+const helper = 2;
+
+export function double(value) {
+	return value * helper;
+}
+
+export function sum(a, b) {
+	return a + b;
+}
@@ -0,0 +1 @@
+export { sum } from "./math";
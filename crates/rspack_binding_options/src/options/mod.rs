@@ -6,7 +6,7 @@ use rspack_core::{
 };
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, MangleExportsPlugin,
-  SideEffectsFlagPlugin,
+  ModuleConcatenationPlugin, SideEffectsFlagPlugin,
 };
 use serde::Deserialize;
 
@@ -76,6 +76,7 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  pub amd: Option<String>,
   pub profile: bool,
   pub builtins: RawBuiltins,
 }
@@ -92,7 +93,7 @@ impl RawOptionsApply for RawOptions {
     let mode = self.mode.unwrap_or_default().into();
     let module: ModuleOptions = self.module.apply(plugins)?;
     let target = self.target.apply(plugins)?;
-    let cache = self.cache.into();
+    let cache = self.cache.try_into()?;
     let experiments = Experiments {
       lazy_compilation: self.experiments.lazy_compilation,
       incremental_rebuild: IncrementalRebuild {
@@ -103,16 +104,19 @@ impl RawOptionsApply for RawOptions {
           .then(IncrementalRebuildMakeState::default),
         emit_asset: self.experiments.incremental_rebuild.emit_asset,
       },
+      sync_web_assembly: self.experiments.sync_web_assembly,
       async_web_assembly: self.experiments.async_web_assembly,
       new_split_chunks: self.experiments.new_split_chunks,
       top_level_await: self.experiments.top_level_await,
+      layers: self.experiments.layers,
       rspack_future: self.experiments.rspack_future.into(),
     };
+    let concatenate_modules_explicit = self.optimization.concatenate_modules_explicit;
     let optimization = IS_ENABLE_NEW_SPLIT_CHUNKS.set(&experiments.new_split_chunks, || {
       self.optimization.apply(plugins)
     })?;
     let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let snapshot = self.snapshot.try_into()?;
     let node = self.node.map(|n| n.into());
     let dev_server: DevServerOptions = self.dev_server.into();
 
@@ -131,9 +135,13 @@ impl RawOptionsApply for RawOptions {
     );
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
     plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
     if experiments.lazy_compilation {
       plugins.push(rspack_plugin_runtime::LazyCompilationPlugin {}.boxed());
     }
+    if experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     if experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
@@ -141,17 +149,25 @@ impl RawOptionsApply for RawOptions {
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
 
     if devtool.source_map() {
-      plugins.push(
-        rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
-          inline: devtool.inline(),
-          append: !devtool.hidden(),
-          namespace: output.unique_name.clone(),
-          columns: !devtool.cheap(),
-          no_sources: devtool.no_sources(),
-          public_path: None,
-        })
-        .boxed(),
-      );
+      plugins.push(rspack_plugin_devtool::DevtoolPlugin.boxed());
+      if !devtool.eval() {
+        plugins.push(
+          rspack_plugin_devtool::SourceMapDevToolPlugin::new(
+            rspack_plugin_devtool::SourceMapDevToolPluginOptions {
+              filename: (!devtool.inline())
+                .then(|| output.source_map_filename.template().to_string()),
+              append: devtool
+                .hidden()
+                .then_some(rspack_plugin_devtool::Append::Disabled),
+              namespace: output.unique_name.clone(),
+              columns: !devtool.cheap(),
+              no_sources: devtool.no_sources(),
+              ..Default::default()
+            },
+          )
+          .boxed(),
+        );
+      }
     }
 
     if experiments.rspack_future.new_treeshaking {
@@ -164,6 +180,9 @@ impl RawOptionsApply for RawOptions {
       if optimization.used_exports.is_enable() {
         plugins.push(FlagDependencyUsagePlugin::default().boxed());
       }
+      if optimization.concatenate_modules {
+        plugins.push(ModuleConcatenationPlugin::new(concatenate_modules_explicit).boxed());
+      }
     }
     if optimization.mangle_exports.is_enable() {
       // We already know mangle_exports != false
@@ -204,6 +223,7 @@ impl RawOptionsApply for RawOptions {
       snapshot,
       optimization,
       node,
+      amd: self.amd,
       dev_server,
       profile: self.profile,
       builtins,
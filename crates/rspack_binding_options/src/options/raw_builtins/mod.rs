@@ -1,9 +1,15 @@
 mod raw_banner;
+mod raw_context_replacement;
 mod raw_copy;
 mod raw_html;
+mod raw_http_uri;
+mod raw_ignore;
 mod raw_limit_chunk_count;
 mod raw_mf;
+mod raw_normal_module_replacement;
 mod raw_progress;
+mod raw_source_map_dev_tool;
+mod raw_swc_css_minimizer;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
@@ -16,13 +22,16 @@ use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePl
 use rspack_error::Result;
 use rspack_napi_shared::NapiResultExt;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
+use rspack_plugin_devtool::SourceMapDevToolPlugin;
 use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
   electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
+use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_library::enable_library_plugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
@@ -30,11 +39,13 @@ use rspack_plugin_mf::{
   ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
   ProvideSharedPlugin,
 };
+use rspack_plugin_normal_module_replacement::NormalModuleReplacementPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, CommonJsChunkFormatPlugin,
   ModuleChunkFormatPlugin,
 };
+use rspack_plugin_schemes::HttpUriPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_wasm::enable_wasm_loading_plugin;
@@ -43,9 +54,14 @@ use rspack_plugin_worker::WorkerPlugin;
 
 use self::raw_mf::{RawConsumeOptions, RawContainerReferencePluginOptions, RawProvideOptions};
 pub use self::{
-  raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
-  raw_mf::RawContainerPluginOptions, raw_progress::RawProgressPluginOptions,
+  raw_banner::RawBannerPluginOptions, raw_context_replacement::RawContextReplacementPluginOptions,
+  raw_copy::RawCopyRspackPluginOptions, raw_html::RawHtmlRspackPluginOptions,
+  raw_http_uri::RawHttpUriPluginOptions, raw_ignore::RawIgnorePluginOptions,
+  raw_limit_chunk_count::RawLimitChunkCountPluginOptions, raw_mf::RawContainerPluginOptions,
+  raw_normal_module_replacement::RawNormalModuleReplacementPluginOptions,
+  raw_progress::RawProgressPluginOptions,
+  raw_source_map_dev_tool::RawSourceMapDevToolPluginOptions,
+  raw_swc_css_minimizer::RawSwcCssMinimizerRspackPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use crate::{
@@ -60,6 +76,9 @@ pub enum BuiltinPluginName {
   DefinePlugin,
   ProvidePlugin,
   BannerPlugin,
+  IgnorePlugin,
+  NormalModuleReplacementPlugin,
+  ContextReplacementPlugin,
   ProgressPlugin,
   EntryPlugin,
   ExternalsPlugin,
@@ -83,6 +102,8 @@ pub enum BuiltinPluginName {
   ModuleFederationRuntimePlugin,
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
+  HttpUriPlugin,
+  SourceMapDevToolPlugin,
 
   // rspack specific plugins
   HttpExternalsRspackPlugin,
@@ -121,6 +142,26 @@ impl RawOptionsApply for BuiltinPlugin {
             .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::IgnorePlugin => {
+        let plugin =
+          IgnorePlugin::new(downcast_into::<RawIgnorePluginOptions>(self.options)?.try_into()?)
+            .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::NormalModuleReplacementPlugin => {
+        let plugin = NormalModuleReplacementPlugin::new(
+          downcast_into::<RawNormalModuleReplacementPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::ContextReplacementPlugin => {
+        let plugin = ContextReplacementPlugin::new(
+          downcast_into::<RawContextReplacementPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::ProgressPlugin => {
         let plugin =
           ProgressPlugin::new(downcast_into::<RawProgressPluginOptions>(self.options)?.into())
@@ -131,7 +172,7 @@ impl RawOptionsApply for BuiltinPlugin {
         let plugin_options = downcast_into::<RawEntryPluginOptions>(self.options)?;
         let context = plugin_options.context.into();
         let entry_request = plugin_options.entry;
-        let options = plugin_options.options.into();
+        let options = plugin_options.options.try_into()?;
         let plugin = EntryPlugin::new(context, entry_request, options).boxed();
         plugins.push(plugin);
       }
@@ -152,7 +193,7 @@ impl RawOptionsApply for BuiltinPlugin {
       }
       BuiltinPluginName::EnableChunkLoadingPlugin => {
         let chunk_loading_type = downcast_into::<String>(self.options)?;
-        enable_chunk_loading_plugin(chunk_loading_type.as_str().into(), plugins);
+        enable_chunk_loading_plugin(chunk_loading_type.as_str().try_into()?, plugins);
       }
       BuiltinPluginName::EnableLibraryPlugin => {
         let library_type = downcast_into::<String>(self.options)?;
@@ -160,9 +201,7 @@ impl RawOptionsApply for BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().try_into()?, plugins);
       }
       BuiltinPluginName::CommonJsChunkFormatPlugin => {
         plugins.push(CommonJsChunkFormatPlugin.boxed());
@@ -234,6 +273,19 @@ impl RawOptionsApply for BuiltinPlugin {
           .collect();
         plugins.push(ConsumeSharedPlugin::new(consumes).boxed())
       }
+      BuiltinPluginName::HttpUriPlugin => {
+        let plugin =
+          HttpUriPlugin::new(downcast_into::<RawHttpUriPluginOptions>(self.options)?.try_into()?)
+            .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::SourceMapDevToolPlugin => {
+        let plugin = SourceMapDevToolPlugin::new(
+          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -249,7 +301,11 @@ impl RawOptionsApply for BuiltinPlugin {
         plugins.push(plugin);
       }
       BuiltinPluginName::SwcCssMinimizerRspackPlugin => {
-        plugins.push(SwcCssMinimizerRspackPlugin {}.boxed())
+        let plugin = SwcCssMinimizerRspackPlugin::new(
+          downcast_into::<RawSwcCssMinimizerRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
       }
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_context_replacement::{
+  ContextReplacementData, ContextReplacementFn, ContextReplacementPluginOptions,
+};
+
+/// The regexp of the context can only be replaced by `newContentRegExp`
+#[napi(object)]
+pub struct RawContextReplacementData {
+  pub request: String,
+  pub resource: Option<String>,
+  pub recursive: bool,
+}
+
+impl From<&ContextReplacementData> for RawContextReplacementData {
+  fn from(value: &ContextReplacementData) -> Self {
+    Self {
+      request: value.request.clone(),
+      resource: value.resource.clone(),
+      recursive: value.recursive,
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawContextReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  #[napi(ts_type = "RegExp")]
+  pub new_content_reg_exp: Option<JsRegExp>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(data: RawContextReplacementData) => RawContextReplacementData")]
+  pub new_content_callback: Option<JsFunction>,
+}
+
+impl TryFrom<RawContextReplacementPluginOptions> for ContextReplacementPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawContextReplacementPluginOptions) -> rspack_error::Result<Self> {
+    let new_content_callback = value
+      .new_content_callback
+      .map(|f| -> rspack_error::Result<_> {
+        let func: ThreadsafeFunction<RawContextReplacementData, RawContextReplacementData> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with external");
+            let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env));
+            Ok(func_use)
+          })?;
+        let func = Arc::new(func);
+        let new_content_callback: ContextReplacementFn = Box::new(move |data| {
+          let func = func.clone();
+          Box::pin(async move {
+            let result = func
+              .call((&data).into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| {
+                internal_error!("Failed to call newContentCallback function: {err}")
+              })??;
+            Ok(ContextReplacementData {
+              request: result.request,
+              resource: result.resource,
+              recursive: result.recursive,
+              reg_exp: data.reg_exp,
+            })
+          })
+        });
+        Ok(new_content_callback)
+      })
+      .transpose()?;
+
+    Ok(Self {
+      resource_regexp: value.resource_reg_exp.to_rspack_regex(),
+      new_content_resource: value.new_content_resource,
+      new_content_recursive: value.new_content_recursive,
+      new_content_reg_exp: value.new_content_reg_exp.map(|r| r.to_rspack_regex()),
+      new_content_callback,
+    })
+  }
+}
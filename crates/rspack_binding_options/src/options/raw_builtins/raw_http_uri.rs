@@ -0,0 +1,79 @@
+use std::{collections::HashMap, sync::Arc};
+
+use derivative::Derivative;
+use napi::{bindgen_prelude::Buffer, Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_schemes::{AllowedUri, HttpResponse, HttpUriPluginOptions};
+
+#[napi(object)]
+pub struct RawHttpResponse {
+  pub status: u32,
+  pub headers: HashMap<String, String>,
+  pub body: Buffer,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawHttpUriPluginOptions {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub allowed_uris: Vec<Either<String, JsRegExp>>,
+  pub lockfile_location: String,
+  pub cache_location: Option<String>,
+  pub frozen: bool,
+  pub upgrade: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(url: string) => Promise<RawHttpResponse>")]
+  pub http_client: JsFunction,
+}
+
+impl TryFrom<RawHttpUriPluginOptions> for HttpUriPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHttpUriPluginOptions) -> rspack_error::Result<Self> {
+    let http_client = value.http_client;
+    let func: ThreadsafeFunction<String, RawHttpResponse> =
+      NAPI_ENV.with(|env| -> anyhow::Result<_> {
+        let env = env.borrow().expect("Failed to get env with external");
+        let func_use =
+          rspack_binding_macros::js_fn_into_threadsafe_fn!(http_client, &Env::from(env));
+        Ok(func_use)
+      })?;
+    let func = Arc::new(func);
+    Ok(Self {
+      allowed_uris: value
+        .allowed_uris
+        .into_iter()
+        .map(|allowed| match allowed {
+          Either::A(prefix) => AllowedUri::String(prefix),
+          Either::B(regexp) => AllowedUri::Regexp(regexp.to_rspack_regex()),
+        })
+        .collect(),
+      lockfile_location: value.lockfile_location.into(),
+      cache_location: value.cache_location.map(Into::into),
+      frozen: value.frozen,
+      upgrade: value.upgrade,
+      http_client: Box::new(move |url: String| {
+        let func = func.clone();
+        Box::pin(async move {
+          let response = func
+            .call(url, ThreadsafeFunctionCallMode::NonBlocking)
+            .into_rspack_result()?
+            .await
+            .map_err(|err| internal_error!("Failed to call httpClient function: {err}"))??;
+          Ok(HttpResponse {
+            status: response.status as u16,
+            headers: response.headers,
+            body: response.body.into(),
+          })
+        })
+      }),
+    })
+  }
+}
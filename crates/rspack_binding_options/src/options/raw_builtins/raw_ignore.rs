@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_ignore::{CheckResourceFn, IgnorePluginOptions};
+
+#[napi(object)]
+pub struct RawCheckResourceFnCtx {
+  pub resource: String,
+  pub context: String,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawIgnorePluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: Option<JsRegExp>,
+  #[napi(ts_type = "RegExp")]
+  pub context_reg_exp: Option<JsRegExp>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(ctx: RawCheckResourceFnCtx) => boolean")]
+  pub check_resource: Option<JsFunction>,
+}
+
+impl TryFrom<RawIgnorePluginOptions> for IgnorePluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawIgnorePluginOptions) -> rspack_error::Result<Self> {
+    let check_resource = value
+      .check_resource
+      .map(|f| -> rspack_error::Result<_> {
+        let func: ThreadsafeFunction<RawCheckResourceFnCtx, bool> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with external");
+            let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env));
+            Ok(func_use)
+          })?;
+        let func = Arc::new(func);
+        let check_resource: CheckResourceFn = Box::new(move |resource, context| {
+          let func = func.clone();
+          let ctx = RawCheckResourceFnCtx {
+            resource: resource.to_string(),
+            context: context.to_string(),
+          };
+          Box::pin(async move {
+            func
+              .call(ctx, ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call checkResource function: {err}"))?
+          })
+        });
+        Ok(check_resource)
+      })
+      .transpose()?;
+
+    Ok(Self {
+      resource_regexp: value.resource_reg_exp.map(|r| r.to_rspack_regex()),
+      context_regexp: value.context_reg_exp.map(|r| r.to_rspack_regex()),
+      check_resource,
+    })
+  }
+}
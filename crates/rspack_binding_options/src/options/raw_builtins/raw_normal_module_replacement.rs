@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::internal_error;
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_normal_module_replacement::{
+  NormalModuleReplacement, NormalModuleReplacementData, NormalModuleReplacementFn,
+  NormalModuleReplacementPluginOptions,
+};
+
+#[napi(object)]
+pub struct RawNormalModuleReplacementData {
+  pub request: String,
+  pub context: String,
+  pub resource: Option<String>,
+}
+
+impl From<NormalModuleReplacementData> for RawNormalModuleReplacementData {
+  fn from(value: NormalModuleReplacementData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      resource: value.resource,
+    }
+  }
+}
+
+impl From<RawNormalModuleReplacementData> for NormalModuleReplacementData {
+  fn from(value: RawNormalModuleReplacementData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      resource: value.resource,
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawNormalModuleReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  #[derivative(Debug = "ignore")]
+  #[napi(
+    ts_type = "string | ((data: RawNormalModuleReplacementData) => RawNormalModuleReplacementData)"
+  )]
+  pub new_resource: Either<String, JsFunction>,
+}
+
+impl TryFrom<RawNormalModuleReplacementPluginOptions> for NormalModuleReplacementPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawNormalModuleReplacementPluginOptions) -> rspack_error::Result<Self> {
+    let new_resource = match value.new_resource {
+      Either::A(s) => NormalModuleReplacement::String(s),
+      Either::B(f) => {
+        let func: ThreadsafeFunction<
+          RawNormalModuleReplacementData,
+          RawNormalModuleReplacementData,
+        > = NAPI_ENV.with(|env| -> anyhow::Result<_> {
+          let env = env.borrow().expect("Failed to get env with external");
+          let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env));
+          Ok(func_use)
+        })?;
+        let func = Arc::new(func);
+        let new_resource: NormalModuleReplacementFn = Box::new(move |data| {
+          let func = func.clone();
+          Box::pin(async move {
+            func
+              .call(data.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call newResource function: {err}"))?
+              .map(Into::into)
+          })
+        });
+        NormalModuleReplacement::Fn(new_resource)
+      }
+    };
+
+    Ok(Self {
+      resource_regexp: value.resource_reg_exp.to_rspack_regex(),
+      new_resource,
+    })
+  }
+}
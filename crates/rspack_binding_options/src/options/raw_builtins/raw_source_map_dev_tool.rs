@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{bindgen_prelude::Either3, Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::{internal_error, Result};
+use rspack_napi_shared::{
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt, NAPI_ENV,
+};
+use rspack_plugin_devtool::{
+  Append, AppendFnCtx, SourceMapDevToolPluginOptions, SourceMapDevToolRule, SourceMapDevToolRules,
+};
+
+#[napi(object)]
+pub struct RawSourceMapDevToolAppendFnCtx {
+  pub filename: String,
+  pub url: String,
+}
+
+impl<'a> From<AppendFnCtx<'a>> for RawSourceMapDevToolAppendFnCtx {
+  fn from(value: AppendFnCtx) -> Self {
+    Self {
+      filename: value.filename.to_string(),
+      url: value.url.to_string(),
+    }
+  }
+}
+
+type RawSourceMapDevToolAppend = Either3<bool, String, JsFunction>;
+struct RawSourceMapDevToolAppendWrapper(RawSourceMapDevToolAppend);
+
+impl TryFrom<RawSourceMapDevToolAppendWrapper> for Option<Append> {
+  type Error = rspack_error::Error;
+  fn try_from(value: RawSourceMapDevToolAppendWrapper) -> Result<Self> {
+    match value.0 {
+      Either3::A(true) => Ok(None),
+      Either3::A(false) => Ok(Some(Append::Disabled)),
+      Either3::B(s) => Ok(Some(Append::String(s))),
+      Either3::C(f) => {
+        let func: ThreadsafeFunction<RawSourceMapDevToolAppendFnCtx, String> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env.borrow().expect("Failed to get env with external");
+            let func_use = rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env));
+            Ok(func_use)
+          })?;
+        let func = Arc::new(func);
+        Ok(Some(Append::Fn(Box::new(move |ctx: AppendFnCtx| {
+          let func = func.clone();
+          Box::pin(async move {
+            func
+              .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call append function: {err}"))?
+          })
+        }))))
+      }
+    }
+  }
+}
+
+type RawSourceMapDevToolRule = Either<String, JsRegExp>;
+type RawSourceMapDevToolRules = Either<RawSourceMapDevToolRule, Vec<RawSourceMapDevToolRule>>;
+struct RawSourceMapDevToolRuleWrapper(RawSourceMapDevToolRule);
+struct RawSourceMapDevToolRulesWrapper(RawSourceMapDevToolRules);
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawSourceMapDevToolPluginOptions {
+  pub filename: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "boolean | string | ((ctx: RawSourceMapDevToolAppendFnCtx) => string)")]
+  pub append: Option<RawSourceMapDevToolAppend>,
+  pub namespace: Option<String>,
+  pub columns: Option<bool>,
+  pub no_sources: Option<bool>,
+  pub public_path: Option<String>,
+  pub module_filename_template: Option<String>,
+  pub fallback_module_filename_template: Option<String>,
+  pub source_root: Option<String>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawSourceMapDevToolRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawSourceMapDevToolRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawSourceMapDevToolRules>,
+}
+
+impl From<RawSourceMapDevToolRuleWrapper> for SourceMapDevToolRule {
+  fn from(x: RawSourceMapDevToolRuleWrapper) -> Self {
+    match x.0 {
+      Either::A(s) => Self::String(s),
+      Either::B(r) => Self::Regexp(r.to_rspack_regex()),
+    }
+  }
+}
+
+impl From<RawSourceMapDevToolRulesWrapper> for SourceMapDevToolRules {
+  fn from(x: RawSourceMapDevToolRulesWrapper) -> Self {
+    match x.0 {
+      Either::A(v) => Self::Single(RawSourceMapDevToolRuleWrapper(v).into()),
+      Either::B(v) => v
+        .into_iter()
+        .map(|v| RawSourceMapDevToolRuleWrapper(v).into())
+        .collect(),
+    }
+  }
+}
+
+impl TryFrom<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
+  type Error = rspack_error::Error;
+  fn try_from(value: RawSourceMapDevToolPluginOptions) -> Result<Self> {
+    Ok(Self {
+      filename: value.filename,
+      append: value
+        .append
+        .map(|v| RawSourceMapDevToolAppendWrapper(v).try_into())
+        .transpose()?
+        .flatten(),
+      namespace: value.namespace.unwrap_or_default(),
+      columns: value.columns.unwrap_or(true),
+      no_sources: value.no_sources.unwrap_or(false),
+      public_path: value.public_path,
+      module_filename_template: value.module_filename_template,
+      fallback_module_filename_template: value.fallback_module_filename_template,
+      source_root: value.source_root,
+      test: value
+        .test
+        .map(|v| RawSourceMapDevToolRulesWrapper(v).into()),
+      include: value
+        .include
+        .map(|v| RawSourceMapDevToolRulesWrapper(v).into()),
+      exclude: value
+        .exclude
+        .map(|v| RawSourceMapDevToolRulesWrapper(v).into()),
+    })
+  }
+}
@@ -1,8 +1,9 @@
 use napi::{bindgen_prelude::Either3, Either};
 use napi_derive::napi;
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
 use rspack_napi_shared::{JsRegExp, JsRegExpExt};
 use rspack_plugin_swc_js_minimizer::{
+  ExtractComments, ExtractCommentsBanner, ExtractCommentsCondition,
   SwcJsMinimizerRspackPluginOptions, SwcJsMinimizerRule, SwcJsMinimizerRules,
 };
 use serde::Deserialize;
@@ -13,10 +14,46 @@ type RawSwcJsMinimizerRules = Either3<String, JsRegExp, Vec<RawSwcJsMinimizerRul
 struct RawSwcJsMinimizerRuleWrapper(RawSwcJsMinimizerRule);
 struct RawSwcJsMinimizerRulesWrapper(RawSwcJsMinimizerRules);
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawExtractComments {
+  #[napi(ts_type = "\"some\" | \"all\" | RegExp")]
+  pub condition: Either<String, JsRegExp>,
+  pub filename: Option<String>,
+  pub banner: Option<Either<bool, String>>,
+}
+
+impl TryFrom<RawExtractComments> for ExtractComments {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExtractComments) -> Result<Self> {
+    let condition = match value.condition {
+      Either::A(s) if s == "some" => ExtractCommentsCondition::Some,
+      Either::A(s) if s == "all" => ExtractCommentsCondition::All,
+      Either::A(s) => {
+        return Err(internal_error!(
+          "Invalid extractComments condition: {s}, expected \"some\", \"all\" or a RegExp"
+        ))
+      }
+      Either::B(r) => ExtractCommentsCondition::Regexp(r.to_rspack_regex()),
+    };
+    let banner = value.banner.and_then(|banner| match banner {
+      Either::A(true) => None,
+      Either::A(false) => Some(ExtractCommentsBanner::Disabled),
+      Either::B(s) => Some(ExtractCommentsBanner::String(s)),
+    });
+    Ok(Self {
+      condition,
+      filename: value.filename.map(Into::into),
+      banner,
+    })
+  }
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawSwcJsMinimizerRspackPluginOptions {
-  pub extract_comments: Option<String>,
+  pub extract_comments: Option<RawExtractComments>,
   pub compress: Either<bool, String>,
   pub mangle: Either<bool, String>,
   pub format: String,
@@ -47,7 +84,7 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for SwcJsMinimizerRspackPlugi
     }
 
     Ok(Self {
-      extract_comments: value.extract_comments,
+      extract_comments: value.extract_comments.map(TryInto::try_into).transpose()?,
       compress: try_deserialize_into(&value.compress)?,
       mangle: try_deserialize_into(&value.mangle)?,
       format: serde_json::from_str(&value.format)?,
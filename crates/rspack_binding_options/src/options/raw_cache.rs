@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::{CacheOptions, FileSystemCacheOptions, MemoryCacheOptions};
+use rspack_core::{CacheCompression, CacheOptions, FileSystemCacheOptions, MemoryCacheOptions};
+use rspack_error::internal_error;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -15,10 +16,17 @@ pub struct RawCacheOptions {
   pub cache_location: String,
   pub name: String,
   pub version: String,
+  pub hash_algorithm: String,
+  pub compression: String,
+  pub idle_timeout: u32,
+  pub idle_timeout_for_initial_store: u32,
+  pub idle_timeout_after_large_changes: u32,
 }
 
-impl From<RawCacheOptions> for CacheOptions {
-  fn from(value: RawCacheOptions) -> CacheOptions {
+impl TryFrom<RawCacheOptions> for CacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCacheOptions) -> rspack_error::Result<CacheOptions> {
     let RawCacheOptions {
       r#type,
       max_generations,
@@ -29,9 +37,14 @@ impl From<RawCacheOptions> for CacheOptions {
       cache_location,
       name,
       version,
+      hash_algorithm,
+      compression,
+      idle_timeout,
+      idle_timeout_for_initial_store,
+      idle_timeout_after_large_changes,
     } = value;
 
-    match r#type.as_str() {
+    Ok(match r#type.as_str() {
       "memory" => CacheOptions::Memory(MemoryCacheOptions { max_generations }),
       "filesystem" => CacheOptions::FileSystem(FileSystemCacheOptions {
         max_age,
@@ -39,10 +52,24 @@ impl From<RawCacheOptions> for CacheOptions {
         build_dependencies,
         cache_directory,
         cache_location,
+        hash_algorithm: hash_algorithm.as_str().try_into()?,
+        compression: match compression.as_str() {
+          "false" => CacheCompression::None,
+          "gzip" => CacheCompression::Gzip,
+          "brotli" => CacheCompression::Brotli,
+          _ => {
+            return Err(internal_error!(
+              "Unsupported cache compression `{compression}`, expected `false`, `gzip` or `brotli`"
+            ))
+          }
+        },
+        idle_timeout,
+        idle_timeout_for_initial_store,
+        idle_timeout_after_large_changes,
         name,
         version,
       }),
       _ => CacheOptions::Disabled,
-    }
+    })
   }
 }
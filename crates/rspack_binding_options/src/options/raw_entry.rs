@@ -25,19 +25,27 @@ pub struct RawEntryOptions {
   pub base_uri: Option<String>,
   pub filename: Option<String>,
   pub library: Option<RawLibraryOptions>,
+  pub layer: Option<String>,
 }
 
-impl From<RawEntryOptions> for EntryOptions {
-  fn from(value: RawEntryOptions) -> Self {
-    Self {
+impl TryFrom<RawEntryOptions> for EntryOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawEntryOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       name: value.name,
       runtime: value.runtime,
-      chunk_loading: value.chunk_loading.as_deref().map(Into::into),
+      chunk_loading: value
+        .chunk_loading
+        .as_deref()
+        .map(TryInto::try_into)
+        .transpose()?,
       async_chunks: value.async_chunks,
       public_path: value.public_path.map(Into::into),
       base_uri: value.base_uri,
       filename: value.filename.map(Into::into),
       library: value.library.map(Into::into),
-    }
+      layer: value.layer,
+    })
   }
 }
@@ -25,9 +25,11 @@ pub struct RawRspackFuture {
 pub struct RawExperiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: RawIncrementalRebuild,
+  pub sync_web_assembly: bool,
   pub async_web_assembly: bool,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  pub layers: bool,
   pub css: bool,
   pub rspack_future: RawRspackFuture,
 }
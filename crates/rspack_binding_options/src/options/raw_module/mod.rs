@@ -10,10 +10,10 @@ use napi_derive::napi;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions, AssetGeneratorOptions,
   AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
-  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, DynamicImportMode, FuncUseCtx,
-  GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions, ModuleOptions,
-  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, ParserOptions,
-  ParserOptionsByModuleType,
+  AssetResourceGeneratorOptions, BoxLoader, DescriptionData, DynamicImportMode, ExportPresenceMode,
+  FuncUseCtx, GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions,
+  JavascriptParserUrl, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
 };
 use rspack_error::internal_error;
 use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
@@ -280,10 +280,12 @@ pub struct RawModuleRule {
   pub side_effects: Option<bool>,
   pub r#use: Option<RawModuleRuleUses>,
   pub r#type: Option<String>,
+  pub layer: Option<String>,
   pub parser: Option<RawParserOptions>,
   pub generator: Option<RawGeneratorOptions>,
   pub resolve: Option<RawResolveOptions>,
   pub issuer: Option<RawRuleSetCondition>,
+  pub issuer_layer: Option<RawRuleSetCondition>,
   pub dependency: Option<RawRuleSetCondition>,
   pub scheme: Option<RawRuleSetCondition>,
   pub mimetype: Option<RawRuleSetCondition>,
@@ -304,24 +306,37 @@ pub struct RawParserOptions {
   pub javascript: Option<RawJavascriptParserOptions>,
 }
 
-impl From<RawParserOptions> for ParserOptions {
-  fn from(value: RawParserOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawParserOptions> for ParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawParserOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
-          .expect("should have an \"asset\" when RawParserOptions.type is \"asset\"")
+          .ok_or_else(|| {
+            internal_error!("should have an \"asset\" when RawParserOptions.type is \"asset\"")
+          })?
           .into(),
       ),
       "javascript" => Self::Javascript(
-        value.javascript.expect("should have an \"javascript\" when RawParserOptions.type is \"javascript\"").into()
+        value
+          .javascript
+          .ok_or_else(|| {
+            internal_error!(
+              "should have an \"javascript\" when RawParserOptions.type is \"javascript\""
+            )
+          })?
+          .try_into()?,
       ),
       "unknown" => Self::Unknown,
-      _ => panic!(
-        "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"javascript\",  \"unknown\".",
-        value.r#type
-      ),
-    }
+      _ => {
+        return Err(internal_error!(
+          "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"javascript\",  \"unknown\".",
+          value.r#type
+        ))
+      }
+    })
   }
 }
 
@@ -330,13 +345,82 @@ impl From<RawParserOptions> for ParserOptions {
 #[napi(object)]
 pub struct RawJavascriptParserOptions {
   pub dynamic_import_mode: String,
-}
+  #[napi(ts_type = r#""true" | "false" | "relative""#)]
+  pub url: Option<String>,
+  /// `"..."` refers to the default worker syntaxes, an empty list disables worker parsing.
+  pub worker: Option<Vec<String>>,
+  pub import_meta: Option<bool>,
+  pub expr_context_critical: Option<bool>,
+  pub unknown_context_critical: Option<bool>,
+  pub wrapped_context_critical: Option<bool>,
+  pub wrapped_context_reg_exp: Option<RawRegexMatcher>,
+  pub strict_export_presence: Option<bool>,
+  #[napi(ts_type = r#""error" | "warn" | "auto" | "false""#)]
+  pub exports_presence: Option<String>,
+  #[napi(ts_type = r#""error" | "warn" | "auto" | "false""#)]
+  pub import_exports_presence: Option<String>,
+  #[napi(ts_type = r#""error" | "warn" | "auto" | "false""#)]
+  pub reexport_exports_presence: Option<String>,
+  pub commonjs_magic_comments: Option<bool>,
+}
+
+impl TryFrom<RawJavascriptParserOptions> for JavascriptParserOptions {
+  type Error = rspack_error::Error;
 
-impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
-  fn from(value: RawJavascriptParserOptions) -> Self {
-    Self {
+  fn try_from(value: RawJavascriptParserOptions) -> rspack_error::Result<Self> {
+    let default = JavascriptParserOptions::default();
+    Ok(Self {
       dynamic_import_mode: DynamicImportMode::from(value.dynamic_import_mode.as_str()),
-    }
+      url: value
+        .url
+        .map(|url| JavascriptParserUrl::from(url.as_str()))
+        .unwrap_or(default.url),
+      worker: value
+        .worker
+        .map(|worker| {
+          worker
+            .into_iter()
+            .flat_map(|syntax| {
+              if syntax == "..." {
+                default.worker.clone()
+              } else {
+                vec![syntax]
+              }
+            })
+            .collect()
+        })
+        .unwrap_or(default.worker),
+      import_meta: value.import_meta.unwrap_or(default.import_meta),
+      expr_context_critical: value
+        .expr_context_critical
+        .unwrap_or(default.expr_context_critical),
+      unknown_context_critical: value
+        .unknown_context_critical
+        .unwrap_or(default.unknown_context_critical),
+      wrapped_context_critical: value
+        .wrapped_context_critical
+        .unwrap_or(default.wrapped_context_critical),
+      wrapped_context_reg_exp: value
+        .wrapped_context_reg_exp
+        .map(|reg_exp| rspack_regex::RspackRegex::with_flags(&reg_exp.source, &reg_exp.flags))
+        .transpose()?
+        .unwrap_or(default.wrapped_context_reg_exp),
+      strict_export_presence: value
+        .strict_export_presence
+        .unwrap_or(default.strict_export_presence),
+      exports_presence: value
+        .exports_presence
+        .map(|mode| ExportPresenceMode::from(mode.as_str())),
+      import_exports_presence: value
+        .import_exports_presence
+        .map(|mode| ExportPresenceMode::from(mode.as_str())),
+      reexport_exports_presence: value
+        .reexport_exports_presence
+        .map(|mode| ExportPresenceMode::from(mode.as_str())),
+      commonjs_magic_comments: value
+        .commonjs_magic_comments
+        .unwrap_or(default.commonjs_magic_comments),
+    })
   }
 }
 
@@ -689,11 +773,13 @@ impl RawOptionsApply for RawModuleRule {
       description_data,
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
-      parser: self.parser.map(|raw| raw.into()),
+      layer: self.layer,
+      parser: self.parser.map(|raw| raw.try_into()).transpose()?,
       generator: self.generator.map(|raw| raw.into()),
       resolve: self.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: self.side_effects,
       issuer: self.issuer.map(|raw| raw.try_into()).transpose()?,
+      issuer_layer: self.issuer_layer.map(|raw| raw.try_into()).transpose()?,
       dependency: self.dependency.map(|raw| raw.try_into()).transpose()?,
       scheme: self.scheme.map(|raw| raw.try_into()).transpose()?,
       mimetype: self.mimetype.map(|raw| raw.try_into()).transpose()?,
@@ -722,7 +808,7 @@ impl RawOptionsApply for RawModuleOptions {
         .parser
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.into())))
+            .map(|(k, v)| Ok((ModuleType::try_from(k.as_str())?, v.try_into()?)))
             .collect::<std::result::Result<ParserOptionsByModuleType, rspack_error::Error>>()
         })
         .transpose()?,
@@ -29,6 +29,9 @@ pub struct RawOptimizationOptions {
   pub inner_graph: bool,
   pub real_content_hash: bool,
   pub mangle_exports: String,
+  pub concatenate_modules: bool,
+  /// `concatenate_modules` is set by the user rather than defaulted by the mode
+  pub concatenate_modules_explicit: bool,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -69,6 +72,7 @@ impl RawOptionsApply for RawOptimizationOptions {
       used_exports: UsedExportsOption::from(self.used_exports.as_str()),
       inner_graph: self.inner_graph,
       mangle_exports: MangleExportsOption::from(self.mangle_exports.as_str()),
+      concatenate_modules: self.concatenate_modules,
     })
   }
 }
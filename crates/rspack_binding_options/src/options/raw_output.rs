@@ -1,12 +1,12 @@
 use napi_derive::napi;
 use rspack_core::{
-  BoxPlugin, CrossOriginLoading, LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject,
-  LibraryOptions,
+  BoxPlugin, CleanOptions, CrossOriginLoading, LibraryCustomUmdObject, LibraryName,
+  LibraryNonUmdObject, LibraryOptions,
 };
 use rspack_core::{LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
 use serde::Deserialize;
 
-use crate::RawOptionsApply;
+use crate::{RawOptionsApply, RawRuleSetCondition};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,6 +23,25 @@ impl From<RawTrustedTypes> for TrustedTypes {
   }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawCleanOptions {
+  pub keep: Option<RawRuleSetCondition>,
+  pub dry: bool,
+}
+
+impl TryFrom<RawCleanOptions> for CleanOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCleanOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
+      keep: value.keep.map(TryInto::try_into).transpose()?,
+      dry: value.dry,
+    })
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -153,7 +172,7 @@ impl From<RawCrossOriginLoading> for CrossOriginLoading {
 #[napi(object)]
 pub struct RawOutputOptions {
   pub path: String,
-  pub clean: bool,
+  pub clean: Option<RawCleanOptions>,
   pub public_path: String,
   pub asset_module_filename: String,
   pub wasm_loading: String,
@@ -197,13 +216,13 @@ impl RawOptionsApply for RawOutputOptions {
   fn apply(self, _: &mut Vec<BoxPlugin>) -> Result<OutputOptions, rspack_error::Error> {
     Ok(OutputOptions {
       path: self.path.into(),
-      clean: self.clean,
+      clean: self.clean.map(TryInto::try_into).transpose()?,
       public_path: self.public_path.into(),
       asset_module_filename: self.asset_module_filename.into(),
-      wasm_loading: self.wasm_loading.as_str().into(),
+      wasm_loading: self.wasm_loading.as_str().try_into()?,
       webassembly_module_filename: self.webassembly_module_filename.into(),
       unique_name: self.unique_name,
-      chunk_loading: self.chunk_loading.as_str().into(),
+      chunk_loading: self.chunk_loading.as_str().try_into()?,
       chunk_loading_global: self.chunk_loading_global.as_str().into(),
       filename: self.filename.into(),
       chunk_filename: self.chunk_filename.into(),
@@ -222,13 +241,13 @@ impl RawOptionsApply for RawOutputOptions {
       module: self.module,
       trusted_types: self.trusted_types.map(Into::into),
       source_map_filename: self.source_map_filename.into(),
-      hash_function: self.hash_function.as_str().into(),
+      hash_function: self.hash_function.as_str().try_into()?,
       hash_digest: self.hash_digest.as_str().into(),
       hash_digest_length: self.hash_digest_length as usize,
       hash_salt: self.hash_salt.into(),
       async_chunks: self.async_chunks,
-      worker_chunk_loading: self.worker_chunk_loading.as_str().into(),
-      worker_wasm_loading: self.worker_wasm_loading.as_str().into(),
+      worker_chunk_loading: self.worker_chunk_loading.as_str().try_into()?,
+      worker_wasm_loading: self.worker_wasm_loading.as_str().try_into()?,
       worker_public_path: self.worker_public_path,
       script_type: self.script_type,
     })
@@ -1,7 +1,10 @@
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{SnapshotOptions, SnapshotPathMatcher, SnapshotStrategy};
+use rspack_error::internal_error;
 use serde::Deserialize;
 
+use crate::RawRegexMatcher;
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -19,21 +22,77 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawSnapshotPathMatcher {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<RawRegexMatcher>,
+}
+
+impl TryFrom<RawSnapshotPathMatcher> for SnapshotPathMatcher {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotPathMatcher) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::String(value.string_matcher.ok_or_else(|| {
+        internal_error!(
+          "should have a string_matcher when RawSnapshotPathMatcher.type is \"string\""
+        )
+      })?)),
+      "regexp" => {
+        let matcher = value.regexp_matcher.ok_or_else(|| {
+          internal_error!(
+            "should have a regexp_matcher when RawSnapshotPathMatcher.type is \"regexp\""
+          )
+        })?;
+        Ok(Self::Regexp(rspack_regex::RspackRegex::with_flags(
+          &matcher.source,
+          &matcher.flags,
+        )?))
+      }
+      _ => Err(internal_error!(
+        "Failed to resolve the snapshot path type {}. Expected type is `string` or `regexp`.",
+        value.r#type
+      )),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub immutable_paths: Vec<RawSnapshotPathMatcher>,
+  pub managed_paths: Vec<RawSnapshotPathMatcher>,
 }
 
-impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+impl TryFrom<RawSnapshotOptions> for SnapshotOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotOptions) -> rspack_error::Result<Self> {
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
-    SnapshotOptions {
+    Ok(SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
-    }
+      immutable_paths: immutable_paths
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<rspack_error::Result<Vec<_>>>()?,
+      managed_paths: managed_paths
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<rspack_error::Result<Vec<_>>>()?,
+    })
   }
 }
@@ -151,7 +151,10 @@ pub struct RawCacheGroupOptions {
   //   pub min_size_reduction: usize,
   //   pub enforce_size_threshold: usize,
   //   pub min_remaining_size: usize,
-  // layer: String,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "RegExp | string")]
+  #[derivative(Debug = "ignore")]
+  pub layer: Option<Either<JsRegExp, JsString>>,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
   pub max_initial_size: Option<f64>,
@@ -252,6 +255,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             .map(create_module_type_filter)
             .unwrap_or_else(rspack_plugin_split_chunks_new::create_default_module_type_filter);
 
+          let layer = v
+            .layer
+            .map(create_module_layer_filter)
+            .unwrap_or_else(rspack_plugin_split_chunks_new::create_default_module_layer_filter);
+
           let mut name = v.name.map_or(default_chunk_option_name(), |name| {
             normalize_raw_chunk_name(name)
           });
@@ -285,6 +293,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             max_async_size,
             max_initial_size,
             r#type,
+            layer,
           }
         }),
     );
@@ -357,3 +366,22 @@ fn create_module_type_filter(
     }
   }
 }
+
+fn create_module_layer_filter(
+  raw: Either<JsRegExp, JsString>,
+) -> rspack_plugin_split_chunks_new::ModuleLayerFilter {
+  match raw {
+    Either::A(js_reg) => {
+      let regex = js_reg.to_rspack_regex();
+      Arc::new(move |m| m.get_layer().map_or(false, |layer| regex.test(layer)))
+    }
+    Either::B(js_str) => {
+      let layer_str = js_str.into_string();
+      // An empty string matches the modules without a layer
+      Arc::new(move |m| match m.get_layer() {
+        Some(layer) => !layer_str.is_empty() && layer.starts_with(layer_str.as_str()),
+        None => layer_str.is_empty(),
+      })
+    }
+  }
+}
@@ -5,12 +5,14 @@ use super::JsCompatSource;
 #[napi(object)]
 pub struct JsAssetInfoRelated {
   pub source_map: Option<String>,
+  pub license: Option<String>,
 }
 
 impl From<JsAssetInfoRelated> for rspack_core::AssetInfoRelated {
   fn from(i: JsAssetInfoRelated) -> Self {
     Self {
       source_map: i.source_map,
+      license: i.license,
     }
   }
 }
@@ -70,6 +72,7 @@ impl From<rspack_core::AssetInfoRelated> for JsAssetInfoRelated {
   fn from(related: rspack_core::AssetInfoRelated) -> Self {
     Self {
       source_map: related.source_map,
+      license: related.license,
     }
   }
 }
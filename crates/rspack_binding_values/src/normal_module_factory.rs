@@ -1,5 +1,8 @@
 use napi_derive::napi;
-use rspack_core::{NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, ResourceData};
+use rspack_core::{
+  ContextModuleBeforeResolveArgs, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
+  ResourceData,
+};
 
 #[napi(object)]
 pub struct JsResolveForSchemeInput {
@@ -75,8 +78,17 @@ impl From<NormalModuleBeforeResolveArgs> for BeforeResolveData {
   }
 }
 
-impl From<NormalModuleAfterResolveArgs<'_>> for AfterResolveData {
-  fn from(value: NormalModuleAfterResolveArgs) -> Self {
+impl From<ContextModuleBeforeResolveArgs> for BeforeResolveData {
+  fn from(value: ContextModuleBeforeResolveArgs) -> Self {
+    Self {
+      context: value.context,
+      request: value.request,
+    }
+  }
+}
+
+impl From<&NormalModuleAfterResolveArgs<'_>> for AfterResolveData {
+  fn from(value: &NormalModuleAfterResolveArgs) -> Self {
     Self {
       context: value.context.to_owned(),
       request: value.request.to_string(),
@@ -223,8 +223,10 @@ pub struct JsStatsModule {
   pub name_for_condition: Option<String>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
   pub assets: Option<Vec<String>>,
+  pub modules: Option<Vec<JsStatsModule>>,
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
+  pub optimization_bailout: Vec<String>,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -262,8 +264,18 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
         .reasons
         .map(|i| i.into_iter().map(Into::into).collect()),
       assets: stats.assets,
+      modules: stats
+        .modules
+        .map(|modules| {
+          modules
+            .into_iter()
+            .map(JsStatsModule::try_from)
+            .collect::<Result<_>>()
+        })
+        .transpose()?,
       source,
       profile: stats.profile.map(|p| p.into()),
+      optimization_bailout: stats.optimization_bailout,
     })
   }
 }
@@ -43,11 +43,14 @@ pub(super) struct CodeSplitter<'me> {
 }
 
 fn add_chunk_in_group(group_options: Option<&GroupOptions>, info: ChunkGroupInfo) -> ChunkGroup {
-  let options = ChunkGroupOptions::default().name_optional(
-    group_options
-      .and_then(|x| x.name())
-      .map(|name| name.to_string()),
-  );
+  let options = match group_options {
+    Some(GroupOptions::ChunkGroup(options)) => options.clone(),
+    _ => ChunkGroupOptions::default().name_optional(
+      group_options
+        .and_then(|x| x.name())
+        .map(|name| name.to_string()),
+    ),
+  };
   let kind = ChunkGroupKind::Normal { options };
   ChunkGroup::new(kind, info)
 }
@@ -573,10 +576,18 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
     self.compilation.chunk_graph.add_chunk(chunk_ukey);
 
     let entry_options = block.get_group_options().and_then(|o| o.entry_options());
+    let group_options = block.get_group_options().cloned();
     if let Some(cgi) = cgi {
       if entry_options.is_some() {
         entrypoint = Some(*cgi);
       } else {
+        if let Some(group_options) = &group_options {
+          self
+            .compilation
+            .chunk_group_by_ukey
+            .expect_get_mut(cgi)
+            .add_options(group_options);
+        }
         c = Some(*cgi);
       }
     } else {
@@ -681,6 +692,13 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
             res = item_chunk_group_ukey;
           }
 
+          if let Some(group_options) = &group_options {
+            self
+              .compilation
+              .chunk_group_by_ukey
+              .expect_get_mut(cgi)
+              .add_options(group_options);
+          }
           self
             .compilation
             .chunk_graph
@@ -781,11 +799,25 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
   }
 
   fn extract_block_modules(&mut self, module: ModuleIdentifier, runtime: Option<&RuntimeSpec>) {
-    self
+    let block_modules_map = self
       .block_modules_runtime_map
       .entry(runtime.cloned().into())
-      .or_default()
-      .insert(module.into(), Vec::new());
+      .or_default();
+    block_modules_map.insert(module.into(), Vec::new());
+    // blocks may have no module dependencies, e.g. `require.ensure([], callback)`
+    let mut blocks = self
+      .compilation
+      .module_graph
+      .module_by_identifier(&module)
+      .expect("should have module")
+      .get_blocks()
+      .to_vec();
+    while let Some(block_id) = blocks.pop() {
+      block_modules_map.insert(block_id.into(), Vec::new());
+      if let Some(block) = self.compilation.module_graph.block_by_id(&block_id) {
+        blocks.extend(block.get_blocks());
+      }
+    }
     let dependencies: Vec<&BoxDependency> =
       if IS_NEW_TREESHAKING.load(std::sync::atomic::Ordering::Relaxed) {
         let mgm = self
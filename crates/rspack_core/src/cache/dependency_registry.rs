@@ -0,0 +1,107 @@
+//! Dependencies are trait objects, the persistent cache saves them by name and looks up the
+//! function to restore them here, like `makeSerializable` in webpack.
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+  BoxDependency, BoxDependencyTemplate, ConstDependency, Dependency, DependencyTemplate,
+  RuntimeRequirementsDependency,
+};
+
+type UnpackDependency = fn(serde_json::Value) -> Option<BoxDependency>;
+type UnpackDependencyTemplate = fn(serde_json::Value) -> Option<BoxDependencyTemplate>;
+
+static DEPENDENCIES: Lazy<DashMap<&'static str, UnpackDependency>> = Lazy::new(Default::default);
+
+static DEPENDENCY_TEMPLATES: Lazy<DashMap<&'static str, UnpackDependencyTemplate>> =
+  Lazy::new(|| {
+    let templates: DashMap<&'static str, UnpackDependencyTemplate> = Default::default();
+    templates.insert("ConstDependency", unpack_template::<ConstDependency>);
+    templates.insert(
+      "RuntimeRequirementsDependency",
+      unpack_template::<RuntimeRequirementsDependency>,
+    );
+    templates
+  });
+
+fn unpack_dependency<D>(data: serde_json::Value) -> Option<BoxDependency>
+where
+  D: Dependency + DeserializeOwned + 'static,
+{
+  serde_json::from_value::<D>(data)
+    .ok()
+    .map(|dep| Box::new(dep) as BoxDependency)
+}
+
+fn unpack_template<T>(data: serde_json::Value) -> Option<BoxDependencyTemplate>
+where
+  T: DependencyTemplate + DeserializeOwned + 'static,
+{
+  serde_json::from_value::<T>(data)
+    .ok()
+    .map(|template| Box::new(template) as BoxDependencyTemplate)
+}
+
+/// Make the dependency restorable from the persistent cache, `name` should be the same as
+/// [Dependency::dependency_debug_name]. The dependency gets a new [crate::DependencyId] when
+/// it's restored.
+///
+/// Call it in [crate::Plugin::apply], the cache is restored after the plugins are applied.
+pub fn register_dependency<D>(name: &'static str)
+where
+  D: Dependency + DeserializeOwned + 'static,
+{
+  DEPENDENCIES.insert(name, unpack_dependency::<D>);
+}
+
+/// Make the dependency template restorable from the persistent cache, `name` should be the same
+/// as the one returned by [DependencyTemplate::pack_template].
+pub fn register_dependency_template<T>(name: &'static str)
+where
+  T: DependencyTemplate + DeserializeOwned + 'static,
+{
+  DEPENDENCY_TEMPLATES.insert(name, unpack_template::<T>);
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PackedDependency {
+  name: String,
+  data: serde_json::Value,
+}
+
+impl PackedDependency {
+  /// `None` when the dependency is not registered or it can't be saved.
+  pub(crate) fn pack_dependency(dep: &dyn Dependency) -> Option<Self> {
+    let name = dep.dependency_debug_name();
+    if !DEPENDENCIES.contains_key(name) {
+      return None;
+    }
+    Some(Self {
+      name: name.to_string(),
+      data: dep.pack()?,
+    })
+  }
+
+  pub(crate) fn pack_template(template: &dyn DependencyTemplate) -> Option<Self> {
+    let (name, data) = template.pack_template()?;
+    if !DEPENDENCY_TEMPLATES.contains_key(name) {
+      return None;
+    }
+    Some(Self {
+      name: name.to_string(),
+      data,
+    })
+  }
+
+  pub(crate) fn unpack_dependency(self) -> Option<BoxDependency> {
+    let unpack = *DEPENDENCIES.get(self.name.as_str())?;
+    unpack(self.data)
+  }
+
+  pub(crate) fn unpack_template(self) -> Option<BoxDependencyTemplate> {
+    let unpack = *DEPENDENCY_TEMPLATES.get(self.name.as_str())?;
+    unpack(self.data)
+  }
+}
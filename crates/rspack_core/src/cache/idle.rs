@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use crate::FileSystemCacheOptions;
+
+/// Decides how long the compiler should stay idle before storing the persistent cache.
+///
+/// A build which takes much longer than storing the cache is treated as a large change,
+/// such a build is expensive to redo, so the cache is stored sooner.
+#[derive(Debug)]
+pub struct IdleStoreScheduler {
+  idle_timeout: Duration,
+  idle_timeout_for_initial_store: Duration,
+  idle_timeout_after_large_changes: Duration,
+  is_initial_store: bool,
+  /// build time of recent builds, older builds have lower weight
+  time_spent_in_build: Duration,
+  avg_time_spent_in_store: Duration,
+}
+
+impl IdleStoreScheduler {
+  pub fn new(options: &FileSystemCacheOptions) -> Self {
+    Self {
+      idle_timeout: Duration::from_millis(options.idle_timeout as u64),
+      idle_timeout_for_initial_store: Duration::from_millis(
+        options.idle_timeout_for_initial_store as u64,
+      ),
+      idle_timeout_after_large_changes: Duration::from_millis(
+        options.idle_timeout_after_large_changes as u64,
+      ),
+      is_initial_store: true,
+      time_spent_in_build: Duration::ZERO,
+      avg_time_spent_in_store: Duration::ZERO,
+    }
+  }
+
+  pub fn record_build(&mut self, build_time: Duration) {
+    self.time_spent_in_build = self.time_spent_in_build.mul_f64(0.9) + build_time;
+  }
+
+  pub fn record_store(&mut self, store_time: Duration) {
+    self.avg_time_spent_in_store = if self.is_initial_store {
+      store_time
+    } else {
+      self.avg_time_spent_in_store.mul_f64(0.9) + store_time.mul_f64(0.1)
+    };
+    self.is_initial_store = false;
+    self.time_spent_in_build = Duration::ZERO;
+  }
+
+  pub fn timeout(&self) -> Duration {
+    if self.is_initial_store && self.idle_timeout_for_initial_store < self.idle_timeout {
+      self.idle_timeout_for_initial_store
+    } else if self.time_spent_in_build > self.avg_time_spent_in_store * 2
+      && self.idle_timeout_after_large_changes < self.idle_timeout
+    {
+      self.idle_timeout_after_large_changes
+    } else {
+      self.idle_timeout
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn idle_store_timeout() {
+    let mut scheduler = IdleStoreScheduler::new(&FileSystemCacheOptions {
+      idle_timeout: 60000,
+      idle_timeout_for_initial_store: 5000,
+      idle_timeout_after_large_changes: 1000,
+      ..Default::default()
+    });
+    scheduler.record_build(Duration::from_secs(10));
+    assert_eq!(scheduler.timeout(), Duration::from_millis(5000));
+
+    scheduler.record_store(Duration::from_secs(1));
+    scheduler.record_build(Duration::from_millis(100));
+    assert_eq!(scheduler.timeout(), Duration::from_millis(60000));
+
+    scheduler.record_build(Duration::from_secs(5));
+    assert_eq!(scheduler.timeout(), Duration::from_millis(1000));
+  }
+}
@@ -2,49 +2,90 @@ use std::{
   path::PathBuf,
   sync::{
     atomic::{AtomicBool, Ordering},
-    Arc,
+    Arc, Mutex,
   },
+  time::{Duration, Instant},
 };
 
-use crate::CompilerOptions;
+use rspack_error::{Error, Result};
+use rspack_fs::InputFileSystem;
+use tokio::task::JoinHandle;
 
+use crate::{CacheOptions, CompilerOptions, Logger};
+
+mod dependency_registry;
+mod idle;
 mod local;
 mod occasion;
+mod profile;
 mod snapshot;
 mod storage;
+pub use dependency_registry::{register_dependency, register_dependency_template};
+use idle::IdleStoreScheduler;
 pub use local::*;
+pub use occasion::MinifyResult;
 use occasion::{
-  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
+  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, MinifyOccasion, Occasion,
+  ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_storage, StorageOptions};
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
   snapshot_manager: Arc<SnapshotManager>,
+  /// only available for the persistent cache
+  idle_store_scheduler: Option<Mutex<IdleStoreScheduler>>,
+  idle_store_task: Mutex<Option<JoinHandle<()>>>,
+  /// error of the last store in background
+  idle_store_error: Mutex<Option<Error>>,
   pub resolve_module_occasion: ResolveModuleOccasion,
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
+  pub minify_occasion: MinifyOccasion,
 }
 
 impl Cache {
-  pub fn new(options: Arc<CompilerOptions>) -> Self {
-    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
+  pub fn new(options: Arc<CompilerOptions>, input_filesystem: Arc<dyn InputFileSystem>) -> Self {
+    let snapshot_manager = Arc::new(SnapshotManager::new(
+      options.snapshot.clone(),
+      input_filesystem,
+    ));
+    let storage_options = StorageOptions::new(&options);
+    let profile =
+      matches!(&options.cache, CacheOptions::FileSystem(fs_options) if fs_options.profile);
     Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
+      idle_store_scheduler: match &options.cache {
+        CacheOptions::FileSystem(fs_options) => {
+          Some(Mutex::new(IdleStoreScheduler::new(fs_options)))
+        }
+        _ => None,
+      },
+      idle_store_task: Default::default(),
+      idle_store_error: Default::default(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&storage_options, "resolve_module"),
         snapshot_manager.clone(),
+        profile,
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&storage_options, "build_module"),
         snapshot_manager,
+        profile,
+      ),
+      code_generate_occasion: CodeGenerateOccasion::new(
+        new_storage(&storage_options, "code_generate"),
+        profile,
+      ),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(
+        new_storage(&storage_options, "create_chunk_assets"),
+        profile,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      minify_occasion: MinifyOccasion::new(new_storage(&storage_options, "minify"), profile),
     }
   }
 
@@ -60,9 +101,125 @@ impl Cache {
     {
       self.snapshot_manager.clear();
     }
+    self.resolve_module_occasion.begin_idle();
+    self.build_module_occasion.begin_idle();
+    self.code_generate_occasion.begin_idle();
+    self.create_chunk_assets_occasion.begin_idle();
+    self.minify_occasion.begin_idle();
+  }
+
+  /// Store the cache after the compiler has been idle for a while, see [FileSystemCacheOptions::idle_timeout].
+  ///
+  /// The store runs in background and it's cancelled when [Cache::end_idle] is called.
+  ///
+  /// [FileSystemCacheOptions::idle_timeout]: crate::FileSystemCacheOptions::idle_timeout
+  pub fn store_when_idle(self: &Arc<Self>, build_time: Duration) {
+    let Some(scheduler) = &self.idle_store_scheduler else {
+      return;
+    };
+    let timeout = {
+      let mut scheduler = scheduler.lock().expect("should lock idle store scheduler");
+      scheduler.record_build(build_time);
+      scheduler.timeout()
+    };
+    // hold a weak reference, the cache is stored on drop if the compiler is dropped before the timeout
+    let cache = Arc::downgrade(self);
+    let task = tokio::spawn(async move {
+      tokio::time::sleep(timeout).await;
+      let _ = tokio::task::spawn_blocking(move || {
+        if let Some(cache) = cache.upgrade() {
+          cache.store_in_idle();
+        }
+      })
+      .await;
+    });
+    if let Some(task) = self
+      .idle_store_task
+      .lock()
+      .expect("should lock idle store task")
+      .replace(task)
+    {
+      task.abort();
+    }
+  }
+
+  fn store_in_idle(&self) {
+    if !self.is_idle.load(Ordering::Acquire) {
+      return;
+    }
+    let start = Instant::now();
+    match self.flush() {
+      Ok(()) => {
+        if self.is_idle.load(Ordering::Acquire)
+          && let Some(scheduler) = &self.idle_store_scheduler
+        {
+          scheduler
+            .lock()
+            .expect("should lock idle store scheduler")
+            .record_store(start.elapsed());
+        }
+      }
+      Err(err) => {
+        *self
+          .idle_store_error
+          .lock()
+          .expect("should lock idle store error") = Some(err);
+      }
+    }
+  }
+
+  /// Take the error of the last store in background.
+  pub fn take_idle_store_error(&self) -> Option<Error> {
+    self
+      .idle_store_error
+      .lock()
+      .ok()
+      .and_then(|mut error| error.take())
+  }
+
+  /// Log the hit and miss counters of each occasion and the restore and store information of
+  /// the persistent storage, only works when `cache.profile` is enabled.
+  pub fn log_profile(&self, logger: &impl Logger) {
+    self.resolve_module_occasion.log_profile(logger);
+    self.build_module_occasion.log_profile(logger);
+    self.code_generate_occasion.log_profile(logger);
+    self.create_chunk_assets_occasion.log_profile(logger);
+    self.minify_occasion.log_profile(logger);
+  }
+
+  /// Save the cache entries to disk, only works with persistent cache.
+  pub fn flush(&self) -> Result<()> {
+    self.resolve_module_occasion.flush()?;
+    self.build_module_occasion.flush()?;
+    self.code_generate_occasion.flush()?;
+    self.create_chunk_assets_occasion.flush()?;
+    self.minify_occasion.flush()
   }
 
   pub fn end_idle(&self) {
     self.is_idle.store(false, Ordering::Release);
+    if let Some(task) = self
+      .idle_store_task
+      .lock()
+      .expect("should lock idle store task")
+      .take()
+    {
+      task.abort();
+    }
+    self.resolve_module_occasion.end_idle();
+    self.build_module_occasion.end_idle();
+    self.code_generate_occasion.end_idle();
+    self.create_chunk_assets_occasion.end_idle();
+    self.minify_occasion.end_idle();
+  }
+}
+
+impl Drop for Cache {
+  fn drop(&mut self) {
+    // store the pending changes which are waiting for idle timeout
+    if let Ok(Some(task)) = self.idle_store_task.get_mut().map(Option::take) {
+      task.abort();
+      let _ = self.flush();
+    }
   }
 }
@@ -3,15 +3,22 @@ use std::{collections::HashMap, path::Path, sync::Arc};
 use futures::Future;
 use rkyv::AlignedVec;
 use rspack_error::{Result, TWithDiagnosticArray};
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
 
+use super::{Occasion, OccasionStorage, PackedSource};
 use crate::{
+  cache::dependency_registry::PackedDependency,
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  BoxModule, BuildExtraDataType, BuildResult, DependencyTemplate, ModuleDependency,
+  cache::storage::{self, PackItem},
+  AsyncDependenciesBlock, BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult,
+  ChunkGroupOptions, DependencyLocation, DependencyTemplate, GroupOptions, ModuleDependency,
   NormalModuleSource,
 };
 
+const LABEL: &str = "build module cache";
+
 #[derive(Debug, Clone)]
 pub struct NormalModuleStorageData {
   source: NormalModuleSource,
@@ -21,7 +28,7 @@ pub struct NormalModuleStorageData {
 
 type NormalModuleStorageExtraData = HashMap<BuildExtraDataType, AlignedVec>;
 
-type Storage = dyn storage::Storage<(
+type Item = (
   // file system info, None when not cacheable
   Option<Snapshot>,
   // build result
@@ -30,24 +37,235 @@ type Storage = dyn storage::Storage<(
   Option<NormalModuleStorageData>,
   // parser and generator data
   Option<NormalModuleStorageExtraData>,
-)>;
+);
+
+type Storage = dyn storage::Storage<Item>;
+
+/// A build result saved in a pack file.
+#[derive(Serialize, Deserialize)]
+struct PackedBuildModule {
+  snapshot: Snapshot,
+  build_meta: BuildMeta,
+  build_info: BuildInfo,
+  hash: Option<String>,
+  /// indexes of the star export dependencies in `dependencies`, the ids are renewed on restore
+  all_star_exports: Vec<usize>,
+  dependencies: Vec<PackedDependency>,
+  blocks: Vec<PackedBlock>,
+  source: PackedSource,
+  /// whether the code generation dependencies is `Some`, only empty ones can be saved
+  has_code_generation_dependencies: bool,
+  presentational_dependencies: Option<Vec<PackedDependency>>,
+  extra_data: HashMap<BuildExtraDataType, Vec<u8>>,
+}
+
+/// An async block of `import()` and the like, blocks with entry options (workers) are not saved.
+#[derive(Serialize, Deserialize)]
+struct PackedBlock {
+  from: Identifier,
+  modifier: String,
+  loc: Option<DependencyLocation>,
+  group_options: Option<ChunkGroupOptions>,
+  dependencies: Vec<PackedDependency>,
+}
+
+impl PackedBlock {
+  fn pack(block: &AsyncDependenciesBlock) -> Option<Self> {
+    if !block.blocks().is_empty() {
+      return None;
+    }
+    let group_options = match block.get_group_options() {
+      Some(GroupOptions::ChunkGroup(options)) => Some(options.clone()),
+      Some(GroupOptions::Entrypoint(_)) => return None,
+      None => None,
+    };
+    let id = block.identifier();
+    Some(Self {
+      from: id.from,
+      modifier: id.modifier().to_string(),
+      loc: block.loc().copied(),
+      group_options,
+      dependencies: block
+        .dependencies()
+        .iter()
+        .map(|dep| PackedDependency::pack_dependency(dep.as_ref()))
+        .collect::<Option<Vec<_>>>()?,
+    })
+  }
+
+  fn unpack(self) -> Option<AsyncDependenciesBlock> {
+    let mut block = AsyncDependenciesBlock::new(self.from, self.modifier, self.loc);
+    if let Some(options) = self.group_options {
+      block.set_group_options(GroupOptions::ChunkGroup(options));
+    }
+    for dep in self.dependencies {
+      block.add_dependency(dep.unpack_dependency()?);
+    }
+    Some(block)
+  }
+}
+
+// Only results of successful builds without diagnostics are saved, with every dependency
+// registered by [crate::cache::register_dependency], others are kept in memory.
+impl PackItem for Item {
+  fn pack(&self) -> Option<Vec<u8>> {
+    let (Some(snapshot), data, Some(module_data), Some(extra_data)) = self else {
+      return None;
+    };
+    let result = &data.inner;
+    if !data.diagnostic.is_empty()
+      // tree shaking analysis of `builtins.treeShaking`
+      || !result.analyze_result.module_identifier.is_empty()
+      || module_data
+        .code_generation_dependencies
+        .as_ref()
+        .is_some_and(|deps| !deps.is_empty())
+    {
+      return None;
+    }
+    let NormalModuleSource::BuiltSucceed(source) = &module_data.source else {
+      return None;
+    };
+
+    let all_star_exports = result
+      .build_info
+      .all_star_exports
+      .iter()
+      .map(|id| result.dependencies.iter().position(|dep| dep.id() == id))
+      .collect::<Option<Vec<_>>>()?;
+    let dependencies = result
+      .dependencies
+      .iter()
+      .map(|dep| PackedDependency::pack_dependency(dep.as_ref()))
+      .collect::<Option<Vec<_>>>()?;
+    let blocks = result
+      .blocks
+      .iter()
+      .map(PackedBlock::pack)
+      .collect::<Option<Vec<_>>>()?;
+    let presentational_dependencies = match &module_data.presentational_dependencies {
+      Some(templates) => Some(
+        templates
+          .iter()
+          .map(|template| PackedDependency::pack_template(template.as_ref()))
+          .collect::<Option<Vec<_>>>()?,
+      ),
+      None => None,
+    };
+
+    let packed = PackedBuildModule {
+      snapshot: snapshot.clone(),
+      build_meta: result.build_meta.clone(),
+      build_info: result.build_info.clone(),
+      hash: result
+        .build_info
+        .hash
+        .as_ref()
+        .map(|hash| hash.encoded().to_string()),
+      all_star_exports,
+      dependencies,
+      blocks,
+      source: PackedSource::pack(source)?,
+      has_code_generation_dependencies: module_data.code_generation_dependencies.is_some(),
+      presentational_dependencies,
+      extra_data: extra_data
+        .iter()
+        .map(|(key, value)| (key.clone(), value.to_vec()))
+        .collect(),
+    };
+    serde_json::to_vec(&packed).ok()
+  }
+
+  fn unpack(bytes: &[u8]) -> Option<Self> {
+    let packed: PackedBuildModule = serde_json::from_slice(bytes).ok()?;
+    let dependencies = packed
+      .dependencies
+      .into_iter()
+      .map(PackedDependency::unpack_dependency)
+      .collect::<Option<Vec<_>>>()?;
+    let all_star_exports = packed
+      .all_star_exports
+      .iter()
+      .map(|index| dependencies.get(*index).map(|dep| *dep.id()))
+      .collect::<Option<Vec<_>>>()?;
+    let blocks = packed
+      .blocks
+      .into_iter()
+      .map(PackedBlock::unpack)
+      .collect::<Option<Vec<_>>>()?;
+    let presentational_dependencies = match packed.presentational_dependencies {
+      Some(templates) => Some(
+        templates
+          .into_iter()
+          .map(PackedDependency::unpack_template)
+          .collect::<Option<Vec<_>>>()?,
+      ),
+      None => None,
+    };
+
+    let build_info = BuildInfo {
+      hash: packed.hash.as_deref().map(RspackHashDigest::from),
+      all_star_exports,
+      ..packed.build_info
+    };
+    let result = BuildResult {
+      build_meta: packed.build_meta,
+      build_info,
+      analyze_result: Default::default(),
+      dependencies,
+      blocks,
+    };
+    let module_data = NormalModuleStorageData {
+      source: NormalModuleSource::BuiltSucceed(packed.source.unpack()?),
+      code_generation_dependencies: packed.has_code_generation_dependencies.then(Vec::new),
+      presentational_dependencies,
+    };
+    let extra_data = packed
+      .extra_data
+      .into_iter()
+      .map(|(key, value)| {
+        let mut aligned = AlignedVec::with_capacity(value.len());
+        aligned.extend_from_slice(&value);
+        (key, aligned)
+      })
+      .collect();
+    Some((
+      Some(packed.snapshot),
+      TWithDiagnosticArray::new(result, vec![]),
+      Some(module_data),
+      Some(extra_data),
+    ))
+  }
+}
 
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
-  storage: Option<Box<Storage>>,
+  storage: OccasionStorage<Item>,
   snapshot_manager: Arc<SnapshotManager>,
 }
 
+impl Occasion for BuildModuleOccasion {
+  type Item = Item;
+
+  fn storage(&self) -> &OccasionStorage<Item> {
+    &self.storage
+  }
+}
+
 impl BuildModuleOccasion {
-  pub fn new(storage: Option<Box<Storage>>, snapshot_manager: Arc<SnapshotManager>) -> Self {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    snapshot_manager: Arc<SnapshotManager>,
+    profile: bool,
+  ) -> Self {
     Self {
-      storage,
+      storage: OccasionStorage::new(LABEL, storage, profile),
       snapshot_manager,
     }
   }
 
   pub fn remove_cache(&self, id: &Identifier) {
-    if let Some(s) = self.storage.as_ref() {
+    if let Some(s) = self.storage.get() {
       s.remove(id);
     }
   }
@@ -61,7 +279,7 @@ impl BuildModuleOccasion {
     G: Fn(&'a mut BoxModule) -> F,
     F: Future<Output = Result<(TWithDiagnosticArray<BuildResult>, &'a mut BoxModule)>>,
   {
-    let storage = match &self.storage {
+    let storage = match self.storage.get() {
       Some(s) => s,
       // no cache return directly
       None => return Ok((Ok(generator(module).await?.0), false)),
@@ -94,11 +312,15 @@ impl BuildModuleOccasion {
               module.parser_and_generator_mut().resume(&extra_data);
             }
           }
+          self.storage.hit();
           return Ok((Ok(data), true));
         } else {
+          self.storage.invalid();
           last_build_result = Some(data.inner);
         }
-      };
+      } else {
+        self.storage.missing();
+      }
       need_cache = true;
     }
 
@@ -1,19 +1,114 @@
+use std::sync::Arc;
+
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
+use super::{Occasion, OccasionStorage, PackedSource};
+use crate::{
+  cache::storage::{self, PackItem},
+  BoxModule, CodeGenerationDataFilename, CodeGenerationDataUrl, CodeGenerationResult, Compilation,
+  NormalModuleSource, RuntimeGlobals, SourceType,
+};
 use crate::{RuntimeSpec, RuntimeSpecSet};
 
-type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
+const LABEL: &str = "code generation cache";
+
+type Item = Vec<(CodeGenerationResult, RuntimeSpec)>;
+
+type Storage = dyn storage::Storage<Item>;
+
+/// A code generation result saved in a pack file.
+#[derive(Serialize, Deserialize)]
+struct PackedCodeGenerationResult {
+  sources: Vec<(SourceType, PackedSource)>,
+  url: Option<String>,
+  filename: Option<String>,
+  runtime_requirements: RuntimeGlobals,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+// Results with chunk init fragments or code generation data other than the url and the
+// filename are kept in memory only.
+impl PackItem for Item {
+  fn pack(&self) -> Option<Vec<u8>> {
+    let packed = self
+      .iter()
+      .map(|(result, runtime)| {
+        if !result.chunk_init_fragments.is_empty() {
+          return None;
+        }
+        let url = result.data.get::<CodeGenerationDataUrl>();
+        let filename = result.data.get::<CodeGenerationDataFilename>();
+        if result.data.len() != usize::from(url.is_some()) + usize::from(filename.is_some()) {
+          return None;
+        }
+        let sources = result
+          .inner
+          .iter()
+          .map(|(source_type, source)| Some((*source_type, PackedSource::pack(source)?)))
+          .collect::<Option<Vec<_>>>()?;
+        Some(PackedCodeGenerationResult {
+          sources,
+          url: url.map(|url| url.inner().to_string()),
+          filename: filename.map(|filename| filename.inner().to_string()),
+          runtime_requirements: result.runtime_requirements,
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_vec(&packed).ok()
+  }
+
+  fn unpack(bytes: &[u8]) -> Option<Self> {
+    let packed: Vec<PackedCodeGenerationResult> = serde_json::from_slice(bytes).ok()?;
+    packed
+      .into_iter()
+      .map(|packed| {
+        let mut result = CodeGenerationResult {
+          runtime_requirements: packed.runtime_requirements,
+          hash: packed.hash.as_deref().map(RspackHashDigest::from),
+          ..Default::default()
+        };
+        for (source_type, source) in packed.sources {
+          result.add(source_type, source.unpack()?);
+        }
+        if let Some(url) = packed.url {
+          result.data.insert(CodeGenerationDataUrl::new(url));
+        }
+        if let Some(filename) = packed.filename {
+          result
+            .data
+            .insert(CodeGenerationDataFilename::new(filename));
+        }
+        let runtime = packed.runtime.into_iter().map(Arc::from).collect();
+        Some((result, runtime))
+      })
+      .collect()
+  }
+}
 
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
-  storage: Option<Box<Storage>>,
+  storage: OccasionStorage<Item>,
+}
+
+impl Occasion for CodeGenerateOccasion {
+  type Item = Item;
+
+  fn storage(&self) -> &OccasionStorage<Item> {
+    &self.storage
+  }
 }
 
 impl CodeGenerateOccasion {
-  pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+  pub fn new(storage: Option<Box<Storage>>, profile: bool) -> Self {
+    Self {
+      storage: OccasionStorage::new(LABEL, storage, profile),
+    }
   }
 
   #[allow(clippy::unwrap_in_result)]
@@ -27,7 +122,7 @@ impl CodeGenerateOccasion {
   where
     G: Fn(&'a BoxModule, RuntimeSpecSet) -> Result<Vec<(CodeGenerationResult, RuntimeSpec)>>,
   {
-    let storage = match &self.storage {
+    let storage = match self.storage.get() {
       Some(s) => s,
       // no cache return directly
       None => return Ok((generator(module, runtimes)?, false)),
@@ -46,8 +141,10 @@ impl CodeGenerateOccasion {
 
       // currently no need to separate module hash by runtime
       if let Some(data) = storage.get(&id) {
+        self.storage.hit();
         return Ok((data, true));
       }
+      self.storage.missing();
 
       if matches!(normal_module.source(), NormalModuleSource::Unbuild) {
         // unbuild and no cache is unexpected
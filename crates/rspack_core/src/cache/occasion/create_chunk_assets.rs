@@ -2,18 +2,38 @@ use futures::Future;
 use rspack_error::Result;
 use rspack_identifier::Identifier;
 
-use crate::{cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
+use super::{Occasion, OccasionStorage};
+use crate::{
+  cache::storage::{self, PackItem},
+  Chunk, Compilation, NormalModuleSource, RenderManifestEntry,
+};
 
-type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
+const LABEL: &str = "create chunk assets cache";
+
+type Item = Vec<RenderManifestEntry>;
+
+type Storage = dyn storage::Storage<Item>;
+
+impl PackItem for Vec<RenderManifestEntry> {}
 
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
-  storage: Option<Box<Storage>>,
+  storage: OccasionStorage<Item>,
+}
+
+impl Occasion for CreateChunkAssetsOccasion {
+  type Item = Item;
+
+  fn storage(&self) -> &OccasionStorage<Item> {
+    &self.storage
+  }
 }
 
 impl CreateChunkAssetsOccasion {
-  pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+  pub fn new(storage: Option<Box<Storage>>, profile: bool) -> Self {
+    Self {
+      storage: OccasionStorage::new(LABEL, storage, profile),
+    }
   }
 
   pub async fn use_cache<'a, G, F>(
@@ -26,7 +46,7 @@ impl CreateChunkAssetsOccasion {
     G: Fn() -> F,
     F: Future<Output = Result<Vec<RenderManifestEntry>>>,
   {
-    let storage = match &self.storage {
+    let storage = match self.storage.get() {
       Some(s) => s,
       // no cache return directly
       None => return generator().await,
@@ -51,8 +71,12 @@ impl CreateChunkAssetsOccasion {
     if is_cache_valid {
       // read
       if let Some(data) = storage.get(&chunk_id) {
+        self.storage.hit();
         return Ok(data);
       }
+      self.storage.missing();
+    } else {
+      self.storage.invalid();
     }
     // run generator and save to cache
     let data = generator().await?;
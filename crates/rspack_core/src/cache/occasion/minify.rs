@@ -0,0 +1,197 @@
+use std::hash::BuildHasherDefault;
+
+use dashmap::DashSet;
+use rspack_error::Result;
+use rspack_identifier::{Identifier, IdentifierHasher, IdentifierSet};
+use rspack_sources::BoxSource;
+use serde::{Deserialize, Serialize};
+
+use super::{Occasion, OccasionStorage, PackedSource};
+use crate::cache::storage::{self, PackItem};
+
+const LABEL: &str = "minify cache";
+
+/// Output of a minimizer for an asset.
+#[derive(Debug, Clone)]
+pub struct MinifyResult {
+  pub source: BoxSource,
+  /// content of the file which the comments are extracted to
+  pub extracted_comments: Option<String>,
+}
+
+type Storage = dyn storage::Storage<MinifyResult>;
+
+#[derive(Serialize, Deserialize)]
+struct PackedMinifyResult {
+  source: PackedSource,
+  extracted_comments: Option<String>,
+}
+
+impl PackItem for MinifyResult {
+  fn pack(&self) -> Option<Vec<u8>> {
+    let packed = PackedMinifyResult {
+      source: PackedSource::pack(&self.source)?,
+      extracted_comments: self.extracted_comments.clone(),
+    };
+    serde_json::to_vec(&packed).ok()
+  }
+
+  fn unpack(bytes: &[u8]) -> Option<Self> {
+    let packed: PackedMinifyResult = serde_json::from_slice(bytes).ok()?;
+    Some(MinifyResult {
+      source: packed.source.unpack()?,
+      extracted_comments: packed.extracted_comments,
+    })
+  }
+}
+
+/// Cache of the minimized assets, keyed by the hash of the minimizer input, e.g. the filename,
+/// the source and the minimizer options. Entries not used in the last compilation are dropped
+/// when the compilation is done, so outdated outputs don't pile up.
+#[derive(Debug)]
+pub struct MinifyOccasion {
+  storage: OccasionStorage<MinifyResult>,
+  used: DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>,
+}
+
+impl Occasion for MinifyOccasion {
+  type Item = MinifyResult;
+
+  fn storage(&self) -> &OccasionStorage<MinifyResult> {
+    &self.storage
+  }
+
+  fn begin_idle(&self) {
+    if let Some(s) = self.storage.get() {
+      let used = self.used.iter().map(|id| *id).collect::<IdentifierSet>();
+      self.used.clear();
+      // nothing is minified when the compilation failed early, keep the entries for the next one
+      if !used.is_empty() {
+        s.retain(&used);
+      }
+      s.begin_idle();
+    }
+  }
+}
+
+impl MinifyOccasion {
+  pub fn new(storage: Option<Box<Storage>>, profile: bool) -> Self {
+    Self {
+      storage: OccasionStorage::new(LABEL, storage, profile),
+      used: Default::default(),
+    }
+  }
+
+  /// Returns the minimized asset and whether it's from the cache.
+  pub fn use_cache<G>(&self, hash: &str, generator: G) -> Result<(MinifyResult, bool)>
+  where
+    G: FnOnce() -> Result<MinifyResult>,
+  {
+    let storage = match self.storage.get() {
+      Some(s) => s,
+      // no cache return directly
+      None => return Ok((generator()?, false)),
+    };
+
+    let id = Identifier::from(hash);
+    self.used.insert(id);
+    if let Some(data) = storage.get(&id) {
+      self.storage.hit();
+      return Ok((data, true));
+    }
+    self.storage.missing();
+
+    // run generator and save to cache
+    let data = generator()?;
+    storage.set(id, data.clone());
+    Ok((data, false))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_sources::{RawSource, Source, SourceExt};
+
+  use super::*;
+  use crate::cache::storage::MemoryStorage;
+
+  fn create_occasion() -> MinifyOccasion {
+    MinifyOccasion::new(Some(Box::new(MemoryStorage::new())), false)
+  }
+
+  fn minify(code: &str) -> Result<MinifyResult> {
+    Ok(MinifyResult {
+      source: RawSource::from(code.to_string()).boxed(),
+      extracted_comments: Some("/*! license */".to_string()),
+    })
+  }
+
+  #[test]
+  fn should_hit_with_the_same_hash() {
+    let occasion = create_occasion();
+    let (_, from_cache) = occasion
+      .use_cache("a", || minify("a"))
+      .expect("should minify");
+    assert!(!from_cache);
+    let (result, from_cache) = occasion
+      .use_cache("a", || panic!("should not minify again"))
+      .expect("should minify");
+    assert!(from_cache);
+    assert_eq!(result.source.source(), "a");
+    assert_eq!(result.extracted_comments.as_deref(), Some("/*! license */"));
+  }
+
+  #[test]
+  fn should_miss_when_the_hash_changes() {
+    let occasion = create_occasion();
+    occasion
+      .use_cache("a", || minify("a"))
+      .expect("should minify");
+    // e.g. the minimizer options changed
+    let (result, from_cache) = occasion
+      .use_cache("b", || minify("b"))
+      .expect("should minify");
+    assert!(!from_cache);
+    assert_eq!(result.source.source(), "b");
+  }
+
+  #[test]
+  fn should_drop_entries_not_used_in_the_last_compilation() {
+    let occasion = create_occasion();
+    occasion
+      .use_cache("a", || minify("a"))
+      .expect("should minify");
+    occasion
+      .use_cache("b", || minify("b"))
+      .expect("should minify");
+    occasion.begin_idle();
+    occasion.end_idle();
+
+    occasion
+      .use_cache("a", || minify("a"))
+      .expect("should minify");
+    occasion.begin_idle();
+    occasion.end_idle();
+
+    let (_, from_cache) = occasion
+      .use_cache("a", || minify("a"))
+      .expect("should minify");
+    assert!(from_cache);
+    let (_, from_cache) = occasion
+      .use_cache("b", || minify("b"))
+      .expect("should minify");
+    assert!(!from_cache);
+  }
+
+  #[test]
+  fn should_restore_extracted_comments() {
+    let result = minify("a").expect("should minify");
+    let restored =
+      MinifyResult::unpack(&result.pack().expect("should pack")).expect("should unpack");
+    assert_eq!(restored.source.source(), "a");
+    assert_eq!(
+      restored.extracted_comments.as_deref(),
+      Some("/*! license */")
+    );
+  }
+}
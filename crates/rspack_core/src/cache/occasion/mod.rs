@@ -1,3 +1,12 @@
+use rspack_error::Result;
+use rspack_sources::{BoxSource, MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource};
+use rspack_sources::{Source, WithoutOriginalOptions};
+use serde::{Deserialize, Serialize};
+
+use super::profile::OccasionProfile;
+use super::storage::Storage;
+use crate::Logger;
+
 mod resolve_module;
 pub use resolve_module::*;
 mod build_module;
@@ -6,3 +15,138 @@ mod code_generate;
 pub use code_generate::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
+mod minify;
+pub use minify::*;
+
+/// The storage of an occasion and its hit and miss counters.
+#[derive(Debug)]
+pub struct OccasionStorage<Item> {
+  label: &'static str,
+  storage: Option<Box<dyn Storage<Item>>>,
+  profile: Option<OccasionProfile>,
+}
+
+impl<Item> OccasionStorage<Item> {
+  pub fn new(label: &'static str, storage: Option<Box<dyn Storage<Item>>>, profile: bool) -> Self {
+    Self {
+      label,
+      storage,
+      profile: OccasionProfile::new(profile),
+    }
+  }
+
+  /// `None` when the cache is disabled.
+  pub fn get(&self) -> Option<&dyn Storage<Item>> {
+    self.storage.as_deref()
+  }
+
+  pub fn hit(&self) {
+    if let Some(profile) = &self.profile {
+      profile.hit();
+    }
+  }
+
+  pub fn missing(&self) {
+    if let Some(profile) = &self.profile {
+      profile.missing();
+    }
+  }
+
+  pub fn invalid(&self) {
+    if let Some(profile) = &self.profile {
+      profile.invalid();
+    }
+  }
+}
+
+/// The lifecycle of an occasion driven by [crate::cache::Cache], the default implementations
+/// pass it through to the storage.
+pub trait Occasion {
+  type Item;
+
+  fn storage(&self) -> &OccasionStorage<Self::Item>;
+
+  fn flush(&self) -> Result<()> {
+    match self.storage().get() {
+      Some(s) => s.flush(),
+      None => Ok(()),
+    }
+  }
+
+  fn begin_idle(&self) {
+    if let Some(s) = self.storage().get() {
+      s.begin_idle();
+    }
+  }
+
+  fn end_idle(&self) {
+    if let Some(s) = self.storage().get() {
+      s.end_idle();
+    }
+  }
+
+  fn log_profile(&self, logger: &impl Logger) {
+    let storage = self.storage();
+    if let Some(profile) = &storage.profile {
+      profile.log(storage.label, storage.get(), logger);
+    }
+  }
+}
+
+/// A source saved in a pack file, the source map is kept if there is one.
+#[derive(Serialize, Deserialize)]
+enum PackedSource {
+  Text(String),
+  /// binary sources of assets
+  Buffer(Vec<u8>),
+  SourceMap {
+    code: String,
+    map: String,
+  },
+}
+
+impl PackedSource {
+  fn pack(source: &BoxSource) -> Option<Self> {
+    if let Some(map) = source.map(&MapOptions::default()) {
+      return Some(Self::SourceMap {
+        code: source.source().to_string(),
+        map: map.to_json().ok()?,
+      });
+    }
+    Some(match String::from_utf8(source.buffer().to_vec()) {
+      Ok(code) => Self::Text(code),
+      Err(err) => Self::Buffer(err.into_bytes()),
+    })
+  }
+
+  fn unpack(self) -> Option<BoxSource> {
+    Some(match self {
+      Self::Text(code) => RawSource::from(code).boxed(),
+      Self::Buffer(buffer) => RawSource::from(buffer).boxed(),
+      Self::SourceMap { code, map } => {
+        let source_map = SourceMap::from_json(&map).ok()?;
+        SourceMapSource::new(WithoutOriginalOptions {
+          value: code,
+          name: source_map.file().unwrap_or_default().to_string(),
+          source_map,
+        })
+        .boxed()
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn pack_binary_source() {
+    let source = RawSource::from(vec![0xff, 0x00, 0x01]).boxed();
+    let packed = PackedSource::pack(&source).expect("should pack");
+    let bytes = serde_json::to_vec(&packed).expect("should serialize");
+    let packed: PackedSource = serde_json::from_slice(&bytes).expect("should deserialize");
+    let unpacked = packed.unpack().expect("should unpack");
+    assert_eq!(unpacked.buffer(), source.buffer());
+  }
+}
@@ -1,26 +1,96 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rspack_identifier::Identifier;
+use rspack_loader_runner::DescriptionData;
+use serde::{Deserialize, Serialize};
 
+use super::{Occasion, OccasionStorage};
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveError, ResolveResult,
+  cache::storage::{self, PackItem},
+  ModuleIdentifier, ResolveArgs, ResolveError, ResolveResult, Resource,
 };
 
-type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
+const LABEL: &str = "resolve module cache";
+
+type Item = (Snapshot, ResolveResult);
+
+type Storage = dyn storage::Storage<Item>;
+
+#[derive(Serialize, Deserialize)]
+enum PackedResolveResult {
+  Resource {
+    path: PathBuf,
+    query: Option<String>,
+    fragment: Option<String>,
+    // path and content of package.json
+    description_data: Option<(PathBuf, serde_json::Value)>,
+  },
+  Ignored,
+}
+
+impl PackItem for (Snapshot, ResolveResult) {
+  fn pack(&self) -> Option<Vec<u8>> {
+    let (snapshot, result) = self;
+    let result = match result {
+      ResolveResult::Resource(resource) => PackedResolveResult::Resource {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        description_data: resource
+          .description_data
+          .as_ref()
+          .map(|data| (data.path().to_path_buf(), data.json().clone())),
+      },
+      ResolveResult::Ignored => PackedResolveResult::Ignored,
+    };
+    serde_json::to_vec(&(snapshot, result)).ok()
+  }
+
+  fn unpack(bytes: &[u8]) -> Option<Self> {
+    let (snapshot, result): (Snapshot, PackedResolveResult) = serde_json::from_slice(bytes).ok()?;
+    let result = match result {
+      PackedResolveResult::Resource {
+        path,
+        query,
+        fragment,
+        description_data,
+      } => ResolveResult::Resource(Resource {
+        path,
+        query,
+        fragment,
+        description_data: description_data
+          .map(|(path, json)| DescriptionData::new(path, Arc::new(json))),
+      }),
+      PackedResolveResult::Ignored => ResolveResult::Ignored,
+    };
+    Some((snapshot, result))
+  }
+}
 
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
-  storage: Option<Box<Storage>>,
+  storage: OccasionStorage<Item>,
   snapshot_manager: Arc<SnapshotManager>,
 }
 
+impl Occasion for ResolveModuleOccasion {
+  type Item = Item;
+
+  fn storage(&self) -> &OccasionStorage<Item> {
+    &self.storage
+  }
+}
+
 impl ResolveModuleOccasion {
-  pub fn new(storage: Option<Box<Storage>>, snapshot_manager: Arc<SnapshotManager>) -> Self {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    snapshot_manager: Arc<SnapshotManager>,
+    profile: bool,
+  ) -> Self {
     Self {
-      storage,
+      storage: OccasionStorage::new(LABEL, storage, profile),
       snapshot_manager,
     }
   }
@@ -34,7 +104,7 @@ impl ResolveModuleOccasion {
     G: Fn(ResolveArgs<'a>) -> F,
     F: Future<Output = Result<ResolveResult, ResolveError>>,
   {
-    let storage = match &self.storage {
+    let storage = match self.storage.get() {
       Some(s) => s,
       // no cache return directly
       None => return Ok((generator(args).await, false)),
@@ -60,9 +130,13 @@ impl ResolveModuleOccasion {
           .unwrap_or(false);
 
         if valid {
+          self.storage.hit();
           return Ok((Ok(data), true));
         }
-      };
+        self.storage.invalid();
+      } else {
+        self.storage.missing();
+      }
     }
 
     // run generator and save to cache
@@ -0,0 +1,127 @@
+use std::{
+  sync::atomic::{AtomicU32, Ordering},
+  time::Duration,
+};
+
+use super::storage::Storage;
+use crate::{LogType, Logger};
+
+/// Hit and miss counters of a cache occasion, only collected when `cache.profile` is enabled.
+#[derive(Debug, Default)]
+pub struct OccasionProfile {
+  hit: AtomicU32,
+  /// there is no entry in the storage
+  missing: AtomicU32,
+  /// the entry is outdated, e.g. its snapshot is invalid
+  invalid: AtomicU32,
+}
+
+impl OccasionProfile {
+  pub fn new(enable: bool) -> Option<Self> {
+    enable.then(Default::default)
+  }
+
+  pub fn hit(&self) {
+    self.hit.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn missing(&self) {
+    self.missing.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn invalid(&self) {
+    self.invalid.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Log the counters of the current compilation and reset them, with the restore and store
+  /// information of the storage since the last log.
+  pub fn log<Item>(
+    &self,
+    label: &'static str,
+    storage: Option<&dyn Storage<Item>>,
+    logger: &impl Logger,
+  ) {
+    let hit = self.hit.swap(0, Ordering::Relaxed);
+    let missing = self.missing.swap(0, Ordering::Relaxed);
+    let invalid = self.invalid.swap(0, Ordering::Relaxed);
+    let total = hit + missing + invalid;
+    if total != 0 {
+      logger.raw(LogType::Cache { label, hit, total });
+      logger.log(format!(
+        "{label}: {hit} hits, {missing} misses without entry, {invalid} misses with outdated entry"
+      ));
+    }
+
+    let Some(profile) = storage.map(|storage| storage.take_profile()) else {
+      return;
+    };
+    if let Some(restore) = profile.restore {
+      logger.log(format!("{label}: restored {restore}"));
+    }
+    if let Some(store) = profile.store {
+      logger.log(format!("{label}: stored {store}"));
+    }
+  }
+}
+
+/// Timings and sizes of a persistent storage since it's last taken.
+#[derive(Debug, Clone, Default)]
+pub struct StorageProfile {
+  pub restore: Option<PackProfile>,
+  pub store: Option<PackProfile>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PackProfile {
+  pub entries: usize,
+  /// size of the pack file in bytes
+  pub size: usize,
+  pub time: Duration,
+}
+
+impl std::fmt::Display for PackProfile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} entries ({} bytes) in {} ms",
+      self.entries,
+      self.size,
+      self.time.as_millis()
+    )
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{CompilationLogger, CompilationLogging};
+
+  #[test]
+  fn log_occasion_profile() {
+    let logging = CompilationLogging::default();
+    let logger = CompilationLogger::new("rspack.Cache".into(), logging.clone());
+    let profile = OccasionProfile::default();
+    profile.hit();
+    profile.hit();
+    profile.missing();
+    profile.invalid();
+    profile.log::<()>("test cache", None, &logger);
+    // counters are reset after logging
+    profile.log::<()>("test cache", None, &logger);
+
+    let logs = logging.get("rspack.Cache").expect("should have logs");
+    assert_eq!(logs.len(), 2);
+    assert!(matches!(
+      logs[0],
+      LogType::Cache {
+        label: "test cache",
+        hit: 2,
+        total: 4
+      }
+    ));
+    assert!(matches!(
+      &logs[1],
+      LogType::Log { message } if message == "test cache: 2 hits, 1 misses without entry, 1 misses with outdated entry"
+    ));
+  }
+}
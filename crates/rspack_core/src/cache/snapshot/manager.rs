@@ -1,11 +1,13 @@
 use std::{
   hash::BuildHasherDefault,
   path::{Path, PathBuf},
-  time::SystemTime,
+  sync::Arc,
+  time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
+use rspack_fs::InputFileSystem;
 use rustc_hash::{FxHashMap as HashMap, FxHasher};
 
 use super::Snapshot;
@@ -19,20 +21,25 @@ use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
 pub struct SnapshotManager {
   /// global snapshot options
   options: SnapshotOptions,
+  input_filesystem: Arc<dyn InputFileSystem>,
   /// cache file update time
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache managed item info, None if the package.json has no version info
+  managed_item_info_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
 
 impl SnapshotManager {
-  pub fn new(options: SnapshotOptions) -> Self {
+  pub fn new(options: SnapshotOptions, input_filesystem: Arc<dyn InputFileSystem>) -> Self {
     Self {
       options,
+      input_filesystem,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      managed_item_info_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
@@ -41,33 +48,62 @@ impl SnapshotManager {
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
+    // TODO file_paths deduplication
     let strategy = f(&self.options);
+    let mut managed_items = HashMap::default();
+    let mut file_paths = Vec::with_capacity(paths.len());
+    for &path in paths {
+      if self.options.immutable_paths.iter().any(|p| p.test(path)) {
+        continue;
+      }
+      if let Some(item) = self
+        .options
+        .managed_paths
+        .iter()
+        .find_map(|p| p.get_managed_item(path))
+      {
+        if managed_items.contains_key(&item) {
+          continue;
+        }
+        // fallback to check the file itself when the package has no version
+        if let Some(info) = self.get_managed_item_info(&item).await {
+          managed_items.insert(item, info);
+          continue;
+        }
+      }
+      file_paths.push(path);
+    }
+
     let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
+    file_update_times.reserve(file_paths.len());
     let mut file_hashes = HashMap::default();
-    file_hashes.reserve(paths.len());
+    file_hashes.reserve(file_paths.len());
     if strategy.timestamp {
-      for &path in paths {
+      for &path in &file_paths {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
     }
     if strategy.hash {
       let hash_cache = &self.hash_cache;
-      for &path in paths {
+      for &path in &file_paths {
         let hash = match hash_cache.get(path) {
           Some(hash) => *hash,
           None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
+            let is_dir = self
+              .input_filesystem
+              .stat(path)
+              .map(|metadata| metadata.is_directory)
+              .unwrap_or_default();
+            let res = if is_dir {
+              let sub_files = self
+                .input_filesystem
+                .read_dir(path)?
+                .into_iter()
+                .map(|name| path.join(name).to_string_lossy().to_string())
+                .collect::<Vec<_>>();
               calc_hash(&sub_files)
             } else {
-              calc_hash(&tokio::fs::read(path).await?)
+              calc_hash(&self.input_filesystem.read(path)?)
             };
             hash_cache.insert(path.to_owned(), res);
             res
@@ -80,14 +116,43 @@ impl SnapshotManager {
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      managed_items,
     })
   }
 
+  /// Get the `name@version` of a package in managed paths,
+  /// `_resolved` is used instead of version if it exists.
+  async fn get_managed_item_info(&self, item: &Path) -> Option<String> {
+    if let Some(info) = self.managed_item_info_cache.get(item) {
+      return info.clone();
+    }
+    let info = self
+      .input_filesystem
+      .read(&item.join("package.json"))
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| {
+        let name = json
+          .get("name")
+          .and_then(|v| v.as_str())
+          .unwrap_or_default();
+        json
+          .get("_resolved")
+          .or_else(|| json.get("version"))
+          .and_then(|v| v.as_str())
+          .map(|version| format!("{name}@{version}"))
+      });
+    self
+      .managed_item_info_cache
+      .insert(item.to_path_buf(), info.clone());
+    info
+  }
+
   pub async fn check_snapshot_valid(&self, snapshot: &Snapshot) -> Result<bool> {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_items,
     } = snapshot;
     if !file_update_times.is_empty() {
       // check update time
@@ -100,7 +165,8 @@ impl SnapshotManager {
         let update_time = match update_time_cache.get(path) {
           Some(t) => *t,
           None => {
-            let t = tokio::fs::metadata(path).await?.modified()?;
+            let mtime_ms = self.input_filesystem.stat(path)?.mtime_ms;
+            let t = UNIX_EPOCH + Duration::from_millis(mtime_ms);
             update_time_cache.insert(path.clone(), t);
             t
           }
@@ -123,7 +189,7 @@ impl SnapshotManager {
         let current_hash = match hash_cache.get(path) {
           Some(h) => *h,
           None => {
-            let res = calc_hash(&tokio::fs::read(path).await?);
+            let res = calc_hash(&self.input_filesystem.read(path)?);
             hash_cache.insert(path.clone(), res);
             res
           }
@@ -134,12 +200,23 @@ impl SnapshotManager {
       }
     }
 
+    // check package version
+    for (item, snapshot_info) in managed_items {
+      if self.modified_files.contains(&item.join("package.json")) {
+        return Ok(false);
+      }
+      if self.get_managed_item_info(item).await.as_ref() != Some(snapshot_info) {
+        return Ok(false);
+      }
+    }
+
     Ok(true)
   }
 
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.managed_item_info_cache.clear();
     self.modified_files.clear();
   }
 
@@ -0,0 +1,84 @@
+use std::io::{Read, Write};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+use crate::CacheCompression;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+const BROTLI_QUALITY: u32 = 5;
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
+pub fn compress(compression: CacheCompression, data: Vec<u8>) -> Vec<u8> {
+  match compression {
+    CacheCompression::None => data,
+    CacheCompression::Gzip => {
+      let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+      encoder
+        .write_all(&data)
+        .and_then(|_| encoder.finish())
+        .expect("should compress to memory")
+    }
+    CacheCompression::Brotli => {
+      let mut res = Vec::new();
+      {
+        let mut encoder = brotli::CompressorWriter::new(
+          &mut res,
+          BROTLI_BUFFER_SIZE,
+          BROTLI_QUALITY,
+          BROTLI_WINDOW_SIZE,
+        );
+        encoder.write_all(&data).expect("should compress to memory");
+      }
+      res
+    }
+  }
+}
+
+/// Returns `None` when the data is not compressed in the expected format.
+pub fn decompress(compression: CacheCompression, data: Vec<u8>) -> Option<Vec<u8>> {
+  match compression {
+    CacheCompression::None => Some(data),
+    CacheCompression::Gzip => {
+      let mut res = Vec::new();
+      GzDecoder::new(data.as_slice()).read_to_end(&mut res).ok()?;
+      Some(res)
+    }
+    CacheCompression::Brotli => {
+      let mut res = Vec::new();
+      brotli::Decompressor::new(data.as_slice(), BROTLI_BUFFER_SIZE)
+        .read_to_end(&mut res)
+        .ok()?;
+      Some(res)
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn assert_round_trip(compression: CacheCompression) {
+    let data = b"rspack rspack rspack".to_vec();
+    let compressed = compress(compression, data.clone());
+    assert_eq!(decompress(compression, compressed.clone()), Some(data));
+
+    let mut broken = compressed;
+    let len = broken.len();
+    broken.truncate(len / 2);
+    assert_eq!(decompress(compression, broken), None);
+  }
+
+  #[test]
+  fn gzip_round_trip() {
+    assert_eq!(
+      &compress(CacheCompression::Gzip, b"rspack".to_vec())[..2],
+      &[0x1f, 0x8b]
+    );
+    assert_round_trip(CacheCompression::Gzip);
+  }
+
+  #[test]
+  fn brotli_round_trip() {
+    assert_round_trip(CacheCompression::Brotli);
+  }
+}
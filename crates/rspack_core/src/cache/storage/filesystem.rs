@@ -0,0 +1,433 @@
+use std::{
+  fs,
+  hash::{BuildHasherDefault, Hash},
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+  },
+  time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::{Identifier, IdentifierHasher, IdentifierSet};
+
+use super::{
+  compression::{compress, decompress},
+  pack::{Pack, PackEntry},
+  PackItem, Storage,
+};
+use crate::cache::profile::{PackProfile, StorageProfile};
+use crate::{CacheCompression, FileSystemCacheOptions};
+
+/// Digest of the pack entry keys and the build dependencies hash
+const DIGEST: HashDigest = HashDigest::Hex;
+
+#[derive(Debug, Clone)]
+pub struct FileSystemStorageOptions {
+  /// Directory to save pack files
+  pub directory: PathBuf,
+  /// Version of the pack, it contains the cache version, the hash function and digest of keys
+  /// and the hash of build dependencies.
+  /// A pack with different version will be dropped as a whole.
+  pub version: String,
+  /// Entries unused for longer than this many milliseconds are dropped when restoring, `None` means never
+  pub max_age: Option<u64>,
+  /// Hash function used to generate the keys of pack entries
+  pub hash_function: HashFunction,
+  pub compression: CacheCompression,
+}
+
+impl FileSystemStorageOptions {
+  pub fn new(context: &Path, options: &FileSystemCacheOptions) -> Self {
+    let directory = if options.cache_location.is_empty() {
+      let cache_directory = if options.cache_directory.is_empty() {
+        context.join("node_modules/.cache/rspack")
+      } else {
+        context.join(&options.cache_directory)
+      };
+      cache_directory.join(if options.name.is_empty() {
+        "default"
+      } else {
+        &options.name
+      })
+    } else {
+      context.join(&options.cache_location)
+    };
+
+    let mut build_dependencies = options
+      .build_dependencies
+      .iter()
+      .map(|item| context.join(item))
+      .collect::<Vec<_>>();
+    build_dependencies.sort();
+    build_dependencies.dedup();
+    let mut hasher = RspackHash::new(&options.hash_algorithm);
+    for path in &build_dependencies {
+      path.hash(&mut hasher);
+      fs::read(path).ok().hash(&mut hasher);
+    }
+
+    Self {
+      directory,
+      version: format!(
+        "{}|{:?}|{:?}|{}",
+        options.version,
+        options.hash_algorithm,
+        DIGEST,
+        hasher.digest(&DIGEST).encoded()
+      ),
+      max_age: (options.max_age > 0).then_some(options.max_age as u64),
+      hash_function: options.hash_algorithm,
+      compression: options.compression,
+    }
+  }
+}
+
+/// Storage that keeps items in memory and saves them to a pack file on [Storage::flush],
+/// the pack file is restored when the storage is created.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  path: PathBuf,
+  version: String,
+  hash_function: HashFunction,
+  compression: CacheCompression,
+  /// item and its last used time, the key is the hash of item identifier
+  data: DashMap<Identifier, (Item, u64), BuildHasherDefault<IdentifierHasher>>,
+  /// A read makes the pack dirty when the last used time moves by more than this, so entries
+  /// which are only read are saved before they reach `max_age`
+  refresh_age: Option<u64>,
+  has_changes: AtomicBool,
+  is_idle: AtomicBool,
+  profile: Mutex<StorageProfile>,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: PackItem,
+{
+  pub fn new(options: &FileSystemStorageOptions, name: &str) -> Self {
+    let path = options.directory.join(format!("{name}.pack"));
+    let data: DashMap<_, _, _> = DashMap::default();
+    let mut has_changes = false;
+    let mut profile = StorageProfile::default();
+
+    let start = Instant::now();
+    if let Ok(bytes) = fs::read(&path) {
+      let size = bytes.len();
+      match decompress(options.compression, bytes).and_then(|bytes| Pack::decode(&bytes)) {
+        Some(pack) if pack.version == options.version => {
+          let now = now();
+          for PackEntry {
+            id,
+            last_used,
+            data: item_data,
+          } in pack.entries
+          {
+            let expired = options
+              .max_age
+              .is_some_and(|max_age| now.saturating_sub(last_used) > max_age);
+            match Item::unpack(&item_data) {
+              Some(item) if !expired => {
+                data.insert(Identifier::from(id), (item, last_used));
+              }
+              _ => has_changes = true,
+            }
+          }
+          profile.restore = Some(PackProfile {
+            entries: data.len(),
+            size,
+            time: start.elapsed(),
+          });
+        }
+        // outdated or broken pack, it will be overwritten on next flush
+        _ => has_changes = true,
+      }
+    }
+
+    Self {
+      path,
+      version: options.version.clone(),
+      hash_function: options.hash_function,
+      compression: options.compression,
+      data,
+      refresh_age: options.max_age.map(|max_age| max_age / 2),
+      has_changes: AtomicBool::new(has_changes),
+      is_idle: AtomicBool::new(true),
+      profile: Mutex::new(profile),
+    }
+  }
+
+  fn write_pack(&self) -> Result<()> {
+    let start = Instant::now();
+    let pack = Pack {
+      version: self.version.clone(),
+      entries: self
+        .data
+        .iter()
+        .filter_map(|entry| {
+          let (item, last_used) = entry.value();
+          item.pack().map(|data| PackEntry {
+            id: entry.key().to_string(),
+            last_used: *last_used,
+            data,
+          })
+        })
+        .collect(),
+    };
+    if let Some(dir) = self.path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    // write to a temp file first to avoid leaving a broken pack when the process exits
+    let temp_path = self.path.with_extension("pack.temp");
+    let bytes = compress(self.compression, pack.encode());
+    fs::write(&temp_path, &bytes)?;
+    if !self.is_idle.load(Ordering::Acquire) {
+      // a new compilation has started and may be changing the entries
+      self.has_changes.store(true, Ordering::Release);
+      fs::remove_file(&temp_path)?;
+      return Ok(());
+    }
+    fs::rename(&temp_path, &self.path)?;
+    if let Ok(mut profile) = self.profile.lock() {
+      profile.store = Some(PackProfile {
+        entries: pack.entries.len(),
+        size: bytes.len(),
+        time: start.elapsed(),
+      });
+    }
+    Ok(())
+  }
+
+  fn key(&self, id: &Identifier) -> Identifier {
+    let mut hasher = RspackHash::new(&self.hash_function);
+    id.as_str().hash(&mut hasher);
+    Identifier::from(hasher.digest(&DIGEST).encoded())
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Clone + std::fmt::Debug + Send + Sync + PackItem,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    self.data.get_mut(&self.key(id)).map(|mut entry| {
+      let now = now();
+      if self
+        .refresh_age
+        .is_some_and(|refresh_age| now.saturating_sub(entry.1) > refresh_age)
+      {
+        self.has_changes.store(true, Ordering::Release);
+      }
+      entry.1 = now;
+      entry.0.clone()
+    })
+  }
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(self.key(&id), (data, now()));
+    self.has_changes.store(true, Ordering::Release);
+  }
+  fn remove(&self, id: &Identifier) {
+    if self.data.remove(&self.key(id)).is_some() {
+      self.has_changes.store(true, Ordering::Release);
+    }
+  }
+  fn clear(&self) {
+    self.data.clear();
+    self.has_changes.store(true, Ordering::Release);
+  }
+  fn retain(&self, ids: &IdentifierSet) {
+    let keys = ids.iter().map(|id| self.key(id)).collect::<IdentifierSet>();
+    let len = self.data.len();
+    self.data.retain(|key, _| keys.contains(key));
+    if self.data.len() != len {
+      self.has_changes.store(true, Ordering::Release);
+    }
+  }
+  fn begin_idle(&self) {
+    self.is_idle.store(true, Ordering::Release);
+  }
+  fn end_idle(&self) {
+    self.is_idle.store(false, Ordering::Release);
+  }
+  fn take_profile(&self) -> StorageProfile {
+    self
+      .profile
+      .lock()
+      .map(|mut profile| std::mem::take(&mut *profile))
+      .unwrap_or_default()
+  }
+  fn flush(&self) -> Result<()> {
+    if !self.is_idle.load(Ordering::Acquire) || !self.has_changes.swap(false, Ordering::AcqRel) {
+      return Ok(());
+    }
+    let res = self.write_pack();
+    if res.is_err() {
+      self.has_changes.store(true, Ordering::Release);
+    }
+    res
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl PackItem for Item {
+    fn pack(&self) -> Option<Vec<u8>> {
+      Some(self.0.as_bytes().to_vec())
+    }
+    fn unpack(bytes: &[u8]) -> Option<Self> {
+      String::from_utf8(bytes.to_vec()).ok().map(Self)
+    }
+  }
+
+  fn create_options(name: &str, version: &str) -> FileSystemStorageOptions {
+    FileSystemStorageOptions {
+      directory: std::env::temp_dir().join(format!("rspack_fs_storage_test_{name}")),
+      version: version.to_string(),
+      max_age: None,
+      hash_function: HashFunction::Xxhash64,
+      compression: CacheCompression::Gzip,
+    }
+  }
+
+  #[test]
+  fn should_restore_from_pack() {
+    let options = create_options("restore", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".into()));
+    storage.set("b".into(), Item("b".into()));
+    storage.remove(&"b".into());
+    storage.flush().expect("should flush");
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    assert_eq!(storage.get(&"b".into()), None);
+
+    let storage = FileSystemStorage::<Item>::new(&create_options("restore", "2"), "test");
+    assert_eq!(storage.get(&"a".into()), None);
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+
+  #[test]
+  fn should_include_hash_function_in_version() {
+    let context = Path::new("/");
+    let options = FileSystemCacheOptions {
+      hash_algorithm: HashFunction::MD4,
+      ..Default::default()
+    };
+    let md4 = FileSystemStorageOptions::new(context, &options);
+    let options = FileSystemCacheOptions {
+      hash_algorithm: HashFunction::Xxhash64,
+      ..options
+    };
+    let xxhash64 = FileSystemStorageOptions::new(context, &options);
+    assert_ne!(md4.version, xxhash64.version);
+  }
+
+  #[test]
+  fn should_drop_expired_entries() {
+    let mut options = create_options("expired", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage
+      .data
+      .insert(storage.key(&"a".into()), (Item("a".into()), 0));
+    storage.set("b".into(), Item("b".into()));
+    storage.flush().expect("should flush");
+
+    options.max_age = Some(60 * 1000);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), None);
+    assert_eq!(storage.get(&"b".into()), Some(Item("b".into())));
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+
+  #[test]
+  fn should_not_flush_after_read() {
+    let options = create_options("read", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".into()));
+    storage.flush().expect("should flush");
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    fs::remove_file(options.directory.join("test.pack")).expect("should remove pack");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    storage.flush().expect("should flush");
+    assert!(!options.directory.join("test.pack").exists());
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+
+  #[test]
+  fn should_keep_read_entries_alive() {
+    let mut options = create_options("read_alive", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.data.insert(
+      storage.key(&"a".into()),
+      (Item("a".into()), now() - 40 * 1000),
+    );
+    storage.set("b".into(), Item("b".into()));
+    storage.flush().expect("should flush");
+
+    // a session which only reads the entry
+    options.max_age = Some(60 * 1000);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    storage.flush().expect("should flush");
+
+    // the last used time before the read is expired with this max age
+    options.max_age = Some(30 * 1000);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+
+  #[test]
+  fn should_retain_entries() {
+    let options = create_options("retain", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".into()));
+    storage.set("b".into(), Item("b".into()));
+    storage.retain(&IdentifierSet::from_iter(["a".into()]));
+    storage.flush().expect("should flush");
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    assert_eq!(storage.get(&"b".into()), None);
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+
+  #[test]
+  fn should_not_flush_out_of_idle() {
+    let options = create_options("idle", "1");
+    let _ = fs::remove_dir_all(&options.directory);
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.end_idle();
+    storage.set("a".into(), Item("a".into()));
+    storage.flush().expect("should flush");
+    assert!(!options.directory.join("test.pack").exists());
+
+    storage.begin_idle();
+    storage.flush().expect("should flush");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".into())));
+    let _ = fs::remove_dir_all(&options.directory);
+  }
+}
@@ -1,7 +1,7 @@
 use std::hash::BuildHasherDefault;
 
 use dashmap::DashMap;
-use rspack_identifier::{Identifier, IdentifierHasher};
+use rspack_identifier::{Identifier, IdentifierHasher, IdentifierSet};
 
 use super::Storage;
 
@@ -31,4 +31,10 @@ where
   fn remove(&self, id: &Identifier) {
     self.data.remove(id);
   }
+  fn clear(&self) {
+    self.data.clear();
+  }
+  fn retain(&self, ids: &IdentifierSet) {
+    self.data.retain(|id, _| ids.contains(id));
+  }
 }
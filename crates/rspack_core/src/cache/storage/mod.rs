@@ -1,27 +1,78 @@
 use std::fmt::Debug;
 
-use rspack_identifier::Identifier;
+use rspack_error::Result;
+use rspack_identifier::{Identifier, IdentifierSet};
 
-use crate::CacheOptions;
+use super::profile::StorageProfile;
+use crate::CompilerOptions;
 
+mod compression;
+mod filesystem;
 mod memory;
-use memory::MemoryStorage;
+mod pack;
+pub use filesystem::{FileSystemStorage, FileSystemStorageOptions};
+pub use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
-  // fn end_idle(&self);
-  // fn clear(&self);
+  /// Write the pending changes to disk, only persistent storage need to implement this.
+  fn flush(&self) -> Result<()> {
+    Ok(())
+  }
+  /// The compiler is idle, pending changes can be written in background.
+  fn begin_idle(&self) {}
+  /// A new compilation starts, a write in progress should be given up.
+  fn end_idle(&self) {}
+  /// Drop all the entries.
+  fn clear(&self) {}
+  /// Drop the entries whose identifier is not in `ids`.
+  fn retain(&self, _ids: &IdentifierSet) {}
+  /// Take the restore and store information recorded since last call.
+  fn take_profile(&self) -> StorageProfile {
+    Default::default()
+  }
+}
+
+/// Conversion between a storage item and the bytes saved in a pack file.
+///
+/// The default implementation returns `None`, which means the item can only live in memory.
+pub trait PackItem: Sized {
+  fn pack(&self) -> Option<Vec<u8>> {
+    None
+  }
+  fn unpack(_bytes: &[u8]) -> Option<Self> {
+    None
+  }
+}
+
+#[derive(Debug)]
+pub enum StorageOptions {
+  Disabled,
+  Memory,
+  FileSystem(FileSystemStorageOptions),
+}
+
+impl StorageOptions {
+  pub fn new(options: &CompilerOptions) -> Self {
+    match &options.cache {
+      crate::CacheOptions::Disabled => Self::Disabled,
+      crate::CacheOptions::Memory(_) => Self::Memory,
+      crate::CacheOptions::FileSystem(fs_options) => Self::FileSystem(
+        FileSystemStorageOptions::new(options.context.as_ref(), fs_options),
+      ),
+    }
+  }
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+pub fn new_storage<Item>(options: &StorageOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: Debug + Clone + Send + Sync + PackItem + 'static,
 {
   match options {
-    CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    StorageOptions::Disabled => None,
+    StorageOptions::Memory => Some(Box::new(MemoryStorage::new())),
+    StorageOptions::FileSystem(options) => Some(Box::new(FileSystemStorage::new(options, name))),
   }
 }
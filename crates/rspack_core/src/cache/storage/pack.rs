@@ -0,0 +1,80 @@
+//! Layout of a pack file, all numbers are little endian:
+//!
+//! | version length (u32) | version | entry* |
+//!
+//! and each entry is:
+//!
+//! | id length (u32) | id | last used time (u64) | data length (u32) | data |
+
+#[derive(Debug)]
+pub struct PackEntry {
+  pub id: String,
+  /// Milliseconds since unix epoch
+  pub last_used: u64,
+  pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct Pack {
+  pub version: String,
+  pub entries: Vec<PackEntry>,
+}
+
+impl Pack {
+  pub fn encode(&self) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_bytes(&mut buf, self.version.as_bytes());
+    for entry in &self.entries {
+      write_bytes(&mut buf, entry.id.as_bytes());
+      buf.extend_from_slice(&entry.last_used.to_le_bytes());
+      write_bytes(&mut buf, &entry.data);
+    }
+    buf
+  }
+
+  /// Returns `None` when the bytes are not a valid pack.
+  pub fn decode(bytes: &[u8]) -> Option<Self> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let version = String::from_utf8(reader.read_bytes()?.to_vec()).ok()?;
+    let mut entries = vec![];
+    while !reader.is_end() {
+      let id = String::from_utf8(reader.read_bytes()?.to_vec()).ok()?;
+      let last_used = u64::from_le_bytes(reader.read(8)?.try_into().ok()?);
+      let data = reader.read_bytes()?.to_vec();
+      entries.push(PackEntry {
+        id,
+        last_used,
+        data,
+      });
+    }
+    Some(Self { version, entries })
+  }
+}
+
+fn write_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+  buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+  buf.extend_from_slice(bytes);
+}
+
+struct Reader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn is_end(&self) -> bool {
+    self.pos >= self.bytes.len()
+  }
+
+  fn read(&mut self, len: usize) -> Option<&'a [u8]> {
+    let end = self.pos.checked_add(len)?;
+    let res = self.bytes.get(self.pos..end)?;
+    self.pos = end;
+    Some(res)
+  }
+
+  fn read_bytes(&mut self) -> Option<&'a [u8]> {
+    let len = u32::from_le_bytes(self.read(4)?.try_into().ok()?);
+    self.read(len as usize)
+  }
+}
@@ -1,10 +1,11 @@
 use std::{fmt::Debug, hash::Hash, sync::Arc};
 
+use indexmap::IndexSet;
 use rspack_database::DatabaseItem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use crate::ChunkGraph;
+use crate::{ChunkGraph, ChunkGroup, ChunkGroupOrderKey};
 use crate::{ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, SourceType};
 use crate::{Compilation, EntryOptions, Filename, ModuleGraph, RuntimeSpec};
 
@@ -361,6 +362,133 @@ impl Chunk {
 
   // pub fn get_all_referenced_async_entry_points() -> HashSet<ChunkUkey> {}
 
+  /// The ids of the chunks in child groups which have a preload or prefetch order, only the last
+  /// chunk of a group is responsible for the children of the group.
+  pub fn get_child_ids_by_orders(
+    &self,
+    compilation: &Compilation,
+    filter_fn: &impl Fn(&ChunkUkey, &Compilation) -> bool,
+  ) -> HashMap<ChunkGroupOrderKey, Vec<String>> {
+    let mut lists: HashMap<ChunkGroupOrderKey, Vec<(i32, &ChunkGroup)>> = HashMap::default();
+    for group_ukey in &self.groups {
+      let group = compilation.chunk_group_by_ukey.expect_get(group_ukey);
+      if group.chunks.last() != Some(&self.ukey) {
+        continue;
+      }
+      for child_ukey in &group.children {
+        let child = compilation.chunk_group_by_ukey.expect_get(child_ukey);
+        for order_key in [ChunkGroupOrderKey::Preload, ChunkGroupOrderKey::Prefetch] {
+          if let Some(order) = child.order(order_key) {
+            lists.entry(order_key).or_default().push((order, child));
+          }
+        }
+      }
+    }
+
+    let mut result = HashMap::default();
+    for (order_key, mut list) in lists {
+      list.sort_by(|(a_order, a), (b_order, b)| {
+        b_order.cmp(a_order).then_with(|| a.index.cmp(&b.index))
+      });
+      let chunk_ids = list
+        .into_iter()
+        .flat_map(|(_, child)| child.chunks.iter())
+        .filter(|chunk_ukey| filter_fn(chunk_ukey, compilation))
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.expect_get(chunk_ukey).id.clone())
+        .collect::<IndexSet<_>>();
+      if !chunk_ids.is_empty() {
+        result.insert(order_key, chunk_ids.into_iter().collect());
+      }
+    }
+    result
+  }
+
+  /// [Chunk::get_child_ids_by_orders] of all async chunks, keyed by the id of the parent chunk.
+  pub fn get_child_ids_by_orders_map(
+    &self,
+    include_direct_children: bool,
+    compilation: &Compilation,
+    filter_fn: &impl Fn(&ChunkUkey, &Compilation) -> bool,
+  ) -> HashMap<ChunkGroupOrderKey, HashMap<String, Vec<String>>> {
+    let mut chunk_maps: HashMap<ChunkGroupOrderKey, HashMap<String, Vec<String>>> =
+      HashMap::default();
+    let mut add_child_ids_by_orders_to_map = |chunk_ukey: &ChunkUkey| {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      let Some(chunk_id) = &chunk.id else {
+        return;
+      };
+      for (order_key, child_ids) in chunk.get_child_ids_by_orders(compilation, filter_fn) {
+        chunk_maps
+          .entry(order_key)
+          .or_default()
+          .insert(chunk_id.clone(), child_ids);
+      }
+    };
+
+    if include_direct_children {
+      let chunks = self
+        .groups
+        .iter()
+        .flat_map(|group_ukey| {
+          compilation
+            .chunk_group_by_ukey
+            .expect_get(group_ukey)
+            .chunks
+            .iter()
+        })
+        .collect::<HashSet<_>>();
+      for chunk_ukey in chunks {
+        add_child_ids_by_orders_to_map(chunk_ukey);
+      }
+    }
+
+    for chunk_ukey in self.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      add_child_ids_by_orders_to_map(&chunk_ukey);
+    }
+
+    chunk_maps
+  }
+
+  /// The children of the groups of this chunk with an `order_key` order, each item is a pair of the
+  /// chunks of the parent group and the chunks of the children, sorted by order descending.
+  pub fn get_children_of_type_in_order(
+    &self,
+    order_key: ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Option<Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>> {
+    let mut list = vec![];
+    for group_ukey in &self.groups {
+      let group = compilation.chunk_group_by_ukey.expect_get(group_ukey);
+      for child_ukey in &group.children {
+        let child = compilation.chunk_group_by_ukey.expect_get(child_ukey);
+        if let Some(order) = child.order(order_key) {
+          list.push((order, group, child));
+        }
+      }
+    }
+    if list.is_empty() {
+      return None;
+    }
+    list.sort_by(|(a_order, a, _), (b_order, b, _)| {
+      b_order.cmp(a_order).then_with(|| a.index.cmp(&b.index))
+    });
+
+    let mut result: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)> = vec![];
+    for (_, group, child) in list {
+      match result.last_mut() {
+        Some((on_chunks, chunks)) if *on_chunks == group.chunks => {
+          for chunk_ukey in &child.chunks {
+            if !chunks.contains(chunk_ukey) {
+              chunks.push(*chunk_ukey);
+            }
+          }
+        }
+        _ => result.push((group.chunks.clone(), child.chunks.clone())),
+      }
+    }
+    Some(result)
+  }
+
   pub fn get_render_hash(&self, length: usize) -> Option<&str> {
     self.hash.as_ref().map(|hash| hash.rendered(length))
   }
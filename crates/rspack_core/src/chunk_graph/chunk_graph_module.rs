@@ -45,6 +45,45 @@ impl ChunkGraph {
       .or_default();
   }
 
+  /// Move `old_module` out of all its chunks and put `new_module` in its place, keeping the
+  /// order of entry modules.
+  pub fn replace_module(&mut self, old_module: ModuleIdentifier, new_module: ModuleIdentifier) {
+    let old_cgm = self.get_chunk_graph_module_mut(old_module);
+    let chunks = std::mem::take(&mut old_cgm.chunks);
+    let entry_in_chunks = std::mem::take(&mut old_cgm.entry_in_chunks);
+
+    for chunk in &chunks {
+      let cgc = self.get_chunk_graph_chunk_mut(*chunk);
+      cgc.modules.remove(&old_module);
+      cgc.modules.insert(new_module);
+    }
+    for chunk in &entry_in_chunks {
+      let cgc = self.get_chunk_graph_chunk_mut(*chunk);
+      cgc.entry_modules = std::mem::take(&mut cgc.entry_modules)
+        .into_iter()
+        .map(|(module, chunk_group)| {
+          if module == old_module {
+            (new_module, chunk_group)
+          } else {
+            (module, chunk_group)
+          }
+        })
+        .collect();
+    }
+
+    self.add_module(new_module);
+    let new_cgm = self.get_chunk_graph_module_mut(new_module);
+    new_cgm.chunks.extend(chunks);
+    new_cgm.entry_in_chunks.extend(entry_in_chunks);
+  }
+
+  pub fn is_entry_module(&self, module_identifier: &ModuleIdentifier) -> bool {
+    self
+      .chunk_graph_module_by_module_identifier
+      .get(module_identifier)
+      .is_some_and(|cgm| !cgm.entry_in_chunks.is_empty())
+  }
+
   pub fn is_module_in_chunk(
     &self,
     module_identifier: &ModuleIdentifier,
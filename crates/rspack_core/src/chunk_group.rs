@@ -1,11 +1,12 @@
 use itertools::Itertools;
 use rspack_database::DatabaseItem;
 use rspack_identifier::IdentifierMap;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
 
 use crate::{Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey};
 use crate::{ChunkLoading, ChunkUkey, Compilation, Filename};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
+use crate::{LibraryOptions, ModuleIdentifier, ModuleLayer, PublicPath, RuntimeSpec};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -264,6 +265,52 @@ impl ChunkGroup {
       ChunkGroupKind::Normal { options } => options.name.as_deref(),
     }
   }
+
+  pub fn add_options(&mut self, options: &GroupOptions) {
+    if let ChunkGroupKind::Normal {
+      options: self_options,
+    } = &mut self.kind
+      && let GroupOptions::ChunkGroup(options) = options
+    {
+      self_options.merge(options);
+    }
+  }
+
+  pub fn order(&self, order_key: ChunkGroupOrderKey) -> Option<i32> {
+    match &self.kind {
+      ChunkGroupKind::Entrypoint { .. } => None,
+      ChunkGroupKind::Normal { options } => options.order(order_key),
+    }
+  }
+
+  /// The children with a preload or prefetch order, sorted by order descending.
+  pub fn get_children_by_orders(
+    &self,
+    chunk_group_by_ukey: &ChunkGroupByUkey,
+  ) -> HashMap<ChunkGroupOrderKey, Vec<ChunkGroupUkey>> {
+    let mut children_by_orders = HashMap::default();
+    for order_key in [ChunkGroupOrderKey::Preload, ChunkGroupOrderKey::Prefetch] {
+      let mut list = self
+        .children
+        .iter()
+        .filter_map(|ukey| {
+          let child = chunk_group_by_ukey.expect_get(ukey);
+          child.order(order_key).map(|order| (order, child))
+        })
+        .collect::<Vec<_>>();
+      if list.is_empty() {
+        continue;
+      }
+      list.sort_by(|(a_order, a), (b_order, b)| {
+        b_order.cmp(a_order).then_with(|| a.index.cmp(&b.index))
+      });
+      children_by_orders.insert(
+        order_key,
+        list.into_iter().map(|(_, child)| child.ukey).collect(),
+      );
+    }
+    children_by_orders
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -313,11 +360,29 @@ pub struct EntryOptions {
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
   pub library: Option<LibraryOptions>,
+  pub layer: Option<ModuleLayer>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChunkGroupOrderKey {
+  Preload,
+  Prefetch,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+impl std::fmt::Display for ChunkGroupOrderKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      ChunkGroupOrderKey::Preload => "preload",
+      ChunkGroupOrderKey::Prefetch => "prefetch",
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
+  pub preload_order: Option<i32>,
+  pub prefetch_order: Option<i32>,
 }
 
 impl ChunkGroupOptions {
@@ -325,6 +390,35 @@ impl ChunkGroupOptions {
     self.name = name;
     self
   }
+
+  pub fn preload_order(mut self, preload_order: Option<i32>) -> Self {
+    self.preload_order = preload_order;
+    self
+  }
+
+  pub fn prefetch_order(mut self, prefetch_order: Option<i32>) -> Self {
+    self.prefetch_order = prefetch_order;
+    self
+  }
+
+  pub fn order(&self, order_key: ChunkGroupOrderKey) -> Option<i32> {
+    match order_key {
+      ChunkGroupOrderKey::Preload => self.preload_order,
+      ChunkGroupOrderKey::Prefetch => self.prefetch_order,
+    }
+  }
+
+  /// Merges the options of another block that refers to the same chunk group,
+  /// the orders keep the highest priority.
+  pub fn merge(&mut self, other: &ChunkGroupOptions) {
+    fn merge_order(order: &mut Option<i32>, other: Option<i32>) {
+      if let Some(other) = other {
+        *order = Some(order.map_or(other, |order| order.max(other)));
+      }
+    }
+    merge_order(&mut self.preload_order, other.preload_order);
+    merge_order(&mut self.prefetch_order, other.prefetch_order);
+  }
 }
 
 #[derive(Debug, Clone)]
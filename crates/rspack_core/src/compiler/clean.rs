@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use async_recursion::async_recursion;
+use rspack_error::Result;
+use rspack_fs::AsyncWritableFileSystem;
+
+use super::Compiler;
+use crate::{CleanOptions, CompilationLogger, Logger};
+
+impl<T> Compiler<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  /// Remove the files in the output directory which are not assets of the current compilation.
+  ///
+  /// The previous emitted assets are diffed against the current assets, the output directory is
+  /// only read when there is no previous emit, e.g. the first build.
+  pub(super) async fn clean_output_path(&mut self, options: &CleanOptions) -> Result<()> {
+    let logger = self.compilation.get_logger("rspack.CleanPlugin");
+    let mut current_files = HashSet::default();
+    let mut current_dirs = HashSet::default();
+    for filename in self.compilation.assets().keys() {
+      let filename = filename
+        .split_once('?')
+        .map(|(filename, _query)| filename)
+        .unwrap_or(filename);
+      let mut dir = filename;
+      while let Some((parent, _)) = dir.rsplit_once('/') {
+        current_dirs.insert(parent.to_string());
+        dir = parent;
+      }
+      current_files.insert(filename.to_string());
+    }
+
+    let mut unused = match self.last_emitted_assets.take() {
+      Some(last_emitted_assets) => last_emitted_assets
+        .into_iter()
+        .filter(|path| !current_files.contains(path) && !current_dirs.contains(path))
+        .collect(),
+      None => {
+        self
+          .diff_to_output_path(&current_files, &current_dirs)
+          .await
+      }
+    };
+    unused.sort();
+    for path in unused {
+      self.remove_unused(path, options, &logger).await?;
+    }
+
+    self.last_emitted_assets = Some(current_files.into_iter().chain(current_dirs).collect());
+    Ok(())
+  }
+
+  /// Paths in the output directory that are neither assets nor directories of assets.
+  async fn diff_to_output_path(
+    &self,
+    current_files: &HashSet<String>,
+    current_dirs: &HashSet<String>,
+  ) -> Vec<String> {
+    let mut unused = vec![];
+    let mut dirs = vec![String::new()];
+    while let Some(dir) = dirs.pop() {
+      // the output directory may not exist yet
+      let Ok(names) = self
+        .output_filesystem
+        .read_dir(self.options.output.path.join(&dir))
+        .await
+      else {
+        continue;
+      };
+      for name in names {
+        let path = if dir.is_empty() {
+          name
+        } else {
+          format!("{dir}/{name}")
+        };
+        if current_dirs.contains(&path) {
+          dirs.push(path);
+        } else if !current_files.contains(&path) {
+          unused.push(path);
+        }
+      }
+    }
+    unused
+  }
+
+  /// Returns whether the path is removed, or would be removed in dry mode.
+  /// A directory is removed only when none of its content is kept.
+  #[async_recursion]
+  async fn remove_unused(
+    &self,
+    path: String,
+    options: &CleanOptions,
+    logger: &CompilationLogger,
+  ) -> Result<bool> {
+    if let Some(keep) = &options.keep
+      && keep.try_match(&path).await?
+    {
+      logger.log(format!("{path} will be kept"));
+      return Ok(false);
+    }
+    let full_path = self.options.output.path.join(&path);
+    // the file may be removed by others
+    let Ok(metadata) = self.output_filesystem.stat(&full_path).await else {
+      return Ok(true);
+    };
+    if metadata.is_directory {
+      let mut removed = true;
+      for name in self.output_filesystem.read_dir(&full_path).await? {
+        removed &= self
+          .remove_unused(format!("{path}/{name}"), options, logger)
+          .await?;
+      }
+      if removed && !options.dry {
+        self.output_filesystem.remove_dir(&full_path).await?;
+      }
+      return Ok(removed);
+    }
+    if options.dry {
+      logger.info(format!("{path} will be removed"));
+    } else {
+      self.output_filesystem.remove_file(&full_path).await?;
+    }
+    Ok(true)
+  }
+}
@@ -15,6 +15,7 @@ use rayon::prelude::{
   IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator,
 };
 use rspack_error::{internal_error, Diagnostic, Result, Severity, TWithDiagnosticArray};
+use rspack_fs::InputFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, IdentifierMap, IdentifierSet};
@@ -38,13 +39,13 @@ use crate::{
   BuildTask, BuildTaskResult, CacheCount, CacheOptions, Chunk, ChunkByUkey, ChunkContentHash,
   ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey, CleanQueue,
   CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, DependencyId,
-  DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint, ErrorSpan,
-  FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module, ModuleFactory,
-  ModuleGraph, ModuleIdentifier, ModuleProfile, PathData, ProcessAssetsArgs,
-  ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs,
-  Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver,
-  SourceType, Stats, TaskResult, WorkerTask,
+  CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, DependenciesBlock,
+  DependencyId, DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint,
+  ErrorSpan, FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module,
+  ModuleFactory, ModuleGraph, ModuleIdentifier, ModuleLayer, ModuleProfile, PathData,
+  ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask,
+  RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec,
+  SharedPluginDriver, SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -96,6 +97,7 @@ pub struct Compilation {
   pub code_generation_results: CodeGenerationResults,
   pub code_generated_modules: IdentifierSet,
   pub cache: Arc<Cache>,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub code_splitting_cache: CodeSplittingCache,
   pub hash: Option<RspackHashDigest>,
   // lazy compilation visit module
@@ -121,6 +123,7 @@ impl Compilation {
     loader_resolver_factory: Arc<ResolverFactory>,
     records: Option<CompilationRecords>,
     cache: Arc<Cache>,
+    input_filesystem: Arc<dyn InputFileSystem>,
   ) -> Self {
     Self {
       hot_index: 0,
@@ -158,6 +161,7 @@ impl Compilation {
       code_generated_modules: Default::default(),
 
       cache,
+      input_filesystem,
       code_splitting_cache: Default::default(),
       hash: None,
       lazy_visit_modules: Default::default(),
@@ -189,7 +193,31 @@ impl Compilation {
       .unwrap_or_default()
   }
 
+  /// Layer of the entry which the dependency is added by `add_entry` or `add_include`
+  fn get_entry_layer(&self, dependency_id: &DependencyId) -> Option<ModuleLayer> {
+    self
+      .entries
+      .values()
+      .find(|entry| {
+        entry.dependencies.contains(dependency_id)
+          || entry.include_dependencies.contains(dependency_id)
+      })
+      .and_then(|entry| entry.options.layer.clone())
+  }
+
+  fn validate_entry_options(&mut self, options: &EntryOptions) {
+    if options.layer.is_some() && !self.options.experiments.layers {
+      self.push_batch_diagnostic(
+        internal_error!(
+          "'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
+        )
+        .into(),
+      );
+    }
+  }
+
   pub fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) {
+    self.validate_entry_options(&options);
     let entry_id = *entry.id();
     self.module_graph.add_dependency(entry);
     if let Some(name) = options.name.clone() {
@@ -209,6 +237,7 @@ impl Compilation {
   }
 
   pub async fn add_include(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
+    self.validate_entry_options(&options);
     let entry_id = *entry.id();
     self.module_graph.add_dependency(entry);
     if let Some(name) = options.name.clone() {
@@ -298,6 +327,9 @@ impl Compilation {
       if let Some(source_map) = asset.info.related.source_map {
         self.delete_asset(&source_map);
       }
+      if let Some(license) = asset.info.related.license {
+        self.delete_asset(&license);
+      }
       self.chunk_by_ukey.iter_mut().for_each(|(_, chunk)| {
         chunk.files.remove(filename);
         chunk.auxiliary_files.remove(filename);
@@ -499,6 +531,11 @@ impl Compilation {
           return;
         }
 
+        let issuer_layer = if let Some(parent_module) = parent_module {
+          parent_module.get_layer().cloned()
+        } else {
+          self.get_entry_layer(&id)
+        };
         self.handle_module_creation(
           &mut factorize_queue,
           parent_module_identifier,
@@ -510,6 +547,7 @@ impl Compilation {
           parent_module
             .and_then(|m| m.as_normal_module())
             .and_then(|module| module.name_for_condition()),
+          issuer_layer,
         );
       });
 
@@ -635,6 +673,7 @@ impl Compilation {
             module
               .as_normal_module()
               .and_then(|module| module.name_for_condition()),
+            module.get_layer().cloned(),
           );
         }
 
@@ -726,6 +765,7 @@ impl Compilation {
                   compiler_options: self.options.clone(),
                   plugin_driver: self.plugin_driver.clone(),
                   cache: self.cache.clone(),
+                  input_filesystem: self.input_filesystem.clone(),
                   current_profile,
                 });
               }
@@ -1008,6 +1048,7 @@ impl Compilation {
     resolve_options: Option<Box<Resolve>>,
     lazy_visit_modules: std::collections::HashSet<String>,
     issuer: Option<Box<str>>,
+    issuer_layer: Option<ModuleLayer>,
   ) {
     let current_profile = self.options.profile.then(Box::<ModuleProfile>::default);
     let dependency = dependencies[0].get_dependency(&self.module_graph).clone();
@@ -1015,6 +1056,7 @@ impl Compilation {
       module_factory: self.get_dependency_factory(dependency.dependency_type()),
       original_module_identifier,
       issuer,
+      issuer_layer,
       original_module_context,
       dependency,
       dependencies,
@@ -1259,9 +1301,43 @@ impl Compilation {
     plugin_driver.finish_modules(self).await?;
     logger.time_end(start);
 
+    let start = logger.time("report dependency errors and warnings");
+    self.report_dependency_errors_and_warnings();
+    logger.time_end(start);
+
     Ok(())
   }
 
+  // https://github.com/webpack/webpack/blob/main/lib/Compilation.js `reportDependencyErrorsAndWarnings`
+  fn report_dependency_errors_and_warnings(&mut self) {
+    let mut diagnostics = vec![];
+    for module_identifier in self.module_graph.modules().keys().sorted() {
+      let module = self
+        .module_graph
+        .module_by_identifier(module_identifier)
+        .expect("should have module");
+      let mut dependencies = module.get_dependencies().to_vec();
+      let mut blocks = module.get_blocks().to_vec();
+      while let Some(block) = blocks.pop() {
+        let Some(block) = self.module_graph.block_by_id(&block) else {
+          continue;
+        };
+        dependencies.extend(block.get_dependencies());
+        blocks.extend(block.get_blocks());
+      }
+      for dependency_id in dependencies {
+        if let Some(dependency_diagnostics) = self
+          .module_graph
+          .dependency_by_id(&dependency_id)
+          .and_then(|dependency| dependency.get_diagnostics(&self.module_graph))
+        {
+          diagnostics.extend(dependency_diagnostics);
+        }
+      }
+    }
+    self.push_batch_diagnostic(diagnostics);
+  }
+
   #[instrument(name = "compilation:seal", skip_all)]
   pub async fn seal(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let logger = self.get_logger("rspack.Compilation");
@@ -1892,4 +1968,5 @@ impl AssetInfo {
 #[derive(Debug, Default, Clone)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
+  pub license: Option<String>,
 }
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Instant};
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
@@ -21,6 +21,7 @@ where
     removed_files: std::collections::HashSet<String>,
   ) -> Result<()> {
     assert!(!changed_files.is_empty() || !removed_files.is_empty());
+    let start = Instant::now();
     let old = self.compilation.get_stats();
     let old_hash = self.compilation.hash.clone();
 
@@ -61,9 +62,9 @@ where
       modified_files.extend(removed_files.iter().map(PathBuf::from));
 
       self.cache.end_idle();
-      self
-        .cache
-        .set_modified_files(modified_files.iter().cloned().collect::<Vec<_>>());
+      let modified_file_list = modified_files.iter().cloned().collect::<Vec<_>>();
+      self.input_filesystem.purge(&modified_file_list);
+      self.cache.set_modified_files(modified_file_list);
       self.plugin_driver.resolver_factory.clear_cache();
 
       let mut new_compilation = Compilation::new(
@@ -74,6 +75,7 @@ where
         self.loader_resolver_factory.clone(),
         Some(records),
         self.cache.clone(),
+        self.input_filesystem.clone(),
       );
 
       if let Some(state) = self.options.get_incremental_rebuild_make_state() {
@@ -129,6 +131,7 @@ where
       };
       self.compile(setup_make_params).await?;
       self.cache.begin_idle();
+      self.store_cache(start.elapsed(), true);
     }
 
     self.compile_done().await?;
@@ -1,18 +1,22 @@
+mod clean;
 mod compilation;
 mod hmr;
 mod make;
 mod queue;
 
-use std::collections::hash_map::Entry;
+use std::collections::{hash_map::Entry, HashSet};
 use std::ops::Deref;
+use std::time::{Duration, Instant};
 use std::{path::Path, sync::Arc};
 
 pub use compilation::*;
 pub use hmr::{collect_changed_modules, CompilationRecords};
 pub use make::MakeParam;
 pub use queue::*;
-use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_error::{Diagnostic, Result};
+use rspack_fs::{
+  AsyncWritableFileSystem, CachedInputFileSystem, InputFileSystem, NativeFileSystem,
+};
 use rspack_futures::FuturesResults;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::FxHashMap as HashMap;
@@ -36,6 +40,7 @@ where
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -44,14 +49,34 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// files and directories of the previous emit, used by `output.clean`
+  last_emitted_assets: Option<HashSet<String>>,
 }
 
 impl<T> Compiler<T>
 where
   T: AsyncWritableFileSystem + Send + Sync,
 {
-  #[instrument(skip_all)]
   pub fn new(options: CompilerOptions, plugins: Vec<BoxPlugin>, output_filesystem: T) -> Self {
+    Self::with_input_filesystem(
+      options,
+      plugins,
+      output_filesystem,
+      Arc::new(CachedInputFileSystem::new(Arc::new(NativeFileSystem))),
+    )
+  }
+
+  /// Create a compiler which reads modules, resolves requests and checks snapshots through `input_filesystem`.
+  ///
+  /// Requests are only resolved through `input_filesystem` with `experiments.rspack_future.new_resolver`,
+  /// the legacy resolver always reads from the disk.
+  #[instrument(skip_all)]
+  pub fn with_input_filesystem(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
     #[cfg(debug_assertions)]
     {
       if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
@@ -59,13 +84,18 @@ where
       }
     }
     let new_resolver = options.experiments.rspack_future.new_resolver;
-    let resolver_factory = Arc::new(ResolverFactory::new(new_resolver, options.resolve.clone()));
+    let resolver_factory = Arc::new(ResolverFactory::new(
+      new_resolver,
+      options.resolve.clone(),
+      input_filesystem.clone(),
+    ));
     let loader_resolver_factory = Arc::new(ResolverFactory::new(
       new_resolver,
       options.resolve_loader.clone(),
+      input_filesystem.clone(),
     ));
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
-    let cache = Arc::new(Cache::new(options.clone()));
+    let cache = Arc::new(Cache::new(options.clone(), input_filesystem.clone()));
     if options.is_new_tree_shaking() {
       IS_NEW_TREESHAKING.store(true, std::sync::atomic::Ordering::SeqCst);
     }
@@ -80,27 +110,37 @@ where
         loader_resolver_factory.clone(),
         None,
         cache.clone(),
+        input_filesystem.clone(),
       ),
       output_filesystem,
+      input_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
       cache,
       emitted_asset_versions: Default::default(),
+      last_emitted_assets: None,
     }
   }
 
+  /// Build once and store the persistent cache before returning, used when not watching.
   pub async fn run(&mut self) -> Result<()> {
-    self.build().await?;
-    Ok(())
+    self.build_and_store(false).await
   }
 
-  #[instrument(name = "build", skip_all)]
+  /// Build and store the persistent cache when the compiler becomes idle, used by watch mode.
   pub async fn build(&mut self) -> Result<()> {
+    self.build_and_store(true).await
+  }
+
+  #[instrument(name = "build", skip_all)]
+  async fn build_and_store(&mut self, store_when_idle: bool) -> Result<()> {
+    let start = Instant::now();
     self.cache.end_idle();
     // TODO: clear the outdated cache entries in resolver,
     // TODO: maybe it's better to use external entries.
     self.plugin_driver.resolver_factory.clear_cache();
+    self.input_filesystem.purge_all();
 
     fast_set(
       &mut self.compilation,
@@ -112,6 +152,7 @@ where
         self.loader_resolver_factory.clone(),
         None,
         self.cache.clone(),
+        self.input_filesystem.clone(),
       ),
     );
 
@@ -119,6 +160,7 @@ where
       .compile(MakeParam::ForceBuildDeps(Default::default()))
       .await?;
     self.cache.begin_idle();
+    self.store_cache(start.elapsed(), store_when_idle);
     self.compile_done().await?;
     Ok(())
   }
@@ -279,6 +321,30 @@ where
     Ok(())
   }
 
+  /// Save the cache to disk, either right now or when the compiler is idle in watch mode,
+  /// a failure only produces a warning.
+  fn store_cache(&mut self, build_time: Duration, store_when_idle: bool) {
+    let logger = self.compilation.get_logger("rspack.Cache");
+    let error = if store_when_idle {
+      // the store in background is logged with the next compilation
+      self.cache.log_profile(&logger);
+      self.cache.store_when_idle(build_time);
+      self.cache.take_idle_store_error()
+    } else {
+      let error = self.cache.flush().err();
+      self.cache.log_profile(&logger);
+      error
+    };
+    if let Some(err) = error {
+      self.compilation.push_diagnostic(Diagnostic::warn(
+        "Cache".to_string(),
+        format!("Failed to store the persistent cache: {err}"),
+        0,
+        0,
+      ));
+    }
+  }
+
   #[instrument(name = "compile_done", skip_all)]
   async fn compile_done(&mut self) -> Result<()> {
     let logger = self.compilation.get_logger("rspack.Compiler");
@@ -305,28 +371,9 @@ where
 
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
-    if self.options.output.clean {
-      if self.emitted_asset_versions.is_empty() {
-        self
-          .output_filesystem
-          .remove_dir_all(&self.options.output.path)
-          .await?;
-      } else {
-        // clean unused file
-        let assets = self.compilation.assets();
-        let _ = self
-          .emitted_asset_versions
-          .iter()
-          .filter_map(|(filename, _version)| {
-            if !assets.contains_key(filename) {
-              let file_path = Path::new(&self.options.output.path).join(filename);
-              Some(self.output_filesystem.remove_file(file_path))
-            } else {
-              None
-            }
-          })
-          .collect::<FuturesResults<_>>();
-      }
+    let options = self.options.clone();
+    if let Some(clean) = &options.output.clean {
+      self.clean_output_path(clean).await?;
     }
 
     self.plugin_driver.emit(&mut self.compilation).await?;
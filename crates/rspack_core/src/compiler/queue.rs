@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
 use rspack_error::{Diagnostic, Result};
+use rspack_fs::InputFileSystem;
 
 use crate::{
   cache::Cache, BoxDependency, BuildContext, BuildResult, Compilation, CompilerContext,
   CompilerOptions, Context, Module, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve, ResolverFactory,
-  SharedPluginDriver, WorkerQueue,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleLayer, ModuleProfile, Resolve,
+  ResolverFactory, SharedPluginDriver, WorkerQueue,
 };
 use crate::{DependencyId, ExportInfo, ExportsInfo, UsageState};
 
@@ -28,6 +29,7 @@ pub struct FactorizeTask {
   pub original_module_identifier: Option<ModuleIdentifier>,
   pub original_module_context: Option<Box<Context>>,
   pub issuer: Option<Box<str>>,
+  pub issuer_layer: Option<ModuleLayer>,
   pub dependency: BoxDependency,
   pub dependencies: Vec<DependencyId>,
   pub is_entry: bool,
@@ -86,6 +88,7 @@ impl WorkerTask for FactorizeTask {
         dependency,
         issuer: self.issuer,
         issuer_identifier: self.original_module_identifier,
+        issuer_layer: self.issuer_layer,
       })
       .await?
       .split_into_parts();
@@ -219,6 +222,7 @@ pub struct BuildTask {
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
   pub cache: Arc<Cache>,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub current_profile: Option<Box<ModuleProfile>>,
 }
 
@@ -243,6 +247,7 @@ impl WorkerTask for BuildTask {
     let resolver_factory = self.resolver_factory;
     let cache = self.cache;
     let plugin_driver = self.plugin_driver;
+    let input_filesystem = self.input_filesystem;
 
     let (build_result, is_cache_valid) = match cache
       .build_module_occasion
@@ -262,6 +267,7 @@ impl WorkerTask for BuildTask {
             },
             plugin_driver: plugin_driver.clone(),
             compiler_options: &compiler_options,
+            input_filesystem: input_filesystem.clone(),
           })
           .await;
 
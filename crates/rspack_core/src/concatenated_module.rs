@@ -0,0 +1,1512 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use indexmap::IndexSet;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::{internal_error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::{Identifiable, IdentifierMap};
+use rspack_sources::{BoxSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::{sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS};
+use swc_core::ecma::ast::{AssignPatProp, EsVersion, Ident, ObjectPatProp, Prop};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::parser::{parse_file_as_module, EsConfig, Syntax};
+use swc_core::ecma::transforms::base::resolver;
+use swc_core::ecma::visit::{Visit, VisitMutWith, VisitWith};
+
+use crate::{
+  contextify, dependencies_block::AsyncDependenciesBlockIdentifier, property_access, property_name,
+  to_identifier, to_normal_comment, ChunkInitFragments, CodeGenerationResult, Compilation,
+  ConcatenationScope, Context, DependenciesBlock, DependencyId, ExportInfoId, ExportInfoProvided,
+  ExportsType, LibIdentOptions, Module, ModuleGraph, ModuleIdentifier, ModuleLayer,
+  ModuleReference, ModuleType, NormalModule, RuntimeGlobals, RuntimeSpec, SourceType, UsageState,
+  UsedName,
+};
+
+static CONCATENATED_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+/// Names which can't be used for the top-level bindings of a concatenated module.
+///
+/// Align with https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/ConcatenatedModule.js#L87-L152
+static RESERVED_NAMES: Lazy<HashSet<JsWord>> = Lazy::new(|| {
+  [
+    // internal names (should always be renamed)
+    crate::DEFAULT_EXPORT,
+    crate::NAMESPACE_OBJECT_EXPORT,
+    // keywords
+    "abstract",
+    "arguments",
+    "async",
+    "await",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "function",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "int",
+    "interface",
+    "let",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "volatile",
+    "while",
+    "with",
+    "yield",
+    // commonjs/amd
+    "module",
+    "__dirname",
+    "__filename",
+    "exports",
+    "require",
+    "define",
+    // js globals
+    "Array",
+    "Date",
+    "eval",
+    "function",
+    "hasOwnProperty",
+    "Infinity",
+    "isFinite",
+    "isNaN",
+    "isPrototypeOf",
+    "length",
+    "Math",
+    "NaN",
+    "name",
+    "Number",
+    "Object",
+    "prototype",
+    "String",
+    "toString",
+    "undefined",
+    "valueOf",
+    // browser globals
+    "alert",
+    "all",
+    "anchor",
+    "anchors",
+    "area",
+    "assign",
+    "blur",
+    "button",
+    "checkbox",
+    "clearInterval",
+    "clearTimeout",
+    "clientInformation",
+    "close",
+    "closed",
+    "confirm",
+    "constructor",
+    "crypto",
+    "decodeURI",
+    "decodeURIComponent",
+    "defaultStatus",
+    "document",
+    "element",
+    "elements",
+    "embed",
+    "embeds",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "event",
+    "fileUpload",
+    "focus",
+    "form",
+    "forms",
+    "frame",
+    "innerHeight",
+    "innerWidth",
+    "layer",
+    "layers",
+    "link",
+    "location",
+    "mimeTypes",
+    "navigate",
+    "navigator",
+    "frames",
+    "frameRate",
+    "hidden",
+    "history",
+    "image",
+    "images",
+    "offscreenBuffering",
+    "open",
+    "opener",
+    "option",
+    "outerHeight",
+    "outerWidth",
+    "packages",
+    "pageXOffset",
+    "pageYOffset",
+    "parent",
+    "parseFloat",
+    "parseInt",
+    "password",
+    "pkcs11",
+    "plugin",
+    "prompt",
+    "propertyIsEnum",
+    "radio",
+    "reset",
+    "screenX",
+    "screenY",
+    "scroll",
+    "secure",
+    "select",
+    "self",
+    "setInterval",
+    "setTimeout",
+    "status",
+    "submit",
+    "taint",
+    "text",
+    "textarea",
+    "top",
+    "unescape",
+    "untaint",
+    "window",
+    // window events
+    "onblur",
+    "onclick",
+    "onerror",
+    "onfocus",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onmouseover",
+    "onload",
+    "onmouseup",
+    "onmousedown",
+    "onsubmit",
+  ]
+  .into_iter()
+  .map(JsWord::from)
+  .collect()
+});
+
+static UNCOOL_EXTRA_INFO_REGEX: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"\.+/|(/index)?\.([a-zA-Z0-9]{1,4})($|\s|\?)|\s*\+\s*\d+\s*modules")
+    .expect("should init regex")
+});
+
+/// A module merging a subgraph of ESM modules into a single scope, created by the
+/// `ModuleConcatenationPlugin`.
+///
+/// Align with https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/ConcatenatedModule.js
+#[derive(Debug)]
+pub struct ConcatenatedModule {
+  id: ModuleIdentifier,
+  root_module: ModuleIdentifier,
+  /// All modules in the concatenation, including the root module, sorted by identifier.
+  modules: Vec<ModuleIdentifier>,
+  module_type: ModuleType,
+  root_user_request: String,
+  root_name_for_condition: Option<Box<str>>,
+  root_layer: Option<ModuleLayer>,
+  size: f64,
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+}
+
+impl ConcatenatedModule {
+  pub fn new(
+    root_module: &NormalModule,
+    mut modules: Vec<ModuleIdentifier>,
+    module_graph: &ModuleGraph,
+  ) -> Self {
+    modules.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+    modules.dedup();
+
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    modules
+      .iter()
+      .map(|m| m.as_str())
+      .collect::<Vec<_>>()
+      .join(" ")
+      .hash(&mut hasher);
+    let id = ModuleIdentifier::from(format!(
+      "{}|{}",
+      root_module.identifier(),
+      hasher.digest(&HashDigest::Hex).encoded()
+    ));
+
+    let size = modules
+      .iter()
+      .filter_map(|m| module_graph.module_by_identifier(m))
+      .map(|m| m.size(&SourceType::JavaScript))
+      .sum();
+
+    Self {
+      id,
+      root_module: root_module.identifier(),
+      modules,
+      module_type: *root_module.module_type(),
+      root_user_request: root_module.user_request().to_string(),
+      root_name_for_condition: root_module.name_for_condition(),
+      root_layer: root_module.get_layer().cloned(),
+      size,
+      blocks: Default::default(),
+      dependencies: Default::default(),
+    }
+  }
+
+  pub fn root_module(&self) -> ModuleIdentifier {
+    self.root_module
+  }
+
+  pub fn modules(&self) -> &[ModuleIdentifier] {
+    &self.modules
+  }
+
+  /// Orders the concatenated modules by evaluation and collects the modules they import
+  /// from outside of the concatenation, which are required like usual.
+  fn create_concatenation_list(
+    &self,
+    module_graph: &ModuleGraph,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ConcatenationEntry> {
+    fn enter_module(
+      module: ModuleIdentifier,
+      modules: &[ModuleIdentifier],
+      module_graph: &ModuleGraph,
+      runtime: Option<&RuntimeSpec>,
+      visited: &mut HashSet<ModuleIdentifier>,
+      list: &mut Vec<ConcatenationEntry>,
+    ) {
+      if !visited.insert(module) {
+        return;
+      }
+      if modules.contains(&module) {
+        for imported in get_concatenated_imports(&module, module_graph, runtime) {
+          enter_module(imported, modules, module_graph, runtime, visited, list);
+        }
+        list.push(ConcatenationEntry::Concatenated(module));
+      } else {
+        list.push(ConcatenationEntry::External(module));
+      }
+    }
+
+    let mut list = vec![];
+    let mut visited = HashSet::default();
+    visited.insert(self.root_module);
+    for imported in get_concatenated_imports(&self.root_module, module_graph, runtime) {
+      enter_module(
+        imported,
+        &self.modules,
+        module_graph,
+        runtime,
+        &mut visited,
+        &mut list,
+      );
+    }
+    list.push(ConcatenationEntry::Concatenated(self.root_module));
+    list
+  }
+}
+
+/// The modules imported by harmony import dependencies of `module`, ordered by source order.
+fn get_concatenated_imports(
+  module: &ModuleIdentifier,
+  module_graph: &ModuleGraph,
+  runtime: Option<&RuntimeSpec>,
+) -> Vec<ModuleIdentifier> {
+  let Some(module) = module_graph.module_by_identifier(module) else {
+    return vec![];
+  };
+  let mut references = module
+    .get_dependencies()
+    .iter()
+    .filter_map(|dependency_id| {
+      let source_order = module_graph
+        .dependency_by_id(dependency_id)?
+        .as_module_dependency()?
+        .source_order()?;
+      let connection = module_graph.connection_by_dependency(dependency_id)?;
+      (connection.original_module_identifier == Some(module.identifier())
+        && connection.is_target_active(module_graph, runtime))
+      .then_some((source_order, connection.module_identifier))
+    })
+    .collect::<Vec<_>>();
+  references.sort_by_key(|(source_order, _)| *source_order);
+  let mut imports = IndexSet::<ModuleIdentifier>::default();
+  imports.extend(references.into_iter().map(|(_, module)| module));
+  imports.into_iter().collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ConcatenationEntry {
+  Concatenated(ModuleIdentifier),
+  External(ModuleIdentifier),
+}
+
+impl ConcatenationEntry {
+  fn module(&self) -> ModuleIdentifier {
+    match self {
+      ConcatenationEntry::Concatenated(module) | ConcatenationEntry::External(module) => *module,
+    }
+  }
+}
+
+#[derive(Debug)]
+struct ConcatenatedInfo {
+  scope: ConcatenationScope,
+  analysis: ScopeAnalysis,
+}
+
+#[derive(Debug)]
+struct ModuleInfo {
+  module: ModuleIdentifier,
+  readable_identifier: String,
+  /// `None` for modules outside of the concatenation
+  concatenated: Option<ConcatenatedInfo>,
+}
+
+/// The final names of the top-level bindings a module contributes to the concatenated scope.
+#[derive(Debug, Default)]
+struct ModuleNames {
+  internal_names: HashMap<JsWord, JsWord>,
+  /// The namespace object of a concatenated module, or the `__webpack_require__` result of an
+  /// external module
+  name: JsWord,
+  interop_namespace_object_name: Option<JsWord>,
+  interop_namespace_object2_name: Option<JsWord>,
+  interop_default_access_name: Option<JsWord>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct InteropUsage {
+  namespace_object: bool,
+  namespace_object2: bool,
+  default_access: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum InteropKind {
+  NamespaceObject,
+  NamespaceObject2,
+  DefaultAccess,
+}
+
+#[derive(Debug, Clone)]
+enum Binding {
+  /// A top-level binding of a concatenated module
+  Symbol {
+    index: usize,
+    name: JsWord,
+    ids: Vec<JsWord>,
+  },
+  NamespaceObject {
+    index: usize,
+    ids: Vec<JsWord>,
+  },
+  External {
+    index: usize,
+    ids: Vec<JsWord>,
+    comment: Option<String>,
+  },
+  Interop {
+    index: usize,
+    kind: InteropKind,
+    ids: Vec<JsWord>,
+  },
+  Raw {
+    expr: &'static str,
+    ids: Vec<JsWord>,
+  },
+}
+
+impl Binding {
+  /// The key of the binding a module reference is resolved to, used to find names that would
+  /// be shadowed at the place of the reference.
+  fn scope_key(&self) -> Option<(usize, JsWord)> {
+    match self {
+      Binding::Symbol { index, name, .. } => Some((*index, name.clone())),
+      Binding::NamespaceObject { index, .. }
+      | Binding::External { index, .. }
+      | Binding::Interop { index, .. } => Some((*index, JsWord::from(""))),
+      Binding::Raw { .. } => None,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct BindingUsage {
+  needed_namespace_objects: IndexSet<usize>,
+  interop: Vec<InteropUsage>,
+}
+
+struct BindingResolver<'a> {
+  module_graph: &'a ModuleGraph,
+  runtime: Option<&'a RuntimeSpec>,
+  modules_map: &'a IdentifierMap<usize>,
+  infos: &'a [ModuleInfo],
+}
+
+impl<'a> BindingResolver<'a> {
+  fn get_final_binding(
+    &self,
+    usage: &mut BindingUsage,
+    index: usize,
+    mut export_name: Vec<JsWord>,
+    strict_harmony_module: bool,
+    visited: &mut HashSet<ExportInfoId>,
+  ) -> Result<Binding> {
+    let module_graph = self.module_graph;
+    let info = &self.infos[index];
+    let mgm = module_graph
+      .module_graph_module_by_identifier(&info.module)
+      .ok_or_else(|| internal_error!("Failed to get module graph module of {}", info.module))?;
+    let exports_type = mgm.get_exports_type(strict_harmony_module);
+
+    if export_name.is_empty() {
+      match exports_type {
+        ExportsType::DefaultOnly => {
+          usage.interop[index].namespace_object2 = true;
+          return Ok(Binding::Interop {
+            index,
+            kind: InteropKind::NamespaceObject2,
+            ids: export_name,
+          });
+        }
+        ExportsType::DefaultWithNamed => {
+          usage.interop[index].namespace_object = true;
+          return Ok(Binding::Interop {
+            index,
+            kind: InteropKind::NamespaceObject,
+            ids: export_name,
+          });
+        }
+        ExportsType::Namespace | ExportsType::Dynamic => {}
+      }
+    } else {
+      match exports_type {
+        ExportsType::Namespace => {}
+        ExportsType::DefaultWithNamed => match export_name[0].as_ref() {
+          "default" => {
+            export_name.remove(0);
+          }
+          "__esModule" => {
+            return Ok(Binding::Raw {
+              expr: "/* __esModule */true",
+              ids: export_name.split_off(1),
+            });
+          }
+          _ => {}
+        },
+        ExportsType::DefaultOnly => {
+          let export_id = export_name.remove(0);
+          if export_id == "__esModule" {
+            return Ok(Binding::Raw {
+              expr: "/* __esModule */true",
+              ids: export_name,
+            });
+          }
+          if export_id != "default" {
+            return Ok(Binding::Raw {
+              expr: "/* non-default import from default-exporting module */undefined",
+              ids: export_name,
+            });
+          }
+        }
+        ExportsType::Dynamic => match export_name[0].as_ref() {
+          "default" => {
+            export_name.remove(0);
+            usage.interop[index].default_access = true;
+            return Ok(Binding::Interop {
+              index,
+              kind: InteropKind::DefaultAccess,
+              ids: export_name,
+            });
+          }
+          "__esModule" => {
+            return Ok(Binding::Raw {
+              expr: "/* __esModule */true",
+              ids: export_name.split_off(1),
+            });
+          }
+          _ => {}
+        },
+      }
+    }
+
+    if export_name.is_empty() {
+      return Ok(match info.concatenated {
+        Some(_) => {
+          usage.needed_namespace_objects.insert(index);
+          Binding::NamespaceObject {
+            index,
+            ids: export_name,
+          }
+        }
+        None => Binding::External {
+          index,
+          ids: export_name,
+          comment: None,
+        },
+      });
+    }
+
+    let exports_info_id = mgm.exports;
+    let export_info = exports_info_id.get_read_only_export_info(&export_name[0], module_graph);
+    if !visited.insert(export_info.id) {
+      return Ok(Binding::Raw {
+        expr: "/* circular reexport */ Object(function x() { x() }())",
+        ids: vec![],
+      });
+    }
+
+    let Some(concatenated) = &info.concatenated else {
+      return Ok(
+        match exports_info_id.get_used_name(
+          module_graph,
+          self.runtime,
+          UsedName::Vec(export_name.clone()),
+        ) {
+          Some(UsedName::Vec(used)) => {
+            let comment =
+              (used != export_name).then(|| to_normal_comment(&export_name.iter().join(".")));
+            Binding::External {
+              index,
+              ids: used,
+              comment,
+            }
+          }
+          _ => Binding::Raw {
+            expr: "/* unused export */ undefined",
+            ids: export_name.split_off(1),
+          },
+        },
+      );
+    };
+
+    if matches!(export_info.provided, Some(ExportInfoProvided::False)) {
+      // It's not provided, but it could be on the prototype
+      usage.needed_namespace_objects.insert(index);
+      return Ok(Binding::NamespaceObject {
+        index,
+        ids: export_name,
+      });
+    }
+
+    if let Some(symbol) = concatenated.scope.exports.get(&export_name[0]) {
+      return Ok(
+        match exports_info_id.get_used_name(
+          module_graph,
+          self.runtime,
+          UsedName::Vec(export_name.clone()),
+        ) {
+          Some(UsedName::Vec(mut used)) => Binding::Symbol {
+            index,
+            name: symbol.clone(),
+            ids: used.split_off(1),
+          },
+          _ => Binding::Raw {
+            expr: "/* unused export */ undefined",
+            ids: export_name.split_off(1),
+          },
+        },
+      );
+    }
+
+    let strict_harmony_module = mgm.get_strict_harmony_module();
+    if let Some(reexport) = concatenated.scope.reexports.get(&export_name[0]) {
+      let Some(target) = self.modules_map.get(&reexport.module) else {
+        return Err(internal_error!(
+          "Target module of reexport from '{}' is not part of the concatenation (export '{}')",
+          info.readable_identifier,
+          export_name[0]
+        ));
+      };
+      let mut ids = reexport.ids.clone();
+      ids.extend(export_name.into_iter().skip(1));
+      return self.get_final_binding(usage, *target, ids, strict_harmony_module, visited);
+    }
+
+    if export_name[0] != "default" {
+      for star in &concatenated.scope.star_reexports {
+        let Some(target) = self.modules_map.get(star) else {
+          continue;
+        };
+        let provided = module_graph
+          .get_exports_info(star)
+          .id
+          .get_read_only_export_info(&export_name[0], module_graph)
+          .provided;
+        if matches!(provided, Some(ExportInfoProvided::True)) {
+          return self.get_final_binding(
+            usage,
+            *target,
+            export_name,
+            strict_harmony_module,
+            visited,
+          );
+        }
+      }
+    }
+
+    Err(internal_error!(
+      "Cannot get final name for export '{}' of {}",
+      export_name.iter().join("."),
+      info.readable_identifier
+    ))
+  }
+}
+
+fn render_binding(
+  binding: &Binding,
+  names: &[ModuleNames],
+  call: bool,
+  direct_import: bool,
+) -> String {
+  let (reference, is_property_access) = match binding {
+    Binding::Symbol { index, name, ids } => {
+      let name = names[*index].internal_names.get(name).unwrap_or(name);
+      (
+        format!("{name}{}", property_access(ids, 0)),
+        !ids.is_empty(),
+      )
+    }
+    Binding::NamespaceObject { index, ids } => (
+      format!("{}{}", names[*index].name, property_access(ids, 0)),
+      !ids.is_empty(),
+    ),
+    Binding::External {
+      index,
+      ids,
+      comment,
+    } => (
+      format!(
+        "{}{}{}",
+        names[*index].name,
+        comment.as_deref().unwrap_or_default(),
+        property_access(ids, 0)
+      ),
+      !ids.is_empty(),
+    ),
+    Binding::Interop { index, kind, ids } => {
+      let names = &names[*index];
+      let base = match kind {
+        InteropKind::NamespaceObject => names
+          .interop_namespace_object_name
+          .as_deref()
+          .unwrap_or_default()
+          .to_string(),
+        InteropKind::NamespaceObject2 => names
+          .interop_namespace_object2_name
+          .as_deref()
+          .unwrap_or_default()
+          .to_string(),
+        InteropKind::DefaultAccess => {
+          let name = names
+            .interop_default_access_name
+            .as_deref()
+            .unwrap_or_default();
+          if call {
+            format!("{name}()")
+          } else {
+            format!("({name}())")
+          }
+        }
+      };
+      (
+        format!("{base}{}", property_access(ids, 0)),
+        !ids.is_empty(),
+      )
+    }
+    Binding::Raw { expr, ids } => (
+      format!("{expr}{}", property_access(ids, 0)),
+      !ids.is_empty(),
+    ),
+  };
+  if is_property_access && call && direct_import {
+    format!("(0, {reference})")
+  } else {
+    reference
+  }
+}
+
+/// Finds a name for a top-level binding of `extra_info`, which is the readable identifier of
+/// the module the binding belongs to, that is used by neither `used_names1` nor `used_names2`.
+fn find_new_name(
+  old_name: &str,
+  used_names1: &HashSet<JsWord>,
+  used_names2: &HashSet<JsWord>,
+  extra_info: &str,
+) -> JsWord {
+  let mut name = match old_name {
+    crate::DEFAULT_EXPORT => String::new(),
+    crate::NAMESPACE_OBJECT_EXPORT => "namespaceObject".to_string(),
+    _ => old_name.to_string(),
+  };
+  let is_used =
+    |name: &JsWord| -> bool { used_names1.contains(name) || used_names2.contains(name) };
+
+  // Remove uncool stuff
+  let extra_info = UNCOOL_EXTRA_INFO_REGEX.replace_all(extra_info, "");
+  for segment in extra_info.split('/').rev() {
+    name = if name.is_empty() {
+      segment.to_string()
+    } else {
+      format!("{segment}_{name}")
+    };
+    let name_ident = JsWord::from(to_identifier(&name));
+    if !is_used(&name_ident) {
+      return name_ident;
+    }
+  }
+
+  let mut i = 0;
+  let mut name_with_number = JsWord::from(to_identifier(&format!("{name}_{i}")));
+  while is_used(&name_with_number) {
+    i += 1;
+    name_with_number = JsWord::from(to_identifier(&format!("{name}_{i}")));
+  }
+  name_with_number
+}
+
+#[derive(Debug, Clone)]
+struct IdentifierRange {
+  start: u32,
+  end: u32,
+  name: JsWord,
+  shorthand: bool,
+}
+
+/// The bindings and references of a module that has been generated with a
+/// [ConcatenationScope].
+#[derive(Debug, Default)]
+struct ScopeAnalysis {
+  top_level_names: IndexSet<JsWord>,
+  top_level_identifiers: Vec<IdentifierRange>,
+  module_references: Vec<(IdentifierRange, ModuleReference)>,
+  unresolved_names: HashSet<JsWord>,
+  nested_names: HashSet<JsWord>,
+}
+
+impl ScopeAnalysis {
+  fn analyze(source: String, readable_identifier: &str) -> Result<Self> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon, source);
+    let mut errors = vec![];
+    let mut program = parse_file_as_module(
+      &fm,
+      Syntax::Es(EsConfig::default()),
+      EsVersion::EsNext,
+      None,
+      &mut errors,
+    )
+    .map_err(|e| {
+      internal_error!(
+        "Failed to parse {readable_identifier} for module concatenation: {}",
+        e.kind().msg()
+      )
+    })?;
+
+    GLOBALS.set(&Globals::default(), || {
+      let unresolved_mark = Mark::new();
+      let top_level_mark = Mark::new();
+      program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+      let mut analysis = ScopeAnalysis::default();
+      program.visit_with(&mut ScopeAnalyzer {
+        analysis: &mut analysis,
+        top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+        unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+        offset: fm.start_pos.0,
+      });
+      Ok(analysis)
+    })
+  }
+}
+
+struct ScopeAnalyzer<'a> {
+  analysis: &'a mut ScopeAnalysis,
+  top_level_ctxt: SyntaxContext,
+  unresolved_ctxt: SyntaxContext,
+  offset: u32,
+}
+
+impl<'a> ScopeAnalyzer<'a> {
+  fn add_ident(&mut self, ident: &Ident, shorthand: bool) {
+    let ctxt = ident.span.ctxt;
+    // property names, labels, etc.
+    if ctxt == SyntaxContext::empty() {
+      return;
+    }
+    let range = IdentifierRange {
+      start: ident.span.lo.0 - self.offset,
+      end: ident.span.hi.0 - self.offset,
+      name: ident.sym.clone(),
+      shorthand,
+    };
+    if ctxt == self.top_level_ctxt {
+      self.analysis.top_level_names.insert(ident.sym.clone());
+      self.analysis.top_level_identifiers.push(range);
+    } else if ctxt == self.unresolved_ctxt {
+      if let Some(reference) = ConcatenationScope::match_module_reference(&ident.sym) {
+        self.analysis.module_references.push((range, reference));
+      } else {
+        self.analysis.unresolved_names.insert(ident.sym.clone());
+      }
+    } else {
+      self.analysis.nested_names.insert(ident.sym.clone());
+    }
+  }
+}
+
+impl<'a> Visit for ScopeAnalyzer<'a> {
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.add_ident(ident, false);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.add_ident(ident, true);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+    if let ObjectPatProp::Assign(AssignPatProp { key, value, .. }) = prop {
+      self.add_ident(key, true);
+      value.visit_with(self);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+}
+
+fn replace_identifier(source: &mut ReplaceSource<BoxSource>, range: &IdentifierRange, name: &str) {
+  if range.shorthand {
+    source.insert(range.end, &format!(": {name}"), None);
+  } else {
+    source.replace(range.start, range.end, name, None);
+  }
+}
+
+impl Identifiable for ConcatenatedModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.id
+  }
+}
+
+impl DependenciesBlock for ConcatenatedModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConcatenatedModule {
+  fn module_type(&self) -> &ModuleType {
+    &self.module_type
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONCATENATED_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "{} + {} modules",
+      context.shorten(&self.root_user_request),
+      self.modules.len() - 1
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    self.size
+  }
+
+  fn name_for_condition(&self) -> Option<Box<str>> {
+    self.root_name_for_condition.clone()
+  }
+
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.root_layer.as_ref()
+  }
+
+  fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(contextify(
+      options.context,
+      &self.root_user_request,
+    )))
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<CodeGenerationResult> {
+    let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+
+    let list = self.create_concatenation_list(module_graph, runtime);
+    let modules_map = Arc::new(
+      list
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.module(), index))
+        .collect::<IdentifierMap<usize>>(),
+    );
+
+    let mut runtime_requirements = RuntimeGlobals::default();
+    let mut chunk_init_fragments = ChunkInitFragments::default();
+    let mut infos = Vec::with_capacity(list.len());
+    let mut sources = Vec::with_capacity(list.len());
+    for entry in &list {
+      let module_identifier = entry.module();
+      let module = module_graph
+        .module_by_identifier(&module_identifier)
+        .ok_or_else(|| internal_error!("Failed to get module {module_identifier}"))?;
+      let readable_identifier = module.readable_identifier(context).to_string();
+      match entry {
+        ConcatenationEntry::Concatenated(_) => {
+          let normal_module = module.as_normal_module().ok_or_else(|| {
+            internal_error!("Concatenated module {readable_identifier} should be a normal module")
+          })?;
+          let mut scope = ConcatenationScope::new(modules_map.clone(), module_identifier);
+          let mut result = normal_module.code_generation_with_concatenation_scope(
+            compilation,
+            runtime,
+            Some(&mut scope),
+          )?;
+          runtime_requirements.insert(result.runtime_requirements);
+          chunk_init_fragments.append(&mut result.chunk_init_fragments);
+          let source = result
+            .inner
+            .remove(&SourceType::JavaScript)
+            .ok_or_else(|| internal_error!("Failed to generate {readable_identifier}"))?;
+          let analysis = ScopeAnalysis::analyze(source.source().to_string(), &readable_identifier)?;
+          sources.push(Some(ReplaceSource::new(source)));
+          infos.push(ModuleInfo {
+            module: module_identifier,
+            readable_identifier,
+            concatenated: Some(ConcatenatedInfo { scope, analysis }),
+          });
+        }
+        ConcatenationEntry::External(_) => {
+          sources.push(None);
+          infos.push(ModuleInfo {
+            module: module_identifier,
+            readable_identifier,
+            concatenated: None,
+          });
+        }
+      }
+    }
+
+    let resolver = BindingResolver {
+      module_graph,
+      runtime,
+      modules_map: &modules_map,
+      infos: &infos,
+    };
+    let mut usage = BindingUsage {
+      needed_namespace_objects: Default::default(),
+      interop: vec![InteropUsage::default(); infos.len()],
+    };
+
+    let get_strict_harmony_module = |module: &ModuleIdentifier| {
+      module_graph
+        .module_graph_module_by_identifier(module)
+        .map(|mgm| mgm.get_strict_harmony_module())
+        .unwrap_or_default()
+    };
+
+    // resolve references between modules, and collect the names a binding must not be
+    // renamed to because they would be shadowed at the place of a reference
+    let mut all_used_names = RESERVED_NAMES.clone();
+    let mut used_names_in_scope: HashMap<(usize, JsWord), HashSet<JsWord>> = HashMap::default();
+    let mut reference_bindings = Vec::with_capacity(infos.len());
+    for info in &infos {
+      let mut bindings = vec![];
+      if let Some(concatenated) = &info.concatenated {
+        all_used_names.extend(concatenated.analysis.unresolved_names.iter().cloned());
+        let strict_harmony_module = get_strict_harmony_module(&info.module);
+        for (_, reference) in &concatenated.analysis.module_references {
+          let binding = resolver.get_final_binding(
+            &mut usage,
+            reference.index,
+            reference.ids.clone(),
+            strict_harmony_module,
+            &mut HashSet::default(),
+          )?;
+          if let Some(key) = binding.scope_key() {
+            used_names_in_scope
+              .entry(key)
+              .or_default()
+              .extend(concatenated.analysis.nested_names.iter().cloned());
+          }
+          bindings.push(binding);
+        }
+      }
+      reference_bindings.push(bindings);
+    }
+
+    // generate names for symbols
+    let empty_names = HashSet::default();
+    let mut names = Vec::with_capacity(infos.len());
+    for (index, (info, source)) in infos.iter().zip(sources.iter_mut()).enumerate() {
+      let mut module_names = ModuleNames::default();
+      let namespace_object_used_names = used_names_in_scope
+        .get(&(index, JsWord::from("")))
+        .unwrap_or(&empty_names);
+      if let Some(concatenated) = &info.concatenated {
+        let source = source
+          .as_mut()
+          .ok_or_else(|| internal_error!("Failed to get source of {}", info.readable_identifier))?;
+        for name in &concatenated.analysis.top_level_names {
+          let used_names = used_names_in_scope
+            .get(&(index, name.clone()))
+            .unwrap_or(&empty_names);
+          if all_used_names.contains(name) || used_names.contains(name) {
+            let mut used_names = used_names.clone();
+            used_names.extend(concatenated.analysis.nested_names.iter().cloned());
+            let new_name = find_new_name(
+              name,
+              &all_used_names,
+              &used_names,
+              &info.readable_identifier,
+            );
+            all_used_names.insert(new_name.clone());
+            module_names.internal_names.insert(name.clone(), new_name);
+          } else {
+            all_used_names.insert(name.clone());
+            module_names
+              .internal_names
+              .insert(name.clone(), name.clone());
+          }
+        }
+        for range in &concatenated.analysis.top_level_identifiers {
+          if let Some(new_name) = module_names.internal_names.get(&range.name)
+            && new_name != &range.name
+          {
+            replace_identifier(source, range, new_name);
+          }
+        }
+        module_names.name = find_new_name(
+          "namespaceObject",
+          &all_used_names,
+          namespace_object_used_names,
+          &info.readable_identifier,
+        );
+      } else {
+        module_names.name = find_new_name(
+          "",
+          &all_used_names,
+          namespace_object_used_names,
+          &info.readable_identifier,
+        );
+      }
+      all_used_names.insert(module_names.name.clone());
+
+      let build_meta = module_graph
+        .module_graph_module_by_identifier(&info.module)
+        .and_then(|mgm| mgm.build_meta.as_ref());
+      let exports_type = build_meta.map(|m| &m.exports_type);
+      if !matches!(exports_type, Some(crate::BuildMetaExportsType::Namespace)) {
+        let name = find_new_name(
+          "namespaceObject",
+          &all_used_names,
+          namespace_object_used_names,
+          &info.readable_identifier,
+        );
+        all_used_names.insert(name.clone());
+        module_names.interop_namespace_object_name = Some(name);
+      }
+      if matches!(exports_type, Some(crate::BuildMetaExportsType::Default))
+        && !matches!(
+          build_meta.map(|m| &m.default_object),
+          Some(crate::BuildMetaDefaultObject::Redirect)
+        )
+      {
+        let name = find_new_name(
+          "namespaceObject2",
+          &all_used_names,
+          namespace_object_used_names,
+          &info.readable_identifier,
+        );
+        all_used_names.insert(name.clone());
+        module_names.interop_namespace_object2_name = Some(name);
+      }
+      if matches!(
+        exports_type,
+        None | Some(crate::BuildMetaExportsType::Dynamic | crate::BuildMetaExportsType::Unset)
+      ) {
+        let name = find_new_name(
+          "default",
+          &all_used_names,
+          namespace_object_used_names,
+          &info.readable_identifier,
+        );
+        all_used_names.insert(name.clone());
+        module_names.interop_default_access_name = Some(name);
+      }
+      names.push(module_names);
+    }
+
+    // define exports of the root module
+    let root_index = list.len() - 1;
+    let root_info = infos
+      .get(root_index)
+      .and_then(|info| info.concatenated.as_ref())
+      .ok_or_else(|| internal_error!("Failed to get root module of {}", self.id))?;
+    let root_strict_harmony_module = get_strict_harmony_module(&self.root_module);
+    let exports_info = module_graph.get_exports_info(&self.root_module);
+    let mut export_infos = exports_info
+      .exports
+      .values()
+      .map(|id| id.get_export_info(module_graph))
+      .filter(|export_info| !matches!(export_info.provided, Some(ExportInfoProvided::False)))
+      .filter_map(|export_info| export_info.name.clone().map(|name| (name, export_info)))
+      .collect::<Vec<_>>();
+    export_infos.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut export_definitions = vec![];
+    let mut unused_exports = vec![];
+    for (name, export_info) in export_infos {
+      let Some(used_name) = export_info.get_used_name(&name, runtime) else {
+        unused_exports.push(name);
+        continue;
+      };
+      let binding = resolver.get_final_binding(
+        &mut usage,
+        root_index,
+        vec![name.clone()],
+        root_strict_harmony_module,
+        &mut HashSet::default(),
+      )?;
+      export_definitions.push((
+        used_name,
+        format!(
+          "/* {} */ {}",
+          if root_info.scope.exports.contains_key(&name) {
+            "binding"
+          } else {
+            "reexport"
+          },
+          render_binding(&binding, &names, false, false)
+        ),
+      ));
+    }
+    export_definitions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // generate namespace objects, which may require more namespace objects
+    let mut namespace_object_sources: HashMap<usize, String> = HashMap::default();
+    let mut i = 0;
+    while let Some(index) = usage.needed_namespace_objects.get_index(i).copied() {
+      i += 1;
+      let info = &infos[index];
+      let strict_harmony_module = get_strict_harmony_module(&info.module);
+      let mut export_infos = module_graph
+        .get_exports_info(&info.module)
+        .exports
+        .values()
+        .map(|id| id.get_export_info(module_graph))
+        .filter(|export_info| !matches!(export_info.provided, Some(ExportInfoProvided::False)))
+        .filter_map(|export_info| export_info.name.clone().map(|name| (name, export_info)))
+        .collect::<Vec<_>>();
+      export_infos.sort_by(|a, b| a.0.cmp(&b.0));
+      let mut definitions = vec![];
+      for (name, export_info) in export_infos {
+        let Some(used_name) = export_info.get_used_name(&name, runtime) else {
+          continue;
+        };
+        let binding = resolver.get_final_binding(
+          &mut usage,
+          index,
+          vec![name],
+          strict_harmony_module,
+          &mut HashSet::default(),
+        )?;
+        definitions.push(format!(
+          "{}: function() {{ return {}; }}",
+          property_name(&used_name)?,
+          render_binding(&binding, &names, false, false)
+        ));
+      }
+      let name = &names[index].name;
+      let define_getters = if definitions.is_empty() {
+        String::new()
+      } else {
+        runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+        format!(
+          "{}({name}, {{\n  {}\n}});\n",
+          RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+          definitions.join(",\n  ")
+        )
+      };
+      runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+      namespace_object_sources.insert(
+        index,
+        format!(
+          "\n// NAMESPACE OBJECT: {}\nvar {name} = {{}};\n{}({name});\n{define_getters}",
+          info.readable_identifier,
+          RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+        ),
+      );
+    }
+
+    // replace references to other modules with their final names
+    for ((info, source), bindings) in infos
+      .iter()
+      .zip(sources.iter_mut())
+      .zip(reference_bindings.iter())
+    {
+      let (Some(concatenated), Some(source)) = (&info.concatenated, source.as_mut()) else {
+        continue;
+      };
+      for ((range, reference), binding) in
+        concatenated.analysis.module_references.iter().zip(bindings)
+      {
+        let final_name = render_binding(binding, &names, reference.call, reference.direct_import);
+        replace_identifier(source, range, &final_name);
+      }
+    }
+
+    let exports_argument = module_graph
+      .module_graph_module_by_identifier(&self.id)
+      .map(|mgm| mgm.get_exports_argument())
+      .unwrap_or_default();
+    let mut result = ConcatSource::default();
+
+    // add harmony compatibility flag (must be first because of possible circular dependencies)
+    if exports_info
+      .other_exports_info
+      .get_used(module_graph, runtime)
+      != UsageState::Unused
+    {
+      runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+      runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+      result.add(RawSource::from(format!(
+        "// ESM COMPAT FLAG\n{}({exports_argument});\n",
+        RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+      )));
+    }
+
+    if !export_definitions.is_empty() {
+      runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+      runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+      let definitions = export_definitions
+        .iter()
+        .map(|(used_name, value)| {
+          Ok(format!(
+            "{}: function() {{ return {value}; }}",
+            property_name(used_name)?
+          ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+      result.add(RawSource::from(format!(
+        "\n// EXPORTS\n{}({exports_argument}, {{\n  {}\n}});\n",
+        RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+        definitions.join(",\n  ")
+      )));
+    }
+
+    if !unused_exports.is_empty() {
+      result.add(RawSource::from(format!(
+        "\n// UNUSED EXPORTS: {}\n",
+        unused_exports.iter().join(", ")
+      )));
+    }
+
+    // define required namespace objects (must be before evaluation modules)
+    for index in 0..infos.len() {
+      if let Some(source) = namespace_object_sources.remove(&index) {
+        result.add(RawSource::from(source));
+      }
+    }
+
+    // evaluate modules in order
+    for ((info, source), names) in infos.iter().zip(sources).zip(names.iter()) {
+      if let Some(source) = source {
+        result.add(RawSource::from(format!(
+          "\n;// CONCATENATED MODULE: {}\n",
+          info.readable_identifier
+        )));
+        result.add(source.boxed());
+      } else {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        let module_id = compilation
+          .chunk_graph
+          .get_module_id(info.module)
+          .as_deref()
+          .ok_or_else(|| {
+            internal_error!("Failed to get module id of {}", info.readable_identifier)
+          })?;
+        result.add(RawSource::from(format!(
+          "\n// EXTERNAL MODULE: {}\nvar {} = {}({});",
+          info.readable_identifier,
+          names.name,
+          RuntimeGlobals::REQUIRE,
+          serde_json::to_string(module_id).map_err(|e| internal_error!(e.to_string()))?
+        )));
+      }
+
+      let index = modules_map
+        .get(&info.module)
+        .copied()
+        .ok_or_else(|| internal_error!("Failed to get index of {}", info.readable_identifier))?;
+      let interop = usage.interop[index];
+      if interop.namespace_object
+        && let Some(interop_name) = &names.interop_namespace_object_name
+      {
+        runtime_requirements.insert(RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT);
+        result.add(RawSource::from(format!(
+          "\nvar {interop_name} = /*#__PURE__*/{}({}, 2);",
+          RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT,
+          names.name
+        )));
+      }
+      if interop.namespace_object2
+        && let Some(interop_name) = &names.interop_namespace_object2_name
+      {
+        runtime_requirements.insert(RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT);
+        result.add(RawSource::from(format!(
+          "\nvar {interop_name} = /*#__PURE__*/{}({});",
+          RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT,
+          names.name
+        )));
+      }
+      if interop.default_access
+        && let Some(interop_name) = &names.interop_default_access_name
+      {
+        runtime_requirements.insert(RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT);
+        result.add(RawSource::from(format!(
+          "\nvar {interop_name} = /*#__PURE__*/{}({});",
+          RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT,
+          names.name
+        )));
+      }
+    }
+
+    let mut code_generation_result = CodeGenerationResult::default();
+    code_generation_result.add(SourceType::JavaScript, result.boxed());
+    code_generation_result.runtime_requirements = runtime_requirements;
+    code_generation_result.chunk_init_fragments = chunk_init_fragments;
+    code_generation_result.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(code_generation_result)
+  }
+}
+
+impl Hash for ConcatenatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConcatenatedModule".hash(state);
+    self.identifier().hash(state);
+    self.modules.hash(state);
+  }
+}
+
+impl PartialEq for ConcatenatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ConcatenatedModule {}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn find_new_name_should_use_module_path() {
+    let mut used = HashSet::default();
+    let empty = HashSet::default();
+    assert_eq!(
+      find_new_name("foo", &used, &empty, "./src/lib/index.js"),
+      JsWord::from("lib_foo")
+    );
+    used.insert(JsWord::from("lib_foo"));
+    assert_eq!(
+      find_new_name("foo", &used, &empty, "./src/lib/index.js"),
+      JsWord::from("src_lib_foo")
+    );
+    used.insert(JsWord::from("src_lib_foo"));
+    assert_eq!(
+      find_new_name("foo", &used, &empty, "./src/lib/index.js"),
+      JsWord::from("src_lib_foo_0")
+    );
+    assert_eq!(
+      find_new_name(crate::DEFAULT_EXPORT, &used, &empty, "./a.js"),
+      JsWord::from("a")
+    );
+    assert_eq!(
+      find_new_name("namespaceObject", &empty, &empty, "./b.js + 2 modules"),
+      JsWord::from("b_namespaceObject")
+    );
+  }
+}
@@ -0,0 +1,179 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use rspack_identifier::IdentifierMap;
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::ModuleIdentifier;
+
+pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
+pub const NAMESPACE_OBJECT_EXPORT: &str = "__WEBPACK_NAMESPACE_OBJECT__";
+const MODULE_REFERENCE_PREFIX: &str = "__WEBPACK_MODULE_REFERENCE__";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleReference {
+  pub index: usize,
+  pub ids: Vec<JsWord>,
+  pub call: bool,
+  pub direct_import: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConcatenationReexport {
+  pub module: ModuleIdentifier,
+  pub ids: Vec<JsWord>,
+}
+
+/// Collects the exports of a module while it is rendered as part of a
+/// concatenated module, and hands out placeholders for references into the
+/// other modules of the same scope.
+///
+/// Align with https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/ConcatenationScope.js
+#[derive(Debug, Clone)]
+pub struct ConcatenationScope {
+  modules_map: Arc<IdentifierMap<usize>>,
+  current_module: ModuleIdentifier,
+  pub exports: HashMap<JsWord, JsWord>,
+  pub reexports: HashMap<JsWord, ConcatenationReexport>,
+  pub star_reexports: Vec<ModuleIdentifier>,
+}
+
+impl ConcatenationScope {
+  pub fn new(modules_map: Arc<IdentifierMap<usize>>, current_module: ModuleIdentifier) -> Self {
+    Self {
+      modules_map,
+      current_module,
+      exports: Default::default(),
+      reexports: Default::default(),
+      star_reexports: Default::default(),
+    }
+  }
+
+  pub fn current_module(&self) -> ModuleIdentifier {
+    self.current_module
+  }
+
+  pub fn is_module_in_scope(&self, module: &ModuleIdentifier) -> bool {
+    self.modules_map.contains_key(module)
+  }
+
+  pub fn register_export(&mut self, export_name: JsWord, symbol: JsWord) {
+    self.exports.entry(export_name).or_insert(symbol);
+  }
+
+  pub fn register_reexport(
+    &mut self,
+    export_name: JsWord,
+    module: ModuleIdentifier,
+    ids: Vec<JsWord>,
+  ) {
+    self
+      .reexports
+      .entry(export_name)
+      .or_insert(ConcatenationReexport { module, ids });
+  }
+
+  pub fn register_star_reexport(&mut self, module: ModuleIdentifier) {
+    if !self.star_reexports.contains(&module) {
+      self.star_reexports.push(module);
+    }
+  }
+
+  pub fn create_module_reference(
+    &self,
+    module: &ModuleIdentifier,
+    ids: &[JsWord],
+    call: bool,
+    direct_import: bool,
+  ) -> String {
+    let index = self
+      .modules_map
+      .get(module)
+      .expect("module should be in concatenation scope");
+    let export_data = serde_json::to_string(ids)
+      .expect("should serialize ids")
+      .bytes()
+      .fold(String::new(), |mut export_data, b| {
+        let _ = write!(export_data, "{b:02x}");
+        export_data
+      });
+    format!(
+      "{MODULE_REFERENCE_PREFIX}{index}_{export_data}{}{}__",
+      if call { "_call" } else { "" },
+      if direct_import { "_directImport" } else { "" },
+    )
+  }
+
+  pub fn is_module_reference(name: &str) -> bool {
+    name.starts_with(MODULE_REFERENCE_PREFIX)
+  }
+
+  pub fn match_module_reference(name: &str) -> Option<ModuleReference> {
+    let rest = name
+      .strip_prefix(MODULE_REFERENCE_PREFIX)?
+      .strip_suffix("__")?;
+    let (rest, direct_import) = match rest.strip_suffix("_directImport") {
+      Some(rest) => (rest, true),
+      None => (rest, false),
+    };
+    let (rest, call) = match rest.strip_suffix("_call") {
+      Some(rest) => (rest, true),
+      None => (rest, false),
+    };
+    let (index, export_data) = rest.split_once('_')?;
+    let index = index.parse::<usize>().ok()?;
+    if export_data.len() % 2 != 0 {
+      return None;
+    }
+    let bytes = (0..export_data.len())
+      .step_by(2)
+      .map(|i| u8::from_str_radix(&export_data[i..i + 2], 16).ok())
+      .collect::<Option<Vec<_>>>()?;
+    let ids: Vec<String> = serde_json::from_slice(&bytes).ok()?;
+    Some(ModuleReference {
+      index,
+      ids: ids.into_iter().map(JsWord::from).collect(),
+      call,
+      direct_import,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn module_reference_round_trip() {
+    let module = ModuleIdentifier::from("./a.js");
+    let mut modules_map = IdentifierMap::default();
+    modules_map.insert(module, 3);
+    let scope = ConcatenationScope::new(Arc::new(modules_map), module);
+
+    let ids = vec![JsWord::from("default"), JsWord::from("$b_c")];
+    let reference = scope.create_module_reference(&module, &ids, true, false);
+    assert!(ConcatenationScope::is_module_reference(&reference));
+    assert_eq!(
+      ConcatenationScope::match_module_reference(&reference),
+      Some(ModuleReference {
+        index: 3,
+        ids,
+        call: true,
+        direct_import: false,
+      })
+    );
+
+    let reference = scope.create_module_reference(&module, &[], false, true);
+    assert_eq!(
+      ConcatenationScope::match_module_reference(&reference),
+      Some(ModuleReference {
+        index: 3,
+        ids: vec![],
+        call: false,
+        direct_import: true,
+      })
+    );
+    assert_eq!(ConcatenationScope::match_module_reference("foo"), None);
+  }
+}
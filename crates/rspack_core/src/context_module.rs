@@ -13,17 +13,18 @@ use regex::{Captures, Regex};
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
-use rspack_regex::RspackRegex;
+use rspack_regex::{regexp_as_str, RspackRegex};
 use rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{
   contextify, get_exports_type_with_strict, stringify_map, to_path, AsyncDependenciesBlock,
   AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult,
   ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation, ContextElementDependency,
   DependenciesBlock, DependencyCategory, DependencyId, DependencyType, ExportsType,
-  FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleType, Resolve,
+  FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleLayer, ModuleType, Resolve,
   ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
   RuntimeSpec, SourceType,
 };
@@ -116,12 +117,14 @@ pub struct ContextOptions {
   pub reg_exp: Option<RspackRegex>,
   // TODO: remove `reg_str`
   pub reg_str: String, // generate context module id
-  pub include: Option<String>,
-  pub exclude: Option<String>,
+  pub include: Option<RspackRegex>,
+  pub exclude: Option<RspackRegex>,
   pub category: DependencyCategory,
   pub request: String,
   pub namespace_object: ContextNameSpaceObject,
   pub chunk_name: Option<String>,
+  pub group_options: Option<ChunkGroupOptions>,
+  pub referenced_exports: Option<Vec<JsWord>>,
 }
 
 impl PartialEq for ContextOptions {
@@ -129,11 +132,13 @@ impl PartialEq for ContextOptions {
     self.mode == other.mode
       && self.recursive == other.recursive
       && self.reg_str == other.reg_str
-      && self.include == other.include
-      && self.exclude == other.exclude
+      && self.include.as_ref().map(regexp_as_str) == other.include.as_ref().map(regexp_as_str)
+      && self.exclude.as_ref().map(regexp_as_str) == other.exclude.as_ref().map(regexp_as_str)
       && self.category == other.category
       && self.request == other.request
       && self.namespace_object == other.namespace_object
+      && self.group_options == other.group_options
+      && self.referenced_exports == other.referenced_exports
   }
 }
 
@@ -149,6 +154,8 @@ impl Hash for ContextOptions {
     self.category.hash(state);
     self.request.hash(state);
     self.namespace_object.hash(state);
+    self.group_options.hash(state);
+    self.referenced_exports.hash(state);
   }
 }
 
@@ -159,6 +166,7 @@ pub struct ContextModuleOptions {
   pub resource_fragment: Option<String>,
   pub context_options: ContextOptions,
   pub resolve_options: Option<Box<Resolve>>,
+  pub layer: Option<ModuleLayer>,
 }
 
 impl Display for ContextModuleOptions {
@@ -167,7 +175,11 @@ impl Display for ContextModuleOptions {
       f,
       "{}|{:?}|{:?}|{:?}",
       self.resource, self.resource_query, self.resource_fragment, self.context_options
-    )
+    )?;
+    if let Some(layer) = &self.layer {
+      write!(f, "|layer: {layer}")?;
+    }
+    Ok(())
   }
 }
 
@@ -575,8 +587,15 @@ impl Module for ContextModule {
     160.0
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.options.layer.as_ref()
+  }
+
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     let mut id = contextify(options.context, &self.options.resource);
+    if let Some(layer) = &self.options.layer {
+      id = format!("({layer})/{id}");
+    }
     id.push_str(format!(" {:?} ", self.options.context_options.mode).as_str());
     if self.options.context_options.recursive {
       id.push_str(" recursive ");
@@ -690,6 +709,11 @@ impl ContextModule {
     }
     for entry in fs::read_dir(dir)? {
       let path = entry?.path();
+      if let Some(exclude) = &options.context_options.exclude
+        && exclude.test(&path.to_string_lossy())
+      {
+        continue;
+      }
       if path.is_dir() {
         if options.context_options.recursive {
           Self::visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
@@ -700,6 +724,10 @@ impl ContextModule {
       {
         // ignore hidden files
         continue;
+      } else if let Some(include) = &options.context_options.include
+        && !include.test(&path.to_string_lossy())
+      {
+        continue;
       } else {
         // FIXME: nodejs resolver return path of context, sometimes is '/a/b', sometimes is '/a/b/'
         let relative_path = {
@@ -741,7 +769,7 @@ impl ContextModule {
             context: options.resource.clone().into(),
             options: options.context_options.clone(),
             resource_identifier: format!("context{}|{}", &options.resource, path.to_string_lossy()),
-            referenced_exports: None,
+            referenced_exports: options.context_options.referenced_exports.clone(),
           });
         })
       }
@@ -784,7 +812,15 @@ impl ContextModule {
     {
       let name = self.options.context_options.chunk_name.clone();
       let mut block = AsyncDependenciesBlock::new(self.identifier, "", None);
-      block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions { name }));
+      block.set_group_options(GroupOptions::ChunkGroup(
+        self
+          .options
+          .context_options
+          .group_options
+          .clone()
+          .unwrap_or_default()
+          .name_optional(name),
+      ));
       for context_element_dependency in context_element_dependencies {
         block.add_dependency(Box::new(context_element_dependency));
       }
@@ -816,7 +852,15 @@ impl ContextModule {
           &context_element_dependency.user_request,
           None,
         );
-        block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions { name }));
+        block.set_group_options(GroupOptions::ChunkGroup(
+          self
+            .options
+            .context_options
+            .group_options
+            .clone()
+            .unwrap_or_default()
+            .name_optional(name),
+        ));
         block.add_dependency(Box::new(context_element_dependency));
         blocks.push(block);
       }
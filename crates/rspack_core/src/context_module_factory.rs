@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_regex::{regexp_as_str, RspackRegex};
 use tracing::instrument;
 
 use crate::{
-  cache::Cache, resolve, BoxModule, ContextModule, ContextModuleOptions, MissingModule, ModuleExt,
-  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier,
-  NormalModuleBeforeResolveArgs, RawModule, ResolveArgs, ResolveError, ResolveResult,
-  SharedPluginDriver,
+  cache::Cache, resolve, BoxModule, ContextModule, ContextModuleAfterResolveArgs,
+  ContextModuleBeforeResolveArgs, ContextModuleOptions, ContextOptions, MissingModule, ModuleExt,
+  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, RawModule,
+  ResolveArgs, ResolveError, ResolveResult, SharedPluginDriver,
 };
 
 #[derive(Debug)]
@@ -23,7 +24,7 @@ impl ModuleFactory for ContextModuleFactory {
     &self,
     mut data: ModuleFactoryCreateData,
   ) -> Result<TWithDiagnosticArray<ModuleFactoryResult>> {
-    if let Ok(Some(before_resolve_result)) = self.before_resolve(&mut data).await {
+    if let Some(before_resolve_result) = self.before_resolve(&mut data).await? {
       return Ok(before_resolve_result);
     }
     Ok(self.resolve(data).await?)
@@ -46,14 +47,16 @@ impl ContextModuleFactory {
       .dependency
       .as_context_dependency_mut()
       .expect("should be module dependency");
-    let mut before_resolve_args = NormalModuleBeforeResolveArgs {
+    let mut before_resolve_args = ContextModuleBeforeResolveArgs {
       request: dependency.request().to_string(),
       context: data.context.to_string(),
+      recursive: dependency.options().recursive,
+      reg_exp: dependency.options().reg_exp.clone(),
     };
-    if let Ok(Some(false)) = self
+    if let Some(false) = self
       .plugin_driver
       .context_module_before_resolve(&mut before_resolve_args)
-      .await
+      .await?
     {
       let specifier = dependency.request();
       let ident = format!("{}{specifier}", data.context);
@@ -72,6 +75,11 @@ impl ContextModuleFactory {
     }
     data.context = before_resolve_args.context.into();
     dependency.set_request(before_resolve_args.request);
+    update_context_options(
+      dependency.options_mut(),
+      before_resolve_args.recursive,
+      before_resolve_args.reg_exp,
+    );
     Ok(None)
   }
 
@@ -114,16 +122,46 @@ impl ContextModuleFactory {
     };
 
     let module = match resource_data {
-      Ok(ResolveResult::Resource(resource)) => Box::new(ContextModule::new(
-        ContextModuleOptions {
+      Ok(ResolveResult::Resource(resource)) => {
+        let mut context_options = dependency.options().clone();
+        let mut after_resolve_args = ContextModuleAfterResolveArgs {
+          request: specifier.to_string(),
           resource: resource.path.to_string_lossy().to_string(),
-          resource_query: resource.query,
-          resource_fragment: resource.fragment,
-          resolve_options: data.resolve_options,
-          context_options: dependency.options().clone(),
-        },
-        plugin_driver.resolver_factory.clone(),
-      )) as BoxModule,
+          recursive: context_options.recursive,
+          reg_exp: context_options.reg_exp.clone(),
+        };
+        if let Some(false) = plugin_driver
+          .context_module_after_resolve(&mut after_resolve_args)
+          .await?
+        {
+          let ident = format!("{}{specifier}", data.context);
+          let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
+          let missing_module = MissingModule::new(
+            module_identifier,
+            format!("{ident} (missing)"),
+            format!("Failed to resolve {specifier}"),
+          )
+          .boxed();
+          return Ok(ModuleFactoryResult::new(missing_module).with_empty_diagnostic());
+        }
+        update_context_options(
+          &mut context_options,
+          after_resolve_args.recursive,
+          after_resolve_args.reg_exp,
+        );
+
+        Box::new(ContextModule::new(
+          ContextModuleOptions {
+            resource: after_resolve_args.resource,
+            resource_query: resource.query,
+            resource_fragment: resource.fragment,
+            resolve_options: data.resolve_options,
+            context_options,
+            layer: data.issuer_layer,
+          },
+          plugin_driver.resolver_factory.clone(),
+        )) as BoxModule
+      }
       Ok(ResolveResult::Ignored) => {
         let ident = format!("{}/{}", data.context, specifier);
         let module_identifier = ModuleIdentifier::from(format!("ignored|{ident}"));
@@ -164,3 +202,18 @@ impl ContextModuleFactory {
     )
   }
 }
+
+/// Applies the `recursive` and `reg_exp` returned by the resolve hooks,
+/// `reg_str` is only regenerated when the regexp is actually replaced.
+fn update_context_options(
+  options: &mut ContextOptions,
+  recursive: bool,
+  reg_exp: Option<RspackRegex>,
+) {
+  options.recursive = recursive;
+  let reg_str = reg_exp.as_ref().map(regexp_as_str);
+  if reg_str != options.reg_exp.as_ref().map(regexp_as_str) {
+    options.reg_str = reg_str.unwrap_or_default().to_string();
+    options.reg_exp = reg_exp;
+  }
+}
@@ -1,5 +1,5 @@
 use rspack_error::{Diagnostic, DIAGNOSTIC_POS_DUMMY};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::{BoxDependency, Compilation, DependencyId, GroupOptions, ModuleIdentifier};
@@ -25,6 +25,10 @@ impl AsyncDependenciesBlockIdentifier {
     Self { from, modifier }
   }
 
+  pub fn modifier(&self) -> &str {
+    &self.modifier
+  }
+
   pub fn get<'a>(&self, compilation: &'a Compilation) -> Option<&'a AsyncDependenciesBlock> {
     compilation.module_graph.block_by_id(self)
   }
@@ -37,7 +41,7 @@ impl AsyncDependenciesBlockIdentifier {
   }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DependencyLocation {
   start: u32,
   end: u32,
@@ -120,6 +124,11 @@ impl AsyncDependenciesBlock {
     self.dependencies.push(dependency);
   }
 
+  /// The dependencies which are not taken by the module graph yet
+  pub fn dependencies(&self) -> &[BoxDependency] {
+    &self.dependencies
+  }
+
   pub fn take_dependencies(&mut self) -> Vec<BoxDependency> {
     std::mem::take(&mut self.dependencies)
   }
@@ -130,6 +139,10 @@ impl AsyncDependenciesBlock {
     // self.blocks.push(block);
   }
 
+  pub fn blocks(&self) -> &[AsyncDependenciesBlock] {
+    &self.blocks
+  }
+
   pub fn take_blocks(&mut self) -> Vec<AsyncDependenciesBlock> {
     std::mem::take(&mut self.blocks)
   }
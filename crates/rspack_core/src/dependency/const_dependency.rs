@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConstDependency {
   pub start: u32,
   pub end: u32,
@@ -37,4 +39,8 @@ impl DependencyTemplate for ConstDependency {
     }
     source.replace(self.start, self.end, self.content.as_ref(), None);
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some(("ConstDependency", serde_json::to_value(self).ok()?))
+  }
 }
@@ -3,6 +3,7 @@ use crate::{ContextOptions, Dependency};
 pub trait ContextDependency: Dependency {
   fn request(&self) -> &str;
   fn options(&self) -> &ContextOptions;
+  fn options_mut(&mut self) -> &mut ContextOptions;
   fn get_context(&self) -> Option<&str>;
   fn resource_identifier(&self) -> &str;
   fn set_request(&mut self, request: String);
@@ -7,6 +7,7 @@ pub enum DependencyCategory {
   Unknown,
   Esm,
   CommonJS,
+  Amd,
   Url,
   CssImport,
   CssCompose,
@@ -19,6 +20,7 @@ impl From<&str> for DependencyCategory {
     match value {
       "esm" => Self::Esm,
       "commonjs" => Self::CommonJS,
+      "amd" => Self::Amd,
       "url" => Self::Url,
       "wasm" => Self::Wasm,
       "css-import" => Self::CssImport,
@@ -36,6 +38,7 @@ impl DependencyCategory {
       DependencyCategory::Unknown => "unknown",
       DependencyCategory::Esm => "esm",
       DependencyCategory::CommonJS => "commonjs",
+      DependencyCategory::Amd => "amd",
       DependencyCategory::Url => "url",
       DependencyCategory::CssImport => "css-import",
       DependencyCategory::CssCompose => "css-compose",
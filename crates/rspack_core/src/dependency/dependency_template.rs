@@ -3,7 +3,9 @@ use std::fmt::Debug;
 use dyn_clone::{clone_trait_object, DynClone};
 use rspack_sources::{BoxSource, ReplaceSource};
 
-use crate::{Compilation, Module, ModuleInitFragments, RuntimeGlobals, RuntimeSpec};
+use crate::{
+  Compilation, ConcatenationScope, Module, ModuleInitFragments, RuntimeGlobals, RuntimeSpec,
+};
 
 pub struct TemplateContext<'a, 'b> {
   pub compilation: &'a Compilation,
@@ -11,6 +13,7 @@ pub struct TemplateContext<'a, 'b> {
   pub runtime_requirements: &'a mut RuntimeGlobals,
   pub init_fragments: &'a mut ModuleInitFragments<'b>,
   pub runtime: Option<&'a RuntimeSpec>,
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
 pub type TemplateReplaceSource = ReplaceSource<BoxSource>;
@@ -24,6 +27,12 @@ pub trait DependencyTemplate: Debug + DynClone + Sync + Send {
     source: &mut TemplateReplaceSource,
     code_generatable_context: &mut TemplateContext,
   );
+
+  /// Name and data of the template saved by the persistent cache, see [crate::Dependency::pack].
+  /// The name should be registered by [crate::cache::register_dependency_template].
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    None
+  }
 }
 
 pub type BoxDependencyTemplate = Box<dyn DependencyTemplate>;
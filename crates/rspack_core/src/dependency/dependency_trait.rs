@@ -1,6 +1,7 @@
 use std::{any::Any, fmt::Debug};
 
 use dyn_clone::{clone_trait_object, DynClone};
+use rspack_error::Diagnostic;
 use rspack_util::ext::AsAny;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::{common::Span, ecma::atoms::JsWord};
@@ -80,6 +81,18 @@ pub trait Dependency:
   fn resource_identifier(&self) -> Option<&str> {
     None
   }
+
+  /// Warnings and errors of the dependency, reported after all modules are built
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    None
+  }
+
+  /// Data of the dependency saved by the persistent cache, `None` means the module containing
+  /// it can only be cached in memory. The dependency should be registered by
+  /// [crate::cache::register_dependency] under its [Dependency::dependency_debug_name].
+  fn pack(&self) -> Option<serde_json::Value> {
+    None
+  }
 }
 
 impl dyn Dependency + '_ {
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 
+use serde::{Deserialize, Serialize};
+
 use crate::ErrorSpan;
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
 // Note: This is almost the same with the old `ResolveKind`
-#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum DependencyType {
   #[default]
   Unknown,
@@ -22,6 +24,8 @@ pub enum DependencyType {
   DynamicImport,
   // import() eager
   DynamicImportEager,
+  // import() weak
+  DynamicImportWeak,
   // cjs require
   CjsRequire,
   // cjs exports
@@ -56,6 +60,16 @@ pub enum DependencyType {
   RequireContext,
   // require.resolve
   RequireResolve,
+  // require.ensure item
+  RequireEnsureItem,
+  // require.include
+  RequireInclude,
+  // amd define
+  AmdDefine,
+  // amd require
+  AmdRequire,
+  // amd require item
+  AmdRequireItem,
   /// wasm import
   WasmImport,
   /// wasm export import
@@ -107,9 +121,15 @@ impl DependencyType {
       // TODO: mode
       DependencyType::ImportContext => Cow::Borrowed("import context"),
       DependencyType::DynamicImportEager => Cow::Borrowed("import() eager"),
+      DependencyType::DynamicImportWeak => Cow::Borrowed("import() weak"),
       DependencyType::CommonJSRequireContext => Cow::Borrowed("commonjs require context"),
       DependencyType::RequireContext => Cow::Borrowed("require.context"),
       DependencyType::RequireResolve => Cow::Borrowed("require.resolve"),
+      DependencyType::RequireEnsureItem => Cow::Borrowed("require.ensure item"),
+      DependencyType::RequireInclude => Cow::Borrowed("require.include"),
+      DependencyType::AmdDefine => Cow::Borrowed("amd define"),
+      DependencyType::AmdRequire => Cow::Borrowed("amd require"),
+      DependencyType::AmdRequireItem => Cow::Borrowed("amd require item"),
       DependencyType::WasmImport => Cow::Borrowed("wasm import"),
       DependencyType::WasmExportImported => Cow::Borrowed("wasm export imported"),
       DependencyType::StaticExports => Cow::Borrowed("static exports"),
@@ -39,6 +39,11 @@ pub trait ModuleDependency: Dependency {
   fn is_export_all(&self) -> Option<bool> {
     None
   }
+
+  /// The order of the import statement in the source, only available for harmony imports
+  fn source_order(&self) -> Option<i32> {
+    None
+  }
 }
 
 clone_trait_object!(ModuleDependency);
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
 
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Serialize, Deserialize)]
 pub struct RuntimeRequirementsDependency {
   pub runtime_requirements: RuntimeGlobals,
 }
@@ -15,6 +17,13 @@ impl DependencyTemplate for RuntimeRequirementsDependency {
       .runtime_requirements
       .insert(self.runtime_requirements);
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some((
+      "RuntimeRequirementsDependency",
+      serde_json::to_value(self).ok()?,
+    ))
+  }
 }
 
 impl RuntimeRequirementsDependency {
@@ -302,7 +302,7 @@ pub fn module_namespace_promise(
         fake_type |= FakeNamespaceObjectMode::MODULE_ID;
         if let Some(header) = header {
           let expr = format!(
-            "{}({module_id_expr}, {fake_type}))",
+            "{}({module_id_expr}, {fake_type})",
             RuntimeGlobals::CREATE_FAKE_NAMESPACE_OBJECT
           );
           appending = format!(".then(function() {{\n {header} return {expr};\n}})");
@@ -357,7 +357,8 @@ pub fn module_raw(
       module_id_expr(request, module_id)
     )
   } else if weak {
-    weak_error(request)
+    // the weak error is used as an expression here
+    format!("(function() {{ {} }}())", weak_error(request))
   } else {
     miss_module(request)
   }
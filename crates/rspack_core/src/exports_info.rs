@@ -7,7 +7,7 @@ use std::sync::Arc;
 use rspack_util::ext::DynHash;
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
 use crate::Nullable;
@@ -40,6 +40,22 @@ impl Default for ExportsInfoId {
 }
 
 impl ExportsInfoId {
+  /// `None` means it's unknown whether the export is provided
+  pub fn is_export_provided(&self, names: &[JsWord], mg: &ModuleGraph) -> Option<bool> {
+    let name = names.first()?;
+    let info = self.get_read_only_export_info(name, mg);
+    if let Some(exports_info) = info.exports_info
+      && names.len() > 1
+    {
+      return exports_info.is_export_provided(&names[1..], mg);
+    }
+    match info.provided? {
+      ExportInfoProvided::True => (names.len() == 1).then_some(true),
+      ExportInfoProvided::False => Some(false),
+      ExportInfoProvided::Null => None,
+    }
+  }
+
   pub fn new() -> Self {
     Self(EXPORTS_INFO_ID.fetch_add(1, Relaxed))
   }
@@ -1409,7 +1425,7 @@ pub enum RuntimeUsageStateType {
   Used,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UsedByExports {
   Set(HashSet<JsWord>),
   Bool(bool),
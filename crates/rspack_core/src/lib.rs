@@ -11,11 +11,16 @@ mod dependencies_block;
 pub use dependencies_block::{
   AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, DependenciesBlock, DependencyLocation,
 };
+mod concatenated_module;
+pub use concatenated_module::*;
+mod concatenation_scope;
+pub use concatenation_scope::*;
 mod fake_namespace_object;
 pub use fake_namespace_object::*;
 mod module_profile;
 pub use module_profile::*;
 use rspack_database::Database;
+use serde::{Deserialize, Serialize};
 pub mod external_module;
 pub use external_module::*;
 mod logger;
@@ -92,7 +97,7 @@ pub use rspack_sources;
 #[cfg(debug_assertions)]
 pub mod debug_info;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SourceType {
   JavaScript,
   Css,
@@ -1,15 +1,25 @@
-use rspack_error::Result;
+use std::sync::Arc;
+
+use rspack_error::{internal_error, Result};
+use rspack_fs::InputFileSystem;
 use rspack_loader_runner::{Content, LoaderRunnerPlugin, ResourceData};
 
 use crate::SharedPluginDriver;
 
 pub struct LoaderRunnerPluginProcessResource {
   pub plugin_driver: SharedPluginDriver,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
 }
 
 impl LoaderRunnerPluginProcessResource {
-  pub fn new(plugin_driver: SharedPluginDriver) -> Self {
-    Self { plugin_driver }
+  pub fn new(
+    plugin_driver: SharedPluginDriver,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
+    Self {
+      plugin_driver,
+      input_filesystem,
+    }
   }
 }
 
@@ -25,6 +35,18 @@ impl LoaderRunnerPlugin for LoaderRunnerPluginProcessResource {
       return Ok(result);
     }
 
+    // resources without a scheme are files, read them through the input filesystem
+    if resource_data.get_scheme().is_none() {
+      let content = self
+        .input_filesystem
+        .read(&resource_data.resource_path)
+        .map_err(|rspack_fs::Error::Io(e)| {
+          let r = resource_data.resource_path.to_string_lossy().to_string();
+          internal_error!("{e}, failed to read {r}")
+        })?;
+      return Ok(Some(Content::from(content)));
+    }
+
     Ok(None)
   }
 }
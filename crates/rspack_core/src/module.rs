@@ -1,42 +1,52 @@
 use std::fmt::Display;
 use std::hash::Hash;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_fs::InputFileSystem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkUkey, CodeGenerationResult, Compilation,
-  CompilerContext, CompilerOptions, ConnectionState, Context, ContextModule, DependenciesBlock,
-  DependencyId, DependencyTemplate, ExternalModule, ModuleDependency, ModuleGraph, ModuleType,
-  NormalModule, RawModule, Resolve, RuntimeSpec, SharedPluginDriver, SourceType,
+  CompilerContext, CompilerOptions, ConcatenatedModule, ConnectionState, Context, ContextModule,
+  DependenciesBlock, DependencyId, DependencyTemplate, ExternalModule, ModuleDependency,
+  ModuleGraph, ModuleType, NormalModule, RawModule, Resolve, RuntimeSpec, SharedPluginDriver,
+  SourceType,
 };
 
+/// Name of the layer a module belongs to, see `experiments.layers`.
+pub type ModuleLayer = String;
+
 pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildExtraDataType {
   CssParserAndGenerator,
   AssetParserAndGenerator,
   JavaScriptParserAndGenerator,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct BuildInfo {
   /// Whether the result is cacheable, i.e shared between builds.
   pub cacheable: bool,
+  // saved by the build module cache separately, like `all_star_exports`
+  #[serde(skip)]
   pub hash: Option<RspackHashDigest>,
   pub strict: bool,
   pub file_dependencies: HashSet<PathBuf>,
@@ -45,11 +55,14 @@ pub struct BuildInfo {
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
   pub harmony_named_exports: HashSet<JsWord>,
+  #[serde(skip)]
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
+  /// Why the module can't be concatenated by `ModuleConcatenationPlugin`, e.g. `eval()`
+  pub module_concatenation_bailout: Option<String>,
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildMetaExportsType {
   #[default]
   Unset,
@@ -67,7 +80,7 @@ pub enum ExportsType {
   Dynamic,
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
 pub enum BuildMetaDefaultObject {
   #[default]
   False,
@@ -75,7 +88,7 @@ pub enum BuildMetaDefaultObject {
   RedirectWarn,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum ModuleArgument {
   #[default]
   Module,
@@ -91,7 +104,7 @@ impl Display for ModuleArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExportsArgument {
   #[default]
   Exports,
@@ -107,7 +120,7 @@ impl Display for ExportsArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
 pub struct BuildMeta {
   pub strict_harmony_module: bool,
   pub has_top_level_await: bool,
@@ -234,6 +247,10 @@ pub trait Module:
     None
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn chunk_condition(&self, _chunk_key: &ChunkUkey, _compilation: &Compilation) -> Option<bool> {
     None
   }
@@ -329,6 +346,7 @@ impl_module_downcast_helpers!(NormalModule, normal_module);
 impl_module_downcast_helpers!(RawModule, raw_module);
 impl_module_downcast_helpers!(ContextModule, context_module);
 impl_module_downcast_helpers!(ExternalModule, external_module);
+impl_module_downcast_helpers!(ConcatenatedModule, concatenated_module);
 
 pub struct LibIdentOptions<'me> {
   pub context: &'me str,
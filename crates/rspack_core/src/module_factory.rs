@@ -3,7 +3,9 @@ use std::{fmt::Debug, path::PathBuf};
 use rspack_error::{Result, TWithDiagnosticArray};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::{BoxDependency, BoxModule, Context, FactoryMeta, ModuleIdentifier, Resolve};
+use crate::{
+  BoxDependency, BoxModule, Context, FactoryMeta, ModuleIdentifier, ModuleLayer, Resolve,
+};
 
 #[derive(Debug)]
 pub struct ModuleFactoryCreateData {
@@ -12,6 +14,7 @@ pub struct ModuleFactoryCreateData {
   pub dependency: BoxDependency,
   pub issuer: Option<Box<str>>,
   pub issuer_identifier: Option<ModuleIdentifier>,
+  pub issuer_layer: Option<ModuleLayer>,
 }
 
 #[derive(Debug)]
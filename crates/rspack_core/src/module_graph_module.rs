@@ -31,6 +31,8 @@ pub struct ModuleGraphModule {
   pub exports: ExportsInfoId,
   pub profile: Option<Box<ModuleProfile>>,
   pub is_async: bool,
+  /// Reasons why optimizations (e.g. module concatenation) were not applied to this module
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -56,6 +58,7 @@ impl ModuleGraphModule {
       exports: exports_info_id,
       profile: None,
       is_async: false,
+      optimization_bailout: vec![],
     }
   }
 
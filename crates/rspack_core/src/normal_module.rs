@@ -28,10 +28,11 @@ use serde_json::json;
 use crate::{
   add_connection_states, contextify, get_context, AsyncDependenciesBlockIdentifier, BoxLoader,
   BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation,
-  CompilerOptions, ConnectionState, Context, DependenciesBlock, DependencyId, DependencyTemplate,
-  GenerateContext, GeneratorOptions, LibIdentOptions, LoaderRunnerPluginProcessResource, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RuntimeSpec, SourceType,
+  CompilerOptions, ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
+  DependencyTemplate, GenerateContext, GeneratorOptions, LibIdentOptions,
+  LoaderRunnerPluginProcessResource, Module, ModuleDependency, ModuleGraph, ModuleIdentifier,
+  ModuleLayer, ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
+  RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -92,6 +93,8 @@ pub struct NormalModule {
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
+  /// The layer of the module, see `experiments.layers`
+  layer: Option<ModuleLayer>,
   /// Affiliated parser and generator to the module type
   parser_and_generator: Box<dyn ParserAndGenerator>,
   /// Resource matched with inline match resource, (`!=!` syntax)
@@ -152,8 +155,10 @@ impl NormalModuleSource {
 pub static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
 
 impl NormalModule {
-  fn create_id(module_type: &ModuleType, request: &str) -> String {
-    if *module_type == ModuleType::Js {
+  fn create_id(module_type: &ModuleType, layer: Option<&ModuleLayer>, request: &str) -> String {
+    if let Some(layer) = layer {
+      format!("{module_type}|{request}|{layer}")
+    } else if *module_type == ModuleType::Js {
       request.to_string()
     } else {
       format!("{module_type}|{request}")
@@ -166,6 +171,7 @@ impl NormalModule {
     user_request: String,
     raw_request: String,
     module_type: impl Into<ModuleType>,
+    layer: Option<ModuleLayer>,
     parser_and_generator: Box<dyn ParserAndGenerator>,
     parser_options: Option<ParserOptions>,
     generator_options: Option<GeneratorOptions>,
@@ -177,7 +183,7 @@ impl NormalModule {
     contains_inline_loader: bool,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, &request);
+    let id = Self::create_id(&module_type, layer.as_ref(), &request);
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -187,6 +193,7 @@ impl NormalModule {
       user_request,
       raw_request,
       module_type,
+      layer,
       parser_and_generator,
       parser_options,
       generator_options,
@@ -347,6 +354,7 @@ impl Module for NormalModule {
       &self.resource_data,
       &[Box::new(LoaderRunnerPluginProcessResource {
         plugin_driver: build_context.plugin_driver.clone(),
+        input_filesystem: build_context.input_filesystem.clone(),
       })],
       build_context.compiler_context,
     )
@@ -443,52 +451,7 @@ impl Module for NormalModule {
     compilation: &Compilation,
     runtime: Option<&RuntimeSpec>,
   ) -> Result<CodeGenerationResult> {
-    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-      for source_type in self.source_types() {
-        let generation_result = self.parser_and_generator.generate(
-          source,
-          self,
-          &mut GenerateContext {
-            compilation,
-            module_generator_options: self.generator_options.as_ref(),
-            runtime_requirements: &mut code_generation_result.runtime_requirements,
-            data: &mut code_generation_result.data,
-            requested_source_type: *source_type,
-            runtime,
-          },
-        )?;
-        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-
-      // If the module build failed and the module is able to emit JavaScript source,
-      // we should emit an error message to the runtime, otherwise we do nothing.
-      if self.source_types().contains(&SourceType::JavaScript) {
-        code_generation_result.add(
-          SourceType::JavaScript,
-          RawSource::from(format!("throw new Error({});\n", json!(error_message))).boxed(),
-        );
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else {
-      Err(internal_error!(
-        "Failed to generate code because ast or source is not set for module {}",
-        self.request
-      ))
-    }
+    self.code_generation_with_concatenation_scope(compilation, runtime, None)
   }
 
   fn name_for_condition(&self) -> Option<Box<str>> {
@@ -504,7 +467,11 @@ impl Module for NormalModule {
 
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModule.js#L362
-    Some(Cow::Owned(contextify(options.context, self.user_request())))
+    let mut ident = contextify(options.context, self.user_request());
+    if let Some(layer) = &self.layer {
+      ident = format!("({layer})/{ident}");
+    }
+    Some(Cow::Owned(ident))
   }
 
   fn get_resolve_options(&self) -> Option<Box<Resolve>> {
@@ -535,6 +502,10 @@ impl Module for NormalModule {
     Some(self.context.clone())
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
+  }
+
   // Port from https://github.com/webpack/webpack/blob/main/lib/NormalModule.js#L1120
   fn get_side_effects_connection_state(
     &self,
@@ -608,6 +579,66 @@ impl NormalModule {
     }
     Ok(RawSource::from(content.into_string_lossy()).boxed())
   }
+
+  /// Generate code for this module, rendering it into `concatenation_scope` when it is
+  /// going to be concatenated into a [crate::ConcatenatedModule].
+  pub fn code_generation_with_concatenation_scope(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    mut concatenation_scope: Option<&mut ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+      for source_type in self.source_types() {
+        // reborrow the scope for each source type
+        #[allow(clippy::needless_option_as_deref)]
+        let concatenation_scope = concatenation_scope.as_deref_mut();
+        let generation_result = self.parser_and_generator.generate(
+          source,
+          self,
+          &mut GenerateContext {
+            compilation,
+            module_generator_options: self.generator_options.as_ref(),
+            runtime_requirements: &mut code_generation_result.runtime_requirements,
+            data: &mut code_generation_result.data,
+            requested_source_type: *source_type,
+            runtime,
+            concatenation_scope,
+          },
+        )?;
+        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+
+      // If the module build failed and the module is able to emit JavaScript source,
+      // we should emit an error message to the runtime, otherwise we do nothing.
+      if self.source_types().contains(&SourceType::JavaScript) {
+        code_generation_result.add(
+          SourceType::JavaScript,
+          RawSource::from(format!("throw new Error({});\n", json!(error_message))).boxed(),
+        );
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else {
+      Err(internal_error!(
+        "Failed to generate code because ast or source is not set for module {}",
+        self.request
+      ))
+    }
+  }
 }
 
 impl Hash for NormalModule {
@@ -6,6 +6,7 @@ use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
 };
 use rspack_loader_runner::{get_scheme, Loader, Scheme};
+use serde::{Deserialize, Serialize};
 use sugar_path::{AsPath, SugarPath};
 use swc_core::common::Span;
 
@@ -15,11 +16,11 @@ use crate::{
   tree_shaking::visitor::{get_side_effects_from_package_json, SideEffects},
   BoxLoader, CompilerContext, CompilerOptions, DependencyCategory, DependencyType, FactorizeArgs,
   FactoryMeta, FuncUseCtx, GeneratorOptions, MissingModule, ModuleExt, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce,
-  ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
-  NormalModuleBeforeResolveArgs, NormalModuleCreateData, ParserOptions, RawModule, Resolve,
-  ResolveArgs, ResolveError, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
-  ResolverFactory, ResourceData, ResourceParsedData, SharedPluginDriver,
+  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRule,
+  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule,
+  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleCreateData,
+  ParserOptions, RawModule, Resolve, ResolveArgs, ResolveError, ResolveOptionsWithDependencyType,
+  ResolveResult, Resolver, ResolverFactory, ResourceData, ResourceParsedData, SharedPluginDriver,
 };
 
 #[derive(Debug)]
@@ -36,15 +37,10 @@ impl ModuleFactory for NormalModuleFactory {
     &self,
     mut data: ModuleFactoryCreateData,
   ) -> Result<TWithDiagnosticArray<ModuleFactoryResult>> {
-    if let Ok(Some(before_resolve_data)) = self.before_resolve(&mut data).await {
+    if let Some(before_resolve_data) = self.before_resolve(&mut data).await? {
       return Ok(before_resolve_data);
     }
-    let (factory_result, diagnostics) = self.factorize(&mut data).await?.split_into_parts();
-    if let Ok(Some(after_resolve_data)) = self.after_resolve(&data, &factory_result).await {
-      return Ok(after_resolve_data);
-    }
-
-    Ok(factory_result.with_diagnostic(diagnostics))
+    self.factorize(&mut data).await
   }
 }
 
@@ -79,10 +75,10 @@ impl NormalModuleFactory {
       request: dependency.request().to_string(),
       context: data.context.to_string(),
     };
-    if let Ok(Some(false)) = self
+    if let Some(false) = self
       .plugin_driver
       .before_resolve(&mut before_resolve_args)
-      .await
+      .await?
     {
       let request_without_match_resource = dependency.request();
       let ident = format!("{}/{request_without_match_resource}", &data.context);
@@ -104,44 +100,6 @@ impl NormalModuleFactory {
     Ok(None)
   }
 
-  async fn after_resolve(
-    &self,
-    data: &ModuleFactoryCreateData,
-    factory_result: &ModuleFactoryResult,
-  ) -> Result<Option<TWithDiagnosticArray<ModuleFactoryResult>>> {
-    let dependency = data
-      .dependency
-      .as_module_dependency()
-      .expect("should be module dependency");
-    if let Ok(Some(false)) = self
-      .plugin_driver
-      .after_resolve(NormalModuleAfterResolveArgs {
-        request: dependency.request(),
-        context: data.context.as_ref(),
-        file_dependencies: &factory_result.file_dependencies,
-        context_dependencies: &factory_result.context_dependencies,
-        missing_dependencies: &factory_result.missing_dependencies,
-        factory_meta: &factory_result.factory_meta,
-      })
-      .await
-    {
-      let request_without_match_resource = dependency.request();
-      let ident = format!("{}/{request_without_match_resource}", &data.context);
-      let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
-
-      let missing_module = MissingModule::new(
-        module_identifier,
-        format!("{ident} (missing)"),
-        format!("Failed to resolve {request_without_match_resource}"),
-      )
-      .boxed();
-      return Ok(Some(
-        ModuleFactoryResult::new(missing_module).with_empty_diagnostic(),
-      ));
-    }
-    Ok(None)
-  }
-
   fn get_loader_resolver(&self) -> Arc<Resolver> {
     self
       .loader_resolver_factory
@@ -180,7 +138,7 @@ impl NormalModuleFactory {
     let mut no_pre_post_auto_loaders = false;
 
     // with scheme, windows absolute path is considered scheme by `url`
-    let (resource_data, from_cache) = if scheme != Scheme::None
+    let (mut resource_data, from_cache) = if scheme != Scheme::None
       && !Path::is_absolute(Path::new(request_without_match_resource))
     {
       // resource with scheme
@@ -194,8 +152,17 @@ impl NormalModuleFactory {
         false,
       )
     }
-    // TODO: resource within scheme, call resolveInScheme hook
-    else {
+    // resource within scheme
+    else if context_scheme != Scheme::None
+      && let Some(resource_data) = plugin_driver
+        .normal_module_factory_resolve_in_scheme(
+          ResourceData::new(request_without_match_resource.to_string(), "".into()),
+          &data.context,
+        )
+        .await?
+    {
+      (resource_data, false)
+    } else {
       {
         request_without_match_resource = {
           let match_resource_match = MATCH_RESOURCE_REGEX.captures(request_without_match_resource);
@@ -435,20 +402,15 @@ impl NormalModuleFactory {
         },
         data.dependency.category(),
         data.issuer.as_deref(),
+        data.issuer_layer.as_deref(),
       )
       .await?;
 
-    let user_request = {
-      let suffix = stringify_loaders_and_resource(&inline_loaders, &resource_data.resource);
-      if let Some(ResourceData { resource, .. }) = match_resource_data.as_ref() {
-        let mut resource = resource.to_owned();
-        resource += "!=!";
-        resource += &*suffix;
-        resource
-      } else {
-        suffix.into_owned()
-      }
-    };
+    let user_request = self.calculate_user_request(
+      &inline_loaders,
+      &resource_data,
+      match_resource_data.as_ref(),
+    );
     let contains_inline = !inline_loaders.is_empty();
 
     let loaders: Vec<BoxLoader> = {
@@ -510,7 +472,7 @@ impl NormalModuleFactory {
       let mut resolved_inline_loaders = vec![];
       let mut resolved_normal_loaders = vec![];
 
-      for l in inline_loaders {
+      for l in &inline_loaders {
         resolved_inline_loaders.push(
           resolve_each(
             plugin_driver,
@@ -583,6 +545,56 @@ impl NormalModuleFactory {
       all_loaders
     };
 
+    let resolved_module_type = self.calculate_module_type(&resolved_module_rules);
+    let resolved_module_layer = self.calculate_module_layer(data, &resolved_module_rules)?;
+    let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
+    let (resolved_parser_options, resolved_generator_options) =
+      self.calculate_parser_and_generator_options(&resolved_module_rules);
+    let factory_meta = FactoryMeta {
+      side_effect_free: self
+        .calculate_side_effects(&resolved_module_rules, &resource_data)
+        .map(|side_effects| !side_effects),
+    };
+
+    let resource = resource_data.resource.clone();
+    let context_dependencies = Default::default();
+    if let Some(false) = plugin_driver
+      .after_resolve(&mut NormalModuleAfterResolveArgs {
+        request: dependency.request(),
+        context: data.context.as_ref(),
+        file_dependencies: &file_dependencies,
+        context_dependencies: &context_dependencies,
+        missing_dependencies: &missing_dependencies,
+        factory_meta: &factory_meta,
+        resource_resolve_data: &mut resource_data,
+      })
+      .await?
+    {
+      let request_without_match_resource = dependency.request();
+      let ident = format!("{}/{request_without_match_resource}", &data.context);
+      let module_identifier = ModuleIdentifier::from(format!("missing|{ident}"));
+
+      let missing_module = MissingModule::new(
+        module_identifier,
+        format!("{ident} (missing)"),
+        format!("Failed to resolve {request_without_match_resource}"),
+      )
+      .boxed();
+      return Ok(Some(
+        ModuleFactoryResult::new(missing_module).with_empty_diagnostic(),
+      ));
+    }
+    // the resource may be replaced by plugins, e.g. `NormalModuleReplacementPlugin`
+    let user_request = if resource_data.resource != resource {
+      self.calculate_user_request(
+        &inline_loaders,
+        &resource_data,
+        match_resource_data.as_ref(),
+      )
+    } else {
+      user_request
+    };
+
     let request = if !loaders.is_empty() {
       let s = loaders
         .iter()
@@ -597,17 +609,7 @@ impl NormalModuleFactory {
 
     let file_dependency = resource_data.resource_path.clone();
 
-    let resolved_module_type = self.calculate_module_type(&resolved_module_rules);
-    let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
-    let (resolved_parser_options, resolved_generator_options) =
-      self.calculate_parser_and_generator_options(&resolved_module_rules);
-    let factory_meta = FactoryMeta {
-      side_effect_free: self
-        .calculate_side_effects(&resolved_module_rules, &resource_data)
-        .map(|side_effects| !side_effects),
-    };
-
-    let resolved_parser_and_generator = self
+    let mut resolved_parser_and_generator = self
       .plugin_driver
       .registered_parser_and_generator_builder
       .get(&resolved_module_type)
@@ -625,6 +627,10 @@ impl NormalModuleFactory {
 
         internal_error!(e)
       })?();
+    self.plugin_driver.normal_module_factory_parser(
+      &resolved_module_type,
+      resolved_parser_and_generator.as_mut(),
+    )?;
 
     let create_data = NormalModuleCreateData {
       dependency_type: data.dependency.dependency_type().clone(),
@@ -644,6 +650,7 @@ impl NormalModuleFactory {
         user_request,
         dependency.request().to_owned(),
         resolved_module_type,
+        resolved_module_layer,
         resolved_parser_and_generator,
         resolved_parser_options,
         resolved_generator_options,
@@ -673,17 +680,36 @@ impl NormalModuleFactory {
     ))
   }
 
+  fn calculate_user_request(
+    &self,
+    inline_loaders: &[ModuleRuleUseLoader],
+    resource_data: &ResourceData,
+    match_resource_data: Option<&ResourceData>,
+  ) -> String {
+    let suffix = stringify_loaders_and_resource(inline_loaders, &resource_data.resource);
+    if let Some(ResourceData { resource, .. }) = match_resource_data {
+      let mut resource = resource.to_owned();
+      resource += "!=!";
+      resource += &*suffix;
+      resource
+    } else {
+      suffix.into_owned()
+    }
+  }
+
   async fn calculate_module_rules<'a>(
     &'a self,
     resource_data: &ResourceData,
     dependency: &DependencyCategory,
     issuer: Option<&'a str>,
+    issuer_layer: Option<&'a str>,
   ) -> Result<Vec<&'a ModuleRule>> {
     let mut rules = Vec::new();
     module_rules_matcher(
       &self.options.module.rules,
       resource_data,
       issuer,
+      issuer_layer,
       dependency,
       &mut rules,
     )
@@ -762,6 +788,32 @@ impl NormalModuleFactory {
     resolved_module_type
   }
 
+  fn calculate_module_layer(
+    &self,
+    data: &ModuleFactoryCreateData,
+    module_rules: &[&ModuleRule],
+  ) -> Result<Option<ModuleLayer>> {
+    let mut resolved_module_layer = None;
+
+    for module_rule in module_rules {
+      if let Some(module_layer) = &module_rule.layer {
+        if !self.options.experiments.layers {
+          return Err(internal_error!(
+            "'Rule.layer' is only allowed when 'experiments.layers' is enabled"
+          ));
+        }
+        resolved_module_layer = Some(module_layer);
+      };
+    }
+
+    // Modules inherit the layer of the issuer if no rule assigns one
+    Ok(
+      resolved_module_layer
+        .or(data.issuer_layer.as_ref())
+        .cloned(),
+    )
+  }
+
   async fn factorize(
     &self,
     data: &mut ModuleFactoryCreateData,
@@ -798,7 +850,7 @@ impl NormalModuleFactory {
 /// Rspan aka `Rspack span`, just avoiding conflict with span in other crate
 /// ## Warning
 /// RSpan is zero based, `Span` of `swc` is 1 based. see https://swc-css.netlify.app/?code=eJzLzC3ILypRSFRIK8rPVVAvSS0u0csqVgcAZaoIKg
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct ErrorSpan {
   pub start: u32,
   pub end: u32,
@@ -1,3 +1,5 @@
+use rspack_hash::HashFunction;
+
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
@@ -20,14 +22,16 @@ pub struct FileSystemCacheOptions {
   pub cache_directory: String,
   /// Locations for the cache. Defaults to path.resolve(cache.cacheDirectory, cache.name)
   pub cache_location: String,
-  // Algorithm used the hash generation.
-  // hash_algorithm: "md4" | "md5" | ...,
-  // Compression type used for the cache files.
-  // compression: false | "gzip" | "brotli",
-  // Dnotes the time period after which the cache storing should happen.
-  // idle_timeout: number,
-  // idle_timeout_for_initial_store: number,
-  // idle_timeout_after_large_changes": number,
+  /// Algorithm used the hash generation.
+  pub hash_algorithm: HashFunction,
+  /// Compression type used for the cache files.
+  pub compression: CacheCompression,
+  /// Time in milliseconds after the compiler becomes idle when the cache storing should happen.
+  pub idle_timeout: u32,
+  /// Same as `idle_timeout`, but used for the initial store.
+  pub idle_timeout_for_initial_store: u32,
+  /// Same as `idle_timeout`, but used when the last build took much longer than storing the cache.
+  pub idle_timeout_after_large_changes: u32,
   /// Name for the cache
   pub name: String,
   // Store strategy
@@ -36,10 +40,24 @@ pub struct FileSystemCacheOptions {
   pub version: String,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheCompression {
+  #[default]
+  None,
+  Gzip,
+  Brotli,
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum CacheOptions {
   #[default]
   Disabled,
   Memory(MemoryCacheOptions),
+  /// Persistent cache saved as pack files under the cache location.
+  ///
+  /// Build results are written to disk when all the dependencies of the module and its async
+  /// blocks are registered by [crate::cache::register_dependency], and code generation results
+  /// when they have no chunk init fragments. Others are kept in memory the same as [CacheOptions::Memory], so are the
+  /// chunk assets which are only reused by rebuilds in watch mode.
   FileSystem(FileSystemCacheOptions),
 }
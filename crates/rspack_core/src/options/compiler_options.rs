@@ -21,6 +21,9 @@ pub struct CompilerOptions {
   pub cache: CacheOptions,
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
+  /// The JSON of the `amd` option which is exposed as `__webpack_require__.amdO`,
+  /// `None` disables the AMD support
+  pub amd: Option<String>,
   pub optimization: Optimization,
   pub profile: bool,
   pub builtins: Builtins,
@@ -1,6 +1,6 @@
 use indexmap::IndexMap;
 
-use crate::{ChunkLoading, DependencyId, EntryOptions, Filename, PublicPath};
+use crate::{ChunkLoading, DependencyId, EntryOptions, Filename, ModuleLayer, PublicPath};
 
 pub type Entry = IndexMap<String, EntryData>;
 
@@ -15,6 +15,7 @@ pub struct EntryDescription {
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
+  pub layer: Option<ModuleLayer>,
 }
 
 #[derive(Debug, Default)]
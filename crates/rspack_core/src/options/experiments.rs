@@ -23,6 +23,11 @@ impl IncrementalRebuildMakeState {
 
 #[derive(Debug, Default)]
 pub struct RspackFuture {
+  /// Use [oxc_resolver] instead of [nodejs_resolver].
+  ///
+  /// Only [oxc_resolver] reads through the input filesystem of the compiler, [nodejs_resolver]
+  /// always reads from the disk and can't resolve files that only exist in a custom
+  /// input filesystem, e.g. a memory filesystem.
   pub new_resolver: bool,
   pub new_treeshaking: bool,
   pub disable_transform_by_default: bool,
@@ -32,8 +37,10 @@ pub struct RspackFuture {
 pub struct Experiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: IncrementalRebuild,
+  pub sync_web_assembly: bool,
   pub async_web_assembly: bool,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  pub layers: bool,
   pub rspack_future: RspackFuture,
 }
@@ -7,8 +7,10 @@ use rspack_error::Result;
 use rspack_regex::RspackRegex;
 use rspack_util::{try_all, try_any};
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::{Filename, ModuleType, PublicPath, Resolve};
+use crate::needs_refactor::DEFAULT_WORKER_SYNTAX;
+use crate::{Filename, ModuleLayer, ModuleType, PublicPath, Resolve};
 
 #[derive(Debug)]
 pub struct ParserOptionsByModuleType(HashMap<ModuleType, ParserOptions>);
@@ -26,6 +28,7 @@ impl ParserOptionsByModuleType {
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum ParserOptions {
   Asset(AssetParserOptions),
   Javascript(JavascriptParserOptions),
@@ -45,7 +48,13 @@ macro_rules! get_parser_option {
 
 impl ParserOptions {
   get_parser_option!(get_asset, Asset, Asset, AssetParserOptions);
-  get_parser_option!(get_javascript, Javascript, Js, JavascriptParserOptions);
+
+  pub fn get_javascript(&self, module_type: &ModuleType) -> Option<&JavascriptParserOptions> {
+    match self {
+      Self::Javascript(value) if module_type.is_js_like() => Some(value),
+      _ => None,
+    }
+  }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -63,7 +72,7 @@ impl From<&str> for DynamicImportMode {
       "weak" => DynamicImportMode::Weak,
       "eager" => DynamicImportMode::Eager,
       "lazy" => DynamicImportMode::Lazy,
-      "lazyOnce" => DynamicImportMode::LazyOnce,
+      "lazy-once" => DynamicImportMode::LazyOnce,
       _ => {
         // TODO: warning
         DynamicImportMode::default()
@@ -72,9 +81,113 @@ impl From<&str> for DynamicImportMode {
   }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JavascriptParserUrl {
+  #[default]
+  Enable,
+  Disable,
+  Relative,
+}
+
+impl From<&str> for JavascriptParserUrl {
+  fn from(value: &str) -> Self {
+    match value {
+      "false" => Self::Disable,
+      "relative" => Self::Relative,
+      _ => Self::Enable,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportPresenceMode {
+  None,
+  Warn,
+  Auto,
+  Error,
+}
+
+impl From<&str> for ExportPresenceMode {
+  fn from(value: &str) -> Self {
+    match value {
+      "false" => Self::None,
+      "warn" => Self::Warn,
+      "error" => Self::Error,
+      _ => Self::Auto,
+    }
+  }
+}
+
+impl ExportPresenceMode {
+  /// `Auto` is an error in strict harmony modules (`.mjs`, `"type": "module"`) and a warning otherwise.
+  pub fn get_effective(&self, strict_harmony_module: bool) -> Self {
+    match self {
+      Self::Auto if strict_harmony_module => Self::Error,
+      Self::Auto => Self::Warn,
+      _ => *self,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
 pub struct JavascriptParserOptions {
   pub dynamic_import_mode: DynamicImportMode,
+  pub url: JavascriptParserUrl,
+  /// Syntaxes treated as workers, e.g. `Worker` or `Worker from worker_threads`, empty to disable.
+  pub worker: Vec<String>,
+  pub import_meta: bool,
+  pub expr_context_critical: bool,
+  pub unknown_context_critical: bool,
+  pub wrapped_context_critical: bool,
+  pub wrapped_context_reg_exp: RspackRegex,
+  pub strict_export_presence: bool,
+  pub exports_presence: Option<ExportPresenceMode>,
+  pub import_exports_presence: Option<ExportPresenceMode>,
+  pub reexport_exports_presence: Option<ExportPresenceMode>,
+  pub commonjs_magic_comments: bool,
+}
+
+impl Default for JavascriptParserOptions {
+  fn default() -> Self {
+    Self {
+      dynamic_import_mode: DynamicImportMode::default(),
+      url: JavascriptParserUrl::default(),
+      worker: DEFAULT_WORKER_SYNTAX
+        .iter()
+        .map(|s| s.to_string())
+        .collect(),
+      import_meta: true,
+      expr_context_critical: true,
+      unknown_context_critical: true,
+      wrapped_context_critical: false,
+      wrapped_context_reg_exp: RspackRegex::new(".*").expect("should be valid regexp"),
+      strict_export_presence: false,
+      exports_presence: None,
+      import_exports_presence: None,
+      reexport_exports_presence: None,
+      commonjs_magic_comments: false,
+    }
+  }
+}
+
+impl JavascriptParserOptions {
+  fn resolve_export_presence(&self, specific: Option<ExportPresenceMode>) -> ExportPresenceMode {
+    specific.or(self.exports_presence).unwrap_or({
+      if self.strict_export_presence {
+        ExportPresenceMode::Error
+      } else {
+        ExportPresenceMode::Auto
+      }
+    })
+  }
+
+  pub fn import_export_presence_mode(&self) -> ExportPresenceMode {
+    self.resolve_export_presence(self.import_exports_presence)
+  }
+
+  pub fn reexport_export_presence_mode(&self) -> ExportPresenceMode {
+    self.resolve_export_presence(self.reexport_exports_presence)
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -334,12 +447,16 @@ pub struct ModuleRule {
   pub resource_fragment: Option<RuleSetCondition>,
   pub dependency: Option<RuleSetCondition>,
   pub issuer: Option<RuleSetCondition>,
+  /// A condition matcher against the layer of the issuer.
+  pub issuer_layer: Option<RuleSetCondition>,
   pub scheme: Option<RuleSetCondition>,
   pub mimetype: Option<RuleSetCondition>,
   pub description_data: Option<DescriptionData>,
   pub side_effects: Option<bool>,
   /// The `ModuleType` to use for the matched resource.
   pub r#type: Option<ModuleType>,
+  /// The layer of the matched module, requires `experiments.layers`.
+  pub layer: Option<ModuleLayer>,
   #[derivative(Debug(format_with = "fmt_use"))]
   pub r#use: ModuleRuleUse,
   pub parser: Option<ParserOptions>,
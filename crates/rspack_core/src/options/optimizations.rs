@@ -128,6 +128,10 @@ pub struct Optimization {
   pub used_exports: UsedExportsOption,
   pub inner_graph: bool,
   pub mangle_exports: MangleExportsOption,
+  /// Only takes effect with `experiments.rspackFuture.newTreeshaking`, and is ignored when
+  /// `experiments.incrementalRebuild.make` is enabled, which is a warning only if it's set
+  /// explicitly.
+  pub concatenate_modules: bool,
 }
 
 pub static DEFAULT_DELIMITER: &str = "~";
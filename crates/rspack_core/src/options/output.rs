@@ -7,19 +7,20 @@ use std::{
 use derivative::Derivative;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
+use rspack_error::internal_error;
 use rspack_hash::RspackHash;
 pub use rspack_hash::{HashDigest, HashFunction, HashSalt};
 use sugar_path::SugarPath;
 
 use crate::{
   parse_resource, AssetInfo, Chunk, ChunkGraph, ChunkGroupByUkey, ChunkKind, Compilation, Module,
-  ResourceParsedData, RuntimeSpec,
+  ResourceParsedData, RuleSetCondition, RuntimeSpec,
 };
 
 #[derive(Debug)]
 pub struct OutputOptions {
   pub path: PathBuf,
-  pub clean: bool,
+  pub clean: Option<CleanOptions>,
   pub public_path: PublicPath,
   pub asset_module_filename: Filename,
   pub wasm_loading: WasmLoading,
@@ -55,6 +56,14 @@ pub struct OutputOptions {
   pub script_type: String,
 }
 
+#[derive(Debug, Default)]
+pub struct CleanOptions {
+  /// Files matched are kept, the condition is tested with the path relative to the output path.
+  pub keep: Option<RuleSetCondition>,
+  /// Log the files that would be removed instead of removing them.
+  pub dry: bool,
+}
+
 impl From<&OutputOptions> for RspackHash {
   fn from(value: &OutputOptions) -> Self {
     Self::with_salt(&value.hash_function, &value.hash_salt)
@@ -72,11 +81,13 @@ pub enum ChunkLoading {
   Disable,
 }
 
-impl From<&str> for ChunkLoading {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for ChunkLoading {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "false" => ChunkLoading::Disable,
-      v => ChunkLoading::Enable(v.into()),
+      "false" => Ok(ChunkLoading::Disable),
+      v => Ok(ChunkLoading::Enable(v.try_into()?)),
     }
   }
 }
@@ -91,15 +102,19 @@ pub enum ChunkLoadingType {
   // TODO: Custom
 }
 
-impl From<&str> for ChunkLoadingType {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for ChunkLoadingType {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "jsonp" => Self::Jsonp,
-      "import-scripts" => Self::ImportScripts,
-      "require" => Self::Require,
-      "async-node" => Self::AsyncNode,
-      "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      "jsonp" => Ok(Self::Jsonp),
+      "import-scripts" => Ok(Self::ImportScripts),
+      "require" => Ok(Self::Require),
+      "async-node" => Ok(Self::AsyncNode),
+      "import" => Ok(Self::Import),
+      _ => Err(internal_error!(
+        "custom chunkLoading \"{value}\" is not supported yet"
+      )),
     }
   }
 }
@@ -110,11 +125,13 @@ pub enum WasmLoading {
   Disable,
 }
 
-impl From<&str> for WasmLoading {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for WasmLoading {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "false" => Self::Disable,
-      v => Self::Enable(v.into()),
+      "false" => Ok(Self::Disable),
+      v => Ok(Self::Enable(v.try_into()?)),
     }
   }
 }
@@ -126,13 +143,17 @@ pub enum WasmLoadingType {
   AsyncNodeModule,
 }
 
-impl From<&str> for WasmLoadingType {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for WasmLoadingType {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "fetch" => Self::Fetch,
-      "async-node" => Self::AsyncNode,
-      "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      "fetch" => Ok(Self::Fetch),
+      "async-node" => Ok(Self::AsyncNode),
+      "async-node-module" => Ok(Self::AsyncNodeModule),
+      _ => Err(internal_error!(
+        "custom wasmLoading \"{value}\" is not supported yet"
+      )),
     }
   }
 }
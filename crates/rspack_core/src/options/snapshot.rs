@@ -1,9 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+#[derive(Debug, Clone)]
+pub enum SnapshotPathMatcher {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPathMatcher {
+  pub fn test(&self, path: &Path) -> bool {
+    match self {
+      Self::String(s) => path.starts_with(s),
+      Self::Regexp(r) => r.test(&path.to_string_lossy()),
+    }
+  }
+
+  /// Get the directory of the innermost package which contains the path, packages nested in
+  /// `node_modules` of a package are items of their own, the same as `getManagedItem` of webpack.
+  ///
+  /// A string matcher is the parent directory of packages, a regexp matcher uses its first capture group,
+  /// or the whole match if there is no capture group, as the parent directory of packages.
+  pub fn get_managed_item(&self, path: &Path) -> Option<PathBuf> {
+    let managed_path = match self {
+      Self::String(s) => PathBuf::from(s),
+      Self::Regexp(r) => {
+        let path = path.to_string_lossy();
+        let range = r.find_group(&path, 1).or_else(|| r.find_group(&path, 0))?;
+        PathBuf::from(&path[..range.end])
+      }
+    };
+    let mut components = path
+      .strip_prefix(&managed_path)
+      .ok()?
+      .components()
+      .peekable();
+    let mut item = managed_path;
+    loop {
+      let name = components.next()?;
+      item.push(name);
+      if name.as_os_str().to_string_lossy().starts_with('@') {
+        item.push(components.next()?);
+      }
+      if components
+        .peek()
+        .is_some_and(|next| next.as_os_str() == "node_modules")
+      {
+        item.push(components.next()?);
+        continue;
+      }
+      return Some(item);
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +70,63 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// An array of paths that are managed by a package manager and contain a version or a hash in their paths.
+  pub immutable_paths: Vec<SnapshotPathMatcher>,
+  /// An array of paths that are managed by a package manager.
+  pub managed_paths: Vec<SnapshotPathMatcher>,
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn get_managed_item() {
+    let matcher = SnapshotPathMatcher::String("/project/node_modules".into());
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/react/index.js")),
+      Some(PathBuf::from("/project/node_modules/react"))
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/@scope/pkg/lib/a.js")),
+      Some(PathBuf::from("/project/node_modules/@scope/pkg"))
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/src/index.js")),
+      None
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/a/node_modules/b/x.js")),
+      Some(PathBuf::from("/project/node_modules/a/node_modules/b"))
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new(
+        "/project/node_modules/@scope/a/node_modules/@scope/b/lib/x.js"
+      )),
+      Some(PathBuf::from(
+        "/project/node_modules/@scope/a/node_modules/@scope/b"
+      ))
+    );
+    // the `node_modules` directory itself is not a package
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/a/node_modules")),
+      None
+    );
+
+    let matcher = SnapshotPathMatcher::Regexp(
+      RspackRegex::new("^(.+?[\\\\/]node_modules[\\\\/])").expect("should be valid regexp"),
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/react/index.js")),
+      Some(PathBuf::from("/project/node_modules/react"))
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/node_modules/a/node_modules/b/x.js")),
+      Some(PathBuf::from("/project/node_modules/a/node_modules/b"))
+    );
+    assert_eq!(
+      matcher.get_managed_item(Path::new("/project/src/index.js")),
+      None
+    );
+  }
 }
@@ -1,15 +1,16 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{any::Any, collections::HashMap, fmt::Debug};
 
 use rkyv::AlignedVec;
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_loader_runner::{AdditionalData, ResourceData};
 use rspack_sources::BoxSource;
+use rspack_util::ext::AsAny;
 
 use crate::{
   tree_shaking::visitor::OptimizeAnalyzeResult, AsyncDependenciesBlock, BoxDependency,
   BuildExtraDataType, BuildInfo, BuildMeta, CodeGenerationData, Compilation, CompilerOptions,
-  DependencyTemplate, GeneratorOptions, Module, ModuleDependency, ModuleIdentifier, ModuleType,
-  ParserOptions, RuntimeGlobals, RuntimeSpec, SourceType,
+  ConcatenationScope, DependencyTemplate, GeneratorOptions, Module, ModuleDependency,
+  ModuleIdentifier, ModuleType, ParserOptions, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug)]
@@ -44,9 +45,10 @@ pub struct GenerateContext<'a> {
   pub data: &'a mut CodeGenerationData,
   pub requested_source_type: SourceType,
   pub runtime: Option<&'a RuntimeSpec>,
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
-pub trait ParserAndGenerator: Send + Sync + Debug {
+pub trait ParserAndGenerator: Send + Sync + Debug + AsAny {
   /// The source types that the generator can generate (the source types you can make requests for)
   fn source_types(&self) -> &[SourceType];
   /// Parse the source and return the dependencies and the ast or source
@@ -65,3 +67,13 @@ pub trait ParserAndGenerator: Send + Sync + Debug {
   /// Resume parser&generator data from cache
   fn resume(&mut self, _extra_data: &HashMap<BuildExtraDataType, AlignedVec>) {}
 }
+
+impl dyn ParserAndGenerator + '_ {
+  pub fn downcast_ref<T: ParserAndGenerator + Any>(&self) -> Option<&T> {
+    self.as_any().downcast_ref::<T>()
+  }
+
+  pub fn downcast_mut<T: ParserAndGenerator + Any>(&mut self) -> Option<&mut T> {
+    self.as_any_mut().downcast_mut::<T>()
+  }
+}
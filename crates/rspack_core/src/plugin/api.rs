@@ -9,7 +9,8 @@ use rspack_sources::BoxSource;
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, AssetEmittedArgs,
   AssetInfo, BoxLoader, BoxModule, ChunkAssetArgs, ChunkHashArgs, CodeGenerationResults,
-  Compilation, CompilationArgs, CompilationParams, CompilerOptions, ContentHashArgs, DoneArgs,
+  Compilation, CompilationArgs, CompilationParams, CompilerOptions, ContentHashArgs, Context,
+  ContextModuleAfterResolveArgs, ContextModuleBeforeResolveArgs, CssChunkHashArgs, DoneArgs,
   FactorizeArgs, JsChunkHashArgs, MakeParam, Module, ModuleFactoryResult, ModuleIdentifier,
   ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   NormalModuleCreateData, OptimizeChunksArgs, ParserAndGenerator, PluginContext, ProcessAssetsArgs,
@@ -42,6 +43,7 @@ pub type PluginRenderModuleContentOutput<'a> = Result<RenderModuleContentArgs<'a
 pub type PluginRenderStartupHookOutput = Result<Option<BoxSource>>;
 pub type PluginRenderHookOutput = Result<Option<BoxSource>>;
 pub type PluginJsChunkHashHookOutput = Result<()>;
+pub type PluginCssChunkHashHookOutput = Result<()>;
 pub type PluginShouldEmitHookOutput = Result<Option<bool>>;
 
 #[async_trait::async_trait]
@@ -119,7 +121,7 @@ pub trait Plugin: Debug + Send + Sync {
   async fn after_resolve(
     &self,
     _ctx: PluginContext,
-    _args: &NormalModuleAfterResolveArgs,
+    _args: &mut NormalModuleAfterResolveArgs,
   ) -> PluginNormalModuleFactoryAfterResolveOutput {
     Ok(None)
   }
@@ -127,11 +129,19 @@ pub trait Plugin: Debug + Send + Sync {
   async fn context_module_before_resolve(
     &self,
     _ctx: PluginContext,
-    _args: &mut NormalModuleBeforeResolveArgs,
+    _args: &mut ContextModuleBeforeResolveArgs,
   ) -> PluginNormalModuleFactoryBeforeResolveOutput {
     Ok(None)
   }
 
+  async fn context_module_after_resolve(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    Ok(None)
+  }
+
   async fn normal_module_factory_create_module(
     &self,
     _ctx: PluginContext,
@@ -149,6 +159,16 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(module)
   }
 
+  /// Called after the parser and generator of a normal module is created, plugins can downcast
+  /// it to tap into a specific parser, e.g. to register javascript parser plugins
+  fn normal_module_factory_parser(
+    &self,
+    _module_type: &ModuleType,
+    _parser: &mut dyn ParserAndGenerator,
+  ) -> Result<()> {
+    Ok(())
+  }
+
   async fn normal_module_factory_resolve_for_scheme(
     &self,
     _ctx: PluginContext,
@@ -157,6 +177,17 @@ pub trait Plugin: Debug + Send + Sync {
     Ok((args, false))
   }
 
+  /// Resolve a request issued by a module with scheme, e.g. a relative import in a `https:` module,
+  /// the `context` is the directory of the issuer with scheme.
+  async fn normal_module_factory_resolve_in_scheme(
+    &self,
+    _ctx: PluginContext,
+    args: ResourceData,
+    _context: &Context,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    Ok((args, false))
+  }
+
   async fn content_hash(
     &self,
     _ctx: PluginContext,
@@ -227,6 +258,15 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  // CssModulesPlugin hook
+  fn css_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut CssChunkHashArgs,
+  ) -> PluginCssChunkHashHookOutput {
+    Ok(())
+  }
+
   fn additional_chunk_runtime_requirements(
     &self,
     _ctx: PluginContext,
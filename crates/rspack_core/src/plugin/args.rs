@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use rspack_hash::RspackHash;
 use rspack_loader_runner::ResourceData;
+use rspack_regex::RspackRegex;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -96,7 +97,7 @@ pub struct NormalModuleBeforeResolveArgs {
   pub request: String,
   pub context: String,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct NormalModuleAfterResolveArgs<'a> {
   pub request: &'a str,
   pub context: &'a str,
@@ -104,6 +105,25 @@ pub struct NormalModuleAfterResolveArgs<'a> {
   pub context_dependencies: &'a HashSet<PathBuf>,
   pub missing_dependencies: &'a HashSet<PathBuf>,
   pub factory_meta: &'a FactoryMeta,
+  /// The resolved resource, plugins can replace it before the module is created
+  pub resource_resolve_data: &'a mut ResourceData,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContextModuleBeforeResolveArgs {
+  pub request: String,
+  pub context: String,
+  pub recursive: bool,
+  pub reg_exp: Option<RspackRegex>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContextModuleAfterResolveArgs {
+  pub request: String,
+  /// The resolved context directory
+  pub resource: String,
+  pub recursive: bool,
+  pub reg_exp: Option<RspackRegex>,
 }
 
 #[derive(Debug)]
@@ -253,6 +273,22 @@ impl<'me> JsChunkHashArgs<'me> {
   }
 }
 
+pub struct CssChunkHashArgs<'a> {
+  pub chunk_ukey: &'a ChunkUkey,
+  pub compilation: &'a Compilation,
+  pub hasher: &'a mut RspackHash,
+}
+
+impl<'me> CssChunkHashArgs<'me> {
+  pub fn chunk(&self) -> &Chunk {
+    self
+      .compilation
+      .chunk_by_ukey
+      .get(self.chunk_ukey)
+      .expect("chunk should exist in chunk_by_ukey")
+  }
+}
+
 pub struct CompilationParams {
   pub normal_module_factory: Arc<NormalModuleFactory>,
   pub context_module_factory: Arc<ContextModuleFactory>,
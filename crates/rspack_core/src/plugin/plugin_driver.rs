@@ -12,19 +12,21 @@ use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, ApplyContext,
   AssetEmittedArgs, BoxLoader, BoxModule, BoxedParserAndGeneratorBuilder, Chunk, ChunkAssetArgs,
   ChunkContentHash, ChunkHashArgs, CodeGenerationResults, Compilation, CompilationArgs,
-  CompilationParams, CompilerOptions, Content, ContentHashArgs, DoneArgs, FactorizeArgs,
-  JsChunkHashArgs, MakeParam, Module, ModuleIdentifier, ModuleType, NormalModule,
+  CompilationParams, CompilerOptions, Content, ContentHashArgs, Context,
+  ContextModuleAfterResolveArgs, ContextModuleBeforeResolveArgs, CssChunkHashArgs, DoneArgs,
+  FactorizeArgs, JsChunkHashArgs, MakeParam, Module, ModuleIdentifier, ModuleType, NormalModule,
   NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleCreateData,
-  OptimizeChunksArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  OptimizeChunksArgs, ParserAndGenerator, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
   PluginAdditionalModuleRequirementsOutput, PluginBuildEndHookOutput, PluginChunkHashHookOutput,
-  PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput,
-  PluginJsChunkHashHookOutput, PluginMakeHookOutput, PluginNormalModuleFactoryAfterResolveOutput,
-  PluginNormalModuleFactoryBeforeResolveOutput, PluginNormalModuleFactoryCreateModuleHookOutput,
-  PluginNormalModuleFactoryModuleHookOutput, PluginProcessAssetsOutput,
-  PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
-  PluginRenderModuleContentOutput, PluginRenderStartupHookOutput, PluginThisCompilationHookOutput,
-  ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
-  RenderStartupArgs, Resolver, ResolverFactory, Stats, ThisCompilationArgs,
+  PluginCompilationHookOutput, PluginContext, PluginCssChunkHashHookOutput,
+  PluginFactorizeHookOutput, PluginJsChunkHashHookOutput, PluginMakeHookOutput,
+  PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
+  PluginNormalModuleFactoryCreateModuleHookOutput, PluginNormalModuleFactoryModuleHookOutput,
+  PluginProcessAssetsOutput, PluginRenderChunkHookOutput, PluginRenderHookOutput,
+  PluginRenderManifestHookOutput, PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
+  PluginThisCompilationHookOutput, ProcessAssetsArgs, RenderArgs, RenderChunkArgs,
+  RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs, Resolver, ResolverFactory, Stats,
+  ThisCompilationArgs,
 };
 
 pub struct PluginDriver {
@@ -303,6 +305,13 @@ impl PluginDriver {
     Ok(())
   }
 
+  pub fn css_chunk_hash(&self, mut args: CssChunkHashArgs) -> PluginCssChunkHashHookOutput {
+    for plugin in &self.plugins {
+      plugin.css_chunk_hash(PluginContext::new(), &mut args)?
+    }
+    Ok(())
+  }
+
   pub fn render_module_content<'a>(
     &'a self,
     mut args: RenderModuleContentArgs<'a>,
@@ -370,11 +379,11 @@ impl PluginDriver {
 
   pub async fn after_resolve(
     &self,
-    args: NormalModuleAfterResolveArgs<'_>,
+    args: &mut NormalModuleAfterResolveArgs<'_>,
   ) -> PluginNormalModuleFactoryAfterResolveOutput {
     for plugin in &self.plugins {
       tracing::trace!("running resolve for scheme:{}", plugin.name());
-      if let Some(data) = plugin.after_resolve(PluginContext::new(), &args).await? {
+      if let Some(data) = plugin.after_resolve(PluginContext::new(), args).await? {
         return Ok(Some(data));
       }
     }
@@ -382,7 +391,7 @@ impl PluginDriver {
   }
   pub async fn context_module_before_resolve(
     &self,
-    args: &mut NormalModuleBeforeResolveArgs,
+    args: &mut ContextModuleBeforeResolveArgs,
   ) -> PluginNormalModuleFactoryBeforeResolveOutput {
     for plugin in &self.plugins {
       tracing::trace!("running resolve for scheme:{}", plugin.name());
@@ -395,6 +404,32 @@ impl PluginDriver {
     }
     Ok(None)
   }
+  pub async fn context_module_after_resolve(
+    &self,
+    args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running context module after resolve:{}", plugin.name());
+      if let Some(data) = plugin
+        .context_module_after_resolve(PluginContext::new(), args)
+        .await?
+      {
+        return Ok(Some(data));
+      }
+    }
+    Ok(None)
+  }
+
+  pub fn normal_module_factory_parser(
+    &self,
+    module_type: &ModuleType,
+    parser: &mut dyn ParserAndGenerator,
+  ) -> Result<()> {
+    for plugin in &self.plugins {
+      plugin.normal_module_factory_parser(module_type, parser)?;
+    }
+    Ok(())
+  }
 
   pub async fn normal_module_factory_resolve_for_scheme(
     &self,
@@ -415,6 +450,27 @@ impl PluginDriver {
     Ok(args)
   }
 
+  /// Returns `None` when no plugin handles the request, it should be resolved normally then.
+  pub async fn normal_module_factory_resolve_in_scheme(
+    &self,
+    args: ResourceData,
+    context: &Context,
+  ) -> Result<Option<ResourceData>> {
+    let mut args = args;
+    for plugin in &self.plugins {
+      tracing::trace!("running resolve in scheme:{}", plugin.name());
+      let (ret, stop) = plugin
+        .normal_module_factory_resolve_in_scheme(PluginContext::new(), args, context)
+        .await?;
+      if stop {
+        return Ok(Some(ret));
+      } else {
+        args = ret;
+      }
+    }
+    Ok(None)
+  }
+
   #[instrument(name = "plugin:additional_chunk_runtime_requirements", skip_all)]
   pub fn additional_chunk_runtime_requirements(
     &self,
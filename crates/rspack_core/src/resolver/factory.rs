@@ -1,6 +1,7 @@
 use std::{hash::BuildHasherDefault, sync::Arc};
 
 use dashmap::DashMap;
+use rspack_fs::{InputFileSystem, NativeFileSystem};
 use rustc_hash::FxHasher;
 
 use super::resolver_impl::Resolver;
@@ -26,7 +27,7 @@ pub struct ResolverFactory {
 
 impl Default for ResolverFactory {
   fn default() -> Self {
-    Self::new(false, Resolve::default())
+    Self::new(false, Resolve::default(), Arc::new(NativeFileSystem))
   }
 }
 
@@ -35,10 +36,14 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
-  pub fn new(new_resolver: bool, options: Resolve) -> Self {
+  pub fn new(
+    new_resolver: bool,
+    options: Resolve,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(new_resolver, options),
+      resolver: Resolver::new(new_resolver, options, input_filesystem),
       resolvers: Default::default(),
     }
   }
@@ -1,10 +1,11 @@
 use std::{
-  fmt,
+  fmt, io,
   path::{Path, PathBuf},
   sync::Arc,
 };
 
 use rspack_error::{internal_error, Error, InternalError, Severity, TraceableError};
+use rspack_fs::{InputFileSystem, NativeFileSystem};
 use rspack_loader_runner::DescriptionData;
 use sugar_path::SugarPath;
 
@@ -77,19 +78,69 @@ impl<'a> ResolveInnerOptions<'a> {
   }
 }
 
+/// Reads files through the [InputFileSystem] of the compiler in [oxc_resolver].
+#[derive(Debug)]
+pub struct ResolverFileSystem(Arc<dyn InputFileSystem>);
+
+impl Default for ResolverFileSystem {
+  fn default() -> Self {
+    Self(Arc::new(NativeFileSystem))
+  }
+}
+
+fn into_io_error(error: rspack_fs::Error) -> io::Error {
+  match error {
+    rspack_fs::Error::Io(error) => error,
+  }
+}
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    let content = self.0.read(path).map_err(into_io_error)?;
+    String::from_utf8(content).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    let metadata = self.0.stat(path).map_err(into_io_error)?;
+    Ok(oxc_resolver::FileMetadata::new(
+      metadata.is_file,
+      metadata.is_directory,
+      metadata.is_symlink,
+    ))
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    let metadata = self.0.lstat(path).map_err(into_io_error)?;
+    Ok(oxc_resolver::FileMetadata::new(
+      metadata.is_file,
+      metadata.is_directory,
+      metadata.is_symlink,
+    ))
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.canonicalize(path).map_err(into_io_error)
+  }
+}
+
 /// Proxy to [nodejs_resolver::Resolver] or [oxc_resolver::Resolver]
 ///
 /// Internal caches are shared.
+/// Only [oxc_resolver] reads files through the [InputFileSystem], [nodejs_resolver] always reads from the disk.
 #[derive(Debug)]
 pub enum Resolver {
   NodejsResolver(nodejs_resolver::Resolver, Arc<nodejs_resolver::Cache>),
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(new_resolver: bool, options: Resolve) -> Self {
+  pub fn new(
+    new_resolver: bool,
+    options: Resolve,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
     if new_resolver {
-      Self::new_oxc_resolver(options)
+      Self::new_oxc_resolver(options, input_filesystem)
     } else {
       Self::new_nodejs_resolver(options)
     }
@@ -103,9 +154,12 @@ impl Resolver {
     Self::NodejsResolver(resolver, cache)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, input_filesystem: Arc<dyn InputFileSystem>) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(
+      ResolverFileSystem(input_filesystem),
+      options,
+    );
     Self::OxcResolver(resolver)
   }
 
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use swc_core::ecma::atoms::JsWord;
 
 bitflags! {
@@ -228,6 +229,51 @@ bitflags! {
     const SHARE_SCOPE_MAP = 1 << 52;
 
     const INITIALIZE_SHARING = 1 << 53;
+
+    /**
+     * the chunk prefetch function
+     */
+    const PREFETCH_CHUNK = 1 << 54;
+
+    /**
+     * an object with handlers to prefetch a chunk
+     */
+    const PREFETCH_CHUNK_HANDLERS = 1 << 55;
+
+    /**
+     * the chunk preload function
+     */
+    const PRELOAD_CHUNK = 1 << 56;
+
+    /**
+     * an object with handlers to preload a chunk
+     */
+    const PRELOAD_CHUNK_HANDLERS = 1 << 57;
+
+    /**
+     * the AMD define function
+     */
+    const AMD_DEFINE = 1 << 58;
+
+    /**
+     * the AMD options
+     */
+    const AMD_OPTIONS = 1 << 59;
+
+    /**
+     * the handler for errors which are not handled by the async dependency block
+     */
+    const UNCAUGHT_ERROR_HANDLER = 1 << 60;
+
+    /**
+     * an object with all relative url properties, used by `new URL()` in `url: "relative"` mode
+     */
+    const RELATIVE_URL = 1 << 61;
+
+    /**
+     * an object containing all installed WebAssembly.Instance export objects keyed by module id
+     */
+    const WASM_INSTANCES = 1 << 62;
   }
 }
 
@@ -302,6 +348,15 @@ impl RuntimeGlobals {
       R::CURRENT_REMOTE_GET_SCOPE => "__webpack_require__.R",
       R::SHARE_SCOPE_MAP => "__webpack_require__.S",
       R::INITIALIZE_SHARING => "__webpack_require__.I",
+      R::PREFETCH_CHUNK => "__webpack_require__.E",
+      R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
+      R::PRELOAD_CHUNK => "__webpack_require__.G",
+      R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
+      R::AMD_DEFINE => "__webpack_require__.amdD",
+      R::AMD_OPTIONS => "__webpack_require__.amdO",
+      R::UNCAUGHT_ERROR_HANDLER => "__webpack_require__.oe",
+      R::RELATIVE_URL => "__webpack_require__.U",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
@@ -325,6 +380,21 @@ impl RuntimeGlobals {
   }
 }
 
+// bitflags 1.3 has no serde support without the feature, saved as the bits
+impl Serialize for RuntimeGlobals {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.bits().serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for RuntimeGlobals {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let bits = u64::deserialize(deserializer)?;
+    Self::from_bits(bits)
+      .ok_or_else(|| serde::de::Error::custom(format!("unknown runtime globals {bits:#x}")))
+  }
+}
+
 impl From<RuntimeGlobals> for JsWord {
   fn from(value: RuntimeGlobals) -> Self {
     value.name().into()
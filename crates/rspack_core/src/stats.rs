@@ -452,8 +452,21 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
+    let modules = nested_modules
+      .then(|| -> Result<_> {
+        let Some(concatenated_module) = module.as_concatenated_module() else {
+          return Ok(vec![]);
+        };
+        let mut modules = concatenated_module
+          .modules()
+          .iter()
+          .filter_map(|m| self.compilation.module_graph.module_by_identifier(m))
+          .map(|m| self.get_module(m, reasons.is_some(), module_assets, false, source))
+          .collect::<Result<Vec<_>>>()?;
+        Self::sort_modules(&mut modules);
+        Ok(modules)
+      })
+      .transpose()?;
     let profile = if let Some(p) = mgm.get_profile()
       && let Some(factory) = p.factory.duration()
       && let Some(integration) = p.integration.duration()
@@ -492,6 +505,7 @@ impl Stats<'_> {
       modules,
       source: source.then(|| module.original_source()).flatten(),
       profile,
+      optimization_bailout: mgm.optimization_bailout.clone(),
     })
   }
 
@@ -538,6 +552,7 @@ impl Stats<'_> {
       modules: None,
       source: None,
       profile: None,
+      optimization_bailout: Vec::new(),
     })
   }
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
@@ -658,6 +673,7 @@ pub struct StatsModule<'a> {
   pub modules: Option<Vec<StatsModule<'a>>>,
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
+  pub optimization_bailout: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -523,6 +523,7 @@ impl<'a> CodeSizeOptimizer<'a> {
               | DependencyType::RequireContext
               | DependencyType::DynamicImport
               | DependencyType::CjsRequire
+              | DependencyType::AmdRequireItem
               | DependencyType::ImportContext
               | DependencyType::ContainerExposed
               | DependencyType::ProvideModuleForShared
@@ -14,3 +14,14 @@ pub fn to_comment(str: &str) -> String {
 
   format!("/*! {} */", result)
 }
+
+#[inline]
+pub fn to_normal_comment(str: &str) -> String {
+  if str.is_empty() {
+    return String::new();
+  }
+
+  let result = COMMENT_END_REGEX.replace_all(str, "* /");
+
+  format!("/* {} */", result)
+}
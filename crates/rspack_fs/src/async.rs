@@ -2,7 +2,7 @@ use std::path::Path;
 
 use futures::future::BoxFuture;
 
-use crate::Result;
+use crate::{FileMetadata, Result};
 
 pub trait AsyncWritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -29,6 +29,21 @@ pub trait AsyncWritableFileSystem {
 
   /// Removes a directory at this path, after removing all its contents. Use carefully.
   fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>>;
+
+  /// Removes an empty directory.
+  fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>>;
+
+  /// Returns the names of the entries in a directory.
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>>;
+
+  /// Returns the metadata of a file or a directory, symbolic links are followed.
+  fn stat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>>;
+
+  /// Returns the metadata of a file, a directory or a symbolic link, symbolic links are not followed.
+  fn lstat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>>;
+
+  /// Renames a file or a directory, replacing the destination if it already exists.
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>>;
 }
 
 pub trait AsyncReadableFileSystem {
@@ -0,0 +1,154 @@
+use std::{
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+
+use crate::{Error, FileMetadata, InputFileSystem, Result};
+
+/// Errors are cached as well, the resolver asks for a lot of missing files.
+type CachedResult<T> = std::result::Result<T, (ErrorKind, String)>;
+type CacheMap<T> = DashMap<PathBuf, CachedResult<T>>;
+
+/// Caches the metadata and directory listings of an [`InputFileSystem`] until they are purged.
+///
+/// The compiler purges the files reported by the watcher before each rebuild, and everything
+/// before a fresh build. File contents are not cached, each file is read once per build and
+/// keeping the contents alive would hold the whole source tree in memory.
+#[derive(Debug)]
+pub struct CachedInputFileSystem {
+  fs: Arc<dyn InputFileSystem>,
+  read_dir_cache: CacheMap<Vec<String>>,
+  stat_cache: CacheMap<FileMetadata>,
+  lstat_cache: CacheMap<FileMetadata>,
+  read_link_cache: CacheMap<PathBuf>,
+  canonicalize_cache: CacheMap<PathBuf>,
+}
+
+impl CachedInputFileSystem {
+  pub fn new(fs: Arc<dyn InputFileSystem>) -> Self {
+    Self {
+      fs,
+      read_dir_cache: Default::default(),
+      stat_cache: Default::default(),
+      lstat_cache: Default::default(),
+      read_link_cache: Default::default(),
+      canonicalize_cache: Default::default(),
+    }
+  }
+}
+
+fn cached<T: Clone>(
+  cache: &CacheMap<T>,
+  path: &Path,
+  f: impl FnOnce(&Path) -> Result<T>,
+) -> Result<T> {
+  if let Some(result) = cache.get(path) {
+    return result
+      .clone()
+      .map_err(|(kind, message)| Error::Io(std::io::Error::new(kind, message)));
+  }
+  let result = f(path);
+  let cached_result = match &result {
+    Ok(value) => Ok(value.clone()),
+    Err(Error::Io(err)) => Err((err.kind(), err.to_string())),
+  };
+  cache.insert(path.to_path_buf(), cached_result);
+  result
+}
+
+impl InputFileSystem for CachedInputFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self.fs.read(file)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    cached(&self.read_dir_cache, dir, |dir| self.fs.read_dir(dir))
+  }
+
+  fn stat(&self, file: &Path) -> Result<FileMetadata> {
+    cached(&self.stat_cache, file, |file| self.fs.stat(file))
+  }
+
+  fn lstat(&self, file: &Path) -> Result<FileMetadata> {
+    cached(&self.lstat_cache, file, |file| self.fs.lstat(file))
+  }
+
+  fn read_link(&self, file: &Path) -> Result<PathBuf> {
+    cached(&self.read_link_cache, file, |file| self.fs.read_link(file))
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    cached(&self.canonicalize_cache, path, |path| {
+      self.fs.canonicalize(path)
+    })
+  }
+
+  fn purge(&self, files: &[PathBuf]) {
+    for file in files {
+      self.stat_cache.remove(file);
+      self.lstat_cache.remove(file);
+      self.read_link_cache.remove(file);
+      // an added or removed file changes the entries of its parent directory
+      self.read_dir_cache.remove(file);
+      if let Some(parent) = file.parent() {
+        self.read_dir_cache.remove(parent);
+      }
+    }
+    // a changed symbolic link may affect any path below it
+    self.canonicalize_cache.clear();
+    self.fs.purge(files);
+  }
+
+  fn purge_all(&self) {
+    self.read_dir_cache.clear();
+    self.stat_cache.clear();
+    self.lstat_cache.clear();
+    self.read_link_cache.clear();
+    self.canonicalize_cache.clear();
+    self.fs.purge_all();
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{MemoryFileSystem, WritableFileSystem};
+
+  #[test]
+  fn cache_until_purged() {
+    let memory_fs = MemoryFileSystem::new();
+    WritableFileSystem::create_dir_all(&memory_fs, "/src").expect("should create dir");
+    WritableFileSystem::write(&memory_fs, "/src/index.js", "a").expect("should write");
+
+    let fs = CachedInputFileSystem::new(Arc::new(memory_fs.clone()));
+    let file = Path::new("/src/index.js");
+    let missing = Path::new("/src/missing.js");
+    assert_eq!(fs.stat(file).expect("should stat").size, 1);
+    assert!(fs.stat(missing).is_err());
+    assert_eq!(
+      fs.read_dir(Path::new("/src")).expect("should read dir"),
+      vec!["index.js"]
+    );
+
+    WritableFileSystem::write(&memory_fs, file, "bb").expect("should write");
+    WritableFileSystem::write(&memory_fs, missing, "c").expect("should write");
+    assert_eq!(fs.stat(file).expect("should stat").size, 1);
+    assert!(fs.stat(missing).is_err());
+    // contents are never cached
+    assert_eq!(fs.read(file).expect("should read"), b"bb");
+
+    fs.purge(&[missing.to_path_buf()]);
+    assert!(fs.stat(missing).is_ok());
+    assert_eq!(
+      fs.read_dir(Path::new("/src")).expect("should read dir"),
+      vec!["index.js", "missing.js"]
+    );
+    assert_eq!(fs.stat(file).expect("should stat").size, 1);
+
+    fs.purge_all();
+    assert_eq!(fs.stat(file).expect("should stat").size, 2);
+  }
+}
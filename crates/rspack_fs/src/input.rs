@@ -0,0 +1,55 @@
+use std::{
+  fmt::Debug,
+  path::{Component, Path, PathBuf},
+};
+
+use crate::{FileMetadata, Result};
+
+/// The file system where the compiler reads its inputs from.
+///
+/// Unlike [`ReadableFileSystem`](crate::ReadableFileSystem), this trait is object safe,
+/// so it can be shared as `Arc<dyn InputFileSystem>` and replaced by an in-memory implementation in tests.
+pub trait InputFileSystem: Debug + Send + Sync {
+  /// Read the entire contents of a file into a bytes vector.
+  fn read(&self, file: &Path) -> Result<Vec<u8>>;
+
+  /// Returns the names of the entries in a directory.
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>>;
+
+  /// Returns the metadata of a file or a directory, symbolic links are followed.
+  fn stat(&self, file: &Path) -> Result<FileMetadata>;
+
+  /// Returns the metadata of a file, a directory or a symbolic link, symbolic links are not followed.
+  fn lstat(&self, file: &Path) -> Result<FileMetadata>;
+
+  /// Reads the target of a symbolic link.
+  fn read_link(&self, file: &Path) -> Result<PathBuf>;
+
+  /// Returns the absolute path with all intermediate components normalized and symbolic links resolved.
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+      match component {
+        Component::CurDir => {}
+        Component::ParentDir => {
+          resolved.pop();
+        }
+        _ => {
+          resolved.push(component);
+          if self.lstat(&resolved)?.is_symlink {
+            let target = self.read_link(&resolved)?;
+            resolved.pop();
+            resolved = self.canonicalize(&resolved.join(target))?;
+          }
+        }
+      }
+    }
+    Ok(resolved)
+  }
+
+  /// Drop the cached information of the given files, only cached file systems need to implement this.
+  fn purge(&self, _files: &[PathBuf]) {}
+
+  /// Drop all cached information, only cached file systems need to implement this.
+  fn purge_all(&self) {}
+}
@@ -10,6 +10,18 @@ pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 mod error;
 pub use error::{Error, Result};
 
+mod metadata;
+pub use metadata::FileMetadata;
+
+mod input;
+pub use input::InputFileSystem;
+
+mod cached;
+pub use cached::CachedInputFileSystem;
+
+mod memory;
+pub use memory::MemoryFileSystem;
+
 cfg_native! {
   mod native;
   pub use native::{NativeFileSystem};
@@ -0,0 +1,448 @@
+use std::{
+  io::ErrorKind,
+  path::{Component, Path, PathBuf},
+  sync::Arc,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+
+use crate::{
+  cfg_async, Error, FileMetadata, InputFileSystem, ReadableFileSystem, Result, WritableFileSystem,
+};
+
+#[derive(Debug, Clone)]
+enum Entry {
+  File { content: Vec<u8>, mtime_ms: u64 },
+  Directory { mtime_ms: u64 },
+}
+
+impl Entry {
+  fn metadata(&self) -> FileMetadata {
+    match self {
+      Entry::File { content, mtime_ms } => FileMetadata {
+        is_file: true,
+        is_directory: false,
+        is_symlink: false,
+        size: content.len() as u64,
+        mtime_ms: *mtime_ms,
+      },
+      Entry::Directory { mtime_ms } => FileMetadata {
+        is_file: false,
+        is_directory: true,
+        is_symlink: false,
+        size: 0,
+        mtime_ms: *mtime_ms,
+      },
+    }
+  }
+}
+
+/// A file system which keeps all files in memory, symbolic links are not supported.
+///
+/// Clones share the same files, so a dev server can keep a clone and serve the assets emitted by the compiler directly.
+/// Root directories (paths without a parent) always exist.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+  entries: Arc<DashMap<PathBuf, Entry>>,
+}
+
+impl MemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  fn entry(&self, path: &Path) -> Result<Entry> {
+    if path.parent().is_none() {
+      return Ok(Entry::Directory { mtime_ms: 0 });
+    }
+    self
+      .entries
+      .get(path)
+      .map(|entry| entry.clone())
+      .ok_or_else(|| error(ErrorKind::NotFound, "no such file or directory", path))
+  }
+
+  fn ensure_directory(&self, dir: &Path) -> Result<()> {
+    match self.entry(dir)? {
+      Entry::Directory { .. } => Ok(()),
+      Entry::File { .. } => Err(error(ErrorKind::Other, "not a directory", dir)),
+    }
+  }
+
+  fn ensure_parent(&self, path: &Path) -> Result<()> {
+    match path.parent() {
+      Some(parent) => self.ensure_directory(parent),
+      None => Err(error(ErrorKind::AlreadyExists, "file already exists", path)),
+    }
+  }
+
+  fn children(&self, dir: &Path) -> Vec<PathBuf> {
+    self
+      .entries
+      .iter()
+      .filter(|item| item.key().parent() == Some(dir))
+      .map(|item| item.key().clone())
+      .collect()
+  }
+
+  fn create_dir_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    self.ensure_parent(&dir)?;
+    if self.entries.contains_key(&dir) {
+      return Err(error(ErrorKind::AlreadyExists, "file already exists", &dir));
+    }
+    self
+      .entries
+      .insert(dir, Entry::Directory { mtime_ms: now() });
+    Ok(())
+  }
+
+  fn create_dir_all_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut ancestors = dir.ancestors().collect::<Vec<_>>();
+    ancestors.reverse();
+    for ancestor in ancestors {
+      match self.entry(ancestor) {
+        Ok(Entry::Directory { .. }) => {}
+        Ok(Entry::File { .. }) => return Err(error(ErrorKind::Other, "not a directory", ancestor)),
+        Err(_) => {
+          self
+            .entries
+            .insert(ancestor.to_path_buf(), Entry::Directory { mtime_ms: now() });
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn write_impl(&self, file: &Path, data: &[u8]) -> Result<()> {
+    let file = normalize(file);
+    self.ensure_parent(&file)?;
+    if let Ok(Entry::Directory { .. }) = self.entry(&file) {
+      return Err(error(ErrorKind::Other, "is a directory", &file));
+    }
+    self.entries.insert(
+      file,
+      Entry::File {
+        content: data.to_vec(),
+        mtime_ms: now(),
+      },
+    );
+    Ok(())
+  }
+
+  fn read_impl(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file);
+    match self.entry(&file)? {
+      Entry::File { content, .. } => Ok(content),
+      Entry::Directory { .. } => Err(error(ErrorKind::Other, "is a directory", &file)),
+    }
+  }
+
+  fn remove_file_impl(&self, file: &Path) -> Result<()> {
+    let file = normalize(file);
+    match self.entry(&file)? {
+      Entry::File { .. } => {
+        self.entries.remove(&file);
+        Ok(())
+      }
+      Entry::Directory { .. } => Err(error(ErrorKind::Other, "is a directory", &file)),
+    }
+  }
+
+  fn remove_dir_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    self.ensure_directory(&dir)?;
+    if !self.children(&dir).is_empty() {
+      return Err(error(ErrorKind::Other, "directory not empty", &dir));
+    }
+    self.entries.remove(&dir);
+    Ok(())
+  }
+
+  fn remove_dir_all_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    self.ensure_directory(&dir)?;
+    self.entries.retain(|path, _| !path.starts_with(&dir));
+    Ok(())
+  }
+
+  fn read_dir_impl(&self, dir: &Path) -> Result<Vec<String>> {
+    let dir = normalize(dir);
+    self.ensure_directory(&dir)?;
+    let mut names = self
+      .children(&dir)
+      .iter()
+      .filter_map(|path| path.file_name())
+      .map(|name| name.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+  }
+
+  fn stat_impl(&self, file: &Path) -> Result<FileMetadata> {
+    self.entry(&normalize(file)).map(|entry| entry.metadata())
+  }
+
+  fn rename_impl(&self, from: &Path, to: &Path) -> Result<()> {
+    let from = normalize(from);
+    let to = normalize(to);
+    let source = self.entry(&from)?;
+    self.ensure_parent(&to)?;
+    if from == to {
+      return Ok(());
+    }
+    match (&source, self.entry(&to)) {
+      (Entry::File { .. }, Ok(Entry::Directory { .. })) => {
+        return Err(error(ErrorKind::Other, "is a directory", &to));
+      }
+      (Entry::Directory { .. }, Ok(Entry::File { .. })) => {
+        return Err(error(ErrorKind::Other, "not a directory", &to));
+      }
+      (Entry::Directory { .. }, Ok(Entry::Directory { .. })) if !self.children(&to).is_empty() => {
+        return Err(error(ErrorKind::Other, "directory not empty", &to));
+      }
+      (Entry::Directory { .. }, _) if to.starts_with(&from) => {
+        return Err(error(
+          ErrorKind::Other,
+          "cannot move a directory into itself",
+          &to,
+        ));
+      }
+      _ => {}
+    }
+    let moved = self
+      .entries
+      .iter()
+      .filter(|item| item.key().starts_with(&from))
+      .map(|item| item.key().clone())
+      .collect::<Vec<_>>();
+    for path in moved {
+      if let Some((path, entry)) = self.entries.remove(&path) {
+        let target = match path.strip_prefix(&from) {
+          Ok(relative) if !relative.as_os_str().is_empty() => to.join(relative),
+          _ => to.clone(),
+        };
+        self.entries.insert(target, entry);
+      }
+    }
+    Ok(())
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_impl(dir.as_ref())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_all_impl(dir.as_ref())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.write_impl(file.as_ref(), data.as_ref())
+  }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> Result<()> {
+    self.remove_file_impl(file.as_ref())
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.remove_dir_all_impl(dir.as_ref())
+  }
+
+  fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.remove_dir_impl(dir.as_ref())
+  }
+
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    self.read_dir_impl(dir.as_ref())
+  }
+
+  fn stat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    self.stat_impl(file.as_ref())
+  }
+
+  fn lstat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    self.stat_impl(file.as_ref())
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    self.rename_impl(from.as_ref(), to.as_ref())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
+    self.read_impl(file.as_ref())
+  }
+}
+
+impl InputFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self.read_impl(file)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    self.read_dir_impl(dir)
+  }
+
+  fn stat(&self, file: &Path) -> Result<FileMetadata> {
+    self.stat_impl(file)
+  }
+
+  fn lstat(&self, file: &Path) -> Result<FileMetadata> {
+    self.stat_impl(file)
+  }
+
+  fn read_link(&self, file: &Path) -> Result<PathBuf> {
+    let file = normalize(file);
+    self.entry(&file)?;
+    Err(error(ErrorKind::InvalidInput, "not a symbolic link", &file))
+  }
+}
+
+cfg_async! {
+  use futures::future::{ready, BoxFuture};
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.create_dir_impl(dir.as_ref())))
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.create_dir_all_impl(dir.as_ref())))
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.write_impl(file.as_ref(), data.as_ref())))
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.remove_file_impl(file.as_ref())))
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.remove_dir_all_impl(dir.as_ref())))
+    }
+
+    fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.remove_dir_impl(dir.as_ref())))
+    }
+
+    fn read_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>> {
+      Box::pin(ready(self.read_dir_impl(dir.as_ref())))
+    }
+
+    fn stat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>> {
+      Box::pin(ready(self.stat_impl(file.as_ref())))
+    }
+
+    fn lstat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>> {
+      Box::pin(ready(self.stat_impl(file.as_ref())))
+    }
+
+    fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+      Box::pin(ready(self.rename_impl(from.as_ref(), to.as_ref())))
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
+      Box::pin(ready(self.read_impl(file.as_ref())))
+    }
+  }
+}
+
+/// Resolve `.` and `..` lexically, as there is no symbolic link in memory.
+fn normalize(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      _ => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+fn error(kind: ErrorKind, message: &str, path: &Path) -> Error {
+  Error::Io(std::io::Error::new(
+    kind,
+    format!("{message}, '{}'", path.display()),
+  ))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn write_and_read() {
+    let fs = MemoryFileSystem::new();
+    WritableFileSystem::create_dir_all(&fs, "/dist/js").expect("should create dir");
+    WritableFileSystem::write(&fs, "/dist/js/main.js", "main").expect("should write");
+    WritableFileSystem::write(&fs, "/dist/index.html", "html").expect("should write");
+
+    assert_eq!(
+      ReadableFileSystem::read(&fs, "/dist/./js/../js/main.js").expect("should read"),
+      b"main"
+    );
+    assert_eq!(
+      WritableFileSystem::read_dir(&fs, "/dist").expect("should read dir"),
+      vec!["index.html", "js"]
+    );
+    let stat = WritableFileSystem::stat(&fs, "/dist/js/main.js").expect("should stat");
+    assert!(stat.is_file);
+    assert_eq!(stat.size, 4);
+    assert!(
+      WritableFileSystem::stat(&fs, "/dist/js")
+        .expect("should stat")
+        .is_directory
+    );
+
+    // clones share the files
+    let clone = fs.clone();
+    assert!(InputFileSystem::read(&clone, Path::new("/dist/index.html")).is_ok());
+
+    // the parent directory must exist
+    assert!(WritableFileSystem::write(&fs, "/other/main.js", "main").is_err());
+    assert!(WritableFileSystem::create_dir(&fs, "/dist/css/nested").is_err());
+  }
+
+  #[test]
+  fn remove_and_rename() {
+    let fs = MemoryFileSystem::new();
+    WritableFileSystem::create_dir_all(&fs, "/dist/js").expect("should create dir");
+    WritableFileSystem::write(&fs, "/dist/js/main.js", "main").expect("should write");
+
+    assert!(WritableFileSystem::remove_dir(&fs, "/dist/js").is_err());
+    WritableFileSystem::rename(&fs, "/dist/js", "/dist/scripts").expect("should rename");
+    assert!(WritableFileSystem::stat(&fs, "/dist/js").is_err());
+    assert_eq!(
+      ReadableFileSystem::read(&fs, "/dist/scripts/main.js").expect("should read"),
+      b"main"
+    );
+
+    WritableFileSystem::remove_file(&fs, "/dist/scripts/main.js").expect("should remove");
+    WritableFileSystem::remove_dir(&fs, "/dist/scripts").expect("should remove");
+    WritableFileSystem::write(&fs, "/dist/main.js", "main").expect("should write");
+    WritableFileSystem::remove_dir_all(&fs, "/dist").expect("should remove");
+    assert!(WritableFileSystem::read_dir(&fs, "/")
+      .expect("should read dir")
+      .is_empty());
+  }
+}
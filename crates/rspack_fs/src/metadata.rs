@@ -0,0 +1,32 @@
+use std::time::UNIX_EPOCH;
+
+/// Metadata of a file, a directory or a symbolic link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  /// Only set by `lstat`, `stat` follows symbolic links.
+  pub is_symlink: bool,
+  /// Size in bytes.
+  pub size: u64,
+  /// Last modification time in milliseconds since the unix epoch.
+  pub mtime_ms: u64,
+}
+
+impl From<std::fs::Metadata> for FileMetadata {
+  fn from(value: std::fs::Metadata) -> Self {
+    let mtime_ms = value
+      .modified()
+      .ok()
+      .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+      .map(|duration| duration.as_millis() as u64)
+      .unwrap_or_default();
+    Self {
+      is_file: value.is_file(),
+      is_directory: value.is_dir(),
+      is_symlink: value.is_symlink(),
+      size: value.len(),
+      mtime_ms,
+    }
+  }
+}
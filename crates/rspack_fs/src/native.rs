@@ -1,11 +1,15 @@
-use std::{fs, path::Path};
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
 
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, InputFileSystem, Result,
 };
 
+#[derive(Debug)]
 pub struct NativeFileSystem;
 
 impl WritableFileSystem for NativeFileSystem {
@@ -20,6 +24,36 @@ impl WritableFileSystem for NativeFileSystem {
   fn write<P: AsRef<std::path::Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
     fs::write(file.as_ref(), data.as_ref()).map_err(Error::from)
   }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> Result<()> {
+    fs::remove_file(file.as_ref()).map_err(Error::from)
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    fs::remove_dir_all(dir.as_ref()).map_err(Error::from)
+  }
+
+  fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    fs::remove_dir(dir.as_ref()).map_err(Error::from)
+  }
+
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    read_dir(dir.as_ref())
+  }
+
+  fn stat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    let metadata = fs::metadata(file.as_ref())?;
+    Ok(metadata.into())
+  }
+
+  fn lstat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    let metadata = fs::symlink_metadata(file.as_ref())?;
+    Ok(metadata.into())
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    fs::rename(from.as_ref(), to.as_ref()).map_err(Error::from)
+  }
 }
 
 impl ReadableFileSystem for NativeFileSystem {
@@ -28,6 +62,40 @@ impl ReadableFileSystem for NativeFileSystem {
   }
 }
 
+impl InputFileSystem for NativeFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    fs::read(file).map_err(Error::from)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    read_dir(dir)
+  }
+
+  fn stat(&self, file: &Path) -> Result<FileMetadata> {
+    let metadata = fs::metadata(file)?;
+    Ok(metadata.into())
+  }
+
+  fn lstat(&self, file: &Path) -> Result<FileMetadata> {
+    let metadata = fs::symlink_metadata(file)?;
+    Ok(metadata.into())
+  }
+
+  fn read_link(&self, file: &Path) -> Result<PathBuf> {
+    fs::read_link(file).map_err(Error::from)
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    dunce::canonicalize(path).map_err(Error::from)
+  }
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<String>> {
+  fs::read_dir(dir)?
+    .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+    .collect()
+}
+
 cfg_async! {
   use futures::future::BoxFuture;
 
@@ -69,6 +137,50 @@ cfg_async! {
       let fut = async move { tokio::fs::remove_dir_all(dir).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let dir = dir.as_ref().to_string_lossy().to_string();
+      let fut = async move { tokio::fs::remove_dir(dir).await.map_err(Error::from) };
+      Box::pin(fut)
+    }
+
+    fn read_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<Vec<String>>> {
+      let dir = dir.as_ref().to_string_lossy().to_string();
+      let fut = async move {
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        let mut res = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+          res.push(entry.file_name().to_string_lossy().to_string());
+        }
+        Ok(res)
+      };
+      Box::pin(fut)
+    }
+
+    fn stat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>> {
+      let file = file.as_ref().to_string_lossy().to_string();
+      let fut = async move {
+        let metadata = tokio::fs::metadata(file).await?;
+        Ok(metadata.into())
+      };
+      Box::pin(fut)
+    }
+
+    fn lstat<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<FileMetadata>> {
+      let file = file.as_ref().to_string_lossy().to_string();
+      let fut = async move {
+        let metadata = tokio::fs::symlink_metadata(file).await?;
+        Ok(metadata.into())
+      };
+      Box::pin(fut)
+    }
+
+    fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+      let from = from.as_ref().to_string_lossy().to_string();
+      let to = to.as_ref().to_string_lossy().to_string();
+      let fut = async move { tokio::fs::rename(from, to).await.map_err(Error::from) };
+      Box::pin(fut)
+    }
   }
 
   impl AsyncReadableFileSystem for AsyncNativeFileSystem {
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use super::Result;
+use super::{FileMetadata, Result};
 
 pub trait WritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -21,6 +21,27 @@ pub trait WritableFileSystem {
   /// Write a slice as the entire contents of a file.
   /// This function will create a file if it does not exist, and will entirely replace its contents if it does.
   fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()>;
+
+  /// Removes a file from the filesystem.
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> Result<()>;
+
+  /// Removes a directory at this path, after removing all its contents. Use carefully.
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()>;
+
+  /// Removes an empty directory.
+  fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()>;
+
+  /// Returns the names of the entries in a directory.
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>>;
+
+  /// Returns the metadata of a file or a directory, symbolic links are followed.
+  fn stat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata>;
+
+  /// Returns the metadata of a file, a directory or a symbolic link, symbolic links are not followed.
+  fn lstat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata>;
+
+  /// Renames a file or a directory, replacing the destination if it already exists.
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()>;
 }
 
 pub trait ReadableFileSystem {
@@ -1,6 +1,6 @@
 use futures::future::BoxFuture;
 use napi::Env;
-use rspack_fs::r#async::AsyncWritableFileSystem;
+use rspack_fs::{r#async::AsyncWritableFileSystem, FileMetadata};
 use rspack_napi_shared::threadsafe_function::ThreadsafeFunctionCallMode;
 
 use crate::node::{ThreadsafeFunctionRef, ThreadsafeNodeFS, TryIntoThreadsafeFunctionRef};
@@ -134,4 +134,120 @@ impl AsyncWritableFileSystem for AsyncNodeWritableFileSystem {
     };
     Box::pin(fut)
   }
+
+  fn remove_dir<P: AsRef<std::path::Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    let dir = dir.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .remove_dir
+        .call(dir, ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+    };
+    Box::pin(fut)
+  }
+
+  fn read_dir<P: AsRef<std::path::Path>>(
+    &self,
+    dir: P,
+  ) -> BoxFuture<'_, rspack_fs::Result<Vec<String>>> {
+    let dir = dir.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .read_dir
+        .call(dir, ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+    };
+    Box::pin(fut)
+  }
+
+  fn stat<P: AsRef<std::path::Path>>(
+    &self,
+    file: P,
+  ) -> BoxFuture<'_, rspack_fs::Result<FileMetadata>> {
+    let file = file.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .stat
+        .call(file, ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+        .map(FileMetadata::from)
+    };
+    Box::pin(fut)
+  }
+
+  fn lstat<P: AsRef<std::path::Path>>(
+    &self,
+    file: P,
+  ) -> BoxFuture<'_, rspack_fs::Result<FileMetadata>> {
+    let file = file.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .lstat
+        .call(file, ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+        .map(FileMetadata::from)
+    };
+    Box::pin(fut)
+  }
+
+  fn rename<P: AsRef<std::path::Path>, Q: AsRef<std::path::Path>>(
+    &self,
+    from: P,
+    to: Q,
+  ) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    let from = from.as_ref().to_string_lossy().to_string();
+    let to = to.as_ref().to_string_lossy().to_string();
+    let fut = async move {
+      self
+        .fs_ts
+        .rename
+        .call((from, to), ThreadsafeFunctionCallMode::NonBlocking)
+        .expect("Failed to call tsfn")
+        .await
+        .expect("Failed to poll")
+        .map_err(|e| {
+          rspack_fs::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            e.to_string(),
+          ))
+        })
+    };
+    Box::pin(fut)
+  }
 }
@@ -1,6 +1,6 @@
 use napi::{Env, JsFunction, NapiRaw, Ref};
 use napi_derive::napi;
-use rspack_fs::cfg_async;
+use rspack_fs::{cfg_async, FileMetadata};
 
 pub(crate) struct JsFunctionRef {
   env: Env,
@@ -33,6 +33,33 @@ pub struct NodeFS {
   pub remove_file: JsFunction,
   pub mkdir: JsFunction,
   pub mkdirp: JsFunction,
+  pub remove_dir_all: JsFunction,
+  pub remove_dir: JsFunction,
+  pub read_dir: JsFunction,
+  pub stat: JsFunction,
+  pub lstat: JsFunction,
+  pub rename: JsFunction,
+}
+
+#[napi(object, js_name = "NodeFsStats")]
+pub struct NodeFsStats {
+  pub is_file: bool,
+  pub is_directory: bool,
+  pub is_symbolic_link: bool,
+  pub size: f64,
+  pub mtime_ms: f64,
+}
+
+impl From<NodeFsStats> for FileMetadata {
+  fn from(value: NodeFsStats) -> Self {
+    Self {
+      is_file: value.is_file,
+      is_directory: value.is_directory,
+      is_symlink: value.is_symbolic_link,
+      size: value.size as u64,
+      mtime_ms: value.mtime_ms as u64,
+    }
+  }
 }
 
 pub(crate) trait TryIntoNodeFSRef {
@@ -43,16 +70,30 @@ impl TryIntoNodeFSRef for NodeFS {
   fn try_into_node_fs_ref(self, env: &Env) -> napi::Result<NodeFSRef> {
     Ok(NodeFSRef {
       write_file: JsFunctionRef::new(*env, self.write_file)?,
+      remove_file: JsFunctionRef::new(*env, self.remove_file)?,
       mkdir: JsFunctionRef::new(*env, self.mkdir)?,
       mkdirp: JsFunctionRef::new(*env, self.mkdirp)?,
+      remove_dir_all: JsFunctionRef::new(*env, self.remove_dir_all)?,
+      remove_dir: JsFunctionRef::new(*env, self.remove_dir)?,
+      read_dir: JsFunctionRef::new(*env, self.read_dir)?,
+      stat: JsFunctionRef::new(*env, self.stat)?,
+      lstat: JsFunctionRef::new(*env, self.lstat)?,
+      rename: JsFunctionRef::new(*env, self.rename)?,
     })
   }
 }
 
 pub(crate) struct NodeFSRef {
   pub(crate) write_file: JsFunctionRef,
+  pub(crate) remove_file: JsFunctionRef,
   pub(crate) mkdir: JsFunctionRef,
   pub(crate) mkdirp: JsFunctionRef,
+  pub(crate) remove_dir_all: JsFunctionRef,
+  pub(crate) remove_dir: JsFunctionRef,
+  pub(crate) read_dir: JsFunctionRef,
+  pub(crate) stat: JsFunctionRef,
+  pub(crate) lstat: JsFunctionRef,
+  pub(crate) rename: JsFunctionRef,
 }
 
 cfg_async! {
@@ -70,6 +111,11 @@ cfg_async! {
     pub mkdir: JsFunction,
     pub mkdirp: JsFunction,
     pub remove_dir_all: JsFunction,
+    pub remove_dir: JsFunction,
+    pub read_dir: JsFunction,
+    pub stat: JsFunction,
+    pub lstat: JsFunction,
+    pub rename: JsFunction,
   }
 
   trait TryIntoJsUnknown {
@@ -154,6 +200,11 @@ cfg_async! {
         mkdir: self.mkdir.try_into_tsfn(env)?,
         mkdirp: self.mkdirp.try_into_tsfn(env)?,
         remove_dir_all: self.remove_dir_all.try_into_tsfn(env)?,
+        remove_dir: self.remove_dir.try_into_tsfn(env)?,
+        read_dir: self.read_dir.try_into_tsfn(env)?,
+        stat: self.stat.try_into_tsfn(env)?,
+        lstat: self.lstat.try_into_tsfn(env)?,
+        rename: self.rename.try_into_tsfn(env)?,
       })
     }
   }
@@ -164,5 +215,10 @@ cfg_async! {
     pub(crate) mkdir: ThreadsafeFunction<String, ()>,
     pub(crate) mkdirp: ThreadsafeFunction<String, Either<String, ()>>,
     pub(crate) remove_dir_all: ThreadsafeFunction<String, Either<String, ()>>,
+    pub(crate) remove_dir: ThreadsafeFunction<String, ()>,
+    pub(crate) read_dir: ThreadsafeFunction<String, Vec<String>>,
+    pub(crate) stat: ThreadsafeFunction<String, NodeFsStats>,
+    pub(crate) lstat: ThreadsafeFunction<String, NodeFsStats>,
+    pub(crate) rename: ThreadsafeFunction<(String, String), ()>,
   }
 }
@@ -1,9 +1,9 @@
 use std::{marker::PhantomData, path::Path};
 
-use napi::Env;
-use rspack_fs::{sync::WritableFileSystem, Error, Result};
+use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown, NapiRaw};
+use rspack_fs::{sync::WritableFileSystem, Error, FileMetadata, Result};
 
-use crate::node::{NodeFS, NodeFSRef, TryIntoNodeFSRef};
+use crate::node::{JsFunctionRef, NodeFS, NodeFSRef, NodeFsStats, TryIntoNodeFSRef};
 
 pub struct NodeWritableFileSystem {
   env: Env,
@@ -19,6 +19,27 @@ impl NodeWritableFileSystem {
       _data: PhantomData,
     })
   }
+
+  fn call_with_paths<R: FromNapiValue>(&self, func: &JsFunctionRef, paths: &[&Path]) -> Result<R> {
+    let func = func.get().expect("Failed to get function");
+    let args = paths
+      .iter()
+      .map(|path| {
+        self
+          .env
+          .create_string(&path.to_string_lossy())
+          .expect("Failed to create string")
+      })
+      .collect::<Vec<_>>();
+    let to_error = |err: napi::Error| {
+      Error::Io(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        err.to_string(),
+      ))
+    };
+    let result: JsUnknown = func.call(None, &args).map_err(to_error)?;
+    unsafe { R::from_napi_value(self.env.raw(), result.raw()) }.map_err(to_error)
+  }
 }
 
 impl WritableFileSystem for NodeWritableFileSystem {
@@ -98,4 +119,35 @@ impl WritableFileSystem for NodeWritableFileSystem {
 
     Ok(())
   }
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> Result<()> {
+    self.call_with_paths::<()>(&self.fs_ref.remove_file, &[file.as_ref()])
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.call_with_paths::<()>(&self.fs_ref.remove_dir_all, &[dir.as_ref()])
+  }
+
+  fn remove_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.call_with_paths::<()>(&self.fs_ref.remove_dir, &[dir.as_ref()])
+  }
+
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    self.call_with_paths(&self.fs_ref.read_dir, &[dir.as_ref()])
+  }
+
+  fn stat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    self
+      .call_with_paths::<NodeFsStats>(&self.fs_ref.stat, &[file.as_ref()])
+      .map(FileMetadata::from)
+  }
+
+  fn lstat<P: AsRef<Path>>(&self, file: P) -> Result<FileMetadata> {
+    self
+      .call_with_paths::<NodeFsStats>(&self.fs_ref.lstat, &[file.as_ref()])
+      .map(FileMetadata::from)
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    self.call_with_paths::<()>(&self.fs_ref.rename, &[from.as_ref(), to.as_ref()])
+  }
 }
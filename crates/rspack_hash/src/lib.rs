@@ -5,21 +5,42 @@ use std::{
 
 use data_encoding::HEXLOWER_PERMISSIVE;
 use md4::Digest;
+use rspack_error::internal_error;
+use sha2::{Sha256, Sha384, Sha512};
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum HashFunction {
   Xxhash64,
+  #[default]
   MD4,
+  Sha256,
+  Sha384,
+  Sha512,
+  /// 64-bit xxh3
+  Xxh3,
+  /// 128-bit xxh3
+  Xxh128,
+  Blake3,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha384" => Ok(HashFunction::Sha384),
+      "sha512" => Ok(HashFunction::Sha512),
+      "xxh3" => Ok(HashFunction::Xxh3),
+      "xxh128" => Ok(HashFunction::Xxh128),
+      "blake3" => Ok(HashFunction::Blake3),
+      _ => Err(internal_error!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"md4\", \"sha256\", \"sha384\", \"sha512\", \"xxh3\", \"xxh128\" or \"blake3\""
+      )),
     }
   }
 }
@@ -57,6 +78,12 @@ impl From<Option<String>> for HashSalt {
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  Sha256(Box<Sha256>),
+  Sha384(Box<Sha384>),
+  Sha512(Box<Sha512>),
+  Xxh3(Box<xxh3::Xxh3>),
+  Xxh128(Box<xxh3::Xxh3>),
+  Blake3(Box<blake3::Hasher>),
 }
 
 impl fmt::Debug for RspackHash {
@@ -64,6 +91,12 @@ impl fmt::Debug for RspackHash {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha384(_) => write!(f, "RspackHash(Sha384)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
+      Self::Xxh3(_) => write!(f, "RspackHash(Xxh3)"),
+      Self::Xxh128(_) => write!(f, "RspackHash(Xxh128)"),
+      Self::Blake3(_) => write!(f, "RspackHash(Blake3)"),
     }
   }
 }
@@ -73,6 +106,12 @@ impl RspackHash {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::Sha256 => Self::Sha256(Box::new(Sha256::new())),
+      HashFunction::Sha384 => Self::Sha384(Box::new(Sha384::new())),
+      HashFunction::Sha512 => Self::Sha512(Box::new(Sha512::new())),
+      HashFunction::Xxh3 => Self::Xxh3(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Xxh128 => Self::Xxh128(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
     }
   }
 
@@ -83,21 +122,31 @@ impl RspackHash {
   }
 
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
-    let inner = match self {
+    RspackHashDigest::new(self.finalize(), digest)
+  }
+
+  /// The raw bytes of the hash, xxh3 hashes are in canonical (big endian) form.
+  pub fn finalize(self) -> Vec<u8> {
+    match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
-    };
-    RspackHashDigest::new(inner, digest)
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha384(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
+      RspackHash::Xxh3(hasher) => hasher.digest().to_be_bytes().to_vec(),
+      RspackHash::Xxh128(hasher) => hasher.digest128().to_be_bytes().to_vec(),
+      RspackHash::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+    }
   }
 }
 
 impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
-      RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
+      RspackHash::Xxhash64(hasher) | RspackHash::Xxh3(hasher) => hasher.finish(),
+      _ => {
         // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
+        let hash = self.clone().finalize();
         let msb_u64: u64 = ((hash[0] as u64) << 56)
           | ((hash[1] as u64) << 48)
           | ((hash[2] as u64) << 40)
@@ -115,6 +164,13 @@ impl Hasher for RspackHash {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha384(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
+      RspackHash::Xxh3(hasher) | RspackHash::Xxh128(hasher) => hasher.update(bytes),
+      RspackHash::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
     }
   }
 }
@@ -142,6 +198,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(encoded: &str) -> Self {
+    Self {
+      encoded: encoded.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -153,3 +217,37 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn hex(function: &str, input: &[u8]) -> String {
+    let mut hasher = RspackHash::new(&function.try_into().expect("should be a hash function"));
+    hasher.write(input);
+    hasher.digest(&HashDigest::Hex).encoded().to_string()
+  }
+
+  #[test]
+  fn hash_functions() {
+    assert_eq!(
+      hex("sha256", b"abc"),
+      "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+      hex("sha512", b"abc"),
+      "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+    assert_eq!(
+      hex("blake3", b""),
+      "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+    assert_eq!(hex("xxh3", b""), "2d06800538d394c2");
+    assert_eq!(hex("xxh128", b""), "99aa06d3014798d86001c324468d497f");
+  }
+
+  #[test]
+  fn unknown_hash_function() {
+    assert!(HashFunction::try_from("sha1024").is_err());
+  }
+}
@@ -36,7 +36,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         devtool: rspack_core::Devtool::default(),
         mode: rspack_core::Mode::None,
         output: rspack_core::OutputOptions {
-          clean: false,
+          clean: None,
           path: Default::default(),
           public_path: Default::default(),
           filename: rspack_core::Filename::from_str("").expect("TODO:"),
@@ -84,6 +84,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         snapshot: Default::default(),
         experiments: Default::default(),
         node: Default::default(),
+        amd: None,
         optimization: rspack_core::Optimization {
           remove_available_modules: false,
           remove_empty_chunks: true,
@@ -92,6 +93,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           used_exports: Default::default(),
           inner_graph: Default::default(),
           mangle_exports: Default::default(),
+          concatenate_modules: Default::default(),
         },
         profile: false,
       }),
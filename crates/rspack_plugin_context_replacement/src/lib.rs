@@ -0,0 +1,138 @@
+// https://github.com/webpack/webpack/blob/main/lib/ContextReplacementPlugin.js
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use rspack_core::{
+  ContextModuleAfterResolveArgs, ContextModuleBeforeResolveArgs, Plugin, PluginContext,
+  PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
+};
+use rspack_error::Result;
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+/// The data passed to [ContextReplacementFn], `resource` is only available after resolving.
+#[derive(Debug, Clone)]
+pub struct ContextReplacementData {
+  pub request: String,
+  pub resource: Option<String>,
+  pub recursive: bool,
+  pub reg_exp: Option<RspackRegex>,
+}
+
+pub type ContextReplacementFn = Box<
+  dyn Fn(ContextReplacementData) -> BoxFuture<'static, Result<ContextReplacementData>>
+    + Sync
+    + Send,
+>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct ContextReplacementPluginOptions {
+  /// Tested against the request before resolving, and the context directory after resolving.
+  pub resource_regexp: RspackRegex,
+  /// Replaces the request before resolving, and the context directory after resolving,
+  /// a relative path is resolved against the original directory.
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  pub new_content_reg_exp: Option<RspackRegex>,
+  /// Called after the options above are applied, receives the data and returns the modified one.
+  #[derivative(Debug = "ignore")]
+  pub new_content_callback: Option<ContextReplacementFn>,
+}
+
+#[derive(Debug)]
+pub struct ContextReplacementPlugin {
+  options: ContextReplacementPluginOptions,
+}
+
+impl ContextReplacementPlugin {
+  pub fn new(options: ContextReplacementPluginOptions) -> Self {
+    Self { options }
+  }
+
+  async fn replace(&self, mut data: ContextReplacementData) -> Result<ContextReplacementData> {
+    if let Some(new_content_recursive) = self.options.new_content_recursive {
+      data.recursive = new_content_recursive;
+    }
+    if let Some(new_content_reg_exp) = &self.options.new_content_reg_exp {
+      data.reg_exp = Some(new_content_reg_exp.clone());
+    }
+    if let Some(new_content_callback) = &self.options.new_content_callback {
+      data = new_content_callback(data).await?;
+    }
+    Ok(data)
+  }
+}
+
+#[async_trait]
+impl Plugin for ContextReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ContextReplacementPlugin"
+  }
+
+  async fn context_module_before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut ContextModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if !self.options.resource_regexp.test(&args.request) {
+      return Ok(None);
+    }
+
+    let data = self
+      .replace(ContextReplacementData {
+        request: self
+          .options
+          .new_content_resource
+          .clone()
+          .unwrap_or_else(|| args.request.clone()),
+        resource: None,
+        recursive: args.recursive,
+        reg_exp: args.reg_exp.clone(),
+      })
+      .await?;
+    args.request = data.request;
+    args.recursive = data.recursive;
+    args.reg_exp = data.reg_exp;
+    Ok(None)
+  }
+
+  async fn context_module_after_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    if !self.options.resource_regexp.test(&args.resource) {
+      return Ok(None);
+    }
+
+    let resource = match &self.options.new_content_resource {
+      Some(new_content_resource) if Path::new(new_content_resource).is_absolute() => {
+        new_content_resource.clone()
+      }
+      Some(new_content_resource) => Path::new(&args.resource)
+        .join(new_content_resource)
+        .absolutize()
+        .to_string_lossy()
+        .to_string(),
+      None => args.resource.clone(),
+    };
+    let data = self
+      .replace(ContextReplacementData {
+        request: args.request.clone(),
+        resource: Some(resource),
+        recursive: args.recursive,
+        reg_exp: args.reg_exp.clone(),
+      })
+      .await?;
+    if let Some(resource) = data.resource {
+      args.resource = resource;
+    }
+    args.recursive = data.recursive;
+    args.reg_exp = data.reg_exp;
+    Ok(None)
+  }
+}
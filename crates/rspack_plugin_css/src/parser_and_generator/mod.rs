@@ -246,6 +246,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           runtime_requirements: generate_context.runtime_requirements,
           runtime: generate_context.runtime,
           init_fragments: &mut init_fragments,
+          concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
         };
 
         module.get_dependencies().iter().for_each(|id| {
@@ -7,8 +7,8 @@ use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
   rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  Chunk, ChunkKind, CssChunkHashArgs, Module, ModuleType, ParserAndGenerator, PathData, Plugin,
+  RenderManifestEntry, SourceType,
 };
 use rspack_core::{
   Compilation, CompilationArgs, CompilationParams, CompilerOptions, DependencyType,
@@ -159,6 +159,31 @@ impl Plugin for CssPlugin {
     Ok(())
   }
 
+  async fn chunk_hash(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: &mut rspack_core::ChunkHashArgs<'_>,
+  ) -> rspack_core::PluginChunkHashHookOutput {
+    let compilation = args.compilation;
+    let has_css_modules = compilation
+      .chunk_graph
+      .get_chunk_modules_iterable_by_source_type(
+        &args.chunk_ukey,
+        SourceType::Css,
+        &compilation.module_graph,
+      )
+      .next()
+      .is_some();
+    if has_css_modules {
+      compilation.plugin_driver.css_chunk_hash(CssChunkHashArgs {
+        chunk_ukey: &args.chunk_ukey,
+        compilation,
+        hasher: args.hasher,
+      })?;
+    }
+    Ok(())
+  }
+
   async fn content_hash(
     &self,
     _ctx: rspack_core::PluginContext,
@@ -194,6 +219,12 @@ impl Plugin for CssPlugin {
         }
       });
 
+    compilation.plugin_driver.css_chunk_hash(CssChunkHashArgs {
+      chunk_ukey: &args.chunk_ukey,
+      compilation,
+      hasher: &mut hasher,
+    })?;
+
     Ok(Some((
       SourceType::Css,
       hasher.digest(&compilation.options.output.hash_digest),
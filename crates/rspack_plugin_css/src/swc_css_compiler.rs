@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
+use preset_env_base::query::{targets_to_versions, Query, Targets};
 use rspack_core::rspack_sources::{self, SourceExt};
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use swc_core::common::comments::{CommentKind, SingleThreadedComments};
 use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName};
 use swc_core::common::{Globals, GLOBALS};
 use swc_core::css::codegen::{
@@ -10,6 +12,8 @@ use swc_core::css::codegen::{
 };
 use swc_core::css::minifier;
 use swc_core::css::parser::{lexer::Lexer, parser::ParserConfig};
+use swc_core::css::prefixer;
+use swc_core::css::visit::VisitMutWith;
 use swc_core::css::{ast::Stylesheet, parser::parser::Parser};
 
 #[derive(Default)]
@@ -81,16 +85,37 @@ impl SwcCssCompiler {
     input_source: String,
     input_source_map: Option<rspack_sources::SourceMap>,
     gen_source_map: SwcCssSourceMapGenConfig,
-  ) -> Result<rspack_sources::BoxSource> {
-    let parsed = self.parse_file(filename, input_source.clone(), Default::default())?;
-    // ignore errors since css in webpack is tolerant, and diagnostics already reported in parse.
-    let (mut ast, _) = parsed.split_into_parts();
+    options: &SwcCssMinifyOptions,
+  ) -> Result<SwcCssMinifyOutput> {
+    let comments = SingleThreadedComments::default();
+    let fm = self
+      .cm
+      .new_source_file(FileName::Custom(filename.to_string()), input_source.clone());
+    let config = ParserConfig::default();
+    let lexer = Lexer::new(SourceFileInput::from(&*fm), Some(&comments), config);
+    let mut parser = Parser::new(lexer, config);
+    let mut ast: Stylesheet = parser
+      .parse_all()
+      .map_err(|e| internal_error!("Css parsing failed {}", e.message()))?;
+    let prefixer_options = options
+      .targets
+      .as_ref()
+      .map(|targets| -> Result<_> {
+        let env = Targets::Query(Query::Multiple(targets.clone()));
+        // validate the targets, prefixer panics on invalid targets.
+        targets_to_versions(Some(env.clone())).map_err(|e| internal_error!(e.to_string()))?;
+        Ok(prefixer::options::Options { env: Some(env) })
+      })
+      .transpose()?;
     GLOBALS.set(&Globals::default(), || {
+      if let Some(prefixer_options) = prefixer_options {
+        ast.visit_mut_with(&mut prefixer::prefixer(prefixer_options));
+      }
       minifier::minify(&mut ast, minifier::options::MinifyOptions::default());
     });
     let (code, source_map) = self.codegen_impl(&ast, gen_source_map, true)?;
-    if let Some(source_map) = source_map {
-      let source = rspack_sources::SourceMapSource::new(rspack_sources::SourceMapSourceOptions {
+    let source = if let Some(source_map) = source_map {
+      rspack_sources::SourceMapSource::new(rspack_sources::SourceMapSourceOptions {
         value: code,
         name: filename,
         source_map: rspack_sources::SourceMap::from_slice(&source_map)
@@ -99,14 +124,45 @@ impl SwcCssCompiler {
         inner_source_map: input_source_map,
         remove_original_source: true,
       })
-      .boxed();
-      Ok(source)
+      .boxed()
     } else {
-      Ok(rspack_sources::RawSource::from(code).boxed())
-    }
+      rspack_sources::RawSource::from(code).boxed()
+    };
+
+    let (leading, trailing) = comments.take_all();
+    let mut comments = leading
+      .take()
+      .into_values()
+      .chain(trailing.take().into_values())
+      .flatten()
+      .filter(|comment| comment.kind == CommentKind::Block)
+      .collect::<Vec<_>>();
+    comments.sort_by_key(|comment| comment.span.lo);
+
+    Ok(SwcCssMinifyOutput {
+      source,
+      comments: comments
+        .into_iter()
+        .map(|comment| comment.text.to_string())
+        .collect(),
+    })
   }
 }
 
+#[derive(Debug, Default)]
+pub struct SwcCssMinifyOptions {
+  /// Browserslist queries, the vendor prefixes required by the targets are added before minifying.
+  pub targets: Option<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub struct SwcCssMinifyOutput {
+  pub source: rspack_sources::BoxSource,
+  /// Block comments of the input including the delimiters,
+  /// the minified source doesn't contain any comments.
+  pub comments: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SwcCssSourceMapGenConfig {
   pub enable: bool,
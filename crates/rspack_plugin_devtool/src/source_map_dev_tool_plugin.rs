@@ -0,0 +1,470 @@
+use std::fmt;
+use std::{collections::HashSet, path::Path};
+
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use pathdiff::diff_paths;
+use rayon::prelude::*;
+use regex::{Captures, Regex};
+use rspack_core::{
+  contextify,
+  rspack_sources::{ConcatSource, MapOptions, RawSource, Source, SourceExt},
+  AssetInfo, Compilation, CompilationAsset, Filename, Logger, ModuleIdentifier, PathData, Plugin,
+  PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs, SourceType,
+};
+use rspack_error::{internal_error, Error, Result};
+use rspack_hash::RspackHash;
+use rspack_regex::RspackRegex;
+use rspack_util::{swc::normalize_custom_filename, try_any_sync};
+use rustc_hash::FxHashMap as HashMap;
+
+static IS_CSS_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.css($|\?)").expect("Invalid regex"));
+static MODULE_FILENAME_PLACEHOLDER: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\[([\w-]+)\]").expect("Invalid regex"));
+static LINE_COMMENT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\n//(.*)$").expect("Invalid regex"));
+
+const DEFAULT_SOURCE_MAPPING_URL_COMMENT: &str = "\n//# sourceMappingURL=[url]";
+const DEFAULT_MODULE_FILENAME_TEMPLATE: &str = "[resourcePath]";
+const DEFAULT_FALLBACK_MODULE_FILENAME_TEMPLATE: &str = "[resourcePath]?[hash]";
+
+#[derive(Debug)]
+pub enum SourceMapDevToolRule {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+#[derive(Debug)]
+pub enum SourceMapDevToolRules {
+  Single(SourceMapDevToolRule),
+  Array(Vec<SourceMapDevToolRule>),
+}
+
+impl FromIterator<SourceMapDevToolRule> for SourceMapDevToolRules {
+  fn from_iter<T: IntoIterator<Item = SourceMapDevToolRule>>(iter: T) -> Self {
+    Self::Array(iter.into_iter().collect())
+  }
+}
+
+impl SourceMapDevToolRule {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+    }
+  }
+}
+
+impl SourceMapDevToolRules {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::Single(s) => s.try_match(data),
+      Self::Array(l) => try_any_sync(l, |i| i.try_match(data)),
+    }
+  }
+}
+
+pub struct AppendFnCtx<'a> {
+  pub filename: &'a str,
+  pub url: &'a str,
+}
+
+pub type AppendFn =
+  Box<dyn for<'a> Fn(AppendFnCtx<'a>) -> BoxFuture<'a, Result<String>> + Sync + Send>;
+
+pub enum Append {
+  // Don't append the source mapping url comment.
+  Disabled,
+  // The comment to append, `[url]` is replaced with the url of the source map.
+  String(String),
+  // Return the comment to append for an asset.
+  Fn(AppendFn),
+}
+
+impl fmt::Debug for Append {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Disabled => f.debug_tuple("Disabled").finish(),
+      Self::String(arg0) => f.debug_tuple("String").field(arg0).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct SourceMapDevToolPluginOptions {
+  // Output filename template of the source map, the source map is inlined as a data url when it's `None`.
+  pub filename: Option<String>,
+  // The source mapping url comment appended to the asset, defaults to `\n//# sourceMappingURL=[url]`.
+  pub append: Option<Append>,
+  // Replaces `[namespace]` in the module filename templates.
+  pub namespace: String,
+  // If false, the source maps only contain line mappings.
+  pub columns: bool,
+  // If true, `sourcesContent` is emptied.
+  pub no_sources: bool,
+  // Prefix of the source map url in the comment.
+  pub public_path: Option<String>,
+  // Template of the `sources` entries, defaults to `[resourcePath]`.
+  pub module_filename_template: Option<String>,
+  // Template used when `module_filename_template` produces duplicated `sources` entries.
+  pub fallback_module_filename_template: Option<String>,
+  // Set as `sourceRoot` of the source maps.
+  pub source_root: Option<String>,
+  // Include all assets that pass test assertion.
+  pub test: Option<SourceMapDevToolRules>,
+  // Include all assets matching any of these conditions.
+  pub include: Option<SourceMapDevToolRules>,
+  // Exclude all assets matching any of these conditions.
+  pub exclude: Option<SourceMapDevToolRules>,
+}
+
+fn match_object(obj: &SourceMapDevToolPluginOptions, str: &str) -> Result<bool> {
+  if let Some(condition) = &obj.test {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.include {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.exclude {
+    if condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+/// Module information available to the module filename templates.
+#[derive(Debug, Default)]
+struct ModuleFilenameInfo {
+  id: Option<String>,
+}
+
+struct ModuleFilenameContext<'a> {
+  compilation: &'a Compilation,
+  namespace: &'a str,
+  modules: HashMap<String, ModuleFilenameInfo>,
+}
+
+impl<'a> ModuleFilenameContext<'a> {
+  fn new(compilation: &'a Compilation, namespace: &'a str) -> Self {
+    let mut modules = HashMap::default();
+    for (identifier, module) in compilation.module_graph.modules() {
+      let Some(normal_module) = module.as_normal_module() else {
+        continue;
+      };
+      let info = || ModuleFilenameInfo {
+        id: get_module_id(compilation, *identifier),
+      };
+      modules.insert(normal_module.request().to_string(), info());
+      modules
+        .entry(
+          normal_module
+            .resource_resolved_data()
+            .resource_path
+            .to_string_lossy()
+            .to_string(),
+        )
+        .or_insert_with(info);
+    }
+    Self {
+      compilation,
+      namespace,
+      modules,
+    }
+  }
+
+  /// Render a module filename template for a `sources` entry, the entry is the request or
+  /// the resource path of the module that produced it.
+  fn create_filename(&self, template: &str, source: &str) -> String {
+    let absolute_identifier = normalize_custom_filename(source);
+    let identifier = contextify(&self.compilation.options.context, absolute_identifier);
+    let (loaders, resource) = identifier.rsplit_once('!').unwrap_or(("", &identifier));
+    let (resource_path, query) = resource
+      .find('?')
+      .map(|i| resource.split_at(i))
+      .unwrap_or((resource, ""));
+    let absolute_resource = absolute_identifier
+      .rsplit_once('!')
+      .map_or(absolute_identifier, |(_, resource)| resource);
+    let absolute_resource_path = absolute_resource
+      .split_once('?')
+      .map_or(absolute_resource, |(path, _)| path);
+    let module = self.modules.get(absolute_identifier);
+
+    MODULE_FILENAME_PLACEHOLDER
+      .replace_all(template, |caps: &Captures| {
+        match caps[1].to_lowercase().as_str() {
+          "identifier" | "short-identifier" => identifier.to_string(),
+          "resource" => resource.to_string(),
+          "resourcepath" | "resource-path" => resource_path.to_string(),
+          "absolute-resource-path" => absolute_resource_path.to_string(),
+          "all-loaders" | "allloaders" | "loaders" => loaders.to_string(),
+          "query" => query.to_string(),
+          "id" => module.and_then(|m| m.id.clone()).unwrap_or_default(),
+          // Same as webpack, only depends on the identifier so the `sources` are stable
+          "hash" => self.hash_identifier(&identifier),
+          "namespace" => self.namespace.to_string(),
+          _ => caps[0].to_string(),
+        }
+      })
+      .into_owned()
+  }
+
+  fn hash_identifier(&self, identifier: &str) -> String {
+    let mut hasher = RspackHash::from(&self.compilation.options.output);
+    std::hash::Hash::hash(identifier, &mut hasher);
+    hasher
+      .digest(&self.compilation.options.output.hash_digest)
+      .rendered(4)
+      .to_string()
+  }
+}
+
+fn get_module_id(compilation: &Compilation, identifier: ModuleIdentifier) -> Option<String> {
+  if !compilation
+    .chunk_graph
+    .chunk_graph_module_by_module_identifier
+    .contains_key(&identifier)
+  {
+    return None;
+  }
+  compilation.chunk_graph.get_module_id(identifier).clone()
+}
+
+#[derive(Debug)]
+pub struct SourceMapDevToolPlugin {
+  source_map_filename: Option<Filename>,
+  source_mapping_url_comment: Option<Append>,
+  module_filename_template: String,
+  fallback_module_filename_template: String,
+  options: SourceMapDevToolPluginOptions,
+}
+
+impl SourceMapDevToolPlugin {
+  pub fn new(mut options: SourceMapDevToolPluginOptions) -> Self {
+    let source_mapping_url_comment = match options.append.take() {
+      Some(Append::Disabled) => None,
+      Some(append) => Some(append),
+      None => Some(Append::String(
+        DEFAULT_SOURCE_MAPPING_URL_COMMENT.to_string(),
+      )),
+    };
+    Self {
+      source_map_filename: options.filename.clone().map(Filename::from),
+      source_mapping_url_comment,
+      module_filename_template: options
+        .module_filename_template
+        .clone()
+        .unwrap_or_else(|| DEFAULT_MODULE_FILENAME_TEMPLATE.to_string()),
+      fallback_module_filename_template: options
+        .fallback_module_filename_template
+        .clone()
+        .unwrap_or_else(|| DEFAULT_FALLBACK_MODULE_FILENAME_TEMPLATE.to_string()),
+      options,
+    }
+  }
+
+  fn render_source_map_filename(
+    &self,
+    compilation: &Compilation,
+    filename: &str,
+    source_map_filename: &Filename,
+    is_css: bool,
+  ) -> String {
+    let chunk = compilation
+      .chunk_by_ukey
+      .values()
+      .find(|chunk| chunk.files.contains(filename) || chunk.auxiliary_files.contains(filename));
+    let Some(chunk) = chunk else {
+      return compilation
+        .get_asset_path(source_map_filename, PathData::default().filename(filename));
+    };
+    let source_type = if is_css {
+      &SourceType::Css
+    } else {
+      &SourceType::JavaScript
+    };
+    compilation.get_asset_path(
+      source_map_filename,
+      PathData::default()
+        .chunk(chunk)
+        .filename(filename)
+        .content_hash_optional(
+          chunk
+            .content_hash
+            .get(source_type)
+            .map(|i| i.rendered(compilation.options.output.hash_digest_length)),
+        ),
+    )
+  }
+
+  async fn render_source_mapping_url_comment(
+    &self,
+    filename: &str,
+    url: &str,
+    is_css: bool,
+  ) -> Result<Option<String>> {
+    let comment = match &self.source_mapping_url_comment {
+      None | Some(Append::Disabled) => return Ok(None),
+      Some(Append::String(comment)) => comment.clone(),
+      Some(Append::Fn(f)) => f(AppendFnCtx { filename, url }).await?,
+    };
+    let comment = if is_css {
+      LINE_COMMENT.replace(&comment, "\n/*$1*/").into_owned()
+    } else {
+      comment
+    };
+    Ok(Some(comment.replace("[url]", url)))
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for SourceMapDevToolPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SourceMapDevToolPlugin"
+  }
+
+  async fn process_assets_stage_dev_tooling(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let logger = args.compilation.get_logger(self.name());
+    let start = logger.time("collect source maps");
+    let module_filename_context =
+      ModuleFilenameContext::new(args.compilation, &self.options.namespace);
+    let maps: HashMap<String, (Vec<u8>, Option<Vec<u8>>)> = args
+      .compilation
+      .assets()
+      .par_iter()
+      .filter_map(|(filename, asset)| asset.get_source().map(|s| (filename, s)))
+      .map(|(filename, source)| {
+        match_object(&self.options, filename).map(|m| (filename, source, m))
+      })
+      .filter_map(|res| match res {
+        Ok((filename, source, true)) => Some(Ok((filename, source))),
+        Ok(_) => None,
+        Err(e) => Some(Err(e)),
+      })
+      .map(|res| {
+        let (filename, source) = res?;
+        let map = source
+          .map(&MapOptions::new(self.options.columns))
+          .map(|mut map| {
+            map.set_file(Some(filename.clone()));
+            let mut used_names = HashSet::new();
+            for source in map.sources_mut() {
+              let mut name =
+                module_filename_context.create_filename(&self.module_filename_template, source);
+              if used_names.contains(&name) {
+                name = module_filename_context
+                  .create_filename(&self.fallback_module_filename_template, source);
+                while used_names.contains(&name) {
+                  name.push('*');
+                }
+              }
+              used_names.insert(name.clone());
+              *source = name;
+            }
+            if self.options.no_sources {
+              for content in map.sources_content_mut() {
+                *content = String::default();
+              }
+            }
+            let mut map_buffer = Vec::new();
+            map
+              .to_writer(&mut map_buffer)
+              .map_err(|e| internal_error!(e.to_string()))?;
+            if let Some(source_root) = &self.options.source_root {
+              let mut value: serde_json::Value =
+                serde_json::from_slice(&map_buffer).map_err(|e| internal_error!(e.to_string()))?;
+              value["sourceRoot"] = serde_json::Value::String(source_root.clone());
+              map_buffer =
+                serde_json::to_vec(&value).map_err(|e| internal_error!(e.to_string()))?;
+            }
+            Ok::<Vec<u8>, Error>(map_buffer)
+          })
+          .transpose()?;
+        let mut code_buffer = Vec::new();
+        source.to_writer(&mut code_buffer)?;
+        Ok((filename.to_owned(), (code_buffer, map)))
+      })
+      .collect::<Result<_>>()?;
+    logger.time_end(start);
+
+    let start = logger.time("emit source map assets");
+    for (filename, (code_buffer, map_buffer)) in maps {
+      let mut asset = args
+        .compilation
+        .assets_mut()
+        .remove(&filename)
+        .expect("should have filename in compilation.assets");
+      // convert to RawSource to reduce one time source map calculation when convert to JsCompatSource
+      let raw_source = RawSource::from(code_buffer).boxed();
+      let Some(map_buffer) = map_buffer else {
+        asset.source = Some(raw_source);
+        args.compilation.emit_asset(filename, asset);
+        continue;
+      };
+      let is_css = IS_CSS_FILE.is_match(&filename);
+      let Some(source_map_filename) = &self.source_map_filename else {
+        let base64 = rspack_base64::encode_to_string(&map_buffer);
+        let url = format!("data:application/json;charset=utf-8;base64,{base64}");
+        let comment = self
+          .render_source_mapping_url_comment(&filename, &url, is_css)
+          .await?;
+        asset.source = Some(match comment {
+          Some(comment) => {
+            ConcatSource::new([raw_source, RawSource::from(comment).boxed()]).boxed()
+          }
+          None => raw_source,
+        });
+        args.compilation.emit_asset(filename, asset);
+        // TODO
+        // chunk.auxiliary_files.add(filename);
+        continue;
+      };
+
+      let source_map_filename =
+        self.render_source_map_filename(args.compilation, &filename, source_map_filename, is_css);
+      let source_map_url = if let Some(public_path) = &self.options.public_path {
+        format!("{public_path}{source_map_filename}")
+      } else if let Some(dirname) = Path::new(&filename).parent()
+        && let Some(relative) = diff_paths(&source_map_filename, dirname)
+      {
+        relative.to_string_lossy().into_owned()
+      } else {
+        source_map_filename.clone()
+      };
+      if let Some(comment) = self
+        .render_source_mapping_url_comment(&filename, &source_map_url, is_css)
+        .await?
+      {
+        asset.source =
+          Some(ConcatSource::new([raw_source, RawSource::from(comment).boxed()]).boxed());
+        asset.info.related.source_map = Some(source_map_filename.clone());
+      } else {
+        asset.source = Some(raw_source);
+      }
+      args.compilation.emit_asset(filename.clone(), asset);
+      let mut source_map_asset_info = AssetInfo::default().with_development(true);
+      if let Some(asset) = args.compilation.assets().get(&filename) {
+        // set source map asset version to be the same as the target asset
+        source_map_asset_info.version = asset.info.version.clone();
+      }
+      args.compilation.emit_asset(
+        source_map_filename,
+        CompilationAsset::new(
+          Some(RawSource::from(map_buffer).boxed()),
+          source_map_asset_info,
+        ),
+      );
+    }
+    logger.time_end(start);
+    Ok(())
+  }
+}
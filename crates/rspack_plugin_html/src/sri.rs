@@ -1,10 +1,10 @@
-use std::str::FromStr;
+use std::{hash::Hasher, str::FromStr};
 
 use rspack_core::rspack_sources::{BoxSource, Source};
+use rspack_hash::{HashFunction, RspackHash};
 #[cfg(feature = "testing")]
 use schemars::JsonSchema;
 use serde::Deserialize;
-use sha2::{Digest, Sha256, Sha384, Sha512};
 
 use crate::visitors::asset::{HTMLPluginTag, HtmlPluginAttribute};
 
@@ -36,27 +36,17 @@ impl FromStr for HtmlSriHashFunction {
 }
 
 pub fn create_digest_from_asset(hash_func: &HtmlSriHashFunction, asset: &BoxSource) -> String {
-  let byte_content = asset.buffer();
-  match hash_func {
-    HtmlSriHashFunction::Sha384 => {
-      let mut hasher = Sha384::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha384-{}", rspack_base64::encode_to_string(digest))
-    }
-    HtmlSriHashFunction::Sha256 => {
-      let mut hasher = Sha256::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha256-{}", rspack_base64::encode_to_string(digest))
-    }
-    HtmlSriHashFunction::Sha512 => {
-      let mut hasher = Sha512::new();
-      hasher.update(byte_content);
-      let digest = &hasher.finalize()[..];
-      format!("sha512-{}", rspack_base64::encode_to_string(digest))
-    }
-  }
+  let (name, function) = match hash_func {
+    HtmlSriHashFunction::Sha256 => ("sha256", HashFunction::Sha256),
+    HtmlSriHashFunction::Sha384 => ("sha384", HashFunction::Sha384),
+    HtmlSriHashFunction::Sha512 => ("sha512", HashFunction::Sha512),
+  };
+  let mut hasher = RspackHash::new(&function);
+  hasher.write(&asset.buffer());
+  format!(
+    "{name}-{}",
+    rspack_base64::encode_to_string(hasher.finalize())
+  )
 }
 
 pub fn add_sri(tag: &mut HTMLPluginTag, sri: &String) {
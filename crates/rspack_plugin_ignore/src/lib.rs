@@ -0,0 +1,154 @@
+#![feature(let_chains)]
+
+// https://github.com/webpack/webpack/blob/main/lib/IgnorePlugin.js
+
+use async_trait::async_trait;
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use rspack_core::{
+  ContextModuleBeforeResolveArgs, NormalModuleBeforeResolveArgs, Plugin, PluginContext,
+  PluginNormalModuleFactoryBeforeResolveOutput,
+};
+use rspack_error::Result;
+use rspack_regex::RspackRegex;
+
+/// Called with the request and the context of a dependency, returns `true` to ignore it.
+pub type CheckResourceFn =
+  Box<dyn for<'a> Fn(&'a str, &'a str) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct IgnorePluginOptions {
+  /// A RegExp to test the request against.
+  pub resource_regexp: Option<RspackRegex>,
+  /// A RegExp to test the context (directory) against.
+  pub context_regexp: Option<RspackRegex>,
+  /// A filter function receiving the request and the context, used instead of the RegExps.
+  #[derivative(Debug = "ignore")]
+  pub check_resource: Option<CheckResourceFn>,
+}
+
+#[derive(Debug)]
+pub struct IgnorePlugin {
+  options: IgnorePluginOptions,
+}
+
+impl IgnorePlugin {
+  pub fn new(options: IgnorePluginOptions) -> Self {
+    Self { options }
+  }
+
+  /// Returns `Some(false)` if the dependency should be ignored, `None` to continue resolving
+  async fn check_ignore(
+    &self,
+    request: &str,
+    context: &str,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if let Some(check_resource) = &self.options.check_resource
+      && check_resource(request, context).await?
+    {
+      return Ok(Some(false));
+    }
+
+    if let Some(resource_regexp) = &self.options.resource_regexp
+      && resource_regexp.test(request)
+    {
+      match &self.options.context_regexp {
+        Some(context_regexp) if !context_regexp.test(context) => {}
+        _ => return Ok(Some(false)),
+      }
+    }
+
+    Ok(None)
+  }
+}
+
+#[async_trait]
+impl Plugin for IgnorePlugin {
+  fn name(&self) -> &'static str {
+    "rspack.IgnorePlugin"
+  }
+
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    self.check_ignore(&args.request, &args.context).await
+  }
+
+  async fn context_module_before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut ContextModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    self.check_ignore(&args.request, &args.context).await
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use futures::executor::block_on;
+
+  use super::*;
+
+  fn regexp(expr: &str) -> Option<RspackRegex> {
+    Some(RspackRegex::new(expr).expect("should be a valid regexp"))
+  }
+
+  fn is_ignored(options: IgnorePluginOptions, request: &str, context: &str) -> bool {
+    let plugin = IgnorePlugin::new(options);
+    let result = block_on(plugin.check_ignore(request, context)).expect("should check");
+    result == Some(false)
+  }
+
+  #[test]
+  fn resource_regexp_only() {
+    let options = || IgnorePluginOptions {
+      resource_regexp: regexp("^\\./locale$"),
+      context_regexp: None,
+      check_resource: None,
+    };
+    assert!(is_ignored(options(), "./locale", "/project/moment"));
+    assert!(is_ignored(options(), "./locale", "/project/other"));
+    assert!(!is_ignored(options(), "./locale/en", "/project/moment"));
+  }
+
+  #[test]
+  fn context_regexp_only() {
+    // like webpack, the context is only checked for the requests matching the resource regexp
+    let options = IgnorePluginOptions {
+      resource_regexp: None,
+      context_regexp: regexp("moment$"),
+      check_resource: None,
+    };
+    assert!(!is_ignored(options, "./locale", "/project/moment"));
+  }
+
+  #[test]
+  fn resource_and_context_regexp() {
+    let options = || IgnorePluginOptions {
+      resource_regexp: regexp("^\\./locale$"),
+      context_regexp: regexp("moment$"),
+      check_resource: None,
+    };
+    assert!(is_ignored(options(), "./locale", "/project/moment"));
+    assert!(!is_ignored(options(), "./locale", "/project/other"));
+    assert!(!is_ignored(options(), "./other", "/project/moment"));
+  }
+
+  #[test]
+  fn check_resource() {
+    let options = IgnorePluginOptions {
+      resource_regexp: None,
+      context_regexp: None,
+      check_resource: Some(Box::new(|request, context| {
+        Box::pin(async move { Ok(request == "./locale" && context.ends_with("moment")) })
+      })),
+    };
+    let plugin = IgnorePlugin::new(options);
+    let check = |request, context| block_on(plugin.check_ignore(request, context)).expect("ok");
+    assert_eq!(check("./locale", "/project/moment"), Some(false));
+    assert_eq!(check("./locale", "/project/other"), None);
+  }
+}
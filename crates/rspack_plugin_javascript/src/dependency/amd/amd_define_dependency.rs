@@ -0,0 +1,132 @@
+use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
+
+/// Rewrites `define([deps], factory)` and its variants to commonjs style module code,
+/// the dependency array itself is rendered by [super::AmdRequireArrayDependency]
+#[derive(Debug, Clone)]
+pub struct AmdDefineDependency {
+  range: (u32, u32),
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  object_range: Option<(u32, u32)>,
+}
+
+impl AmdDefineDependency {
+  pub fn new(
+    range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    object_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      range,
+      array_range,
+      function_range,
+      object_range,
+    }
+  }
+
+  fn branch(&self) -> Branch {
+    match (
+      self.array_range.is_some(),
+      self.object_range.is_some(),
+      self.function_range.is_some(),
+    ) {
+      (false, false, true) => Branch::F,
+      (false, true, false) => Branch::O,
+      (false, true, true) => Branch::OF,
+      (true, false, true) => Branch::AF,
+      (true, true, false) => Branch::AO,
+      (true, true, true) => Branch::AOF,
+      // `define([deps])` and `define()` are not recognized by the scanner
+      _ => unreachable!("unexpected amd define branch"),
+    }
+  }
+}
+
+// TODO: support named modules (`define("name", ...)`) as local modules
+#[allow(clippy::upper_case_acronyms)]
+enum Branch {
+  F,
+  O,
+  OF,
+  AF,
+  AO,
+  AOF,
+}
+
+impl Branch {
+  fn runtime_requirements(&self) -> RuntimeGlobals {
+    match self {
+      Branch::F | Branch::OF => {
+        RuntimeGlobals::REQUIRE | RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE
+      }
+      Branch::AF | Branch::AOF => RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE,
+      Branch::O | Branch::AO => RuntimeGlobals::MODULE,
+    }
+  }
+
+  fn definition(&self) -> &'static str {
+    match self {
+      Branch::F => "var __WEBPACK_AMD_DEFINE_RESULT__;",
+      Branch::O | Branch::AO => "",
+      Branch::OF => "var __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+      Branch::AF => "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+      Branch::AOF => {
+        "var __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_RESULT__;"
+      }
+    }
+  }
+
+  fn content(&self) -> String {
+    match self {
+      Branch::F => format!(
+        "!(__WEBPACK_AMD_DEFINE_RESULT__ = (#).call(exports, {}, exports, module),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE
+      ),
+      Branch::O => "!(module.exports = #)".to_string(),
+      Branch::OF => format!(
+        "!(__WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.call(exports, {}, exports, module)) :\n\t\t__WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE
+      ),
+      Branch::AF => "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_RESULT__ = (#).apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))".to_string(),
+      Branch::AO => "!(#, module.exports = #)".to_string(),
+      Branch::AOF => "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__)) : __WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))".to_string(),
+    }
+  }
+}
+
+impl DependencyTemplate for AmdDefineDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let branch = self.branch();
+    code_generatable_context
+      .runtime_requirements
+      .insert(branch.runtime_requirements());
+
+    let definition = branch.definition();
+    if !definition.is_empty() {
+      source.insert(0, definition, None);
+    }
+
+    let content = branch.content();
+    let mut texts = content.split('#');
+    // the source in these ranges is kept, and the text between them is replaced,
+    // the object range and function range are the same one when both exist
+    let kept_ranges = [self.array_range, self.object_range.or(self.function_range)];
+    let mut current = self.range.0;
+    for (start, end) in kept_ranges.into_iter().flatten() {
+      let text = texts.next().unwrap_or_default();
+      source.replace(current, start, text, None);
+      current = end;
+    }
+    source.replace(
+      current,
+      self.range.1,
+      texts.next().unwrap_or_default(),
+      None,
+    );
+  }
+}
@@ -0,0 +1,67 @@
+use rspack_core::{module_raw, DependencyId, DependencyTemplate, RuntimeGlobals};
+use rspack_core::{TemplateContext, TemplateReplaceSource};
+
+#[derive(Debug, Clone)]
+pub enum AmdRequireArrayItem {
+  /// `"require"`, which is the `__webpack_require__` of the module
+  Require,
+  /// `"exports"`, which is the `exports` of the module
+  Exports,
+  /// `"module"`, which is the `module` of the module
+  Module,
+  Dependency {
+    id: DependencyId,
+    request: String,
+  },
+}
+
+/// Replaces the dependency array of `define` and `require` with the required modules
+#[derive(Debug, Clone)]
+pub struct AmdRequireArrayDependency {
+  start: u32,
+  end: u32,
+  items: Vec<AmdRequireArrayItem>,
+}
+
+impl AmdRequireArrayDependency {
+  pub fn new(start: u32, end: u32, items: Vec<AmdRequireArrayItem>) -> Self {
+    Self { start, end, items }
+  }
+}
+
+impl DependencyTemplate for AmdRequireArrayDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let items = self
+      .items
+      .iter()
+      .map(|item| match item {
+        AmdRequireArrayItem::Require => {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+          RuntimeGlobals::REQUIRE.name().to_string()
+        }
+        AmdRequireArrayItem::Exports => {
+          runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+          "exports".to_string()
+        }
+        AmdRequireArrayItem::Module => {
+          runtime_requirements.insert(RuntimeGlobals::MODULE);
+          "module".to_string()
+        }
+        AmdRequireArrayItem::Dependency { id, request } => {
+          module_raw(compilation, runtime_requirements, id, request, false)
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+    source.replace(self.start, self.end, &format!("[{items}]"), None);
+  }
+}
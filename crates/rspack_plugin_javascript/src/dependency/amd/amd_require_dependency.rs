@@ -0,0 +1,128 @@
+use rspack_core::{block_promise, AsContextDependency, AsModuleDependency, Dependency};
+use rspack_core::{DependencyId, DependencyTemplate, RuntimeGlobals};
+use rspack_core::{TemplateContext, TemplateReplaceSource};
+
+/// Rewrites `require([deps], callback, errorCallback)` to load the block of the dependencies,
+/// the dependency array itself is rendered by [super::AmdRequireArrayDependency]
+#[derive(Debug, Clone)]
+pub struct AmdRequireDependency {
+  id: DependencyId,
+  outer_range: (u32, u32),
+  array_range: (u32, u32),
+  function_range: Option<(u32, u32)>,
+  error_callback_range: Option<(u32, u32)>,
+  function_bind_this: bool,
+  error_callback_bind_this: bool,
+}
+
+impl AmdRequireDependency {
+  pub fn new(
+    outer_range: (u32, u32),
+    array_range: (u32, u32),
+    function_range: Option<(u32, u32)>,
+    error_callback_range: Option<(u32, u32)>,
+    function_bind_this: bool,
+    error_callback_bind_this: bool,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      outer_range,
+      array_range,
+      function_range,
+      error_callback_range,
+      function_bind_this,
+      error_callback_bind_this,
+    }
+  }
+}
+
+impl Dependency for AmdRequireDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "AmdRequireDependency"
+  }
+}
+
+impl DependencyTemplate for AmdRequireDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let block = compilation.module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation);
+
+    let Some(function_range) = self.function_range else {
+      runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+      source.replace(
+        self.outer_range.0,
+        self.array_range.0,
+        &format!("{promise}.then(function() {{"),
+        None,
+      );
+      source.replace(
+        self.array_range.1,
+        self.outer_range.1,
+        &format!(";}})['catch']({})", RuntimeGlobals::UNCAUGHT_ERROR_HANDLER),
+        None,
+      );
+      return;
+    };
+
+    let bind_this = |bind_this: bool| if bind_this { ".bind(this)" } else { "" };
+    source.replace(
+      self.outer_range.0,
+      self.array_range.0,
+      &format!("{promise}.then(function() {{ "),
+      None,
+    );
+    source.insert(
+      self.array_range.0,
+      "var __WEBPACK_AMD_REQUIRE_ARRAY__ = ",
+      None,
+    );
+    source.replace(self.array_range.1, function_range.0, "; (", None);
+    source.insert(
+      function_range.1,
+      ").apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);",
+      None,
+    );
+    if let Some(error_callback_range) = self.error_callback_range {
+      source.replace(
+        function_range.1,
+        error_callback_range.0,
+        &format!("}}{})['catch'](", bind_this(self.function_bind_this)),
+        None,
+      );
+      source.replace(
+        error_callback_range.1,
+        self.outer_range.1,
+        &format!("{})", bind_this(self.error_callback_bind_this)),
+        None,
+      );
+    } else {
+      runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+      source.replace(
+        function_range.1,
+        self.outer_range.1,
+        &format!(
+          "}}{})['catch']({})",
+          bind_this(self.function_bind_this),
+          RuntimeGlobals::UNCAUGHT_ERROR_HANDLER
+        ),
+        None,
+      );
+    }
+  }
+}
+
+impl AsModuleDependency for AmdRequireDependency {}
+impl AsContextDependency for AmdRequireDependency {}
@@ -0,0 +1,60 @@
+use rspack_core::{AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory};
+use rspack_core::{DependencyId, DependencyType, ErrorSpan, ModuleDependency};
+
+/// A module request in the dependency array of `define` and `require`,
+/// the rendering is done by [super::AmdRequireArrayDependency]
+#[derive(Debug, Clone)]
+pub struct AmdRequireItemDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl AmdRequireItemDependency {
+  pub fn new(request: String, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for AmdRequireItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "AmdRequireItemDependency"
+  }
+}
+
+impl ModuleDependency for AmdRequireItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for AmdRequireItemDependency {}
+impl AsContextDependency for AmdRequireItemDependency {}
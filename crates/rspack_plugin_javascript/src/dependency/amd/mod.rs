@@ -0,0 +1,9 @@
+mod amd_define_dependency;
+mod amd_require_array_dependency;
+mod amd_require_dependency;
+mod amd_require_item_dependency;
+
+pub use amd_define_dependency::*;
+pub use amd_require_array_dependency::*;
+pub use amd_require_dependency::*;
+pub use amd_require_item_dependency::*;
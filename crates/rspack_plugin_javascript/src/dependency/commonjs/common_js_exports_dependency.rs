@@ -5,9 +5,10 @@ use rspack_core::{
   ModuleGraph, NormalInitFragment, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
   UsedName,
 };
+use serde::{Deserialize, Serialize};
 use swc_core::atoms::Atom;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ExportsBase {
   Exports,
   ModuleExports,
@@ -45,8 +46,9 @@ impl ExportsBase {
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommonJsExportsDependency {
+  #[serde(skip)]
   id: DependencyId,
   range: (u32, u32),
   value_range: Option<(u32, u32)>,
@@ -99,6 +101,10 @@ impl Dependency for CommonJsExportsDependency {
       ..Default::default()
     })
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
 }
 
 impl AsModuleDependency for CommonJsExportsDependency {}
@@ -3,9 +3,11 @@ use rspack_core::{AsContextDependency, Dependency, DependencyCategory, Dependenc
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ErrorSpan, ModuleDependency};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommonJsRequireDependency {
+  #[serde(skip)]
   id: DependencyId,
   request: String,
   optional: bool,
@@ -52,6 +54,10 @@ impl Dependency for CommonJsRequireDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "CommonJsRequireDependency"
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
 }
 
 impl ModuleDependency for CommonJsRequireDependency {
@@ -2,12 +2,18 @@ mod common_js_exports_dependency;
 mod common_js_require_dependency;
 // mod common_js
 mod module_decorator_dependency;
+mod require_ensure_dependency;
+mod require_ensure_item_dependency;
 mod require_header_dependency;
+mod require_include_dependency;
 mod require_resolve_dependency;
 
 pub use common_js_exports_dependency::CommonJsExportsDependency;
 pub use common_js_exports_dependency::ExportsBase;
 pub use common_js_require_dependency::CommonJsRequireDependency;
 pub use module_decorator_dependency::ModuleDecoratorDependency;
+pub use require_ensure_dependency::RequireEnsureDependency;
+pub use require_ensure_item_dependency::RequireEnsureItemDependency;
 pub use require_header_dependency::RequireHeaderDependency;
+pub use require_include_dependency::RequireIncludeDependency;
 pub use require_resolve_dependency::RequireResolveDependency;
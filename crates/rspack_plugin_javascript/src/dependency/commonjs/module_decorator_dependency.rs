@@ -2,8 +2,9 @@ use rspack_core::{
   DependencyTemplate, InitFragmentKey, InitFragmentStage, NormalInitFragment, RuntimeGlobals,
   TemplateContext, TemplateReplaceSource,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleDecoratorDependency {
   decorator: RuntimeGlobals,
 }
@@ -58,4 +59,11 @@ impl DependencyTemplate for ModuleDecoratorDependency {
       None,
     )));
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some((
+      "ModuleDecoratorDependency",
+      serde_json::to_value(self).ok()?,
+    ))
+  }
 }
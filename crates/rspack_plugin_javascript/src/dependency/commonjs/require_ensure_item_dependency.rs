@@ -0,0 +1,69 @@
+use rspack_core::{AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory};
+use rspack_core::{DependencyId, DependencyType, ErrorSpan, ExtendedReferencedExport};
+use rspack_core::{ModuleDependency, ModuleGraph, RuntimeSpec};
+
+/// A module in the dependency array of `require.ensure`, which is only loaded
+/// and executed when it's required in the callback
+#[derive(Debug, Clone)]
+pub struct RequireEnsureItemDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl RequireEnsureItemDependency {
+  pub fn new(request: String, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsureItem
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireEnsureItemDependency"
+  }
+}
+
+impl ModuleDependency for RequireEnsureItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl AsDependencyTemplate for RequireEnsureItemDependency {}
+impl AsContextDependency for RequireEnsureItemDependency {}
@@ -1,9 +1,11 @@
 use rspack_core::{AsContextDependency, AsModuleDependency, Dependency};
 use rspack_core::{DependencyId, DependencyLocation};
 use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequireHeaderDependency {
+  #[serde(skip)]
   id: DependencyId,
   loc: DependencyLocation,
 }
@@ -49,4 +51,8 @@ impl DependencyTemplate for RequireHeaderDependency {
       None,
     );
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some(("RequireHeaderDependency", serde_json::to_value(self).ok()?))
+  }
 }
@@ -0,0 +1,82 @@
+use rspack_core::{AsContextDependency, Dependency, DependencyCategory, DependencyId};
+use rspack_core::{DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport};
+use rspack_core::{ModuleDependency, ModuleGraph, RuntimeSpec};
+use rspack_core::{TemplateContext, TemplateReplaceSource};
+
+/// `require.include("./a")` includes the module in the chunk without executing it
+#[derive(Debug, Clone)]
+pub struct RequireIncludeDependency {
+  start: u32,
+  end: u32,
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl RequireIncludeDependency {
+  pub fn new(start: u32, end: u32, request: String, span: Option<ErrorSpan>) -> Self {
+    Self {
+      start,
+      end,
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for RequireIncludeDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireInclude
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireIncludeDependency"
+  }
+}
+
+impl ModuleDependency for RequireIncludeDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl DependencyTemplate for RequireIncludeDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(self.start, self.end, "undefined", None);
+  }
+}
+
+impl AsContextDependency for RequireIncludeDependency {}
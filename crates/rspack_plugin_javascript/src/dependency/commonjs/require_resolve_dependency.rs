@@ -3,11 +3,13 @@ use rspack_core::{
   DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
   TemplateContext, TemplateReplaceSource,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequireResolveDependency {
   pub start: u32,
   pub end: u32,
+  #[serde(skip)]
   pub id: DependencyId,
   pub request: String,
   pub weak: bool,
@@ -56,6 +58,10 @@ impl Dependency for RequireResolveDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "RequireResolveDependency"
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
 }
 
 impl ModuleDependency for RequireResolveDependency {
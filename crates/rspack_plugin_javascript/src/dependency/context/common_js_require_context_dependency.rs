@@ -68,6 +68,10 @@ impl ContextDependency for CommonJsRequireContextDependency {
     &self.options
   }
 
+  fn options_mut(&mut self) -> &mut ContextOptions {
+    &mut self.options
+  }
+
   fn get_context(&self) -> Option<&str> {
     None
   }
@@ -64,6 +64,10 @@ impl ContextDependency for ImportContextDependency {
     &self.options
   }
 
+  fn options_mut(&mut self) -> &mut ContextOptions {
+    &mut self.options
+  }
+
   fn request(&self) -> &str {
     &self.options.request
   }
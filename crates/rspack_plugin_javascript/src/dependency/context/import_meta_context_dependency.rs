@@ -60,6 +60,10 @@ impl ContextDependency for ImportMetaContextDependency {
     &self.options
   }
 
+  fn options_mut(&mut self) -> &mut ContextOptions {
+    &mut self.options
+  }
+
   fn get_context(&self) -> Option<&str> {
     None
   }
@@ -22,8 +22,16 @@ fn create_resource_identifier_for_context_dependency(
     .as_ref()
     .map(regexp_as_str)
     .unwrap_or_default();
-  let include = options.include.as_deref().unwrap_or_default();
-  let exclude = options.exclude.as_deref().unwrap_or_default();
+  let include = options
+    .include
+    .as_ref()
+    .map(regexp_as_str)
+    .unwrap_or_default();
+  let exclude = options
+    .exclude
+    .as_ref()
+    .map(regexp_as_str)
+    .unwrap_or_default();
   let mode = options.mode.as_str();
   // TODO: need `RawChunkGroupOptions`
   let id = format!(
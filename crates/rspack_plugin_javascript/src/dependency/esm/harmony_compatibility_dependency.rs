@@ -2,10 +2,11 @@ use rspack_core::{
   DependencyTemplate, InitFragmentKey, InitFragmentStage, NormalInitFragment, RuntimeGlobals,
   TemplateContext, TemplateReplaceSource,
 };
+use serde::{Deserialize, Serialize};
 
 // Mark module `__esModule`.
 // Add `__webpack_require__.r(__webpack_exports__);`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyCompatibilityDependency;
 
 impl DependencyTemplate for HarmonyCompatibilityDependency {
@@ -19,8 +20,12 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
       init_fragments,
       compilation,
       module,
+      concatenation_scope,
       ..
     } = code_generatable_context;
+    if concatenation_scope.is_some() {
+      return;
+    }
     let mgm = compilation
       .module_graph
       .module_graph_module_by_identifier(&module.identifier())
@@ -63,4 +68,11 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
       )));
     }
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some((
+      "HarmonyCompatibilityDependency",
+      serde_json::to_value(self).ok()?,
+    ))
+  }
 }
@@ -1,11 +1,10 @@
+pub use rspack_core::DEFAULT_EXPORT;
 use rspack_core::{AsContextDependency, AsModuleDependency, Dependency};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
+use serde::{Deserialize, Serialize};
 
-pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
-// pub const NAMESPACE_OBJECT_EXPORT: &'static str = "__WEBPACK_NAMESPACE_OBJECT__";
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnonymousFunctionRangeInfo {
   pub is_async: bool,
   pub is_generator: bool,
@@ -13,12 +12,13 @@ pub struct AnonymousFunctionRangeInfo {
   pub first_parmas_start: Option<u32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyExportExpressionDependency {
   pub start: u32,
   pub end: u32,
   pub declaration: bool,
   pub function: Option<AnonymousFunctionRangeInfo>,
+  #[serde(skip)]
   pub id: DependencyId,
 }
 
@@ -98,6 +98,13 @@ impl DependencyTemplate for HarmonyExportExpressionDependency {
       );
     }
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some((
+      "HarmonyExportExpressionDependency",
+      serde_json::to_value(self).ok()?,
+    ))
+  }
 }
 
 impl AsContextDependency for HarmonyExportExpressionDependency {}
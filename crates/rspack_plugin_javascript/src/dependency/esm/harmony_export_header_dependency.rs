@@ -1,9 +1,10 @@
 use rspack_core::{DependencyTemplate, TemplateContext, TemplateReplaceSource};
+use serde::{Deserialize, Serialize};
 
 // Remove `export` label.
 // Before: `export const a = 1`
 // After: `const a = 1`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyExportHeaderDependency {
   pub position: u32,
 }
@@ -22,4 +23,11 @@ impl DependencyTemplate for HarmonyExportHeaderDependency {
   ) {
     source.replace(self.position, self.position + 6 /* export */, "", None);
   }
+
+  fn pack_template(&self) -> Option<(&'static str, serde_json::Value)> {
+    Some((
+      "HarmonyExportHeaderDependency",
+      serde_json::to_value(self).ok()?,
+    ))
+  }
 }
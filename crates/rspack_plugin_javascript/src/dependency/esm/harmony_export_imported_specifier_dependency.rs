@@ -5,22 +5,28 @@ use rspack_core::{
   create_exports_object_referenced, create_no_exports_referenced, export_from_import,
   get_exports_type, get_import_var, process_export_info, AsContextDependency, ConnectionState,
   Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate,
-  DependencyType, ExportInfoId, ExportInfoProvided, ExportNameOrSpec, ExportSpec, ExportsInfoId,
-  ExportsOfExportsSpec, ExportsSpec, ExportsType, ExtendedReferencedExport,
-  HarmonyExportInitFragment, ModuleDependency, ModuleGraph, ModuleIdentifier, RuntimeSpec,
-  TemplateContext, TemplateReplaceSource, UsageState, UsedName,
+  DependencyType, ExportInfoId, ExportInfoProvided, ExportNameOrSpec, ExportPresenceMode,
+  ExportSpec, ExportsInfoId, ExportsOfExportsSpec, ExportsSpec, ExportsType,
+  ExtendedReferencedExport, HarmonyExportInitFragment, ModuleDependency, ModuleGraph,
+  ModuleIdentifier, RuntimeSpec, TemplateContext, TemplateReplaceSource, UsageState, UsedName,
 };
+use rspack_error::Diagnostic;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
-use super::{create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply};
+use super::{
+  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply,
+  harmony_import_dependency_get_linking_diagnostics,
+};
 
 // Create _webpack_require__.d(__webpack_exports__, {}).
 // case1: `import { a } from 'a'; export { a }`
 // case2: `export { a } from 'a';`
 // case3: `export * from 'a'`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyExportImportedSpecifierDependency {
+  #[serde(skip)]
   pub id: DependencyId,
   pub source_order: i32,
   pub request: JsWord,
@@ -32,11 +38,14 @@ pub struct HarmonyExportImportedSpecifierDependency {
   // Because it is shared by multiply HarmonyExportImportedSpecifierDependency, so put it to `BuildInfo`
   // pub active_exports: HashSet<JsWord>,
   // pub all_star_exports: Option<Vec<DependencyId>>,
-  pub other_star_exports: Option<Vec<DependencyId>>,
+  // `other_star_exports` are the star exports before this one in `all_star_exports`, they are
+  // looked up from `BuildInfo` as the dependency ids are renewed when restored from the cache
   pub export_all: bool,
+  export_presence_mode: ExportPresenceMode,
 }
 
 impl HarmonyExportImportedSpecifierDependency {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     request: JsWord,
     source_order: i32,
@@ -44,7 +53,7 @@ impl HarmonyExportImportedSpecifierDependency {
     mode_ids: Vec<(JsWord, Option<JsWord>)>,
     name: Option<JsWord>,
     export_all: bool,
-    other_star_exports: Option<Vec<DependencyId>>,
+    export_presence_mode: ExportPresenceMode,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
@@ -56,7 +65,7 @@ impl HarmonyExportImportedSpecifierDependency {
       ids,
       resource_identifier,
       export_all,
-      other_star_exports,
+      export_presence_mode,
     }
   }
 
@@ -415,39 +424,24 @@ impl HarmonyExportImportedSpecifierDependency {
     &self,
     module_graph: &ModuleGraph,
   ) -> Option<DiscoverActiveExportsFromOtherStarExportsRet> {
-    if let Some(other_star_exports) = &self.other_star_exports {
-      if other_star_exports.is_empty() {
-        return None;
-      }
-    } else {
+    if !self.export_all {
       return None;
     }
-    let i = self.other_star_exports.as_ref()?.len();
-
+    // the count of other star exports, which are the ones before this dependency
     let all_star_exports = self.all_star_exports(module_graph);
-    if !all_star_exports.is_empty() {
-      let (names, dependency_indices) =
-        determine_export_assignments(module_graph, all_star_exports.clone(), None);
-
-      return Some(DiscoverActiveExportsFromOtherStarExportsRet {
-        names,
-        names_slice: dependency_indices[i - 1],
-        dependency_indices,
-        dependency_index: i,
-      });
+    let i = all_star_exports.iter().position(|id| id == &self.id)?;
+    if i == 0 {
+      return None;
     }
 
-    if let Some(other_star_exports) = &self.other_star_exports {
-      let (names, dependency_indices) =
-        determine_export_assignments(module_graph, other_star_exports.clone(), Some(self.id));
-      return Some(DiscoverActiveExportsFromOtherStarExportsRet {
-        names,
-        names_slice: dependency_indices[i - 1],
-        dependency_indices,
-        dependency_index: i,
-      });
-    }
-    None
+    let (names, dependency_indices) =
+      determine_export_assignments(module_graph, all_star_exports.clone(), None);
+    Some(DiscoverActiveExportsFromOtherStarExportsRet {
+      names,
+      names_slice: dependency_indices[i - 1],
+      dependency_indices,
+      dependency_index: i,
+    })
   }
 }
 
@@ -469,6 +463,25 @@ impl DependencyTemplate for HarmonyExportImportedSpecifierDependency {
     let module = &code_generatable_context.module;
     let runtime = code_generatable_context.runtime;
 
+    if let Some(scope) = code_generatable_context.concatenation_scope.as_deref_mut() {
+      if let Some(imported_module) = compilation
+        .module_graph
+        .module_identifier_by_dependency_id(&self.id)
+      {
+        if self.export_all {
+          scope.register_star_reexport(*imported_module);
+        }
+        for (name, imported) in &self.ids {
+          scope.register_reexport(
+            name.clone(),
+            *imported_module,
+            imported.clone().map(|i| vec![i]).unwrap_or_default(),
+          );
+        }
+      }
+      return;
+    }
+
     let mgm = compilation
       .module_graph
       .module_graph_module_by_identifier(&module.identifier())
@@ -766,10 +779,27 @@ impl Dependency for HarmonyExportImportedSpecifierDependency {
       })
   }
 
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let name = self.name.as_ref()?;
+    harmony_import_dependency_get_linking_diagnostics(
+      module_graph,
+      &self.id,
+      &self.get_ids(module_graph),
+      &self.request,
+      &format!("(reexported as '{name}')"),
+      self.export_presence_mode,
+      self.span(),
+    )
+  }
+
   fn dependency_debug_name(&self) -> &'static str {
     "HarmonyExportImportedSpecifierDependency"
   }
 
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
@@ -780,6 +810,10 @@ impl ModuleDependency for HarmonyExportImportedSpecifierDependency {
     &self.request
   }
 
+  fn source_order(&self) -> Option<i32> {
+    Some(self.source_order)
+  }
+
   fn user_request(&self) -> &str {
     &self.request
   }
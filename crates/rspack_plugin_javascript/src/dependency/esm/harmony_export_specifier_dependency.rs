@@ -3,11 +3,13 @@ use rspack_core::{
   DependencyTemplate, DependencyType, ExportNameOrSpec, ExportsOfExportsSpec, ExportsSpec,
   HarmonyExportInitFragment, ModuleGraph, TemplateContext, TemplateReplaceSource, UsedName,
 };
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
 // Create _webpack_require__.d(__webpack_exports__, {}) for each export.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyExportSpecifierDependency {
+  #[serde(skip)]
   id: DependencyId,
   name: JsWord,
   value: JsWord, // id
@@ -28,6 +30,10 @@ impl Dependency for HarmonyExportSpecifierDependency {
     "HarmonyExportSpecifierDependency"
   }
 
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn id(&self) -> &DependencyId {
     &self.id
   }
@@ -75,8 +81,13 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
       compilation,
       module,
       runtime,
+      concatenation_scope,
       ..
     } = code_generatable_context;
+    if let Some(scope) = concatenation_scope {
+      scope.register_export(self.name.clone(), self.value.clone());
+      return;
+    }
 
     let mgm = compilation
       .module_graph
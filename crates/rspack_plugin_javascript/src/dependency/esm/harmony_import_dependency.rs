@@ -9,13 +9,15 @@ use rspack_core::{
   InitFragmentKey, InitFragmentStage, ModuleDependency, ModuleIdentifier, NormalInitFragment,
   RuntimeGlobals, TemplateContext, TemplateReplaceSource,
 };
-use rspack_core::{ModuleGraph, RuntimeSpec};
+use rspack_core::{ExportPresenceMode, ExportsType, ModuleGraph, RuntimeSpec};
+use rspack_error::Diagnostic;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
 use super::create_resource_identifier_for_esm_dependency;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Specifier {
   Namespace(JsWord),
   Default(JsWord),
@@ -23,10 +25,11 @@ pub enum Specifier {
 }
 
 // HarmonyImportDependency is merged HarmonyImportSideEffectDependency.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyImportSideEffectDependency {
   pub request: JsWord,
   pub source_order: i32,
+  #[serde(skip)]
   pub id: DependencyId,
   pub span: Option<ErrorSpan>,
   pub specifiers: Vec<Specifier>,
@@ -88,6 +91,11 @@ pub fn harmony_import_dependency_apply<T: ModuleDependency>(
   if !is_target_active {
     return;
   }
+  if let Some(scope) = &code_generatable_context.concatenation_scope
+    && scope.is_module_in_scope(&ref_mgm.module_identifier)
+  {
+    return;
+  }
   if module_dependency.is_export_all() == Some(false) {
     let specifiers = specifiers
       .iter()
@@ -224,11 +232,66 @@ pub fn harmony_import_dependency_apply<T: ModuleDependency>(
   }
 }
 
+// `HarmonyImportDependency.getLinkingErrors` in webpack
+pub fn harmony_import_dependency_get_linking_diagnostics(
+  module_graph: &ModuleGraph,
+  id: &DependencyId,
+  ids: &[JsWord],
+  user_request: &str,
+  additional_message: &str,
+  export_presence_mode: ExportPresenceMode,
+  span: Option<ErrorSpan>,
+) -> Option<Vec<Diagnostic>> {
+  let first = ids.first()?;
+  let imported_module = module_graph.module_identifier_by_dependency_id(id)?;
+  let strict = module_graph
+    .parent_module_by_dependency_id(id)
+    .and_then(|parent| module_graph.module_graph_module_by_identifier(&parent))?
+    .get_strict_harmony_module();
+  let exports_type = module_graph
+    .module_graph_module_by_identifier(imported_module)?
+    .get_exports_type(strict);
+  let message = match exports_type {
+    ExportsType::Namespace | ExportsType::DefaultWithNamed => {
+      if matches!(exports_type, ExportsType::DefaultWithNamed) && first == "default" {
+        return None;
+      }
+      let provided = module_graph
+        .get_exports_info(imported_module)
+        .id
+        .is_export_provided(ids, module_graph);
+      if provided != Some(false) {
+        return None;
+      }
+      format!(
+        "export '{}' {additional_message} was not found in '{user_request}'",
+        ids.iter().map(|id| id.as_ref()).collect::<Vec<&str>>().join(".")
+      )
+    }
+    ExportsType::DefaultOnly if first != "default" => format!(
+      "Can't import the named export '{first}' {additional_message} from default-exporting module (only default export is available)"
+    ),
+    _ => return None,
+  };
+  let (start, end) = span.map_or((0, 0), |span| (span.start as usize, span.end as usize));
+  let title = String::from("HarmonyLinkingError");
+  let diagnostic = match export_presence_mode.get_effective(strict) {
+    ExportPresenceMode::None | ExportPresenceMode::Auto => return None,
+    ExportPresenceMode::Warn => Diagnostic::warn(title, message, start, end),
+    ExportPresenceMode::Error => Diagnostic::error(title, message, start, end),
+  };
+  Some(vec![diagnostic])
+}
+
 impl Dependency for HarmonyImportSideEffectDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "HarmonyImportSideEffectDependency"
   }
 
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn id(&self) -> &DependencyId {
     &self.id
   }
@@ -270,6 +333,10 @@ impl ModuleDependency for HarmonyImportSideEffectDependency {
     Some(self.export_all)
   }
 
+  fn source_order(&self) -> Option<i32> {
+    Some(self.source_order)
+  }
+
   fn request(&self) -> &str {
     &self.request
   }
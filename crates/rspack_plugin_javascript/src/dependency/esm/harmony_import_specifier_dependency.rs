@@ -3,19 +3,23 @@ use rspack_core::{
   create_exports_object_referenced, export_from_import, get_dependency_used_by_exports_condition,
   get_exports_type, tree_shaking::symbol::DEFAULT_JS_WORD, AsContextDependency, Compilation,
   ConnectionState, Dependency, DependencyCategory, DependencyCondition, DependencyId,
-  DependencyTemplate, DependencyType, ExportsType, ExtendedReferencedExport, ModuleDependency,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource, UsedByExports,
+  DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource, UsedByExports,
 };
+use rspack_error::Diagnostic;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::{common::Span, ecma::atoms::JsWord};
 
 use super::{
-  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply, Specifier,
+  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply,
+  harmony_import_dependency_get_linking_diagnostics, Specifier,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarmonyImportSpecifierDependency {
+  #[serde(skip)]
   pub id: DependencyId,
   request: JsWord,
   source_order: i32,
@@ -31,6 +35,7 @@ pub struct HarmonyImportSpecifierDependency {
   referenced_properties_in_destructuring: Option<HashSet<JsWord>>,
   resource_identifier: String,
   span_for_on_usage_search: Span,
+  export_presence_mode: ExportPresenceMode,
 }
 
 impl HarmonyImportSpecifierDependency {
@@ -47,6 +52,7 @@ impl HarmonyImportSpecifierDependency {
     specifier: Specifier,
     referenced_properties_in_destructuring: Option<HashSet<JsWord>>,
     span_for_on_usage_search: Span,
+    export_presence_mode: ExportPresenceMode,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
@@ -65,6 +71,7 @@ impl HarmonyImportSpecifierDependency {
       referenced_properties_in_destructuring,
       resource_identifier,
       span_for_on_usage_search,
+      export_presence_mode,
     }
   }
 
@@ -169,9 +176,20 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
     }
 
     let ids = self.get_ids(&compilation.module_graph);
-    compilation
-      .module_graph
-      .module_identifier_by_dependency_id(&self.id);
+    if let Some(scope) = code_generatable_context.concatenation_scope.as_deref()
+      && let Some(module) = compilation
+        .module_graph
+        .module_identifier_by_dependency_id(&self.id)
+      && scope.is_module_in_scope(module)
+    {
+      let reference = scope.create_module_reference(module, &ids, self.call, self.direct_import);
+      if self.shorthand {
+        source.insert(self.end, format!(": {reference}").as_str(), None);
+      } else {
+        source.replace(self.start, self.end, reference.as_str(), None);
+      }
+      return;
+    }
     let import_var = get_import_var(&compilation.module_graph, self.id);
 
     // TODO: scope hoist
@@ -239,10 +257,29 @@ impl Dependency for HarmonyImportSpecifierDependency {
       .unwrap_or_else(|| self.ids.clone())
   }
 
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let name = match &self.specifier {
+      Specifier::Namespace(local) | Specifier::Default(local) | Specifier::Named(local, _) => local,
+    };
+    harmony_import_dependency_get_linking_diagnostics(
+      module_graph,
+      &self.id,
+      &self.get_ids(module_graph),
+      &self.request,
+      &format!("(imported as '{name}')"),
+      self.export_presence_mode,
+      self.span(),
+    )
+  }
+
   fn dependency_debug_name(&self) -> &'static str {
     "HarmonyImportSpecifierDependency"
   }
 
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
@@ -253,6 +290,10 @@ impl ModuleDependency for HarmonyImportSpecifierDependency {
     &self.request
   }
 
+  fn source_order(&self) -> Option<i32> {
+    Some(self.source_order)
+  }
+
   fn user_request(&self) -> &str {
     &self.request
   }
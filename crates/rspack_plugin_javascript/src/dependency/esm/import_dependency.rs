@@ -1,13 +1,15 @@
 use rspack_core::{module_namespace_promise, DependencyType, ErrorSpan, ImportDependencyTrait};
-use rspack_core::{AsContextDependency, Dependency};
-use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
+use rspack_core::{AsContextDependency, Dependency, ExtendedReferencedExport, ModuleGraph};
+use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate, RuntimeSpec};
 use rspack_core::{ModuleDependency, TemplateContext, TemplateReplaceSource};
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportDependency {
   start: u32,
   end: u32,
+  #[serde(skip)]
   id: DependencyId,
   request: JsWord,
   span: Option<ErrorSpan>,
@@ -53,6 +55,10 @@ impl Dependency for ImportDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "ImportDependency"
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
 }
 
 impl ModuleDependency for ImportDependency {
@@ -67,6 +73,14 @@ impl ModuleDependency for ImportDependency {
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_referenced_exports(
+    &self,
+    module_graph: &ModuleGraph,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    ImportDependencyTrait::get_referenced_exports(self, module_graph, runtime)
+  }
 }
 
 impl ImportDependencyTrait for ImportDependency {
@@ -4,12 +4,14 @@ use rspack_core::{
   ModuleDependency, ModuleGraph, ReferencedExport, RuntimeSpec, TemplateContext,
   TemplateReplaceSource,
 };
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportEagerDependency {
   start: u32,
   end: u32,
+  #[serde(skip)]
   id: DependencyId,
   request: JsWord,
   span: Option<ErrorSpan>,
@@ -55,6 +57,10 @@ impl Dependency for ImportEagerDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "ImportEagerDependency"
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
 }
 
 impl ModuleDependency for ImportEagerDependency {
@@ -76,7 +82,10 @@ impl ModuleDependency for ImportEagerDependency {
     _runtime: Option<&RuntimeSpec>,
   ) -> Vec<ExtendedReferencedExport> {
     if let Some(referenced_exports) = &self.referenced_exports {
-      vec![ReferencedExport::new(referenced_exports.clone(), false).into()]
+      referenced_exports
+        .iter()
+        .map(|name| ReferencedExport::new(vec![name.clone()], false).into())
+        .collect()
     } else {
       vec![ExtendedReferencedExport::Array(vec![])]
     }
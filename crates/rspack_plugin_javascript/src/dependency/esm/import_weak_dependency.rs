@@ -0,0 +1,132 @@
+use rspack_core::{
+  module_namespace_promise, AsContextDependency, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport, ImportDependencyTrait,
+  ModuleDependency, ModuleGraph, ReferencedExport, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
+};
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::atoms::JsWord;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportWeakDependency {
+  start: u32,
+  end: u32,
+  #[serde(skip)]
+  id: DependencyId,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  referenced_exports: Option<Vec<JsWord>>,
+}
+
+impl ImportWeakDependency {
+  pub fn new(
+    start: u32,
+    end: u32,
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    referenced_exports: Option<Vec<JsWord>>,
+  ) -> Self {
+    Self {
+      start,
+      end,
+      request,
+      span,
+      id: DependencyId::new(),
+      referenced_exports,
+    }
+  }
+}
+
+impl Dependency for ImportWeakDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::DynamicImportWeak
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "ImportWeakDependency"
+  }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+}
+
+impl ModuleDependency for ImportWeakDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn weak(&self) -> bool {
+    true
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    if let Some(referenced_exports) = &self.referenced_exports {
+      referenced_exports
+        .iter()
+        .map(|name| ReferencedExport::new(vec![name.clone()], false).into())
+        .collect()
+    } else {
+      vec![ExtendedReferencedExport::Array(vec![])]
+    }
+  }
+}
+
+impl ImportDependencyTrait for ImportWeakDependency {
+  fn referenced_exports(&self) -> Option<&Vec<JsWord>> {
+    self.referenced_exports.as_ref()
+  }
+}
+
+impl DependencyTemplate for ImportWeakDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let block = code_generatable_context
+      .compilation
+      .module_graph
+      .get_parent_block(&self.id);
+    source.replace(
+      self.start,
+      self.end,
+      module_namespace_promise(
+        code_generatable_context,
+        &self.id,
+        block,
+        &self.request,
+        self.dependency_type().as_str().as_ref(),
+        true,
+      )
+      .as_str(),
+      None,
+    );
+  }
+}
+
+impl AsContextDependency for ImportWeakDependency {}
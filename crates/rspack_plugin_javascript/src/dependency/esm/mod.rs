@@ -7,6 +7,7 @@ mod harmony_import_dependency;
 mod harmony_import_specifier_dependency;
 mod import_dependency;
 mod import_eager_dependency;
+mod import_weak_dependency;
 
 pub use harmony_compatibility_dependency::HarmonyCompatibilityDependency;
 pub use harmony_export_expression_dependency::HarmonyExportExpressionDependency;
@@ -14,11 +15,14 @@ pub use harmony_export_expression_dependency::{AnonymousFunctionRangeInfo, DEFAU
 pub use harmony_export_header_dependency::HarmonyExportHeaderDependency;
 pub use harmony_export_imported_specifier_dependency::HarmonyExportImportedSpecifierDependency;
 pub use harmony_export_specifier_dependency::HarmonyExportSpecifierDependency;
-pub use harmony_import_dependency::harmony_import_dependency_apply;
+pub use harmony_import_dependency::{
+  harmony_import_dependency_apply, harmony_import_dependency_get_linking_diagnostics,
+};
 pub use harmony_import_dependency::{HarmonyImportSideEffectDependency, Specifier};
 pub use harmony_import_specifier_dependency::HarmonyImportSpecifierDependency;
 pub use import_dependency::ImportDependency;
 pub use import_eager_dependency::ImportEagerDependency;
+pub use import_weak_dependency::ImportWeakDependency;
 use rspack_core::DependencyCategory;
 
 pub fn create_resource_identifier_for_esm_dependency(request: &str) -> String {
@@ -1,3 +1,4 @@
+mod amd;
 mod commonjs;
 mod context;
 mod esm;
@@ -8,6 +9,7 @@ mod pure_expression_dependency;
 mod url;
 mod worker;
 
+pub use amd::*;
 pub use commonjs::*;
 pub use context::*;
 pub use esm::*;
@@ -4,11 +4,14 @@ use rspack_core::{
   TemplateReplaceSource, UsageState, UsedByExports, UsedName,
 };
 use rustc_hash::FxHashSet as HashSet;
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PureExpressionDependency {
   pub start: u32,
   pub end: u32,
   pub used_by_exports: Option<UsedByExports>,
+  #[serde(skip)]
   id: DependencyId,
   pub module_identifier: ModuleIdentifier,
 }
@@ -37,6 +40,11 @@ impl Dependency for PureExpressionDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "PureExpressionDependency"
   }
+
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn get_module_evaluation_side_effects_state(
     &self,
     _module_graph: &ModuleGraph,
@@ -4,20 +4,29 @@ use rspack_core::{
   ErrorSpan, ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
   UsedByExports,
 };
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::JsWord;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct URLDependency {
   start: u32,
   end: u32,
+  #[serde(skip)]
   id: DependencyId,
   request: JsWord,
   span: Option<ErrorSpan>,
   used_by_exports: Option<UsedByExports>,
+  relative: bool,
 }
 
 impl URLDependency {
-  pub fn new(start: u32, end: u32, request: JsWord, span: Option<ErrorSpan>) -> Self {
+  pub fn new(
+    start: u32,
+    end: u32,
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    relative: bool,
+  ) -> Self {
     Self {
       start,
       end,
@@ -25,6 +34,7 @@ impl URLDependency {
       request,
       span,
       used_by_exports: None,
+      relative,
     }
   }
 }
@@ -34,6 +44,10 @@ impl Dependency for URLDependency {
     "URLDependency"
   }
 
+  fn pack(&self) -> Option<serde_json::Value> {
+    serde_json::to_value(self).ok()
+  }
+
   fn id(&self) -> &DependencyId {
     &self.id
   }
@@ -81,21 +95,28 @@ impl DependencyTemplate for URLDependency {
       ..
     } = code_generatable_context;
 
-    runtime_requirements.insert(RuntimeGlobals::BASE_URI);
     runtime_requirements.insert(RuntimeGlobals::REQUIRE);
 
-    source.replace(
-      self.start,
-      self.end,
+    // `start` and `end` cover the whole `new URL()` expression in relative mode, and only the
+    // arguments otherwise
+    let content = if self.relative {
+      runtime_requirements.insert(RuntimeGlobals::RELATIVE_URL);
+      format!(
+        "new {}(/* asset import */{}({}))",
+        RuntimeGlobals::RELATIVE_URL,
+        RuntimeGlobals::REQUIRE,
+        module_id(compilation, &self.id, &self.request, false),
+      )
+    } else {
+      runtime_requirements.insert(RuntimeGlobals::BASE_URI);
       format!(
         "/* asset import */{}({}), {}",
         RuntimeGlobals::REQUIRE,
         module_id(compilation, &self.id, &self.request, false),
         RuntimeGlobals::BASE_URI
       )
-      .as_str(),
-      None,
-    );
+    };
+    source.replace(self.start, self.end, content.as_str(), None);
   }
 }
 
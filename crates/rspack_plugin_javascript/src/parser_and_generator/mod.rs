@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
 use rspack_ast::RspackAst;
 use rspack_core::rspack_sources::{
   BoxSource, MapOptions, OriginalSource, RawSource, ReplaceSource, Source, SourceExt, SourceMap,
@@ -8,8 +11,8 @@ use rspack_core::tree_shaking::js_module::JsModule;
 use rspack_core::tree_shaking::visitor::OptimizeAnalyzeResult;
 use rspack_core::{
   render_init_fragments, AsyncDependenciesBlockIdentifier, Compilation, DependenciesBlock,
-  DependencyId, GenerateContext, Module, ParseContext, ParseResult, ParserAndGenerator, SourceType,
-  TemplateContext, TemplateReplaceSource,
+  DependencyId, GenerateContext, JavascriptParserOptions, Module, ParseContext, ParseResult,
+  ParserAndGenerator, SourceType, TemplateContext, TemplateReplaceSource,
 };
 use rspack_error::{
   internal_error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray,
@@ -18,17 +21,33 @@ use swc_core::common::SyntaxContext;
 
 use crate::ast::CodegenOptions;
 use crate::inner_graph_plugin::InnerGraphPlugin;
+use crate::parser_plugin::{BoxJavascriptParserPlugin, JavaScriptParserPluginDrive};
 use crate::utils::syntax_by_module_type;
 use crate::visitors::ScanDependenciesResult;
 use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
 use crate::{SideEffectsFlagPluginVisitor, SyntaxContextInfo};
+
+static DEFAULT_PARSER_OPTIONS: Lazy<JavascriptParserOptions> =
+  Lazy::new(JavascriptParserOptions::default);
+
 #[derive(Debug)]
-pub struct JavaScriptParserAndGenerator;
+pub struct JavaScriptParserAndGenerator {
+  /// Shared by the modules, it's only replaced when a plugin is added to this module
+  parser_plugin_drive: Arc<JavaScriptParserPluginDrive>,
+}
 
 #[allow(unused)]
 impl JavaScriptParserAndGenerator {
-  pub(crate) fn new() -> Self {
-    Self {}
+  pub(crate) fn new(parser_plugin_drive: Arc<JavaScriptParserPluginDrive>) -> Self {
+    Self {
+      parser_plugin_drive,
+    }
+  }
+
+  /// Registers a plugin to the hooks of the javascript parser of this module, it's called after
+  /// the plugins registered to [crate::JsPlugin] and before the builtin plugins
+  pub fn add_parser_plugin(&mut self, plugin: BoxJavascriptParserPlugin) {
+    self.parser_plugin_drive = Arc::new(self.parser_plugin_drive.with_plugin(plugin.into()));
   }
 
   fn source_block(
@@ -90,8 +109,21 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       build_meta,
       module_identifier,
       mut additional_data,
+      module_parser_options,
       ..
     } = parse_context;
+    // `Rule.parser` takes precedence over `module.parser`
+    let parser_options = module_parser_options
+      .and_then(|p| p.get_javascript(module_type))
+      .or_else(|| {
+        compiler_options
+          .module
+          .parser
+          .as_ref()
+          .and_then(|p| p.get(module_type))
+          .and_then(|p| p.get_javascript(module_type))
+      })
+      .unwrap_or(&DEFAULT_PARSER_OPTIONS);
 
     let mut diagnostics: Vec<Diagnostic> = vec![];
     let syntax = syntax_by_module_type(
@@ -192,9 +224,11 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         resource_data,
         compiler_options,
         module_type,
+        parser_options,
         build_info,
         build_meta,
         module_identifier,
+        &self.parser_plugin_drive,
       )
     }) {
       Ok(result) => result,
@@ -310,6 +344,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         runtime_requirements: generate_context.runtime_requirements,
         init_fragments: &mut init_fragments,
         runtime: generate_context.runtime,
+        concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
       };
 
       module.get_dependencies().iter().for_each(|dependency_id| {
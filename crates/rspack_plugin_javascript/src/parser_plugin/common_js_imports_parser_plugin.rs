@@ -0,0 +1,305 @@
+use rspack_core::{context_reg_exp, ConstDependency, ContextMode, ContextNameSpaceObject};
+use rspack_core::{ContextOptions, DependencyCategory, SpanExt};
+use swc_core::common::{Spanned, SyntaxContext};
+use swc_core::ecma::ast::{BinExpr, CallExpr, Expr, Ident, IfStmt, Lit, MemberExpr, UnaryExpr};
+use swc_core::ecma::visit::VisitWith;
+
+use super::JavascriptParserPlugin;
+use crate::dependency::RequireResolveDependency;
+use crate::dependency::{CommonJsRequireContextDependency, RequireHeaderDependency};
+use crate::dependency::{CommonJsRequireDependency, RequireIncludeDependency};
+use crate::utils::{evaluate_to_string, expression_logic_operator, BasicEvaluatedExpression};
+use crate::utils::{try_extract_magic_comment_options, Continue, EvaluateExpression};
+use crate::visitors::context_helper::{critical_dependency_warning, scanner_context_module};
+use crate::visitors::JavascriptParser;
+
+/// Same as `CommonJsImportsParserPlugin` in webpack, handles `require` and the `require.*` functions.
+// TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
+// https://github.com/web-infra-dev/rspack/issues/3544
+#[derive(Debug)]
+pub struct CommonJsImportsParserPlugin;
+
+impl CommonJsImportsParserPlugin {
+  // `require(/* webpackIgnore: true */ "./a")`, only when `commonjsMagicComments` is enabled
+  fn is_ignored_by_magic_comment(parser: &mut JavascriptParser, call_expr: &CallExpr) -> bool {
+    if !parser.javascript_options.commonjs_magic_comments {
+      return false;
+    }
+    let Some(arg) = call_expr.args.first() else {
+      return false;
+    };
+    let options = try_extract_magic_comment_options(
+      parser.comments,
+      arg.span(),
+      call_expr.span,
+      parser.warning_diagnostics,
+    );
+    matches!(options.ignore, Some(true))
+  }
+
+  fn add_require_resolve(parser: &mut JavascriptParser, node: &CallExpr, weak: bool) {
+    if let Some(Lit::Str(str)) = node.args.first().and_then(|x| x.expr.as_lit()) {
+      parser
+        .dependencies
+        .push(Box::new(RequireResolveDependency::new(
+          node.span.real_lo(),
+          node.span.real_hi(),
+          str.value.to_string(),
+          weak,
+          node.span.into(),
+          parser.in_try,
+        )));
+    }
+  }
+
+  fn add_require_include(parser: &mut JavascriptParser, node: &CallExpr) {
+    if let [arg] = node.args.as_slice()
+      && let Some(Lit::Str(str)) = arg.expr.as_lit()
+    {
+      parser
+        .dependencies
+        .push(Box::new(RequireIncludeDependency::new(
+          node.span.real_lo(),
+          node.span.real_hi(),
+          str.value.to_string(),
+          Some(node.span.into()),
+        )));
+    }
+  }
+
+  fn replace_require_resolve(parser: &mut JavascriptParser, expr: &Expr, value: &'static str) {
+    if matches!(
+      parser.get_name_for_expr(expr).as_deref(),
+      Some("require" | "require.resolve" | "require.resolveWeak")
+    ) {
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span().real_lo(),
+          expr.span().real_hi(),
+          value.into(),
+          None,
+        )));
+    }
+  }
+
+  // `require("./a")` and `require(cond ? "./a" : "./b")`, returns whether any dependency is added
+  fn require_handler(parser: &mut JavascriptParser, call_expr: &CallExpr) -> bool {
+    let [argument] = call_expr.args.as_slice() else {
+      return false;
+    };
+    let param = parser.evaluate_expression(&argument.expr);
+    let mut added = false;
+    let mut add_require_item = |parser: &mut JavascriptParser, p: &BasicEvaluatedExpression| {
+      if !p.is_string() {
+        return false;
+      }
+      parser
+        .dependencies
+        .push(Box::new(CommonJsRequireDependency::new(
+          p.string().to_string(),
+          Some(call_expr.span.into()),
+          p.range().0,
+          p.range().1,
+          parser.in_try,
+        )));
+      added = true;
+      true
+    };
+
+    if param.is_conditional() {
+      let mut is_expression = false;
+      for p in param.options() {
+        if !add_require_item(parser, p) {
+          is_expression = true;
+        }
+      }
+      if !is_expression {
+        parser
+          .presentational_dependencies
+          .push(Box::new(RequireHeaderDependency::new(
+            call_expr.callee.span().real_lo(),
+            call_expr.callee.span().hi().0,
+          )));
+      }
+    }
+
+    if add_require_item(parser, &param) {
+      parser
+        .presentational_dependencies
+        .push(Box::new(RequireHeaderDependency::new(
+          call_expr.callee.span().real_lo(),
+          call_expr.callee.span_hi().0,
+        )));
+    }
+    added
+  }
+
+  // `require(expr)` which can't be evaluated to strings, e.g. `require("./locale/" + name)`
+  fn require_context_handler(parser: &mut JavascriptParser, call_expr: &CallExpr) {
+    let [argument] = call_expr.args.as_slice() else {
+      return;
+    };
+    if argument.spread.is_some() {
+      call_expr.args.visit_with(parser);
+      return;
+    }
+    let Some((context, reg)) = scanner_context_module(
+      &argument.expr,
+      parser.javascript_options.wrapped_context_reg_exp.source(),
+    ) else {
+      if parser.javascript_options.expr_context_critical {
+        parser.warning_diagnostics.push(critical_dependency_warning(
+          "the request of a dependency is an expression",
+          call_expr.span,
+        ));
+      }
+      call_expr.args.visit_with(parser);
+      return;
+    };
+    if parser.javascript_options.wrapped_context_critical {
+      parser.warning_diagnostics.push(critical_dependency_warning(
+        "a part of the request of a dependency is an expression",
+        call_expr.span,
+      ));
+    }
+    parser
+      .dependencies
+      .push(Box::new(CommonJsRequireContextDependency::new(
+        call_expr.callee.span().real_lo(),
+        call_expr.callee.span().real_hi(),
+        call_expr.span.real_hi(),
+        ContextOptions {
+          chunk_name: None,
+          group_options: None,
+          referenced_exports: None,
+          mode: ContextMode::Sync,
+          recursive: true,
+          reg_exp: context_reg_exp(&reg, ""),
+          reg_str: reg,
+          include: None,
+          exclude: None,
+          category: DependencyCategory::CommonJS,
+          request: context,
+          namespace_object: ContextNameSpaceObject::Unset,
+        },
+        Some(call_expr.span.into()),
+      )));
+  }
+}
+
+impl JavascriptParserPlugin for CommonJsImportsParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    match for_name {
+      "require" => {
+        if Self::is_ignored_by_magic_comment(parser, expr) {
+          expr.args.visit_with(parser);
+        } else if Self::require_handler(parser, expr) {
+          // the request is evaluated, there is nothing else to walk
+        } else if expr.args.len() == 1 {
+          Self::require_context_handler(parser, expr);
+        } else {
+          expr.args.visit_with(parser);
+        }
+      }
+      "require.resolve" => Self::add_require_resolve(parser, expr, false),
+      "require.resolveWeak" => Self::add_require_resolve(parser, expr, true),
+      "require.include" => Self::add_require_include(parser, expr),
+      // `require.context`, `require.ensure` and so on are handled by other scanners
+      _ if for_name.starts_with("require.") => expr.args.visit_with(parser),
+      _ => return None,
+    }
+    Some(true)
+  }
+
+  fn member(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    // `require.cache`, `require.main` and so on are handled by other scanners
+    for_name.starts_with("require.").then_some(true)
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name != "require" {
+      return None;
+    }
+    if parser.javascript_options.unknown_context_critical {
+      parser.warning_diagnostics.push(critical_dependency_warning(
+        "require function is used in a way in which dependencies cannot be statically extracted",
+        ident.span,
+      ));
+    }
+    Some(true)
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if !matches!(
+      for_name,
+      "require" | "require.resolve" | "require.resolveWeak" | "require.ensure" | "require.include"
+    ) {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        "'function'".into(),
+        None,
+      )));
+    Some(true)
+  }
+
+  fn statement_if(&self, parser: &mut JavascriptParser, stmt: &IfStmt) -> Option<bool> {
+    Self::replace_require_resolve(parser, &stmt.test, "true");
+    None
+  }
+
+  fn binary_expression(&self, parser: &mut JavascriptParser, expr: &BinExpr) -> Option<bool> {
+    let value = if parser.in_if { "true" } else { "undefined" };
+    Self::replace_require_resolve(parser, &expr.left, value);
+    Self::replace_require_resolve(parser, &expr.right, value);
+
+    // the left side of `&&` and `||` can't be evaluated, walk it as usual
+    let (Some(deps), c) = expression_logic_operator(parser, expr) else {
+      return None;
+    };
+    for dep in deps {
+      parser.presentational_dependencies.push(Box::new(dep))
+    }
+    if c.contains(Continue::LEFT) {
+      expr.left.visit_with(parser);
+    }
+    if c.contains(Continue::RIGHT) {
+      expr.right.visit_with(parser);
+    }
+    Some(true)
+  }
+
+  fn evaluate_typeof(
+    &self,
+    expression: &Ident,
+    start: u32,
+    end: u32,
+    unresolved_mark: SyntaxContext,
+  ) -> Option<BasicEvaluatedExpression> {
+    if expression.sym.as_str() == "require" && expression.span.ctxt == unresolved_mark {
+      Some(evaluate_to_string("function".to_string(), start, end))
+    } else {
+      None
+    }
+  }
+}
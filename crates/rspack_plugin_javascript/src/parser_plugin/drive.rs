@@ -1,23 +1,201 @@
-use super::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
+use std::sync::Arc;
+
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  BinExpr, CallExpr, Ident, IfStmt, ImportDecl, MemberExpr, ModuleDecl, NewExpr, Program, UnaryExpr,
+};
+
+use super::{
+  CommonJsImportsParserPlugin, JavascriptParserPlugin, RequireEnsureDependenciesBlockParserPlugin,
+  SharedJavascriptParserPlugin,
+};
 use crate::utils::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
 
+#[derive(Debug, Default)]
 pub struct JavaScriptParserPluginDrive {
-  plugins: Vec<BoxJavascriptParserPlugin>,
+  plugins: Vec<SharedJavascriptParserPlugin>,
+  /// The number of the registered plugins, the builtin plugins are placed after them
+  registered: usize,
 }
 
 impl JavaScriptParserPluginDrive {
-  pub fn new(plugins: Vec<BoxJavascriptParserPlugin>) -> Self {
-    Self { plugins }
+  pub fn new(plugins: Vec<SharedJavascriptParserPlugin>) -> Self {
+    Self {
+      registered: plugins.len(),
+      plugins,
+    }
+  }
+
+  /// The builtin plugins are called after the registered plugins, same as webpack
+  pub(crate) fn with_builtin_plugins(mut plugins: Vec<SharedJavascriptParserPlugin>) -> Self {
+    let registered = plugins.len();
+    plugins.push(Arc::new(RequireEnsureDependenciesBlockParserPlugin));
+    plugins.push(Arc::new(CommonJsImportsParserPlugin));
+    Self {
+      plugins,
+      registered,
+    }
+  }
+
+  /// Returns a drive with `plugin` registered after the registered plugins
+  pub(crate) fn with_plugin(&self, plugin: SharedJavascriptParserPlugin) -> Self {
+    let mut plugins = self.plugins.clone();
+    plugins.insert(self.registered, plugin);
+    Self {
+      plugins,
+      registered: self.registered + 1,
+    }
   }
 }
 
 impl JavascriptParserPlugin for JavaScriptParserPluginDrive {
+  fn program(&self, parser: &mut JavascriptParser, ast: &Program) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.program(parser, ast);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn import(
+    &self,
+    parser: &mut JavascriptParser,
+    statement: &ImportDecl,
+    source: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.import(parser, statement, source);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn export(&self, parser: &mut JavascriptParser, statement: &ModuleDecl) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.export(parser, statement);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn export_import(
+    &self,
+    parser: &mut JavascriptParser,
+    statement: &ModuleDecl,
+    source: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.export_import(parser, statement, source);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn statement_if(&self, parser: &mut JavascriptParser, stmt: &IfStmt) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.statement_if(parser, stmt);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.identifier(parser, ident, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.call(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.member(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn new_expression(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &NewExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.new_expression(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.r#typeof(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn binary_expression(&self, parser: &mut JavascriptParser, expr: &BinExpr) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.binary_expression(parser, expr);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
   fn evaluate_typeof(
     &self,
-    expression: &swc_core::ecma::ast::Ident,
+    expression: &Ident,
     start: u32,
     end: u32,
-    unresolved_mark: swc_core::common::SyntaxContext,
+    unresolved_mark: SyntaxContext,
   ) -> Option<BasicEvaluatedExpression> {
     for plugin in &self.plugins {
       let res = plugin.evaluate_typeof(expression, start, end, unresolved_mark);
@@ -27,4 +205,30 @@ impl JavascriptParserPlugin for JavaScriptParserPluginDrive {
     }
     None
   }
+
+  fn evaluate_identifier(
+    &self,
+    parser: &JavascriptParser,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    for plugin in &self.plugins {
+      let res = plugin.evaluate_identifier(parser, ident, start, end);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn can_rename(&self, parser: &JavascriptParser, str: &str) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.can_rename(parser, str);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
 }
@@ -1,5 +1,11 @@
+mod common_js_imports_parser_plugin;
 mod drive;
+mod require_ensure_dependencies_block_parser_plugin;
 mod r#trait;
 
+pub(crate) use self::common_js_imports_parser_plugin::CommonJsImportsParserPlugin;
 pub use self::drive::JavaScriptParserPluginDrive;
-pub use self::r#trait::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
+pub use self::r#trait::{
+  BoxJavascriptParserPlugin, JavascriptParserPlugin, SharedJavascriptParserPlugin,
+};
+pub(crate) use self::require_ensure_dependencies_block_parser_plugin::RequireEnsureDependenciesBlockParserPlugin;
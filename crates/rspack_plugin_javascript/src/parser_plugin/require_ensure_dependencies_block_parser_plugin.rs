@@ -0,0 +1,147 @@
+use rspack_core::{
+  AsyncDependenciesBlock, BoxDependency, ChunkGroupOptions, DependencyLocation, ErrorSpan,
+  GroupOptions, SpanExt,
+};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Lit, Pat};
+use swc_core::ecma::visit::VisitWith;
+
+use super::JavascriptParserPlugin;
+use crate::dependency::{RequireEnsureDependency, RequireEnsureItemDependency};
+use crate::visitors::JavascriptParser;
+
+/// Same as `RequireEnsureDependenciesBlockParserPlugin` in webpack, handles
+/// `require.ensure(["./a", "./b"], function (require) {}, function (error) {}, "chunk-name")`.
+///
+/// The body of the callback is walked in the context of the created block, so every `require`
+/// in it, either the free `require` or the first parameter of the callback, is loaded with the block.
+#[derive(Debug)]
+pub struct RequireEnsureDependenciesBlockParserPlugin;
+
+impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != "require.ensure"
+      || !(parser.module_type.is_js_auto() || parser.module_type.is_js_dynamic())
+    {
+      return None;
+    }
+    let args = &expr.args;
+    if args.iter().any(|arg| arg.spread.is_some()) {
+      return None;
+    }
+    let (dependencies, callback, error_callback, chunk_name) = match args.as_slice() {
+      [dependencies, callback] => (dependencies, callback, None, None),
+      [dependencies, callback, third] => match get_str(&third.expr) {
+        Some(chunk_name) => (dependencies, callback, None, Some(chunk_name)),
+        None => (dependencies, callback, Some(third), None),
+      },
+      [dependencies, callback, error_callback, chunk_name] => (
+        dependencies,
+        callback,
+        Some(error_callback),
+        Some(get_str(&chunk_name.expr)?),
+      ),
+      _ => return None,
+    };
+
+    let requests = match &*dependencies.expr {
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|element| match element {
+          Some(ExprOrSpread {
+            spread: None,
+            expr: box Expr::Lit(Lit::Str(str)),
+          }) => Some(str),
+          _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?,
+      Expr::Lit(Lit::Str(str)) => vec![str],
+      _ => return None,
+    };
+
+    // the dependencies added while walking the callback belong to the block
+    let parent_dependencies = std::mem::take(parser.dependencies);
+    parser.dependencies.extend(requests.into_iter().map(|str| {
+      Box::new(RequireEnsureItemDependency::new(
+        str.value.to_string(),
+        Some(str.span.into()),
+      )) as BoxDependency
+    }));
+    walk_callback(parser, &callback.expr);
+    let block_dependencies = std::mem::replace(parser.dependencies, parent_dependencies);
+
+    let span = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    let dep = RequireEnsureDependency::new(
+      (expr.span.real_lo(), expr.span.real_hi()),
+      span(&callback.expr),
+      error_callback.map(|arg| span(&arg.expr)),
+    );
+    let span = ErrorSpan::from(expr.span);
+    let mut block = AsyncDependenciesBlock::new(
+      parser.module_identifier,
+      format!("{}:{}", span.start, span.end),
+      Some(DependencyLocation::new(span.start, span.end)),
+    );
+    block.set_group_options(GroupOptions::ChunkGroup(
+      ChunkGroupOptions::default().name_optional(chunk_name),
+    ));
+    for dep in block_dependencies {
+      block.add_dependency(dep);
+    }
+    block.add_dependency(Box::new(dep));
+    parser.blocks.push(block);
+
+    // the error callback is called outside of the block
+    if let Some(error_callback) = error_callback {
+      error_callback.expr.visit_with(parser);
+    }
+    Some(true)
+  }
+}
+
+// `function (require) {}`, `(require) => {}` and `function (require) {}.bind(this)`,
+// the callback is called with `__webpack_require__` as the first argument
+fn walk_callback(parser: &mut JavascriptParser, expr: &Expr) {
+  match expr {
+    Expr::Fn(f) => {
+      if let Some(param) = f.function.params.first() {
+        rename_require_param(parser, &param.pat);
+      }
+      f.function.body.visit_with(parser);
+    }
+    Expr::Arrow(f) => {
+      if let Some(param) = f.params.first() {
+        rename_require_param(parser, param);
+      }
+      f.body.visit_with(parser);
+    }
+    Expr::Paren(paren) => walk_callback(parser, &paren.expr),
+    Expr::Call(CallExpr {
+      callee: Callee::Expr(box Expr::Member(member)),
+      args,
+      ..
+    }) if member
+      .prop
+      .as_ident()
+      .is_some_and(|prop| prop.sym == "bind") =>
+    {
+      walk_callback(parser, &member.obj);
+      args.visit_with(parser);
+    }
+    _ => expr.visit_with(parser),
+  }
+}
+
+fn rename_require_param(parser: &mut JavascriptParser, pat: &Pat) {
+  if let Pat::Ident(ident) = pat {
+    parser.rename_identifier(ident.id.to_id(), "require".to_string());
+  }
+}
+
+fn get_str(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+    _ => None,
+  }
+}
@@ -1,13 +1,140 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  BinExpr, CallExpr, Ident, IfStmt, ImportDecl, MemberExpr, ModuleDecl, NewExpr, Program, UnaryExpr,
+};
+
 use crate::utils::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
+
+/// Hooks of the [JavascriptParser], same as the hooks of `JavascriptParser` in webpack.
+///
+/// The walking hooks return `Some(true)` when the node is handled, the parser won't walk into
+/// the node and the remaining plugins won't be called.
+/// `for_name` is the name of a free variable or a member chain on it, e.g. `require.resolve`,
+/// a variable renamed by [JavascriptParserPlugin::can_rename] is named by the original name.
+///
+/// Only CommonJS `require` (including `require.resolve` and `require.include`) and
+/// `require.ensure` are builtin plugins, handling such a call prevents the builtin behavior.
+/// The other builtin features, e.g. `import`, `export`, `import()` and `new URL()`, are
+/// separate scanners which always run, the hooks are still called for them but can't take over.
+pub trait JavascriptParserPlugin: Debug + Send + Sync {
+  /// The top level program, it's called before walking the program
+  fn program(&self, _parser: &mut JavascriptParser, _ast: &Program) -> Option<bool> {
+    None
+  }
+
+  /// `import` statements, `source` is the request of the statement
+  fn import(
+    &self,
+    _parser: &mut JavascriptParser,
+    _statement: &ImportDecl,
+    _source: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// `export` statements, including the re-exports
+  fn export(&self, _parser: &mut JavascriptParser, _statement: &ModuleDecl) -> Option<bool> {
+    None
+  }
+
+  /// Re-export statements with a request, e.g. `export { a } from './a'` and `export * from './a'`
+  fn export_import(
+    &self,
+    _parser: &mut JavascriptParser,
+    _statement: &ModuleDecl,
+    _source: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// `if` statements, it's called before walking the statement
+  fn statement_if(&self, _parser: &mut JavascriptParser, _stmt: &IfStmt) -> Option<bool> {
+    None
+  }
+
+  /// Free identifiers used as an expression
+  fn identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    _ident: &Ident,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  fn call(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &CallExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  fn member(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &MemberExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  fn new_expression(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &NewExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// `typeof` of a free variable or a member chain on it, e.g. `typeof require.resolve`
+  fn r#typeof(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &UnaryExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// Binary expressions, including the logical expressions `&&`, `||` and `??`
+  fn binary_expression(&self, _parser: &mut JavascriptParser, _expr: &BinExpr) -> Option<bool> {
+    None
+  }
 
-pub trait JavascriptParserPlugin {
   fn evaluate_typeof(
     &self,
-    expression: &swc_core::ecma::ast::Ident,
-    start: u32,
-    end: u32,
-    unresolved_mark: swc_core::common::SyntaxContext, // remove this after `parser.scope.definitions`
-  ) -> Option<BasicEvaluatedExpression>;
+    _expression: &Ident,
+    _start: u32,
+    _end: u32,
+    _unresolved_mark: SyntaxContext, // remove this after `parser.scope.definitions`
+  ) -> Option<BasicEvaluatedExpression> {
+    None
+  }
+
+  /// Evaluates a free identifier or a member chain on it
+  fn evaluate_identifier(
+    &self,
+    _parser: &JavascriptParser,
+    _ident: &str,
+    _start: u32,
+    _end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    None
+  }
+
+  /// Returns `Some(true)` to keep tracking the free variable `str` when it's assigned to a
+  /// variable, e.g. `const r = require`, the calls of `r` are called with the name `require`
+  fn can_rename(&self, _parser: &JavascriptParser, _str: &str) -> Option<bool> {
+    None
+  }
 }
 
 pub type BoxJavascriptParserPlugin = Box<dyn JavascriptParserPlugin>;
+pub type SharedJavascriptParserPlugin = Arc<dyn JavascriptParserPlugin>;
@@ -1,6 +1,8 @@
 use std::hash::Hash;
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use rspack_core::cache::{register_dependency, register_dependency_template};
 use rspack_core::rspack_sources::BoxSource;
 use rspack_core::{
   get_js_chunk_filename_template, AdditionalChunkRuntimeRequirementsArgs, ChunkHashArgs, ChunkKind,
@@ -12,7 +14,16 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_hash::RspackHash;
 
+use crate::dependency::{
+  CommonJsExportsDependency, CommonJsRequireDependency, HarmonyCompatibilityDependency,
+  HarmonyExportExpressionDependency, HarmonyExportHeaderDependency,
+  HarmonyExportImportedSpecifierDependency, HarmonyExportSpecifierDependency,
+  HarmonyImportSideEffectDependency, HarmonyImportSpecifierDependency, ImportDependency,
+  ImportEagerDependency, ImportWeakDependency, ModuleDecoratorDependency, PureExpressionDependency,
+  RequireHeaderDependency, RequireResolveDependency, URLDependency,
+};
 use crate::parser_and_generator::JavaScriptParserAndGenerator;
+use crate::parser_plugin::JavaScriptParserPluginDrive;
 use crate::JsPlugin;
 
 #[async_trait]
@@ -25,42 +36,74 @@ impl Plugin for JsPlugin {
     ctx: PluginContext<&mut rspack_core::ApplyContext>,
     options: &mut CompilerOptions,
   ) -> Result<()> {
-    let create_parser_and_generator =
-      move || Box::new(JavaScriptParserAndGenerator::new()) as Box<dyn ParserAndGenerator>;
+    // dependencies of commonjs and esm modules can be restored from the persistent cache
+    register_dependency::<CommonJsRequireDependency>("CommonJsRequireDependency");
+    register_dependency::<CommonJsExportsDependency>("CommonJsExportsDependency");
+    register_dependency::<RequireResolveDependency>("RequireResolveDependency");
+    register_dependency::<HarmonyImportSideEffectDependency>("HarmonyImportSideEffectDependency");
+    register_dependency::<HarmonyImportSpecifierDependency>("HarmonyImportSpecifierDependency");
+    register_dependency::<HarmonyExportSpecifierDependency>("HarmonyExportSpecifierDependency");
+    register_dependency::<HarmonyExportImportedSpecifierDependency>(
+      "HarmonyExportImportedSpecifierDependency",
+    );
+    register_dependency::<ImportDependency>("ImportDependency");
+    register_dependency::<ImportEagerDependency>("ImportEagerDependency");
+    register_dependency::<ImportWeakDependency>("ImportWeakDependency");
+    register_dependency::<URLDependency>("URLDependency");
+    register_dependency::<PureExpressionDependency>("PureExpressionDependency");
+    register_dependency_template::<ModuleDecoratorDependency>("ModuleDecoratorDependency");
+    register_dependency_template::<RequireHeaderDependency>("RequireHeaderDependency");
+    register_dependency_template::<HarmonyCompatibilityDependency>(
+      "HarmonyCompatibilityDependency",
+    );
+    register_dependency_template::<HarmonyExportHeaderDependency>("HarmonyExportHeaderDependency");
+    register_dependency_template::<HarmonyExportExpressionDependency>(
+      "HarmonyExportExpressionDependency",
+    );
+
+    let parser_plugin_drive = Arc::new(JavaScriptParserPluginDrive::with_builtin_plugins(
+      self.parser_plugins.clone(),
+    ));
+    let create_parser_and_generator = move || {
+      Box::new(JavaScriptParserAndGenerator::new(
+        parser_plugin_drive.clone(),
+      )) as Box<dyn ParserAndGenerator>
+    };
 
     if options.should_transform_by_default() {
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Ts,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Tsx,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::Jsx,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::JsxEsm,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
       ctx.context.register_parser_and_generator_builder(
         ModuleType::JsxDynamic,
-        Box::new(create_parser_and_generator),
+        Box::new(create_parser_and_generator.clone()),
       );
     }
 
-    ctx
-      .context
-      .register_parser_and_generator_builder(ModuleType::Js, Box::new(create_parser_and_generator));
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::Js,
+      Box::new(create_parser_and_generator.clone()),
+    );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsEsm,
-      Box::new(create_parser_and_generator),
+      Box::new(create_parser_and_generator.clone()),
     );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsDynamic,
-      Box::new(create_parser_and_generator),
+      Box::new(create_parser_and_generator.clone()),
     );
 
     Ok(())
@@ -109,6 +152,21 @@ impl Plugin for JsPlugin {
       DependencyType::RequireResolve,
       params.normal_module_factory.clone(),
     );
+    // AMDPlugin
+    args.compilation.set_dependency_factory(
+      DependencyType::AmdRequireItem,
+      params.normal_module_factory.clone(),
+    );
+    // RequireEnsurePlugin
+    args.compilation.set_dependency_factory(
+      DependencyType::RequireEnsureItem,
+      params.normal_module_factory.clone(),
+    );
+    // RequireIncludePlugin
+    args.compilation.set_dependency_factory(
+      DependencyType::RequireInclude,
+      params.normal_module_factory.clone(),
+    );
     // RequireContextPlugin
     args.compilation.set_dependency_factory(
       DependencyType::RequireContext,
@@ -136,6 +194,10 @@ impl Plugin for JsPlugin {
       DependencyType::DynamicImportEager,
       params.normal_module_factory.clone(),
     );
+    args.compilation.set_dependency_factory(
+      DependencyType::DynamicImportWeak,
+      params.normal_module_factory.clone(),
+    );
     args.compilation.set_dependency_factory(
       DependencyType::ImportContext,
       params.context_module_factory.clone(),
@@ -5,12 +5,14 @@ pub mod impl_plugin_for_js_plugin;
 pub mod infer_async_modules_plugin;
 pub mod inner_graph_plugin;
 mod mangle_exports_plugin;
+mod module_concatenation_plugin;
 mod side_effects_flag_plugin;
 use std::hash::Hash;
 
 pub use flag_dependency_exports_plugin::*;
 pub use flag_dependency_usage_plugin::*;
 pub use mangle_exports_plugin::*;
+pub use module_concatenation_plugin::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, JsChunkHashArgs,
@@ -20,15 +22,26 @@ use rspack_error::Result;
 use rspack_hash::RspackHash;
 pub use side_effects_flag_plugin::*;
 
+use crate::parser_plugin::{BoxJavascriptParserPlugin, SharedJavascriptParserPlugin};
 use crate::runtime::{render_chunk_modules, render_iife, render_runtime_modules, stringify_array};
 use crate::utils::is_diff_mode;
 
 #[derive(Debug)]
-pub struct JsPlugin;
+pub struct JsPlugin {
+  parser_plugins: Vec<SharedJavascriptParserPlugin>,
+}
 
 impl JsPlugin {
   pub fn new() -> Self {
-    Self {}
+    Self {
+      parser_plugins: vec![],
+    }
+  }
+
+  /// Registers a plugin to the hooks of the javascript parser, the plugins are called in the
+  /// order they are registered, before the builtin plugins
+  pub fn add_parser_plugin(&mut self, plugin: BoxJavascriptParserPlugin) {
+    self.parser_plugins.push(plugin.into());
   }
 
   pub fn render_require(&self, chunk_ukey: &ChunkUkey, compilation: &Compilation) -> BoxSource {
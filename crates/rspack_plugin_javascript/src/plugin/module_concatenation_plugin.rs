@@ -0,0 +1,535 @@
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use indexmap::IndexSet;
+use rspack_core::{
+  BuildMetaExportsType, Compilation, ConcatenatedModule, ExportInfoProvided, Logger, ModuleExt,
+  ModuleGraphModule, ModuleIdentifier, OptimizeChunksArgs, Plugin, UsageState,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_identifier::{Identifiable, IdentifierMap, IdentifierSet};
+
+fn format_bailout_reason(msg: &str) -> String {
+  format!("ModuleConcatenation bailout: {msg}")
+}
+
+#[derive(Debug, Clone)]
+enum Problem {
+  /// The module can't be concatenated, see the inner bailout reason of the module
+  Module(ModuleIdentifier),
+  Reason(String),
+}
+
+#[derive(Debug)]
+struct ConcatConfiguration {
+  root_module: ModuleIdentifier,
+  modules: IndexSet<ModuleIdentifier>,
+  warnings: Vec<(ModuleIdentifier, Problem)>,
+}
+
+impl ConcatConfiguration {
+  fn new(root_module: ModuleIdentifier) -> Self {
+    let mut modules = IndexSet::default();
+    modules.insert(root_module);
+    Self {
+      root_module,
+      modules,
+      warnings: vec![],
+    }
+  }
+}
+
+struct ConcatenationContext<'a> {
+  compilation: &'a Compilation,
+  possible_inners: &'a IdentifierSet,
+  inner_bailout_reasons: &'a IdentifierMap<String>,
+  failure_cache: IdentifierMap<Problem>,
+}
+
+impl<'a> ConcatenationContext<'a> {
+  fn readable_identifier(&self, module: &ModuleIdentifier) -> String {
+    self
+      .compilation
+      .module_graph
+      .module_by_identifier(module)
+      .map(|m| {
+        m.readable_identifier(&self.compilation.options.context)
+          .to_string()
+      })
+      .unwrap_or_else(|| module.to_string())
+  }
+
+  fn format_bailout_warning(&self, module: &ModuleIdentifier, problem: &Problem) -> String {
+    let readable_identifier = self.readable_identifier(module);
+    match problem {
+      Problem::Reason(reason) => format_bailout_reason(&format!(
+        "Cannot concat with {readable_identifier}: {reason}"
+      )),
+      Problem::Module(problem_module) => {
+        let reason = self
+          .inner_bailout_reasons
+          .get(problem_module)
+          .map(|reason| format!(": {reason}"))
+          .unwrap_or_default();
+        if problem_module == module {
+          format_bailout_reason(&format!("Cannot concat with {readable_identifier}{reason}"))
+        } else {
+          format_bailout_reason(&format!(
+            "Cannot concat with {readable_identifier} because of {}{reason}",
+            self.readable_identifier(problem_module)
+          ))
+        }
+      }
+    }
+  }
+
+  /// The modules imported by harmony import dependencies of `module`, in dependency order.
+  fn get_imports(&self, module: &ModuleIdentifier) -> Vec<ModuleIdentifier> {
+    let module_graph = &self.compilation.module_graph;
+    let Some(module) = module_graph.module_by_identifier(module) else {
+      return vec![];
+    };
+    let mut imports = IndexSet::<ModuleIdentifier>::default();
+    for dependency_id in module.get_dependencies() {
+      let is_harmony_import = module_graph
+        .dependency_by_id(dependency_id)
+        .and_then(|dependency| dependency.as_module_dependency())
+        .and_then(|dependency| dependency.source_order())
+        .is_some();
+      if !is_harmony_import {
+        continue;
+      }
+      if let Some(connection) = module_graph.connection_by_dependency(dependency_id)
+        && connection.is_target_active(module_graph, None)
+      {
+        imports.insert(connection.module_identifier);
+      }
+    }
+    imports.into_iter().collect()
+  }
+
+  fn try_to_add(
+    &mut self,
+    config: &mut ConcatConfiguration,
+    module: ModuleIdentifier,
+  ) -> Option<Problem> {
+    if let Some(problem) = self.failure_cache.get(&module) {
+      return Some(problem.clone());
+    }
+
+    // Already added?
+    if config.modules.contains(&module) {
+      return None;
+    }
+
+    // Not possible to add?
+    if !self.possible_inners.contains(&module) {
+      let problem = Problem::Module(module);
+      self.failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let compilation = self.compilation;
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+
+    // Module must be in the same chunks like the referencing module
+    if chunk_graph.get_module_chunks(module) != chunk_graph.get_module_chunks(config.root_module) {
+      let problem = Problem::Reason(format!(
+        "Module {} is not in the same chunk(s) as {}",
+        self.readable_identifier(&module),
+        self.readable_identifier(&config.root_module)
+      ));
+      return Some(problem);
+    }
+
+    let mut incoming_modules = IndexSet::<ModuleIdentifier>::default();
+    let mut unsupported_importers = IndexSet::<ModuleIdentifier>::default();
+    if let Some(mgm) = module_graph.module_graph_module_by_identifier(&module) {
+      let mut connections = mgm
+        .incoming_connections
+        .iter()
+        .filter_map(|id| module_graph.connection_by_connection_id(id))
+        .filter(|connection| connection.is_active(module_graph, None))
+        .collect::<Vec<_>>();
+      connections.sort_by(|a, b| {
+        a.original_module_identifier
+          .map(|m| m.as_str())
+          .cmp(&b.original_module_identifier.map(|m| m.as_str()))
+      });
+      for connection in connections {
+        // Modules which are not in any chunk don't affect the concatenation
+        if let Some(origin_module) = connection.original_module_identifier
+          && chunk_graph.get_number_of_module_chunks(origin_module) == 0
+        {
+          continue;
+        }
+        let is_harmony_import = module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dependency| dependency.as_module_dependency())
+          .and_then(|dependency| dependency.source_order())
+          .is_some();
+        match connection.original_module_identifier {
+          Some(origin_module) if is_harmony_import => {
+            incoming_modules.insert(origin_module);
+          }
+          Some(origin_module) => {
+            unsupported_importers.insert(origin_module);
+          }
+          None => {
+            unsupported_importers.insert(module);
+          }
+        }
+      }
+    }
+
+    if !unsupported_importers.is_empty() {
+      let problem = Problem::Reason(format!(
+        "Module {} is referenced from these modules with unsupported syntax: {}",
+        self.readable_identifier(&module),
+        unsupported_importers
+          .iter()
+          .map(|m| self.readable_identifier(m))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ));
+      self.failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let backup = config.modules.len();
+    // Add the module
+    config.modules.insert(module);
+
+    // Every module which depends on the added module must be in the configuration too.
+    for origin_module in incoming_modules {
+      if let Some(problem) = self.try_to_add(config, origin_module) {
+        config.modules.truncate(backup);
+        self.failure_cache.insert(module, problem.clone());
+        return Some(problem);
+      }
+    }
+
+    // Add imports to the current configuration
+    for import in self.get_imports(&module) {
+      if let Some(problem) = self.try_to_add(config, import) {
+        config.warnings.push((import, problem));
+      }
+    }
+    None
+  }
+}
+
+/// Merges strictly-ESM module subgraphs into a single [ConcatenatedModule] per subgraph.
+///
+/// Align with https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/ModuleConcatenationPlugin.js
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPlugin {
+  /// `optimization.concatenateModules` is set by the user rather than defaulted by the mode
+  explicit: bool,
+}
+
+impl ModuleConcatenationPlugin {
+  pub fn new(explicit: bool) -> Self {
+    Self { explicit }
+  }
+}
+
+#[async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleConcatenationPlugin"
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    if !compilation.options.is_new_tree_shaking()
+      || !compilation.options.optimization.concatenate_modules
+    {
+      return Ok(());
+    }
+    let logger = compilation.get_logger(self.name());
+    // TODO: the module graph is reused by incremental rebuild, which can't handle the
+    // concatenated modules yet
+    if compilation.options.is_incremental_rebuild_make_enabled() {
+      let message = "optimization.concatenateModules is ignored because experiments.incrementalRebuild.make is enabled";
+      if self.explicit {
+        compilation.push_diagnostic(Diagnostic::warn(
+          "ModuleConcatenation".to_string(),
+          message.to_string(),
+          0,
+          0,
+        ));
+      } else {
+        logger.debug(message);
+      }
+      let bailout = format_bailout_reason("experiments.incrementalRebuild.make is enabled");
+      let modules = compilation
+        .module_graph
+        .modules()
+        .keys()
+        .copied()
+        .collect::<Vec<_>>();
+      for module in modules {
+        if let Some(mgm) = compilation
+          .module_graph
+          .module_graph_module_by_identifier_mut(&module)
+          && !mgm.optimization_bailout.contains(&bailout)
+        {
+          mgm.optimization_bailout.push(bailout.clone());
+        }
+      }
+      return Ok(());
+    }
+
+    let start = logger.time("select relevant modules");
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+    let mut bailouts: Vec<(ModuleIdentifier, String)> = vec![];
+    let mut inner_bailout_reasons = IdentifierMap::<String>::default();
+    let mut relevant_modules = vec![];
+    let mut possible_inners = IdentifierSet::default();
+
+    let mut modules = module_graph.modules().keys().copied().collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+    for module_identifier in modules {
+      if chunk_graph.get_number_of_module_chunks(module_identifier) == 0 {
+        continue;
+      }
+      let Some(module) = module_graph.module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      let Some(mgm) = module_graph.module_graph_module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      let mut set_bailout_reason = |reason: String| {
+        bailouts.push((module_identifier, format_bailout_reason(&reason)));
+        inner_bailout_reasons.insert(module_identifier, reason);
+      };
+
+      // Only harmony modules are valid for optimization
+      if module.as_normal_module().is_none()
+        || !matches!(
+          mgm.build_meta.as_ref().map(|m| &m.exports_type),
+          Some(BuildMetaExportsType::Namespace)
+        )
+      {
+        set_bailout_reason("Module is not an ECMAScript module".to_string());
+        continue;
+      }
+
+      // Some expressions are not compatible with module concatenation
+      // because they may produce unexpected results. The plugin bails out
+      // if some were detected upfront.
+      if let Some(reason) = mgm
+        .build_info
+        .as_ref()
+        .and_then(|i| i.module_concatenation_bailout.as_ref())
+      {
+        set_bailout_reason(format!("Module uses {reason}"));
+        continue;
+      }
+
+      // Must not be an async module
+      if mgm.is_async {
+        set_bailout_reason("Module is async".to_string());
+        continue;
+      }
+
+      // Must be in strict mode
+      if !mgm.build_info.as_ref().is_some_and(|i| i.strict) {
+        set_bailout_reason("Module is not in strict mode".to_string());
+        continue;
+      }
+
+      // Exports must be known (and not dynamic)
+      let exports_info = module_graph.get_exports_info(&module_identifier);
+      let mut unknown_provided_exports = exports_info
+        .exports
+        .values()
+        .map(|id| id.get_export_info(module_graph))
+        .filter(|export_info| {
+          export_info.get_used(None) != UsageState::Unused
+            && !matches!(
+              export_info.provided,
+              Some(ExportInfoProvided::True | ExportInfoProvided::False)
+            )
+        })
+        .filter_map(|export_info| export_info.name.as_ref().map(|name| name.to_string()))
+        .collect::<Vec<_>>();
+      unknown_provided_exports.sort();
+      let other_exports_info = exports_info
+        .other_exports_info
+        .get_export_info(module_graph);
+      if other_exports_info.get_used(None) != UsageState::Unused
+        && !matches!(
+          other_exports_info.provided,
+          Some(ExportInfoProvided::True | ExportInfoProvided::False)
+        )
+      {
+        unknown_provided_exports.push("other exports".to_string());
+      }
+      if !unknown_provided_exports.is_empty() {
+        set_bailout_reason(format!(
+          "List of module exports is dynamic ({})",
+          unknown_provided_exports.join(", ")
+        ));
+        continue;
+      }
+
+      relevant_modules.push(module_identifier);
+
+      // Module must not be an entry point
+      if chunk_graph.is_entry_module(&module_identifier) {
+        inner_bailout_reasons.insert(module_identifier, "Module is an entry point".to_string());
+        continue;
+      }
+
+      possible_inners.insert(module_identifier);
+    }
+    logger.time_end(start);
+
+    let start = logger.time("sort relevant modules");
+    // sort by depth
+    // modules with lower depth are more likely suited as roots
+    // this improves performance, because modules already selected as inner are skipped
+    let mut depths = IdentifierMap::<usize>::default();
+    let mut queue = compilation
+      .entry_modules()
+      .map(|module| (module, 0))
+      .collect::<VecDeque<_>>();
+    while let Some((module_identifier, depth)) = queue.pop_front() {
+      if depths.contains_key(&module_identifier) {
+        continue;
+      }
+      depths.insert(module_identifier, depth);
+      let Some(module) = module_graph.module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      for connection in module_graph.get_outgoing_connections(module) {
+        if !depths.contains_key(&connection.module_identifier) {
+          queue.push_back((connection.module_identifier, depth + 1));
+        }
+      }
+    }
+    relevant_modules.sort_by_key(|module| depths.get(module).copied().unwrap_or(usize::MAX));
+    logger.time_end(start);
+
+    let start = logger.time("find modules to concatenate");
+    let mut context = ConcatenationContext {
+      compilation,
+      possible_inners: &possible_inners,
+      inner_bailout_reasons: &inner_bailout_reasons,
+      failure_cache: Default::default(),
+    };
+    let mut configurations = vec![];
+    let mut used_as_inner = IdentifierSet::default();
+    for current_root in &relevant_modules {
+      // when used by another configuration as inner:
+      // the other configuration is better and we can skip this one
+      if used_as_inner.contains(current_root) {
+        continue;
+      }
+
+      // create a configuration with the root
+      let mut config = ConcatConfiguration::new(*current_root);
+
+      // try to add all imports
+      for import in context.get_imports(current_root) {
+        if let Some(problem) = context.try_to_add(&mut config, import) {
+          config.warnings.push((import, problem));
+        }
+      }
+
+      for (module, problem) in &config.warnings {
+        bailouts.push((*module, context.format_bailout_warning(module, problem)));
+      }
+
+      if config.modules.len() > 1 {
+        used_as_inner.extend(
+          config
+            .modules
+            .iter()
+            .filter(|module| *module != current_root)
+            .copied(),
+        );
+        configurations.push(config);
+      }
+    }
+    logger.time_end(start);
+
+    let start = logger.time("create concatenated modules");
+    for (module, bailout) in bailouts {
+      if let Some(mgm) = compilation
+        .module_graph
+        .module_graph_module_by_identifier_mut(&module)
+        && !mgm.optimization_bailout.contains(&bailout)
+      {
+        mgm.optimization_bailout.push(bailout);
+      }
+    }
+
+    for config in configurations {
+      let ConcatConfiguration {
+        root_module,
+        modules,
+        ..
+      } = config;
+      let module_graph = &mut compilation.module_graph;
+      let Some(root) = module_graph
+        .module_by_identifier(&root_module)
+        .and_then(|m| m.as_normal_module())
+      else {
+        continue;
+      };
+      let new_module =
+        ConcatenatedModule::new(root, modules.iter().copied().collect(), module_graph);
+      let new_module_identifier = new_module.identifier();
+      let Some(root_mgm) = module_graph.module_graph_module_by_identifier(&root_module) else {
+        continue;
+      };
+      let mut new_mgm = ModuleGraphModule::new(
+        new_module_identifier,
+        root_mgm.module_type,
+        // the concatenated module shares the exports of the root module
+        root_mgm.exports,
+      );
+      new_mgm.build_info = root_mgm.build_info.clone();
+      new_mgm.build_meta = root_mgm.build_meta.clone();
+      new_mgm.factory_meta = root_mgm.factory_meta.clone();
+      new_mgm.is_async = root_mgm.is_async;
+
+      // redirect the references from outside of the concatenation to the new module
+      let incoming_dependencies = root_mgm
+        .incoming_connections
+        .iter()
+        .filter_map(|id| module_graph.connection_by_connection_id(id))
+        .filter(|connection| {
+          connection
+            .original_module_identifier
+            .map_or(true, |m| !modules.contains(&m))
+        })
+        .map(|connection| connection.dependency_id)
+        .collect::<Vec<_>>();
+
+      module_graph.add_module_graph_module(new_mgm);
+      module_graph.add_module(new_module.boxed());
+      for dependency_id in incoming_dependencies {
+        module_graph.update_module(&dependency_id, &new_module_identifier);
+      }
+
+      let chunk_graph = &mut compilation.chunk_graph;
+      chunk_graph.replace_module(root_module, new_module_identifier);
+      for module in modules.iter().filter(|m| **m != root_module) {
+        let chunks = chunk_graph.get_module_chunks(*module).clone();
+        for chunk in chunks {
+          chunk_graph.disconnect_chunk_and_module(&chunk, *module);
+        }
+      }
+      compilation.include_module_ids.insert(new_module_identifier);
+    }
+    logger.time_end(start);
+
+    Ok(())
+  }
+}
@@ -3,7 +3,8 @@ use rspack_core::SpanExt;
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::{BinExpr, BinaryOp};
 
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::utils::EvaluateExpression;
+use crate::visitors::JavascriptParser;
 
 // FIXME: a temp hack to avoid bwchecker.
 bitflags::bitflags! {
@@ -16,11 +17,11 @@ bitflags::bitflags! {
 }
 
 pub fn expression_logic_operator(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  parser: &JavascriptParser<'_>,
   expr: &BinExpr,
 ) -> (Option<Vec<ConstDependency>>, Continue) {
   if expr.op == BinaryOp::LogicalAnd || expr.op == BinaryOp::LogicalOr {
-    let param = scanner.evaluate_expression(&expr.left);
+    let param = parser.evaluate_expression(&expr.left);
     let boolean = param.as_bool();
     let Some(bool) = boolean else {
       return (None, Continue::NO);
@@ -61,7 +62,7 @@ pub fn expression_logic_operator(
   }
   // else if expr.op == BinaryOp::NullishCoalescing {
   //   // TODO: support `??`
-  //   expr.visit_children_with(scanner);
+  //   expr.visit_children_with(parser);
   //   None
   // }
 }
@@ -1,14 +1,13 @@
 use rspack_core::SpanExt;
 use swc_core::ecma::ast::{BinExpr, BinaryOp};
 
-use crate::utils::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::utils::{BasicEvaluatedExpression, EvaluateExpression};
 
 /// `eql` is `true` for `===` and `false` for `!==`
-fn handle_strict_equality_comparison<'a>(
+fn handle_strict_equality_comparison(
   eql: bool,
-  expr: &'a BinExpr,
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
+  expr: &BinExpr,
+  scanner: &impl EvaluateExpression,
 ) -> Option<BasicEvaluatedExpression> {
   assert!(expr.op == BinaryOp::EqEqEq || expr.op == BinaryOp::NotEqEq);
   let left = scanner.evaluate_expression(&expr.left);
@@ -26,9 +25,9 @@ fn handle_strict_equality_comparison<'a>(
   }
 }
 
-pub fn eval_binary_expression<'a>(
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
-  expr: &'a BinExpr,
+pub fn eval_binary_expression(
+  scanner: &impl EvaluateExpression,
+  expr: &BinExpr,
 ) -> Option<BasicEvaluatedExpression> {
   match expr.op {
     BinaryOp::EqEqEq => handle_strict_equality_comparison(true, expr, scanner),
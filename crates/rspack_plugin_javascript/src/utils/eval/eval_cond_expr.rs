@@ -1,10 +1,9 @@
 use swc_core::ecma::ast::CondExpr;
 
-use super::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use super::{BasicEvaluatedExpression, EvaluateExpression};
 
 pub fn eval_cond_expression(
-  scanner: &CommonJsImportDependencyScanner,
+  scanner: &impl EvaluateExpression,
   cond: &CondExpr,
 ) -> Option<BasicEvaluatedExpression> {
   let condition = scanner.evaluate_expression(&cond.test);
@@ -2,8 +2,7 @@ use rspack_core::SpanExt;
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::Tpl;
 
-use super::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use super::{BasicEvaluatedExpression, EvaluateExpression};
 
 #[derive(Debug, Clone)]
 pub enum TemplateStringKind {
@@ -12,7 +11,7 @@ pub enum TemplateStringKind {
 }
 
 fn get_simplified_template_result(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  scanner: &impl EvaluateExpression,
   node: &Tpl,
 ) -> (Vec<BasicEvaluatedExpression>, Vec<BasicEvaluatedExpression>) {
   let mut quasis: Vec<BasicEvaluatedExpression> = vec![];
@@ -55,7 +54,7 @@ fn get_simplified_template_result(
 }
 
 pub fn eval_tpl_expression(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  scanner: &impl EvaluateExpression,
   tpl: &Tpl,
 ) -> Option<BasicEvaluatedExpression> {
   let (quasis, mut parts) = get_simplified_template_result(scanner, tpl);
@@ -1,22 +1,15 @@
 use rspack_core::SpanExt;
 use swc_core::ecma::ast::{UnaryExpr, UnaryOp};
 
-use super::BasicEvaluatedExpression;
-use crate::parser_plugin::JavascriptParserPlugin;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use super::{BasicEvaluatedExpression, EvaluateExpression};
 
 fn eval_typeof(
-  scanner: &CommonJsImportDependencyScanner,
+  scanner: &impl EvaluateExpression,
   expr: &UnaryExpr,
 ) -> Option<BasicEvaluatedExpression> {
   assert!(expr.op == UnaryOp::TypeOf);
   if let Some(ident) = expr.arg.as_ident()
-    && let res = scanner.plugin_drive.evaluate_typeof(
-      ident,
-      expr.span.real_lo(),
-      expr.span.hi().0,
-      scanner.unresolved_ctxt,
-    )
+    && let res = scanner.evaluate_typeof(ident, expr.span.real_lo(), expr.span.hi().0)
     && res.is_some()
   {
     return res;
@@ -27,7 +20,7 @@ fn eval_typeof(
 }
 
 pub fn eval_unary_expression(
-  scanner: &CommonJsImportDependencyScanner,
+  scanner: &impl EvaluateExpression,
   expr: &UnaryExpr,
 ) -> Option<BasicEvaluatedExpression> {
   match expr.op {
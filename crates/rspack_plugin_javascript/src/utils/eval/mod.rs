@@ -5,6 +5,7 @@ mod eval_tpl_expr;
 mod eval_unary_expr;
 
 use rspack_core::DependencyLocation;
+use swc_core::ecma::ast::{Expr, Ident};
 
 pub use self::eval_binary_expr::eval_binary_expression;
 pub use self::eval_cond_expr::eval_cond_expression;
@@ -12,6 +13,18 @@ pub use self::eval_lit_expr::eval_lit_expr;
 pub use self::eval_tpl_expr::{eval_tpl_expression, TemplateStringKind};
 pub use self::eval_unary_expr::eval_unary_expression;
 
+/// Visitors which could evaluate expressions, same as `JavascriptParser.evaluateExpression` in webpack
+pub trait EvaluateExpression {
+  fn evaluate_expression(&self, expr: &Expr) -> BasicEvaluatedExpression;
+
+  fn evaluate_typeof(
+    &self,
+    ident: &Ident,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression>;
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Ty {
@@ -252,3 +265,9 @@ pub fn evaluate_to_string(value: String, start: u32, end: u32) -> BasicEvaluated
   eval.set_string(value);
   eval
 }
+
+pub fn evaluate_to_boolean(value: bool, start: u32, end: u32) -> BasicEvaluatedExpression {
+  let mut eval = BasicEvaluatedExpression::with_range(start, end);
+  eval.set_bool(value);
+  eval
+}
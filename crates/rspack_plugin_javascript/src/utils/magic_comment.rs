@@ -0,0 +1,342 @@
+//! Webpack magic comments in dynamic imports, e.g. `import(/* webpackChunkName: "foo" */ "./foo")`.
+//!
+//! The content of a magic comment is parsed as the body of a JavaScript object literal, only
+//! literal values (string, number, boolean, null, regular expression and arrays of them) are
+//! supported.
+
+use std::fmt;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{DynamicImportMode, SpanExt};
+use rspack_error::Diagnostic;
+use rspack_regex::RspackRegex;
+use swc_core::common::comments::{Comment, CommentKind, Comments};
+use swc_core::common::{BytePos, Span};
+use swc_core::ecma::ast::{Expr, Lit, Prop, PropName, PropOrSpread, UnaryOp};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::parser::{lexer::Lexer, Parser, StringInput};
+
+static WEBPACK_MAGIC_COMMENT_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"(^|\W)webpack[A-Z][A-Za-z]+:").expect("invalid webpack magic comment regexp")
+});
+
+#[derive(Debug, Clone)]
+pub enum MagicCommentValue {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  RegExp { exp: String, flags: String },
+  Array(Vec<MagicCommentValue>),
+}
+
+impl fmt::Display for MagicCommentValue {
+  // Same as `String(value)` in JavaScript
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      MagicCommentValue::Null => write!(f, "null"),
+      MagicCommentValue::Bool(b) => write!(f, "{b}"),
+      MagicCommentValue::Number(n) => write!(f, "{n}"),
+      MagicCommentValue::String(s) => write!(f, "{s}"),
+      MagicCommentValue::RegExp { exp, flags } => write!(f, "/{exp}/{flags}"),
+      MagicCommentValue::Array(items) => {
+        for (index, item) in items.iter().enumerate() {
+          if index > 0 {
+            write!(f, ",")?;
+          }
+          write!(f, "{item}")?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+fn eval_magic_comment_value(expr: &Expr) -> Result<MagicCommentValue, String> {
+  match expr {
+    Expr::Paren(paren) => eval_magic_comment_value(&paren.expr),
+    Expr::Lit(Lit::Null(_)) => Ok(MagicCommentValue::Null),
+    Expr::Lit(Lit::Bool(b)) => Ok(MagicCommentValue::Bool(b.value)),
+    Expr::Lit(Lit::Num(n)) => Ok(MagicCommentValue::Number(n.value)),
+    Expr::Lit(Lit::Str(s)) => Ok(MagicCommentValue::String(s.value.to_string())),
+    Expr::Lit(Lit::Regex(r)) => Ok(MagicCommentValue::RegExp {
+      exp: r.exp.to_string(),
+      flags: r.flags.to_string(),
+    }),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() && tpl.quasis.len() == 1 => {
+      let quasi = tpl.quasis.first().expect("should have one quasi");
+      Ok(MagicCommentValue::String(
+        quasi
+          .cooked
+          .as_ref()
+          .map(|cooked| cooked.to_string())
+          .unwrap_or_else(|| quasi.raw.to_string()),
+      ))
+    }
+    Expr::Unary(unary) if matches!(unary.op, UnaryOp::Minus) => {
+      match eval_magic_comment_value(&unary.arg)? {
+        MagicCommentValue::Number(n) => Ok(MagicCommentValue::Number(-n)),
+        value => Err(format!("Unexpected negation of {value}")),
+      }
+    }
+    Expr::Array(array) => array
+      .elems
+      .iter()
+      .map(|elem| match elem {
+        Some(elem) if elem.spread.is_none() => eval_magic_comment_value(&elem.expr),
+        _ => Err("Only literal array elements are supported".to_string()),
+      })
+      .collect::<Result<Vec<_>, _>>()
+      .map(MagicCommentValue::Array),
+    Expr::Ident(ident) => Err(format!("{} is not defined", ident.sym)),
+    _ => Err("Only literal values are supported".to_string()),
+  }
+}
+
+/// Parses the text of a magic comment like `webpackChunkName: "foo", webpackPrefetch: true`.
+pub fn parse_magic_comment(text: &str) -> Result<Vec<(String, MagicCommentValue)>, String> {
+  // Parse the text directly instead of adding it to a `SourceMap`, which would keep every
+  // magic comment of the process alive. The spans of the result are not used.
+  let source = format!("({{{text}}})");
+  let input = StringInput::new(&source, BytePos(1), BytePos(1 + source.len() as u32));
+  let mut parser = Parser::new_from(Lexer::new(
+    Default::default(),
+    Default::default(),
+    input,
+    None,
+  ));
+  let expr = parser
+    .parse_expr()
+    .map_err(|e| e.kind().msg().to_string())?;
+  let errors = parser.take_errors();
+  if let Some(error) = errors.first() {
+    return Err(error.kind().msg().to_string());
+  }
+  let Some(object) = expr.as_paren().and_then(|paren| paren.expr.as_object()) else {
+    return Err("Unexpected magic comment".to_string());
+  };
+  object
+    .props
+    .iter()
+    .map(|prop| {
+      let PropOrSpread::Prop(prop) = prop else {
+        return Err("Spread elements are not supported".to_string());
+      };
+      match prop.as_ref() {
+        Prop::KeyValue(key_value) => {
+          let key = match &key_value.key {
+            PropName::Ident(ident) => ident.sym.to_string(),
+            PropName::Str(s) => s.value.to_string(),
+            _ => return Err("Only identifier or string keys are supported".to_string()),
+          };
+          Ok((key, eval_magic_comment_value(&key_value.value)?))
+        }
+        Prop::Shorthand(ident) => Err(format!("{} is not defined", ident.sym)),
+        _ => Err("Only key-value pairs are supported".to_string()),
+      }
+    })
+    .collect()
+}
+
+/// The options of a dynamic import, collected from its magic comments.
+#[derive(Debug, Default)]
+pub struct MagicCommentOptions {
+  pub ignore: Option<bool>,
+  pub chunk_name: Option<String>,
+  pub mode: Option<DynamicImportMode>,
+  pub prefetch_order: Option<i32>,
+  pub preload_order: Option<i32>,
+  pub include: Option<RspackRegex>,
+  pub exclude: Option<RspackRegex>,
+  pub exports: Option<Vec<JsWord>>,
+}
+
+fn magic_comment_warning(message: String, span: Span) -> Diagnostic {
+  Diagnostic::warn(
+    "Magic comments warning".to_string(),
+    message,
+    span.real_lo() as usize,
+    span.real_hi() as usize,
+  )
+}
+
+fn parse_mode(mode: &str) -> Option<DynamicImportMode> {
+  match mode {
+    "lazy" => Some(DynamicImportMode::Lazy),
+    "lazy-once" => Some(DynamicImportMode::LazyOnce),
+    "eager" => Some(DynamicImportMode::Eager),
+    "weak" => Some(DynamicImportMode::Weak),
+    _ => None,
+  }
+}
+
+fn parse_order(
+  key: &str,
+  value: &MagicCommentValue,
+  span: Span,
+  warning_diagnostics: &mut Vec<Diagnostic>,
+) -> Option<i32> {
+  match value {
+    MagicCommentValue::Bool(true) => Some(0),
+    MagicCommentValue::Number(n) if n.is_finite() => Some(*n as i32),
+    _ => {
+      warning_diagnostics.push(magic_comment_warning(
+        format!("`{key}` expected true or a number, but received: {value}."),
+        span,
+      ));
+      None
+    }
+  }
+}
+
+fn parse_regexp(
+  key: &str,
+  value: &MagicCommentValue,
+  span: Span,
+  warning_diagnostics: &mut Vec<Diagnostic>,
+) -> Option<RspackRegex> {
+  if let MagicCommentValue::RegExp { exp, flags } = value
+    && let Ok(regexp) = RspackRegex::with_flags(exp, flags)
+  {
+    Some(regexp)
+  } else {
+    warning_diagnostics.push(magic_comment_warning(
+      format!("`{key}` expected a regular expression, but received: {value}."),
+      span,
+    ));
+    None
+  }
+}
+
+/// Collects the magic comments in front of `span`, the warnings point to `error_span`.
+pub fn try_extract_magic_comment_options(
+  comments: Option<&dyn Comments>,
+  span: Span,
+  error_span: Span,
+  warning_diagnostics: &mut Vec<Diagnostic>,
+) -> MagicCommentOptions {
+  let mut options = MagicCommentOptions::default();
+  let leading_comments: Vec<Comment> = comments
+    .and_then(|comments| comments.get_leading(span.lo))
+    .unwrap_or_default();
+  for comment in leading_comments
+    .iter()
+    .filter(|c| matches!(c.kind, CommentKind::Block))
+    .filter(|c| WEBPACK_MAGIC_COMMENT_REGEXP.is_match(&c.text))
+  {
+    let pairs = match parse_magic_comment(&comment.text) {
+      Ok(pairs) => pairs,
+      Err(message) => {
+        warning_diagnostics.push(magic_comment_warning(
+          format!(
+            "Compilation error while processing magic comment(-s): /*{}*/: {message}",
+            comment.text
+          ),
+          comment.span,
+        ));
+        continue;
+      }
+    };
+    for (key, value) in pairs {
+      match key.as_str() {
+        "webpackIgnore" => match value {
+          MagicCommentValue::Bool(ignore) => options.ignore = Some(ignore),
+          value => warning_diagnostics.push(magic_comment_warning(
+            format!("`webpackIgnore` expected a boolean, but received: {value}."),
+            error_span,
+          )),
+        },
+        "webpackChunkName" => match value {
+          MagicCommentValue::String(chunk_name) => options.chunk_name = Some(chunk_name),
+          value => warning_diagnostics.push(magic_comment_warning(
+            format!("`webpackChunkName` expected a string, but received: {value}."),
+            error_span,
+          )),
+        },
+        "webpackMode" => match value {
+          MagicCommentValue::String(mode) if let Some(mode) = parse_mode(&mode) => {
+            options.mode = Some(mode)
+          }
+          value => warning_diagnostics.push(magic_comment_warning(
+            format!(
+              "`webpackMode` expected 'lazy', 'lazy-once', 'eager' or 'weak', but received: {value}."
+            ),
+            error_span,
+          )),
+        },
+        "webpackPrefetch" => {
+          options.prefetch_order = parse_order(&key, &value, error_span, warning_diagnostics);
+        }
+        "webpackPreload" => {
+          options.preload_order = parse_order(&key, &value, error_span, warning_diagnostics);
+        }
+        "webpackInclude" => {
+          options.include = parse_regexp(&key, &value, error_span, warning_diagnostics);
+        }
+        "webpackExclude" => {
+          options.exclude = parse_regexp(&key, &value, error_span, warning_diagnostics);
+        }
+        "webpackExports" => match &value {
+          MagicCommentValue::String(export) => options.exports = Some(vec![JsWord::from(export.as_str())]),
+          MagicCommentValue::Array(items)
+            if items
+              .iter()
+              .all(|item| matches!(item, MagicCommentValue::String(_))) =>
+          {
+            options.exports = Some(
+              items
+                .iter()
+                .filter_map(|item| match item {
+                  MagicCommentValue::String(export) => Some(JsWord::from(export.as_str())),
+                  _ => None,
+                })
+                .collect(),
+            )
+          }
+          value => warning_diagnostics.push(magic_comment_warning(
+            format!("`webpackExports` expected a string or an array of strings, but received: {value}."),
+            error_span,
+          )),
+        },
+        _ => {}
+      }
+    }
+  }
+  options
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_magic_comment_as_object_literal() {
+    let pairs = parse_magic_comment(
+      r#" webpackChunkName: "foo", webpackPrefetch: 1, 'webpackInclude': /\.js$/i, webpackExports: ["a", `b`] "#,
+    )
+    .expect("should parse");
+    let pairs = pairs
+      .into_iter()
+      .map(|(key, value)| format!("{key}={value}"))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      pairs,
+      vec![
+        "webpackChunkName=foo",
+        "webpackPrefetch=1",
+        "webpackInclude=/\\.js$/i",
+        "webpackExports=a,b"
+      ]
+    );
+  }
+
+  #[test]
+  fn parse_magic_comment_errors() {
+    assert_eq!(
+      parse_magic_comment("webpackChunkName: foo").expect_err("should fail"),
+      "foo is not defined"
+    );
+    assert!(parse_magic_comment("webpackChunkName: \"foo").is_err());
+  }
+}
@@ -1,6 +1,7 @@
 mod r#const;
 mod eval;
 mod get_prop_from_obj;
+mod magic_comment;
 pub mod mangle_exports;
 
 use std::path::Path;
@@ -14,9 +15,13 @@ use swc_core::ecma::parser::{EsConfig, TsConfig};
 
 pub(crate) use self::eval::{
   eval_binary_expression, eval_cond_expression, eval_lit_expr, eval_tpl_expression,
-  eval_unary_expression, evaluate_to_string, BasicEvaluatedExpression,
+  eval_unary_expression,
+};
+pub use self::eval::{
+  evaluate_to_boolean, evaluate_to_string, BasicEvaluatedExpression, EvaluateExpression,
 };
 pub use self::get_prop_from_obj::*;
+pub use self::magic_comment::*;
 pub use self::r#const::*;
 
 fn syntax_by_ext(
@@ -0,0 +1,377 @@
+use rspack_core::{
+  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, ConstDependency, Dependency,
+  DependencyLocation, ErrorSpan, ModuleIdentifier, RuntimeGlobals, SpanExt,
+};
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::common::{Spanned, SyntaxContext};
+use swc_core::ecma::ast::{ArrayLit, CallExpr, Callee, Expr, ExprOrSpread, Id, Ident, Lit};
+use swc_core::ecma::ast::{MemberExpr, MemberProp, Pat, UnaryExpr, UnaryOp};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::get_function_params;
+use crate::dependency::{AmdDefineDependency, AmdRequireArrayDependency, AmdRequireArrayItem};
+use crate::dependency::{
+  AmdRequireDependency, AmdRequireItemDependency, CommonJsRequireDependency,
+};
+
+const AMD_ARGUMENTS: [&str; 3] = ["require", "exports", "module"];
+
+// Refer to `AMDPlugin` in webpack, handles:
+// - `define([deps], factory)`, `define(factory)`, `define(object)` and the named variants
+// - `require([deps], callback, errorCallback)`
+// - `define.amd`, `require.amd`, `typeof define`
+pub struct AmdScanner<'a> {
+  module_identifier: ModuleIdentifier,
+  dependencies: &'a mut Vec<BoxDependency>,
+  blocks: &'a mut Vec<AsyncDependenciesBlock>,
+  presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+  unresolved_ctxt: SyntaxContext,
+  // The parameters of the amd factories and callbacks which are `require` at runtime
+  require_ids: HashSet<Id>,
+  // The dependencies of the `require([deps], callback)` block which is being visited
+  block_dependencies: Option<Vec<BoxDependency>>,
+}
+
+impl<'a> AmdScanner<'a> {
+  pub fn new(
+    module_identifier: ModuleIdentifier,
+    dependencies: &'a mut Vec<BoxDependency>,
+    blocks: &'a mut Vec<AsyncDependenciesBlock>,
+    presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+    unresolved_ctxt: SyntaxContext,
+  ) -> Self {
+    Self {
+      module_identifier,
+      dependencies,
+      blocks,
+      presentational_dependencies,
+      unresolved_ctxt,
+      require_ids: Default::default(),
+      block_dependencies: None,
+    }
+  }
+
+  fn add_dependency(&mut self, dependency: BoxDependency) {
+    if let Some(block_dependencies) = &mut self.block_dependencies {
+      block_dependencies.push(dependency);
+    } else {
+      self.dependencies.push(dependency);
+    }
+  }
+
+  fn is_unresolved_ident(&self, expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Ident(ident) if ident.sym == name && ident.span.ctxt == self.unresolved_ctxt)
+  }
+
+  // The free `require`, or the parameters of the amd factories which are `require` at runtime
+  fn is_require(&self, expr: &Expr) -> bool {
+    self.is_unresolved_ident(expr, "require")
+      || matches!(expr, Expr::Ident(ident) if self.require_ids.contains(&ident.to_id()))
+  }
+
+  // `define.amd` or `require.amd`
+  fn is_amd_member(&self, expr: &Expr) -> bool {
+    matches!(expr, Expr::Member(MemberExpr {
+      obj,
+      prop: MemberProp::Ident(prop),
+      ..
+    }) if prop.sym == "amd"
+      && (self.is_unresolved_ident(obj, "define") || self.is_unresolved_ident(obj, "require")))
+  }
+
+  /// Returns the items and the requests of the dependency array,
+  /// `None` if the array contains anything other than string literals
+  fn process_array<'e>(
+    &mut self,
+    array: &'e ArrayLit,
+  ) -> Option<(Vec<AmdRequireArrayItem>, Vec<&'e str>)> {
+    let mut requests = vec![];
+    for element in &array.elems {
+      let Some(ExprOrSpread {
+        spread: None,
+        expr: box Expr::Lit(Lit::Str(str)),
+      }) = element
+      else {
+        return None;
+      };
+      requests.push(str);
+    }
+    let mut items = vec![];
+    let mut names = vec![];
+    for str in requests {
+      let item = match str.value.as_ref() {
+        "require" => AmdRequireArrayItem::Require,
+        "exports" => AmdRequireArrayItem::Exports,
+        "module" => AmdRequireArrayItem::Module,
+        request => {
+          let dep = AmdRequireItemDependency::new(request.to_string(), Some(str.span.into()));
+          let item = AmdRequireArrayItem::Dependency {
+            id: *dep.id(),
+            request: request.to_string(),
+          };
+          self.add_dependency(Box::new(dep));
+          item
+        }
+      };
+      items.push(item);
+      names.push(str.value.as_ref());
+    }
+    Some((items, names))
+  }
+
+  fn process_define(&mut self, call_expr: &CallExpr) -> bool {
+    let args = &call_expr.args;
+    if args.iter().any(|arg| arg.spread.is_some()) {
+      return false;
+    }
+    // TODO: support named modules as local modules, the name is ignored for now
+    let (array, factory) = match args.as_slice() {
+      [factory] => (None, &factory.expr),
+      [first, factory] => match &*first.expr {
+        Expr::Lit(Lit::Str(_)) => (None, &factory.expr),
+        Expr::Array(array) => (Some(array), &factory.expr),
+        _ => return false,
+      },
+      [name, array, factory] => match (&*name.expr, &*array.expr) {
+        (Expr::Lit(Lit::Str(_)), Expr::Array(array)) => (Some(array), &factory.expr),
+        _ => return false,
+      },
+      _ => return false,
+    };
+
+    let (array_range, names) = if let Some(array) = array {
+      let Some((items, names)) = self.process_array(array) else {
+        return false;
+      };
+      let range = (array.span.real_lo(), array.span.real_hi());
+      self
+        .presentational_dependencies
+        .push(Box::new(AmdRequireArrayDependency::new(
+          range.0, range.1, items,
+        )));
+      (Some(range), names)
+    } else {
+      (None, AMD_ARGUMENTS.to_vec())
+    };
+
+    let factory_range = (factory.span().real_lo(), factory.span().real_hi());
+    let (function_range, object_range) = if let Some(params) = get_function_params(factory) {
+      self.add_require_params(&params, &names);
+      (Some(factory_range), None)
+    } else if factory.is_object() {
+      (None, Some(factory_range))
+    } else {
+      (Some(factory_range), Some(factory_range))
+    };
+
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdDefineDependency::new(
+        (call_expr.span.real_lo(), call_expr.span.real_hi()),
+        array_range,
+        function_range,
+        object_range,
+      )));
+    factory.visit_with(self);
+    true
+  }
+
+  fn process_require(&mut self, call_expr: &CallExpr) -> bool {
+    let args = &call_expr.args;
+    if args.is_empty() || args.len() > 3 || args.iter().any(|arg| arg.spread.is_some()) {
+      return false;
+    }
+    let Expr::Array(array) = &*args[0].expr else {
+      return false;
+    };
+
+    let parent_dependencies = self.block_dependencies.replace(vec![]);
+    let Some((items, names)) = self.process_array(array) else {
+      self.block_dependencies = parent_dependencies;
+      return false;
+    };
+    let array_range = (array.span.real_lo(), array.span.real_hi());
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdRequireArrayDependency::new(
+        array_range.0,
+        array_range.1,
+        items,
+      )));
+
+    let callback = args.get(1).map(|arg| unwrap_bind_this(&arg.expr));
+    let error_callback = args.get(2).map(|arg| unwrap_bind_this(&arg.expr));
+    if let Some((callback, _)) = callback
+      && let Some(params) = get_function_params(callback)
+    {
+      self.add_require_params(&params, &names);
+    }
+    let dep = AmdRequireDependency::new(
+      (call_expr.span.real_lo(), call_expr.span.real_hi()),
+      array_range,
+      callback.map(|(expr, _)| (expr.span().real_lo(), expr.span().real_hi())),
+      error_callback.map(|(expr, _)| (expr.span().real_lo(), expr.span().real_hi())),
+      callback.is_some_and(|(_, bind_this)| bind_this),
+      error_callback.is_some_and(|(_, bind_this)| bind_this),
+    );
+    self.add_dependency(Box::new(dep));
+    if let Some((callback, _)) = callback {
+      callback.visit_with(self);
+    }
+    let block_dependencies = std::mem::replace(&mut self.block_dependencies, parent_dependencies)
+      .expect("should have block dependencies");
+
+    let span = ErrorSpan::from(call_expr.span);
+    let mut block = AsyncDependenciesBlock::new(
+      self.module_identifier,
+      format!("{}:{}", span.start, span.end),
+      Some(DependencyLocation::new(span.start, span.end)),
+    );
+    for dep in block_dependencies {
+      block.add_dependency(dep);
+    }
+    self.blocks.push(block);
+
+    // the error callback is called outside of the block
+    if let Some((error_callback, _)) = error_callback {
+      error_callback.visit_with(self);
+    }
+    true
+  }
+
+  // The parameters which map to `require` in the dependency array (or in the default
+  // `require, exports, module` arguments) are treated as the free `require`
+  fn add_require_params(&mut self, params: &[&Pat], names: &[&str]) {
+    for (param, name) in params.iter().zip(names) {
+      if let Pat::Ident(ident) = param
+        && *name == "require"
+      {
+        self.require_ids.insert(ident.id.to_id());
+      }
+    }
+  }
+
+  // `require("./a")` in amd factories, where `require` is a parameter of the factory
+  fn process_local_require(&mut self, call_expr: &CallExpr) -> bool {
+    let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee else {
+      return false;
+    };
+    if !self.require_ids.contains(&ident.to_id()) {
+      return false;
+    }
+    let [ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(str)),
+    }] = call_expr.args.as_slice()
+    else {
+      return false;
+    };
+    self.add_dependency(Box::new(CommonJsRequireDependency::new(
+      str.value.to_string(),
+      Some(call_expr.span.into()),
+      str.span.real_lo(),
+      str.span.hi().0,
+      false,
+    )));
+    true
+  }
+}
+
+impl Visit for AmdScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if self.is_unresolved_ident(callee, "define") && self.process_define(call_expr) {
+        return;
+      }
+      if self.is_require(callee) && self.process_require(call_expr) {
+        return;
+      }
+      if self.process_local_require(call_expr) {
+        return;
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if unary_expr.op == UnaryOp::TypeOf {
+      let value = if self.is_unresolved_ident(&unary_expr.arg, "define") {
+        Some("\"function\"")
+      } else if self.is_amd_member(&unary_expr.arg) {
+        Some("\"object\"")
+      } else {
+        None
+      };
+      if let Some(value) = value {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            unary_expr.span.real_lo(),
+            unary_expr.span.real_hi(),
+            value.into(),
+            None,
+          )));
+        return;
+      }
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    if self.is_amd_member(expr) {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span().real_lo(),
+          expr.span().real_hi(),
+          RuntimeGlobals::AMD_OPTIONS.name().into(),
+          Some(RuntimeGlobals::AMD_OPTIONS),
+        )));
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    // `define` which is not called directly, e.g. `var d = define`
+    if ident.sym == "define" && ident.span.ctxt == self.unresolved_ctxt {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          ident.span.real_lo(),
+          ident.span.real_hi(),
+          RuntimeGlobals::AMD_DEFINE.name().into(),
+          Some(RuntimeGlobals::AMD_DEFINE),
+        )));
+    }
+  }
+}
+
+// `function() {}.bind(this)`
+fn unwrap_bind_this(expr: &Expr) -> (&Expr, bool) {
+  if let Expr::Call(CallExpr {
+    callee:
+      Callee::Expr(box Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+      })),
+    args,
+    ..
+  }) = expr
+    && prop.sym == "bind"
+    && matches!(
+      args.as_slice(),
+      [ExprOrSpread {
+        spread: None,
+        expr: box Expr::This(_),
+      }]
+    )
+    && get_function_params(obj).is_some()
+  {
+    return (obj, true);
+  }
+  (expr, false)
+}
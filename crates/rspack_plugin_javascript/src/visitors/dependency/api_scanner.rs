@@ -5,7 +5,7 @@ use rspack_core::{
 use swc_core::{
   common::{Spanned, SyntaxContext},
   ecma::{
-    ast::{AssignExpr, AssignOp, Expr, Ident, Pat, PatOrExpr, VarDeclarator},
+    ast::{AssignExpr, AssignOp, CallExpr, Callee, Expr, Ident, Pat, PatOrExpr, VarDeclarator},
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
@@ -144,6 +144,7 @@ impl Visit for ApiScanner<'_> {
           )));
       }
       WEBPACK_MODULE => {
+        self.build_info.module_concatenation_bailout = Some(WEBPACK_MODULE.to_string());
         self
           .presentational_dependencies
           .push(Box::new(ModuleArgumentDependency::new(
@@ -209,6 +210,16 @@ impl Visit for ApiScanner<'_> {
     }
   }
 
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
+      && ident.span.ctxt == self.unresolved_ctxt
+      && &ident.sym == "eval"
+    {
+      self.build_info.module_concatenation_bailout = Some("eval()".to_string());
+    }
+    call_expr.visit_children_with(self);
+  }
+
   fn visit_expr(&mut self, expr: &Expr) {
     if expr_matcher::is_require_cache(expr) {
       self
@@ -220,6 +231,7 @@ impl Visit for ApiScanner<'_> {
           Some(RuntimeGlobals::MODULE_CACHE),
         )));
     } else if expr_matcher::is_webpack_module_id(expr) {
+      self.build_info.module_concatenation_bailout = Some(format!("{WEBPACK_MODULE}.id"));
       self
         .presentational_dependencies
         .push(Box::new(RuntimeRequirementsDependency::new(
@@ -1,5 +1,8 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
+use rspack_core::SpanExt;
+use rspack_error::Diagnostic;
+use swc_core::common::Span;
 use swc_core::ecma::ast::{
   BinExpr, BinaryOp, CallExpr, Callee, Expr, Lit, MemberProp, TaggedTpl, Tpl,
 };
@@ -18,18 +21,37 @@ fn split_context_from_prefix(prefix: String) -> (String, String) {
   }
 }
 
-pub fn scanner_context_module(expr: &Expr) -> Option<(String, String)> {
+/// Returns the context and the regexp of a wrapped context request, the dynamic parts are matched
+/// by `wrapped_context_reg_exp`, e.g. `.*`.
+pub fn scanner_context_module(
+  expr: &Expr,
+  wrapped_context_reg_exp: &str,
+) -> Option<(String, String)> {
   match expr {
-    Expr::Tpl(tpl) if !tpl.exprs.is_empty() => {
-      Some(scan_context_module_tpl(tpl, TemplateStringKind::Cooked))
-    }
-    Expr::Bin(bin) => scan_context_module_bin(bin),
-    Expr::Call(call) => scan_context_module_concat_call(call),
-    Expr::TaggedTpl(t_tpl) => Some(scan_context_module_tagged_tpl(t_tpl)),
+    Expr::Tpl(tpl) if !tpl.exprs.is_empty() => Some(scan_context_module_tpl(
+      tpl,
+      TemplateStringKind::Cooked,
+      wrapped_context_reg_exp,
+    )),
+    Expr::Bin(bin) => scan_context_module_bin(bin, wrapped_context_reg_exp),
+    Expr::Call(call) => scan_context_module_concat_call(call, wrapped_context_reg_exp),
+    Expr::TaggedTpl(t_tpl) => Some(scan_context_module_tagged_tpl(
+      t_tpl,
+      wrapped_context_reg_exp,
+    )),
     _ => None,
   }
 }
 
+pub fn critical_dependency_warning(message: &str, span: Span) -> Diagnostic {
+  Diagnostic::warn(
+    String::from("Critical dependency"),
+    format!("Critical dependency: {message}"),
+    span.real_lo() as usize,
+    span.real_hi() as usize,
+  )
+}
+
 static META_REG: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"[-\[\]\\/{}()*+?.^$|]").expect("Failed to initialize `MATCH_RESOURCE_REGEX`")
 });
@@ -40,7 +62,11 @@ fn quote_meta(str: String) -> String {
 }
 
 // require(`./${a}.js`)
-fn scan_context_module_tpl(tpl: &Tpl, kind: TemplateStringKind) -> (String, String) {
+fn scan_context_module_tpl(
+  tpl: &Tpl,
+  kind: TemplateStringKind,
+  wrapped_context_reg_exp: &str,
+) -> (String, String) {
   let prefix_raw = tpl
     .quasis
     .first()
@@ -69,12 +95,12 @@ fn scan_context_module_tpl(tpl: &Tpl, kind: TemplateStringKind) -> (String, Stri
         TemplateStringKind::Cooked => s.cooked.as_ref().unwrap_or(&s.raw),
       }
       .to_string()
-        + ".*"
+        + wrapped_context_reg_exp
     })
     .collect::<Vec<String>>()
     .join("");
   let reg = format!(
-    "^{prefix}.*{inner_reg}{postfix_raw}$",
+    "^{prefix}{wrapped_context_reg_exp}{inner_reg}{postfix_raw}$",
     prefix = quote_meta(prefix),
     postfix_raw = quote_meta(postfix_raw)
   );
@@ -82,7 +108,10 @@ fn scan_context_module_tpl(tpl: &Tpl, kind: TemplateStringKind) -> (String, Stri
 }
 
 // require("./" + a + ".js")
-fn scan_context_module_bin(bin: &BinExpr) -> Option<(String, String)> {
+fn scan_context_module_bin(
+  bin: &BinExpr,
+  wrapped_context_reg_exp: &str,
+) -> Option<(String, String)> {
   if !is_add_op_bin_expr(bin) {
     return None;
   }
@@ -103,7 +132,7 @@ fn scan_context_module_bin(bin: &BinExpr) -> Option<(String, String)> {
 
   let (context, prefix) = split_context_from_prefix(prefix_raw);
   let reg = format!(
-    "^{prefix}.*{postfix_raw}$",
+    "^{prefix}{wrapped_context_reg_exp}{postfix_raw}$",
     prefix = quote_meta(prefix),
     postfix_raw = quote_meta(postfix_raw)
   );
@@ -133,7 +162,10 @@ fn is_add_op_bin_expr(bin: &BinExpr) -> bool {
 // require("./".concat(a, ".js"))
 // babel/swc will transform template literal to string concat, so we need to handle this case
 // see https://github.com/webpack/webpack/pull/5679
-fn scan_context_module_concat_call(expr: &CallExpr) -> Option<(String, String)> {
+fn scan_context_module_concat_call(
+  expr: &CallExpr,
+  wrapped_context_reg_exp: &str,
+) -> Option<(String, String)> {
   if !is_concat_call(expr) {
     return None;
   }
@@ -154,7 +186,7 @@ fn scan_context_module_concat_call(expr: &CallExpr) -> Option<(String, String)>
 
   let (context, prefix) = split_context_from_prefix(prefix_raw);
   let reg = format!(
-    "^{prefix}.*{postfix_raw}$",
+    "^{prefix}{wrapped_context_reg_exp}{postfix_raw}$",
     prefix = quote_meta(prefix),
     postfix_raw = quote_meta(postfix_raw)
   );
@@ -163,7 +195,10 @@ fn scan_context_module_concat_call(expr: &CallExpr) -> Option<(String, String)>
 }
 
 // require(String.raw`./${a}.js`)
-fn scan_context_module_tagged_tpl(tpl: &TaggedTpl) -> (String, String) {
+fn scan_context_module_tagged_tpl(
+  tpl: &TaggedTpl,
+  wrapped_context_reg_exp: &str,
+) -> (String, String) {
   match tpl.tag.as_member() {
     Some(tag)
       if tag
@@ -177,7 +212,11 @@ fn scan_context_module_tagged_tpl(tpl: &TaggedTpl) -> (String, String) {
           .map(|ident| ident.sym == *"raw")
           .unwrap_or(false) =>
     {
-      scan_context_module_tpl(tpl.tpl.as_ref(), TemplateStringKind::Raw)
+      scan_context_module_tpl(
+        tpl.tpl.as_ref(),
+        TemplateStringKind::Raw,
+        wrapped_context_reg_exp,
+      )
     }
     _ => (String::from("."), String::new()),
   }
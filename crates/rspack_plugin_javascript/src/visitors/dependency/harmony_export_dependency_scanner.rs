@@ -1,6 +1,6 @@
 use rspack_core::{
   tree_shaking::symbol::DEFAULT_JS_WORD, BoxDependency, BoxDependencyTemplate, BuildInfo,
-  ConstDependency, SpanExt,
+  ConstDependency, JavascriptParserOptions, SpanExt,
 };
 use rustc_hash::FxHashMap as HashMap;
 use swc_core::{
@@ -30,6 +30,7 @@ pub struct HarmonyExportDependencyScanner<'a, 'b> {
   pub build_info: &'a mut BuildInfo,
   pub rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
   pub comments: Option<&'b SwcComments>,
+  parser_options: &'a JavascriptParserOptions,
 }
 
 impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
@@ -40,6 +41,7 @@ impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
     build_info: &'a mut BuildInfo,
     rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
     comments: Option<&'b SwcComments>,
+    parser_options: &'a JavascriptParserOptions,
   ) -> Self {
     Self {
       dependencies,
@@ -48,6 +50,7 @@ impl<'a, 'b> HarmonyExportDependencyScanner<'a, 'b> {
       build_info,
       rewrite_usage_span,
       comments,
+      parser_options,
     }
   }
 }
@@ -139,7 +142,7 @@ impl<'a, 'b> Visit for HarmonyExportDependencyScanner<'a, 'b> {
                     mode_ids,
                     Some(export.clone()),
                     false,
-                    None,
+                    self.parser_options.reexport_export_presence_mode(),
                   )));
               } else {
                 self
@@ -1,7 +1,8 @@
 use indexmap::IndexMap;
 use rspack_core::{
   extract_member_expression_chain, tree_shaking::symbol::DEFAULT_JS_WORD, BoxDependency,
-  BoxDependencyTemplate, BuildInfo, ConstDependency, DependencyType, SpanExt,
+  BoxDependencyTemplate, BuildInfo, ConstDependency, DependencyType, ExportPresenceMode,
+  JavascriptParserOptions, SpanExt,
 };
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::atoms::JsWord;
@@ -70,6 +71,7 @@ pub struct HarmonyImportDependencyScanner<'a> {
   pub imports: Imports,
   pub build_info: &'a mut BuildInfo,
   pub rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
+  parser_options: &'a JavascriptParserOptions,
   last_harmony_import_order: i32,
 }
 
@@ -80,6 +82,7 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
     import_map: &'a mut ImportMap,
     build_info: &'a mut BuildInfo,
     rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
+    parser_options: &'a JavascriptParserOptions,
   ) -> Self {
     Self {
       dependencies,
@@ -88,6 +91,7 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
       imports: Default::default(),
       build_info,
       rewrite_usage_span,
+      parser_options,
       last_harmony_import_order: 0,
     }
   }
@@ -99,6 +103,7 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
   fn visit_program(&mut self, program: &Program) {
     // collect import map info
     program.visit_children_with(self);
+    let reexport_export_presence_mode = self.parser_options.reexport_export_presence_mode();
     for ((request, dependency_type, source_order), importer_info) in
       std::mem::take(&mut self.imports).into_iter()
     {
@@ -120,7 +125,7 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
                   vec![],
                   Some(n.clone()),
                   false,
-                  None,
+                  reexport_export_presence_mode,
                 )));
               self.build_info.harmony_named_exports.insert(n.clone());
             }
@@ -140,14 +145,13 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
                   ids,
                   Some(name.clone()),
                   false,
-                  None,
+                  reexport_export_presence_mode,
                 )));
               self.build_info.harmony_named_exports.insert(name);
             }
           });
       }
       if importer_info.exports_all {
-        let list = Some(self.build_info.all_star_exports.clone());
         let export_imported_dep = HarmonyExportImportedSpecifierDependency::new(
           request.clone(),
           source_order,
@@ -155,7 +159,7 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
           vec![],
           None,
           true,
-          list,
+          reexport_export_presence_mode,
         );
 
         self
@@ -180,6 +184,7 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
       self.import_map,
       self.dependencies,
       self.rewrite_usage_span,
+      self.parser_options.import_export_presence_mode(),
     ));
   }
 
@@ -349,6 +354,7 @@ pub struct HarmonyImportRefDependencyScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   pub properties_in_destructuring: HashMap<JsWord, HashSet<JsWord>>,
   pub rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
+  export_presence_mode: ExportPresenceMode,
 }
 
 impl<'a> HarmonyImportRefDependencyScanner<'a> {
@@ -356,6 +362,7 @@ impl<'a> HarmonyImportRefDependencyScanner<'a> {
     import_map: &'a ImportMap,
     dependencies: &'a mut Vec<BoxDependency>,
     rewrite_usage_span: &'a mut HashMap<Span, ExtraSpanInfo>,
+    export_presence_mode: ExportPresenceMode,
   ) -> Self {
     Self {
       import_map,
@@ -364,6 +371,7 @@ impl<'a> HarmonyImportRefDependencyScanner<'a> {
       enter_new_expr: false,
       properties_in_destructuring: HashMap::default(),
       rewrite_usage_span,
+      export_presence_mode,
     }
   }
 }
@@ -413,6 +421,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
               reference.specifier.clone(),
               None,
               shorthand.span,
+              self.export_presence_mode,
             )));
         }
       }
@@ -439,6 +448,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
           reference.specifier.clone(),
           self.properties_in_destructuring.remove(&ident.sym),
           ident.span,
+          self.export_presence_mode,
         )));
     }
   }
@@ -487,6 +497,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
           reference.specifier.clone(),
           None,
           opt_chain_expr.span,
+          self.export_presence_mode,
         )));
       return;
     }
@@ -528,6 +539,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
             reference.specifier.clone(),
             None,
             member_expr.span,
+            self.export_presence_mode,
           )));
         return;
       }
@@ -596,17 +608,20 @@ mod test {
       harmony_named_exports: Default::default(),
       all_star_exports: Default::default(),
       need_create_require: false,
+      module_concatenation_bailout: None,
     };
     let mut import_map = Default::default();
     let mut deps = vec![];
     let mut presentation_deps = vec![];
     let mut rewrite_usage_span = Default::default();
+    let parser_options = Default::default();
     let mut scanner = HarmonyImportDependencyScanner::new(
       &mut deps,
       &mut presentation_deps,
       &mut import_map,
       &mut build_info,
       &mut rewrite_usage_span,
+      &parser_options,
     );
 
     program.visit_with(&mut scanner);
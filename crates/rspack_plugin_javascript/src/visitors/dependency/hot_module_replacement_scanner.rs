@@ -1,4 +1,4 @@
-use rspack_core::{BoxDependency, BoxDependencyTemplate, BuildMeta, ErrorSpan, SpanExt};
+use rspack_core::{BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta, ErrorSpan, SpanExt};
 use swc_core::{
   common::Spanned,
   ecma::{
@@ -20,6 +20,7 @@ use crate::{
 pub struct HotModuleReplacementScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   pub presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+  pub build_info: &'a mut BuildInfo,
   pub build_meta: &'a BuildMeta,
 }
 
@@ -29,11 +30,13 @@ impl<'a> HotModuleReplacementScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+    build_info: &'a mut BuildInfo,
     build_meta: &'a BuildMeta,
   ) -> Self {
     Self {
       dependencies,
       presentational_dependencies,
+      build_info,
       build_meta,
     }
   }
@@ -106,6 +109,7 @@ impl<'a> Visit for HotModuleReplacementScanner<'a> {
 
   fn visit_expr(&mut self, expr: &Expr) {
     if expr_matcher::is_module_hot(expr) || expr_matcher::is_import_meta_webpack_hot(expr) {
+      self.build_info.module_concatenation_bailout = Some("Hot Module Replacement".to_string());
       self
         .presentational_dependencies
         .push(Box::new(ModuleArgumentDependency::new(
@@ -1,22 +1,23 @@
-use once_cell::sync::Lazy;
 use rspack_core::{
   clean_regexp_in_context_module, context_reg_exp, AsyncDependenciesBlock, DependencyLocation,
   DynamicImportMode, ErrorSpan, GroupOptions, JavascriptParserOptions, ModuleIdentifier,
 };
 use rspack_core::{BoxDependency, BuildMeta, ChunkGroupOptions, ContextMode};
 use rspack_core::{ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt};
+use rspack_error::Diagnostic;
 use rspack_regex::{regexp_as_str, RspackRegex};
-use swc_core::common::comments::{CommentKind, Comments};
-use swc_core::common::{Span, Spanned};
+use swc_core::common::comments::Comments;
+use swc_core::common::Spanned;
 use swc_core::ecma::ast::{CallExpr, Callee, Expr, Lit};
 use swc_core::ecma::atoms::JsWord;
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
-use super::context_helper::scanner_context_module;
+use super::context_helper::{critical_dependency_warning, scanner_context_module};
 use super::is_import_meta_context_call;
 use crate::dependency::{ImportContextDependency, ImportDependency};
-use crate::dependency::{ImportEagerDependency, ImportMetaContextDependency};
+use crate::dependency::{ImportEagerDependency, ImportMetaContextDependency, ImportWeakDependency};
 use crate::utils::{get_bool_by_obj_prop, get_literal_str_by_obj_prop, get_regex_by_obj_prop};
+use crate::utils::{try_extract_magic_comment_options, MagicCommentOptions};
 
 pub struct ImportScanner<'a> {
   module_identifier: ModuleIdentifier,
@@ -24,7 +25,8 @@ pub struct ImportScanner<'a> {
   pub blocks: &'a mut Vec<AsyncDependenciesBlock>,
   pub comments: Option<&'a dyn Comments>,
   pub build_meta: &'a BuildMeta,
-  pub options: Option<&'a JavascriptParserOptions>,
+  pub options: &'a JavascriptParserOptions,
+  pub warning_diagnostics: &'a mut Vec<Diagnostic>,
 }
 
 fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaContextDependency> {
@@ -72,6 +74,8 @@ fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaCo
     let reg_str = regexp_as_str(&regexp).to_string();
     ContextOptions {
       chunk_name: None,
+      group_options: None,
+      referenced_exports: None,
       reg_exp: clean_regexp_in_context_module(regexp),
       reg_str,
       include: None,
@@ -85,6 +89,8 @@ fn create_import_meta_context_dependency(node: &CallExpr) -> Option<ImportMetaCo
   } else {
     ContextOptions {
       chunk_name: None,
+      group_options: None,
+      referenced_exports: None,
       recursive: true,
       mode: ContextMode::Sync,
       include: None,
@@ -111,7 +117,8 @@ impl<'a> ImportScanner<'a> {
     blocks: &'a mut Vec<AsyncDependenciesBlock>,
     comments: Option<&'a dyn Comments>,
     build_meta: &'a BuildMeta,
-    options: Option<&'a JavascriptParserOptions>,
+    options: &'a JavascriptParserOptions,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       module_identifier,
@@ -120,37 +127,60 @@ impl<'a> ImportScanner<'a> {
       comments,
       build_meta,
       options,
+      warning_diagnostics,
     }
   }
 
-  fn try_extract_webpack_chunk_name(&self, first_arg_span_of_import_call: &Span) -> Option<String> {
-    static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
-      regex::Regex::new(r#"webpackChunkName\s*:\s*("(?P<_1>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)"|'(?P<_2>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)'|`(?P<_3>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)`)"#)
-        .expect("invalid regex")
-    });
-    self
-      .comments
-      .with_leading(first_arg_span_of_import_call.lo, |comments| {
-        let ret = comments
-          .iter()
-          .rev()
-          .filter(|c| matches!(c.kind, CommentKind::Block))
-          .find_map(|comment| {
-            WEBPACK_CHUNK_NAME_CAPTURE_RE
-              .captures(&comment.text)
-              .and_then(|captures| {
-                if let Some(cap) = captures.name("_1") {
-                  Some(cap)
-                } else if let Some(cap) = captures.name("_2") {
-                  Some(cap)
-                } else {
-                  captures.name("_3")
-                }
-              })
-              .map(|mat| mat.as_str().to_string())
-          });
-        ret
-      })
+  fn add_import(
+    &mut self,
+    node: &CallExpr,
+    request: JsWord,
+    mode: DynamicImportMode,
+    magic_comment_options: MagicCommentOptions,
+  ) {
+    let span = ErrorSpan::from(node.span);
+    match mode {
+      DynamicImportMode::Eager => {
+        self.dependencies.push(Box::new(ImportEagerDependency::new(
+          node.span.real_lo(),
+          node.span.real_hi(),
+          request,
+          Some(span),
+          magic_comment_options.exports,
+        )));
+      }
+      DynamicImportMode::Weak => {
+        self.dependencies.push(Box::new(ImportWeakDependency::new(
+          node.span.real_lo(),
+          node.span.real_hi(),
+          request,
+          Some(span),
+          magic_comment_options.exports,
+        )));
+      }
+      DynamicImportMode::Lazy | DynamicImportMode::LazyOnce => {
+        let dep = Box::new(ImportDependency::new(
+          node.span.real_lo(),
+          node.span.real_hi(),
+          request,
+          Some(span),
+          magic_comment_options.exports,
+        ));
+        let mut block = AsyncDependenciesBlock::new(
+          self.module_identifier,
+          format!("{}:{}", span.start, span.end),
+          Some(DependencyLocation::new(span.start, span.end)),
+        );
+        block.set_group_options(GroupOptions::ChunkGroup(
+          ChunkGroupOptions::default()
+            .name_optional(magic_comment_options.chunk_name)
+            .prefetch_order(magic_comment_options.prefetch_order)
+            .preload_order(magic_comment_options.preload_order),
+        ));
+        block.add_dependency(dep);
+        self.blocks.push(block);
+      }
+    }
   }
 }
 
@@ -178,48 +208,26 @@ impl Visit for ImportScanner<'_> {
       return;
     }
 
-    let mode = self
-      .options
-      .map(|o| o.dynamic_import_mode)
-      .unwrap_or_default();
+    let magic_comment_options = try_extract_magic_comment_options(
+      self.comments,
+      dyn_imported.span(),
+      node.span,
+      self.warning_diagnostics,
+    );
+    if matches!(magic_comment_options.ignore, Some(true)) {
+      node.visit_children_with(self);
+      return;
+    }
+
+    let mode = magic_comment_options
+      .mode
+      .unwrap_or(self.options.dynamic_import_mode);
 
     match dyn_imported.expr.as_ref() {
       Expr::Lit(Lit::Str(imported)) => {
-        if matches!(mode, DynamicImportMode::Eager) {
-          let dep = ImportEagerDependency::new(
-            node.span.real_lo(),
-            node.span.real_hi(),
-            imported.value.clone(),
-            Some(node.span.into()),
-            // TODO scan dynamic import referenced exports
-            None,
-          );
-          self.dependencies.push(Box::new(dep));
-          return;
-        }
-        let chunk_name = self.try_extract_webpack_chunk_name(&imported.span);
-        let span = ErrorSpan::from(node.span);
-        let dep = Box::new(ImportDependency::new(
-          node.span.real_lo(),
-          node.span.real_hi(),
-          imported.value.clone(),
-          Some(span),
-          // TODO scan dynamic import referenced exports
-          None,
-        ));
-        let mut block = AsyncDependenciesBlock::new(
-          self.module_identifier,
-          format!("{}:{}", span.start, span.end),
-          Some(DependencyLocation::new(span.start, span.end)),
-        );
-        block.set_group_options(GroupOptions::ChunkGroup(
-          ChunkGroupOptions::default().name_optional(chunk_name),
-        ));
-        block.add_dependency(dep);
-        self.blocks.push(block);
+        self.add_import(node, imported.value.clone(), mode, magic_comment_options);
       }
       Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-        let chunk_name = self.try_extract_webpack_chunk_name(&tpl.span);
         let request = JsWord::from(
           tpl
             .quasis
@@ -228,30 +236,33 @@ impl Visit for ImportScanner<'_> {
             .raw
             .to_string(),
         );
-        let span = ErrorSpan::from(node.span);
-        let dep = Box::new(ImportDependency::new(
-          node.span.real_lo(),
-          node.span.real_hi(),
-          request,
-          Some(span),
-          None,
-        ));
-        let mut block = AsyncDependenciesBlock::new(
-          self.module_identifier,
-          format!("{}:{}", span.start, span.end),
-          Some(DependencyLocation::new(span.start, span.end)),
-        );
-        block.set_group_options(GroupOptions::ChunkGroup(
-          ChunkGroupOptions::default().name_optional(chunk_name),
-        ));
-        block.add_dependency(dep);
-        self.blocks.push(block);
+        self.add_import(node, request, mode, magic_comment_options);
       }
       _ => {
-        let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) else {
+        let Some((context, reg)) = scanner_context_module(
+          dyn_imported.expr.as_ref(),
+          self.options.wrapped_context_reg_exp.source(),
+        ) else {
+          if self.options.expr_context_critical {
+            self.warning_diagnostics.push(critical_dependency_warning(
+              "the request of a dependency is an expression",
+              node.span,
+            ));
+          }
           return;
         };
-        let chunk_name = self.try_extract_webpack_chunk_name(&dyn_imported.span());
+        if self.options.wrapped_context_critical {
+          self.warning_diagnostics.push(critical_dependency_warning(
+            "a part of the request of a dependency is an expression",
+            node.span,
+          ));
+        }
+        let mode = match mode {
+          DynamicImportMode::Lazy => ContextMode::Lazy,
+          DynamicImportMode::LazyOnce => ContextMode::LazyOnce,
+          DynamicImportMode::Eager => ContextMode::Eager,
+          DynamicImportMode::Weak => ContextMode::AsyncWeak,
+        };
         self
           .dependencies
           .push(Box::new(ImportContextDependency::new(
@@ -259,13 +270,19 @@ impl Visit for ImportScanner<'_> {
             import_call.span.real_hi(),
             node.span.real_hi(),
             ContextOptions {
-              chunk_name,
-              mode: ContextMode::Lazy,
+              chunk_name: magic_comment_options.chunk_name,
+              group_options: Some(
+                ChunkGroupOptions::default()
+                  .prefetch_order(magic_comment_options.prefetch_order)
+                  .preload_order(magic_comment_options.preload_order),
+              ),
+              referenced_exports: magic_comment_options.exports,
+              mode,
               recursive: true,
               reg_exp: context_reg_exp(&reg, ""),
               reg_str: reg,
-              include: None,
-              exclude: None,
+              include: magic_comment_options.include,
+              exclude: magic_comment_options.exclude,
               category: DependencyCategory::Esm,
               request: context,
               namespace_object: if self.build_meta.strict_harmony_module {
@@ -1,9 +1,9 @@
+mod amd_scanner;
 mod api_scanner;
 mod common_js_export_scanner;
-pub(crate) mod common_js_import_dependency_scanner;
 mod common_js_scanner;
 mod compatibility_scanner;
-mod context_helper;
+pub(crate) mod context_helper;
 mod export_info_api_scanner;
 mod harmony_detection_scanner;
 mod harmony_export_dependency_scanner;
@@ -13,15 +13,19 @@ mod hot_module_replacement_scanner;
 mod import_meta_scanner;
 mod import_scanner;
 mod node_stuff_scanner;
+mod parser;
 mod require_context_scanner;
 mod url_scanner;
 mod util;
 mod worker_scanner;
 
+use std::sync::Arc;
+
 use rspack_ast::javascript::Program;
 use rspack_core::{
   AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta,
-  CompilerOptions, ModuleIdentifier, ModuleType, ResourceData,
+  CompilerOptions, JavascriptParserOptions, JavascriptParserUrl, ModuleIdentifier, ModuleType,
+  ResourceData,
 };
 use rspack_error::{Diagnostic, Result};
 use rustc_hash::FxHashMap as HashMap;
@@ -31,11 +35,11 @@ use swc_core::ecma::atoms::JsWord;
 pub use util::*;
 
 use self::harmony_import_dependency_scanner::ImportMap;
+pub use self::parser::JavascriptParser;
 use self::{
-  api_scanner::ApiScanner, common_js_export_scanner::CommonJsExportDependencyScanner,
-  common_js_import_dependency_scanner::CommonJsImportDependencyScanner,
-  common_js_scanner::CommonJsScanner, compatibility_scanner::CompatibilityScanner,
-  export_info_api_scanner::ExportInfoApiScanner,
+  amd_scanner::AmdScanner, api_scanner::ApiScanner,
+  common_js_export_scanner::CommonJsExportDependencyScanner, common_js_scanner::CommonJsScanner,
+  compatibility_scanner::CompatibilityScanner, export_info_api_scanner::ExportInfoApiScanner,
   harmony_detection_scanner::HarmonyDetectionScanner,
   harmony_export_dependency_scanner::HarmonyExportDependencyScanner,
   harmony_import_dependency_scanner::HarmonyImportDependencyScanner,
@@ -45,6 +49,7 @@ use self::{
   node_stuff_scanner::NodeStuffScanner, require_context_scanner::RequireContextScanner,
   url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
+use crate::parser_plugin::JavaScriptParserPluginDrive;
 
 pub struct ScanDependenciesResult {
   pub dependencies: Vec<BoxDependency>,
@@ -72,9 +77,11 @@ pub fn scan_dependencies(
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
   module_type: &ModuleType,
+  parser_options: &JavascriptParserOptions,
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  parser_plugin_drive: &Arc<JavaScriptParserPluginDrive>,
 ) -> Result<ScanDependenciesResult> {
   let mut warning_diagnostics: Vec<Diagnostic> = vec![];
   let mut errors = vec![];
@@ -103,12 +110,18 @@ pub fn scan_dependencies(
     unresolved_ctxt,
   ));
 
-  // TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
-  // https://github.com/web-infra-dev/rspack/issues/3544
-  program.visit_with(&mut CommonJsImportDependencyScanner::new(
+  program.visit_with(&mut JavascriptParser::new(
+    module_identifier,
     &mut dependencies,
+    &mut blocks,
     &mut presentational_dependencies,
+    &mut warning_diagnostics,
+    resource_data,
+    module_type,
+    parser_options,
+    comments.as_ref().map(|c| c as &dyn Comments),
     unresolved_ctxt,
+    parser_plugin_drive.clone(),
   ));
   if module_type.is_js_auto() || module_type.is_js_dynamic() {
     program.visit_with(&mut CommonJsScanner::new(
@@ -133,6 +146,15 @@ pub fn scan_dependencies(
         resource_data,
       ));
     }
+    if compiler_options.amd.is_some() {
+      program.visit_with(&mut AmdScanner::new(
+        module_identifier,
+        &mut dependencies,
+        &mut blocks,
+        &mut presentational_dependencies,
+        unresolved_ctxt,
+      ));
+    }
   }
 
   let mut import_map = Default::default();
@@ -153,6 +175,7 @@ pub fn scan_dependencies(
       &mut import_map,
       build_info,
       &mut rewrite_usage_span,
+      parser_options,
     ));
     let comments = program.comments.as_ref();
     program.visit_with(&mut HarmonyExportDependencyScanner::new(
@@ -162,6 +185,7 @@ pub fn scan_dependencies(
       build_info,
       &mut rewrite_usage_span,
       comments,
+      parser_options,
     ));
 
     if build_meta.esm {
@@ -170,9 +194,13 @@ pub fn scan_dependencies(
       })
     }
 
-    let mut worker_syntax_scanner = rspack_core::needs_refactor::WorkerSyntaxScanner::new(
-      rspack_core::needs_refactor::DEFAULT_WORKER_SYNTAX,
-    );
+    let worker_syntax = parser_options
+      .worker
+      .iter()
+      .map(|s| s.as_str())
+      .collect::<Vec<_>>();
+    let mut worker_syntax_scanner =
+      rspack_core::needs_refactor::WorkerSyntaxScanner::new(&worker_syntax);
     program.visit_with(&mut worker_syntax_scanner);
     let worker_syntax_list = &worker_syntax_scanner.into();
     let mut worker_scanner = WorkerScanner::new(
@@ -184,13 +212,21 @@ pub fn scan_dependencies(
     blocks.append(&mut worker_scanner.blocks);
     dependencies.append(&mut worker_scanner.dependencies);
     presentational_dependencies.append(&mut worker_scanner.presentational_dependencies);
-    program.visit_with(&mut UrlScanner::new(&mut dependencies, worker_syntax_list));
-    program.visit_with(&mut ImportMetaScanner::new(
-      &mut presentational_dependencies,
-      resource_data,
-      compiler_options,
-      &mut warning_diagnostics,
-    ));
+    if parser_options.url != JavascriptParserUrl::Disable {
+      program.visit_with(&mut UrlScanner::new(
+        &mut dependencies,
+        worker_syntax_list,
+        parser_options.url == JavascriptParserUrl::Relative,
+      ));
+    }
+    if parser_options.import_meta {
+      program.visit_with(&mut ImportMetaScanner::new(
+        &mut presentational_dependencies,
+        resource_data,
+        compiler_options,
+        &mut warning_diagnostics,
+      ));
+    }
   }
 
   program.visit_with(&mut ImportScanner::new(
@@ -199,18 +235,15 @@ pub fn scan_dependencies(
     &mut blocks,
     comments.as_ref().map(|c| c as &dyn Comments),
     build_meta,
-    compiler_options
-      .module
-      .parser
-      .as_ref()
-      .and_then(|p| p.get(module_type))
-      .and_then(|p| p.get_javascript(module_type)),
+    parser_options,
+    &mut warning_diagnostics,
   ));
 
   if compiler_options.dev_server.hot {
     program.visit_with(&mut HotModuleReplacementScanner::new(
       &mut dependencies,
       &mut presentational_dependencies,
+      build_info,
       build_meta,
     ));
   }
@@ -0,0 +1,486 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, JavascriptParserOptions,
+  ModuleIdentifier, ModuleType, ResourceData, SpanExt,
+};
+use rspack_error::Diagnostic;
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::common::comments::Comments;
+use swc_core::common::{Spanned, SyntaxContext};
+use swc_core::ecma::ast::{
+  AssignExpr, BinExpr, CallExpr, Callee, Expr, Id, Ident, IfStmt, Lit, MemberExpr, MemberProp,
+  ModuleDecl, NewExpr, Pat, PatOrExpr, Program, TryStmt, UnaryExpr, UnaryOp, VarDeclarator,
+};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::parser_plugin::{JavaScriptParserPluginDrive, JavascriptParserPlugin};
+use crate::utils::{
+  eval_binary_expression, eval_cond_expression, eval_lit_expr, eval_tpl_expression,
+  eval_unary_expression, BasicEvaluatedExpression, EvaluateExpression,
+};
+
+/// Walks the program and calls the hooks of the builtin and the registered
+/// [JavascriptParserPlugin]s, the plugins add their dependencies to the parser
+pub struct JavascriptParser<'parser> {
+  pub module_identifier: ModuleIdentifier,
+  pub dependencies: &'parser mut Vec<BoxDependency>,
+  pub blocks: &'parser mut Vec<AsyncDependenciesBlock>,
+  pub presentational_dependencies: &'parser mut Vec<BoxDependencyTemplate>,
+  pub warning_diagnostics: &'parser mut Vec<Diagnostic>,
+  pub resource_data: &'parser ResourceData,
+  pub module_type: &'parser ModuleType,
+  pub javascript_options: &'parser JavascriptParserOptions,
+  pub comments: Option<&'parser dyn Comments>,
+  pub unresolved_ctxt: SyntaxContext,
+  /// Whether the parser is walking a `try` statement
+  pub in_try: bool,
+  /// Whether the parser is walking an `if` statement
+  pub in_if: bool,
+  plugin_drive: Arc<JavaScriptParserPluginDrive>,
+  // variables which are assigned with a free variable, see `JavascriptParserPlugin::can_rename`
+  renamed_identifiers: HashMap<Id, String>,
+}
+
+impl<'parser> JavascriptParser<'parser> {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    module_identifier: ModuleIdentifier,
+    dependencies: &'parser mut Vec<BoxDependency>,
+    blocks: &'parser mut Vec<AsyncDependenciesBlock>,
+    presentational_dependencies: &'parser mut Vec<BoxDependencyTemplate>,
+    warning_diagnostics: &'parser mut Vec<Diagnostic>,
+    resource_data: &'parser ResourceData,
+    module_type: &'parser ModuleType,
+    javascript_options: &'parser JavascriptParserOptions,
+    comments: Option<&'parser dyn Comments>,
+    unresolved_ctxt: SyntaxContext,
+    plugin_drive: Arc<JavaScriptParserPluginDrive>,
+  ) -> Self {
+    Self {
+      module_identifier,
+      dependencies,
+      blocks,
+      presentational_dependencies,
+      warning_diagnostics,
+      resource_data,
+      module_type,
+      javascript_options,
+      comments,
+      unresolved_ctxt,
+      in_try: false,
+      in_if: false,
+      plugin_drive,
+      renamed_identifiers: Default::default(),
+    }
+  }
+
+  /// Names the variable `id` by the free variable `name`, e.g. the parameter `require` of the
+  /// `require.ensure` callback is called as the free `require`
+  pub fn rename_identifier(&mut self, id: Id, name: String) {
+    self.renamed_identifiers.insert(id, name);
+  }
+
+  /// The name of a free variable or a member chain on it, e.g. `require.resolve`
+  pub fn get_name_for_expr(&self, expr: &Expr) -> Option<String> {
+    match expr {
+      Expr::Ident(ident) => self.get_free_name(ident),
+      Expr::Member(member) => self.get_name_for_member(member),
+      _ => None,
+    }
+  }
+
+  fn get_name_for_member(&self, member: &MemberExpr) -> Option<String> {
+    let mut members = vec![];
+    let mut expr = member;
+    let root = loop {
+      members.push(match &expr.prop {
+        MemberProp::Ident(ident) => ident.sym.to_string(),
+        MemberProp::Computed(computed) => match &*computed.expr {
+          Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+          _ => return None,
+        },
+        MemberProp::PrivateName(_) => return None,
+      });
+      match &*expr.obj {
+        Expr::Member(member) => expr = member,
+        Expr::Ident(ident) => break self.get_free_name(ident)?,
+        _ => return None,
+      }
+    };
+    members.push(root);
+    members.reverse();
+    Some(members.join("."))
+  }
+
+  fn get_free_name(&self, ident: &Ident) -> Option<String> {
+    if ident.span.ctxt == self.unresolved_ctxt {
+      Some(ident.sym.to_string())
+    } else {
+      self.renamed_identifiers.get(&ident.to_id()).cloned()
+    }
+  }
+
+  // same as `JavascriptParser._initializeEvaluating` in webpack
+  fn evaluating(&self, expr: &Expr) -> Option<BasicEvaluatedExpression> {
+    match expr {
+      Expr::Tpl(tpl) => eval_tpl_expression(self, tpl),
+      Expr::Lit(lit) => eval_lit_expr(lit),
+      Expr::Cond(cond) => eval_cond_expression(self, cond),
+      Expr::Unary(unary) => eval_unary_expression(self, unary),
+      Expr::Bin(binary) => eval_binary_expression(self, binary),
+      Expr::Paren(paren) => self.evaluating(&paren.expr),
+      Expr::Ident(_) | Expr::Member(_) => {
+        let name = self.get_name_for_expr(expr)?;
+        self
+          .plugin_drive
+          .evaluate_identifier(self, &name, expr.span().real_lo(), expr.span_hi().0)
+      }
+      _ => None,
+    }
+  }
+
+  // walks the callee without calling the hooks on itself, it's already called by `call` or `new_expression`
+  fn walk_callee(&mut self, callee: &Expr) {
+    match callee {
+      Expr::Ident(_) => {}
+      Expr::Member(member) => {
+        member.obj.visit_with(self);
+        if let MemberProp::Computed(computed) = &member.prop {
+          computed.visit_with(self);
+        }
+      }
+      _ => callee.visit_with(self),
+    }
+  }
+}
+
+impl EvaluateExpression for JavascriptParser<'_> {
+  fn evaluate_expression(&self, expr: &Expr) -> BasicEvaluatedExpression {
+    match self.evaluating(expr) {
+      Some(evaluated) => evaluated,
+      None => BasicEvaluatedExpression::with_range(expr.span().real_lo(), expr.span_hi().0),
+    }
+  }
+
+  fn evaluate_typeof(
+    &self,
+    ident: &Ident,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    self
+      .plugin_drive
+      .evaluate_typeof(ident, start, end, self.unresolved_ctxt)
+  }
+}
+
+impl Visit for JavascriptParser<'_> {
+  noop_visit_type!();
+
+  fn visit_program(&mut self, program: &Program) {
+    let plugin_drive = self.plugin_drive.clone();
+    if plugin_drive.program(self, program) == Some(true) {
+      return;
+    }
+    program.visit_children_with(self);
+  }
+
+  fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+    let plugin_drive = self.plugin_drive.clone();
+    match decl {
+      ModuleDecl::Import(import) => {
+        if plugin_drive.import(self, import, &import.src.value) == Some(true) {
+          return;
+        }
+      }
+      ModuleDecl::ExportDecl(_)
+      | ModuleDecl::ExportNamed(_)
+      | ModuleDecl::ExportDefaultDecl(_)
+      | ModuleDecl::ExportDefaultExpr(_)
+      | ModuleDecl::ExportAll(_) => {
+        if plugin_drive.export(self, decl) == Some(true) {
+          return;
+        }
+        let source = match decl {
+          ModuleDecl::ExportNamed(named) => named.src.as_ref(),
+          ModuleDecl::ExportAll(all) => Some(&all.src),
+          _ => None,
+        };
+        if let Some(source) = source
+          && plugin_drive.export_import(self, decl, &source.value) == Some(true)
+        {
+          return;
+        }
+      }
+      _ => {}
+    }
+    decl.visit_children_with(self);
+  }
+
+  fn visit_try_stmt(&mut self, stmt: &TryStmt) {
+    let old_in_try = std::mem::replace(&mut self.in_try, true);
+    stmt.visit_children_with(self);
+    self.in_try = old_in_try;
+  }
+
+  fn visit_if_stmt(&mut self, stmt: &IfStmt) {
+    let plugin_drive = self.plugin_drive.clone();
+    if plugin_drive.statement_if(self, stmt) == Some(true) {
+      return;
+    }
+    let old_in_if = std::mem::replace(&mut self.in_if, true);
+    stmt.visit_children_with(self);
+    self.in_if = old_in_if;
+  }
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Expr::Ident(ident) = expr {
+      if let Some(name) = self.get_free_name(ident) {
+        let plugin_drive = self.plugin_drive.clone();
+        plugin_drive.identifier(self, ident, &name);
+      }
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      call_expr.visit_children_with(self);
+      return;
+    };
+    if let Some(name) = self.get_name_for_expr(callee) {
+      let plugin_drive = self.plugin_drive.clone();
+      if plugin_drive.call(self, call_expr, &name) == Some(true) {
+        return;
+      }
+    }
+    self.walk_callee(callee);
+    call_expr.args.visit_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+    if let Some(name) = self.get_name_for_expr(&new_expr.callee) {
+      let plugin_drive = self.plugin_drive.clone();
+      if plugin_drive.new_expression(self, new_expr, &name) == Some(true) {
+        return;
+      }
+    }
+    self.walk_callee(&new_expr.callee);
+    new_expr.args.visit_with(self);
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
+    if let Some(name) = self.get_name_for_member(member_expr) {
+      let plugin_drive = self.plugin_drive.clone();
+      if plugin_drive.member(self, member_expr, &name) == Some(true) {
+        return;
+      }
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if unary_expr.op == UnaryOp::TypeOf
+      && let Some(name) = self.get_name_for_expr(&unary_expr.arg)
+    {
+      let plugin_drive = self.plugin_drive.clone();
+      if plugin_drive.r#typeof(self, unary_expr, &name) == Some(true) {
+        return;
+      }
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
+    let plugin_drive = self.plugin_drive.clone();
+    if plugin_drive.binary_expression(self, bin_expr) == Some(true) {
+      return;
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    // the assigned free variables are not expressions
+    match &assign_expr.left {
+      PatOrExpr::Pat(box Pat::Ident(_)) | PatOrExpr::Expr(box Expr::Ident(_)) => {}
+      left => left.visit_with(self),
+    }
+    assign_expr.right.visit_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    if let Pat::Ident(binding) = &declarator.name
+      && let Some(init) = &declarator.init
+      && let Some(name) = self.get_name_for_expr(init)
+      && self.plugin_drive.can_rename(self, &name) == Some(true)
+    {
+      self.renamed_identifiers.insert(binding.id.to_id(), name);
+      return;
+    }
+    declarator.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::{Arc, Mutex};
+
+  use rspack_core::{JavascriptParserOptions, ModuleType, ResourceData};
+  use swc_core::common::SyntaxContext;
+  use swc_core::ecma::ast::{CallExpr, ImportDecl, MemberExpr, ModuleDecl, NewExpr};
+
+  use super::JavascriptParser;
+  use crate::ast::parse;
+  use crate::parser_plugin::{JavaScriptParserPluginDrive, JavascriptParserPlugin};
+  use crate::utils::{evaluate_to_boolean, BasicEvaluatedExpression, EvaluateExpression};
+  use crate::visitors::swc_visitor::resolver;
+
+  #[derive(Debug, Default)]
+  struct RecordPlugin {
+    records: Arc<Mutex<Vec<String>>>,
+  }
+
+  impl RecordPlugin {
+    fn record(&self, record: String) {
+      self.records.lock().expect("should lock").push(record);
+    }
+  }
+
+  impl JavascriptParserPlugin for RecordPlugin {
+    fn import(
+      &self,
+      _parser: &mut JavascriptParser,
+      _statement: &ImportDecl,
+      source: &str,
+    ) -> Option<bool> {
+      self.record(format!("import {source}"));
+      Some(true)
+    }
+
+    fn export_import(
+      &self,
+      _parser: &mut JavascriptParser,
+      _statement: &ModuleDecl,
+      source: &str,
+    ) -> Option<bool> {
+      self.record(format!("export_import {source}"));
+      Some(true)
+    }
+
+    fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+      let key = parser.evaluate_expression(&expr.args[0].expr);
+      let key = key.as_string()?;
+      self.record(format!("call {for_name} {key}"));
+      Some(true)
+    }
+
+    fn new_expression(
+      &self,
+      parser: &mut JavascriptParser,
+      expr: &NewExpr,
+      for_name: &str,
+    ) -> Option<bool> {
+      let args = expr.args.as_ref()?;
+      let feature = parser.evaluate_expression(&args[0].expr);
+      let feature = feature.as_string()?;
+      self.record(format!("new {for_name} {feature}"));
+      Some(true)
+    }
+
+    fn member(
+      &self,
+      _parser: &mut JavascriptParser,
+      _expr: &MemberExpr,
+      for_name: &str,
+    ) -> Option<bool> {
+      self.record(format!("member {for_name}"));
+      Some(true)
+    }
+
+    fn evaluate_identifier(
+      &self,
+      _parser: &JavascriptParser,
+      ident: &str,
+      start: u32,
+      end: u32,
+    ) -> Option<BasicEvaluatedExpression> {
+      (ident == "FEATURE_A").then(|| evaluate_to_boolean(true, start, end))
+    }
+
+    fn can_rename(&self, _parser: &JavascriptParser, str: &str) -> Option<bool> {
+      (str == "i18n.t").then_some(true)
+    }
+  }
+
+  #[test]
+  fn should_call_hooks() {
+    let mut ast = parse(
+      r#"
+      import a from './a';
+      export * from './b';
+      const t = i18n.t;
+      t(`greeting.${"hello"}`);
+      i18n.t('bye');
+      new Feature(FEATURE_A ? 'a' : 'b');
+      process.env.NODE_ENV;
+      function local(i18n) {
+        i18n.t('local');
+      }
+    "#
+      .into(),
+      swc_core::ecma::parser::Syntax::Es(Default::default()),
+      "",
+      &ModuleType::Js,
+    )
+    .unwrap();
+    ast.transform(|program, context| {
+      program.visit_mut_with(&mut resolver(
+        context.unresolved_mark,
+        context.top_level_mark,
+        false,
+      ));
+    });
+
+    let records = Arc::new(Mutex::new(vec![]));
+    let plugin_drive = Arc::new(JavaScriptParserPluginDrive::new(vec![Arc::new(
+      RecordPlugin {
+        records: records.clone(),
+      },
+    )]));
+    let resource_data = ResourceData::new("/a.js".into(), "/a.js".into());
+    let mut dependencies = vec![];
+    let mut blocks = vec![];
+    let mut presentational_dependencies = vec![];
+    let mut warning_diagnostics = vec![];
+    let javascript_options = JavascriptParserOptions::default();
+    ast.visit(|program, context| {
+      program.visit_with(&mut JavascriptParser::new(
+        "/a.js".into(),
+        &mut dependencies,
+        &mut blocks,
+        &mut presentational_dependencies,
+        &mut warning_diagnostics,
+        &resource_data,
+        &ModuleType::Js,
+        &javascript_options,
+        None,
+        SyntaxContext::empty().apply_mark(context.unresolved_mark),
+        plugin_drive,
+      ));
+    });
+
+    assert_eq!(
+      *records.lock().unwrap(),
+      vec![
+        "import ./a",
+        "export_import ./b",
+        "call i18n.t greeting.hello",
+        "call i18n.t bye",
+        "new Feature a",
+        "member process.env.NODE_ENV",
+      ]
+    );
+  }
+}
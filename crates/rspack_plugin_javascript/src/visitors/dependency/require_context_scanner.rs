@@ -57,6 +57,8 @@ impl Visit for RequireContextScanner<'_> {
             node.span.real_hi(),
             ContextOptions {
               chunk_name: None,
+              group_options: None,
+              referenced_exports: None,
               mode,
               recursive,
               reg_exp: clean_regexp_in_context_module(reg_exp),
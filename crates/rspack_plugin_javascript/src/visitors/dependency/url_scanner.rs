@@ -1,4 +1,4 @@
-use rspack_core::BoxDependency;
+use rspack_core::{BoxDependency, SpanExt};
 use swc_core::ecma::{
   ast::NewExpr,
   visit::{noop_visit_type, Visit, VisitWith},
@@ -9,6 +9,7 @@ use crate::dependency::URLDependency;
 pub struct UrlScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  relative: bool,
 }
 
 // new URL("./foo.png", import.meta.url);
@@ -16,10 +17,12 @@ impl<'a> UrlScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     worker_syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    relative: bool,
   ) -> Self {
     Self {
       dependencies,
       worker_syntax_list,
+      relative,
     }
   }
 }
@@ -38,11 +41,17 @@ impl Visit for UrlScanner<'_> {
       return;
     }
     if let Some((start, end, request)) = rspack_core::needs_refactor::match_new_url(new_expr) {
+      let (start, end) = if self.relative {
+        (new_expr.span.real_lo(), new_expr.span.real_hi())
+      } else {
+        (start, end)
+      };
       self.dependencies.push(Box::new(URLDependency::new(
         start,
         end,
         request.into(),
         Some(new_expr.span.into()),
+        self.relative,
       )));
     } else {
       new_expr.visit_children_with(self);
@@ -2,7 +2,7 @@ use rustc_hash::FxHashSet as HashSet;
 use swc_core::{
   common::SyntaxContext,
   ecma::{
-    ast::{CallExpr, Expr, MemberExpr, ObjectPat, ObjectPatProp, PropName},
+    ast::{CallExpr, Expr, MemberExpr, ObjectPat, ObjectPatProp, Pat, PropName},
     atoms::JsWord,
   },
 };
@@ -32,6 +32,16 @@ pub fn collect_destructuring_assignment_properties(
   Some(properties)
 }
 
+/// The parameters of `function (a, b) {}` and `(a, b) => {}`
+pub fn get_function_params(expr: &Expr) -> Option<Vec<&Pat>> {
+  match expr {
+    Expr::Fn(f) => Some(f.function.params.iter().map(|param| &param.pat).collect()),
+    Expr::Arrow(f) => Some(f.params.iter().collect()),
+    Expr::Paren(paren) => get_function_params(&paren.expr),
+    _ => None,
+  }
+}
+
 pub(crate) mod expr_matcher {
   use std::sync::Arc;
 
@@ -79,7 +89,6 @@ pub(crate) mod expr_matcher {
   // - `import.meta.xxx` is a MemberExpr
   // - Matching would ignore Span and SyntaxContext
   define_expr_matchers!({
-    is_require: "require",
     is_require_context: "require.context",
     is_require_resolve: "require.resolve",
     is_require_resolve_weak: "require.resolveWeak",
@@ -81,6 +81,7 @@ impl<'a> WorkerScanner<'a> {
       base_uri: None,
       filename: None,
       library: None,
+      layer: None,
     })));
     block.add_dependency(dep);
     self.blocks.push(block);
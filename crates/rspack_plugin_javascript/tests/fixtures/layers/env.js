@@ -0,0 +1 @@
+export const env = typeof window === "undefined" ? "server" : "client";
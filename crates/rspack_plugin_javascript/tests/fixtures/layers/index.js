@@ -0,0 +1,3 @@
+import { env } from "./shared";
+
+console.log(env);
@@ -0,0 +1 @@
+export { env } from "./env";
@@ -0,0 +1,11 @@
+const request = "a";
+import(/* webpackChunkName: "named-chunk" */ "./child/a.js");
+import(/* webpackMode: "eager" */ "./child/b.js");
+import(/* webpackMode: "weak" */ "./child/c.js");
+import(/* webpackIgnore: true */ "./ignored.js");
+import(/* webpackPrefetch: true, webpackPreload: 1 */ "./child/d.js");
+import(
+	/* webpackInclude: /[ab]\.js$/, webpackExclude: /b\.js$/, webpackChunkName: "context-[request]" */ `./child/${request}.js`
+);
+import(/* webpackExports: ["a"] */ "./child/a.js").then(({ a }) => a);
+import(/* webpackMode: "lazy-once" */ `./lazy-once/${request}.js`);
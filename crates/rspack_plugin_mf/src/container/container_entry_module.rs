@@ -108,9 +108,9 @@ impl Module for ContainerEntryModule {
     let mut blocks = vec![];
     for (name, options) in &self.exposes {
       let mut block = AsyncDependenciesBlock::new(self.identifier, name, None);
-      block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions {
-        name: options.name.clone(),
-      }));
+      block.set_group_options(GroupOptions::ChunkGroup(
+        ChunkGroupOptions::default().name_optional(options.name.clone()),
+      ));
       for request in options.import.iter() {
         let dep = ContainerExposedDependency::new(name.clone(), request.clone());
         block.add_dependency(Box::new(dep));
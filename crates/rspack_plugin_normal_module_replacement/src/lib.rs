@@ -0,0 +1,152 @@
+// https://github.com/webpack/webpack/blob/main/lib/NormalModuleReplacementPlugin.js
+
+use std::fmt;
+use std::path::Path;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use rspack_core::{
+  parse_resource, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, Plugin,
+  PluginContext, PluginNormalModuleFactoryAfterResolveOutput,
+  PluginNormalModuleFactoryBeforeResolveOutput, ResourceData, ResourceParsedData,
+};
+use rspack_error::{internal_error, Result};
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+/// The data passed to [NormalModuleReplacementFn], `resource` is only available after resolving.
+#[derive(Debug, Clone)]
+pub struct NormalModuleReplacementData {
+  pub request: String,
+  pub context: String,
+  pub resource: Option<String>,
+}
+
+pub type NormalModuleReplacementFn = Box<
+  dyn Fn(NormalModuleReplacementData) -> BoxFuture<'static, Result<NormalModuleReplacementData>>
+    + Sync
+    + Send,
+>;
+
+pub enum NormalModuleReplacement {
+  /// Replaces the request before resolving, and the resource after resolving,
+  /// a relative path is resolved against the directory of the original resource.
+  String(String),
+  /// Receives the data of the matched dependency and returns the modified one.
+  Fn(NormalModuleReplacementFn),
+}
+
+impl fmt::Debug for NormalModuleReplacement {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::String(arg0) => f.debug_tuple("String").field(arg0).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct NormalModuleReplacementPluginOptions {
+  /// Tested against the request before resolving, and the resource after resolving.
+  pub resource_regexp: RspackRegex,
+  pub new_resource: NormalModuleReplacement,
+}
+
+#[derive(Debug)]
+pub struct NormalModuleReplacementPlugin {
+  options: NormalModuleReplacementPluginOptions,
+}
+
+impl NormalModuleReplacementPlugin {
+  pub fn new(options: NormalModuleReplacementPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait]
+impl Plugin for NormalModuleReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NormalModuleReplacementPlugin"
+  }
+
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if !self.options.resource_regexp.test(&args.request) {
+      return Ok(None);
+    }
+
+    match &self.options.new_resource {
+      NormalModuleReplacement::String(new_resource) => {
+        args.request = new_resource.clone();
+      }
+      NormalModuleReplacement::Fn(f) => {
+        let data = f(NormalModuleReplacementData {
+          request: args.request.clone(),
+          context: args.context.clone(),
+          resource: None,
+        })
+        .await?;
+        args.request = data.request;
+        args.context = data.context;
+      }
+    }
+    Ok(None)
+  }
+
+  async fn after_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleAfterResolveArgs,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    let resource_data = &mut *args.resource_resolve_data;
+    if !self.options.resource_regexp.test(&resource_data.resource) {
+      return Ok(None);
+    }
+
+    let new_resource = match &self.options.new_resource {
+      NormalModuleReplacement::String(new_resource) => {
+        if Path::new(new_resource).is_absolute() {
+          new_resource.clone()
+        } else {
+          let dir = resource_data
+            .resource_path
+            .parent()
+            .unwrap_or(&resource_data.resource_path);
+          dir
+            .join(new_resource)
+            .absolutize()
+            .to_string_lossy()
+            .to_string()
+        }
+      }
+      NormalModuleReplacement::Fn(f) => {
+        let data = f(NormalModuleReplacementData {
+          request: args.request.to_string(),
+          context: args.context.to_string(),
+          resource: Some(resource_data.resource.clone()),
+        })
+        .await?;
+        match data.resource {
+          Some(resource) => resource,
+          None => return Ok(None),
+        }
+      }
+    };
+
+    if new_resource != resource_data.resource {
+      let ResourceParsedData {
+        path,
+        query,
+        fragment,
+      } = parse_resource(&new_resource)
+        .ok_or_else(|| internal_error!("Invalid replaced resource: {new_resource}"))?;
+      *resource_data = ResourceData::new(new_resource, path)
+        .query_optional(query)
+        .fragment_optional(fragment);
+    }
+    Ok(None)
+  }
+}
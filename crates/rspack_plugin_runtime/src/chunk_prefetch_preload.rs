@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkGroupOrderKey, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+
+use crate::runtime_module::{
+  ChunkPrefetchPreloadFunctionRuntimeModule, ChunkPrefetchStartupRuntimeModule,
+  ChunkPrefetchTriggerRuntimeModule, ChunkPreloadTriggerRuntimeModule,
+};
+
+/// Prefetches or preloads the children of a chunk group by their `webpackPrefetch` and
+/// `webpackPreload` orders, the chunk loading plugins add the handlers which inject the links.
+#[derive(Debug)]
+pub struct ChunkPrefetchPreloadPlugin;
+
+#[async_trait]
+impl Plugin for ChunkPrefetchPreloadPlugin {
+  fn name(&self) -> &'static str {
+    "ChunkPrefetchPreloadPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      == 0
+    {
+      return Ok(());
+    }
+    let startup_child_chunks = compilation
+      .chunk_by_ukey
+      .expect_get(chunk_ukey)
+      .get_children_of_type_in_order(ChunkGroupOrderKey::Prefetch, compilation);
+    if let Some(startup_child_chunks) = startup_child_chunks {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK);
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::ON_CHUNKS_LOADED);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchStartupRuntimeModule::new(startup_child_chunks).boxed(),
+      );
+    }
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+    let mut chunk_map = compilation
+      .chunk_by_ukey
+      .expect_get(chunk_ukey)
+      .get_child_ids_by_orders_map(false, compilation, &|_, _| true);
+
+    if let Some(prefetch_map) = chunk_map.remove(&ChunkGroupOrderKey::Prefetch) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchTriggerRuntimeModule::new(prefetch_map).boxed(),
+      );
+    }
+    if let Some(preload_map) = chunk_map.remove(&ChunkGroupOrderKey::Preload) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPreloadTriggerRuntimeModule::new(preload_map).boxed(),
+      );
+    }
+
+    // the handlers are required here, so the chunk loading plugins could see them in
+    // `runtime_requirements_in_tree`
+    if runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "prefetch",
+          RuntimeGlobals::PREFETCH_CHUNK,
+          RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    if runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "preload",
+          RuntimeGlobals::PRELOAD_CHUNK,
+          RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    Ok(())
+  }
+}
@@ -49,6 +49,13 @@ impl Plugin for JsonpChunkLoadingPlugin {
           runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
           runtime_requirements.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
         }
+        RuntimeGlobals::PREFETCH_CHUNK_HANDLERS | RuntimeGlobals::PRELOAD_CHUNK_HANDLERS
+          if is_enabled_for_chunk =>
+        {
+          has_jsonp_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
         RuntimeGlobals::ON_CHUNKS_LOADED | RuntimeGlobals::BASE_URI if is_enabled_for_chunk => {
           has_jsonp_chunk_loading = true;
         }
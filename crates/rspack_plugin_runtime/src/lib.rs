@@ -12,6 +12,8 @@ mod css_modules;
 pub use css_modules::CssModulesPlugin;
 mod array_push_callback_chunk_format;
 pub use array_push_callback_chunk_format::ArrayPushCallbackChunkFormatPlugin;
+mod chunk_prefetch_preload;
+pub use chunk_prefetch_preload::ChunkPrefetchPreloadPlugin;
 mod common_js_chunk_loading;
 pub use common_js_chunk_loading::CommonJsChunkLoadingPlugin;
 mod jsonp_chunk_loading;
@@ -39,6 +39,13 @@ impl Plugin for ModuleChunkLoadingPlugin {
           has_chunk_loading = true;
           compilation.add_runtime_module(chunk, ExportWebpackRequireRuntimeModule::new().boxed());
         }
+        RuntimeGlobals::PREFETCH_CHUNK_HANDLERS | RuntimeGlobals::PRELOAD_CHUNK_HANDLERS
+          if is_enabled_for_chunk =>
+        {
+          has_chunk_loading = true;
+          runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        }
         RuntimeGlobals::ON_CHUNKS_LOADED | RuntimeGlobals::BASE_URI if is_enabled_for_chunk => {
           has_chunk_loading = true;
         }
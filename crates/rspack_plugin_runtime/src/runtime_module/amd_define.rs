@@ -0,0 +1,31 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+#[derive(Debug, Eq)]
+pub struct AmdDefineRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for AmdDefineRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/amd_define"),
+    }
+  }
+}
+
+impl RuntimeModule for AmdDefineRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(include_str!("runtime/amd_define.js")).boxed()
+  }
+}
+
+impl_runtime_module!(AmdDefineRuntimeModule);
@@ -0,0 +1,32 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+#[derive(Debug, Eq)]
+pub struct AmdOptionsRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for AmdOptionsRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/amd_options"),
+    }
+  }
+}
+
+impl RuntimeModule for AmdOptionsRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let options = compilation.options.amd.as_deref().unwrap_or("{}");
+    RawSource::from(format!("{} = {};\n", RuntimeGlobals::AMD_OPTIONS, options)).boxed()
+  }
+}
+
+impl_runtime_module!(AmdOptionsRuntimeModule);
@@ -0,0 +1,91 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_javascript::runtime::stringify_array;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchStartupRuntimeModule {
+  id: Identifier,
+  // pairs of the chunks of a parent group and the prefetched chunks of its children
+  startup_chunks: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>,
+  chunk: Option<ChunkUkey>,
+}
+
+impl ChunkPrefetchStartupRuntimeModule {
+  pub fn new(startup_chunks: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_startup"),
+      startup_chunks,
+      chunk: None,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk_ukey = self.chunk.expect("The chunk should be attached");
+    let chunk_ids = |chunks: &[ChunkUkey]| {
+      chunks
+        .iter()
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.expect_get(chunk_ukey).id.clone())
+        .collect::<Vec<_>>()
+    };
+    let source = self
+      .startup_chunks
+      .iter()
+      .map(|(on_chunks, chunks)| {
+        let on_chunk_ids = chunk_ids(
+          &on_chunks
+            .iter()
+            .filter(|on_chunk| **on_chunk == chunk_ukey)
+            .copied()
+            .collect::<Vec<_>>(),
+        );
+        let child_ids = chunk_ids(chunks);
+        let body = if child_ids.len() < 3 {
+          child_ids
+            .iter()
+            .map(|id| {
+              format!(
+                "{}({});",
+                RuntimeGlobals::PREFETCH_CHUNK,
+                serde_json::to_string(id).expect("invalid chunk id")
+              )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+        } else {
+          format!(
+            "{}.map({});",
+            stringify_array(&child_ids),
+            RuntimeGlobals::PREFETCH_CHUNK
+          )
+        };
+        format!(
+          "{}(0, {}, function() {{\n{body}\n}}, 5);",
+          RuntimeGlobals::ON_CHUNKS_LOADED,
+          stringify_array(&on_chunk_ids),
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    RawSource::from(source).boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Trigger
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchStartupRuntimeModule);
@@ -0,0 +1,48 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  stringify_map, Compilation, RuntimeModule, RuntimeModuleStage,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_javascript::runtime::stringify_array;
+use rustc_hash::FxHashMap as HashMap;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPreloadTriggerRuntimeModule {
+  id: Identifier,
+  chunk_map: HashMap<String, Vec<String>>,
+}
+
+impl ChunkPreloadTriggerRuntimeModule {
+  pub fn new(chunk_map: HashMap<String, Vec<String>>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_preload_trigger"),
+      chunk_map,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPreloadTriggerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    let chunk_map = self
+      .chunk_map
+      .iter()
+      .map(|(chunk_id, child_ids)| (chunk_id.clone(), stringify_array(child_ids)))
+      .collect::<HashMap<_, _>>();
+    RawSource::from(
+      include_str!("runtime/chunk_preload_trigger.js")
+        .replace("$CHUNK_MAP$", &stringify_map(&chunk_map)),
+    )
+    .boxed()
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Trigger
+  }
+}
+
+impl_runtime_module!(ChunkPreloadTriggerRuntimeModule);
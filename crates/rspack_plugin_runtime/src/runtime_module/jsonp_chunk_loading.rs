@@ -6,7 +6,8 @@ use rspack_core::{
 use rspack_identifier::Identifier;
 
 use crate::runtime_module::utils::{
-  chunk_has_js, get_initial_chunk_ids, render_condition_map, stringify_chunks,
+  chunk_has_js, get_initial_chunk_ids, render_condition_map, render_prefetch_cross_origin_loading,
+  render_preload_cross_origin_loading, stringify_chunks,
 };
 
 #[derive(Debug, Default, Eq)]
@@ -67,15 +68,66 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       .runtime_requirements
       .contains(RuntimeGlobals::ON_CHUNKS_LOADED);
 
-    if with_loading {
+    let with_prefetch = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    let with_preload = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+
+    let js_matcher = if with_loading || with_prefetch || with_preload {
       let condition_map =
         compilation
           .chunk_graph
           .get_chunk_condition_map(&chunk.ukey, compilation, chunk_has_js);
+      render_condition_map(&condition_map)
+    } else {
+      String::new()
+    };
+
+    if with_loading {
       // If chunkId not corresponding chunkName will skip load it.
       source.add(RawSource::from(
-        include_str!("runtime/jsonp_chunk_loading.js")
-          .replace("JS_MATCHER", &render_condition_map(&condition_map)),
+        include_str!("runtime/jsonp_chunk_loading.js").replace("JS_MATCHER", &js_matcher),
+      ));
+    }
+
+    if with_prefetch {
+      source.add(RawSource::from(
+        include_str!("runtime/chunk_loading_with_prefetch.js")
+          .replace("JS_MATCHER", &js_matcher)
+          .replace(
+            "$CROSS_ORIGIN_LOADING$",
+            &render_prefetch_cross_origin_loading(&compilation.options.output.cross_origin_loading),
+          ),
+      ));
+    }
+
+    if with_preload {
+      let script_type = &compilation.options.output.script_type;
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_preload.js")
+          .replace("JS_MATCHER", &js_matcher)
+          .replace(
+            "$SCRIPT_TYPE$",
+            &if script_type == "false" || script_type == "module" {
+              String::new()
+            } else {
+              format!("link.type = '{script_type}';")
+            },
+          )
+          .replace(
+            "$LINK_REL$",
+            if script_type == "module" {
+              "link.rel = \"modulepreload\";"
+            } else {
+              "link.rel = \"preload\";\n\t\tlink.as = \"script\";"
+            },
+          )
+          .replace(
+            "$CROSS_ORIGIN_LOADING$",
+            &render_preload_cross_origin_loading(&compilation.options.output.cross_origin_loading),
+          ),
       ));
     }
 
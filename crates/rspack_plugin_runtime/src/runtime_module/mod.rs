@@ -1,6 +1,12 @@
+mod amd_define;
+mod amd_options;
 mod async_module;
 mod auto_public_path;
 mod base_uri;
+mod chunk_prefetch_preload_function;
+mod chunk_prefetch_startup;
+mod chunk_prefetch_trigger;
+mod chunk_preload_trigger;
 mod compat_get_default_export;
 mod create_fake_namespace_object;
 mod create_script_url;
@@ -27,14 +33,22 @@ mod normal;
 mod on_chunk_loaded;
 mod public_path;
 mod readfile_chunk_loading;
+mod relative_url;
 mod require_js_chunk_loading;
 mod startup_chunk_dependencies;
 mod startup_entry_point;
 mod system_context;
+mod uncaught_error_handler;
 mod utils;
+pub use amd_define::AmdDefineRuntimeModule;
+pub use amd_options::AmdOptionsRuntimeModule;
 pub use async_module::AsyncRuntimeModule;
 pub use auto_public_path::AutoPublicPathRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
+pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
+pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
+pub use chunk_prefetch_trigger::ChunkPrefetchTriggerRuntimeModule;
+pub use chunk_preload_trigger::ChunkPreloadTriggerRuntimeModule;
 pub use compat_get_default_export::CompatGetDefaultExportRuntimeModule;
 pub use create_fake_namespace_object::CreateFakeNamespaceObjectRuntimeModule;
 pub use create_script_url::CreateScriptUrlRuntimeModule;
@@ -61,8 +75,10 @@ pub use normal::NormalRuntimeModule;
 pub use on_chunk_loaded::OnChunkLoadedRuntimeModule;
 pub use public_path::PublicPathRuntimeModule;
 pub use readfile_chunk_loading::ReadFileChunkLoadingRuntimeModule;
+pub use relative_url::RelativeUrlRuntimeModule;
 pub use require_js_chunk_loading::RequireChunkLoadingRuntimeModule;
 pub use startup_chunk_dependencies::StartupChunkDependenciesRuntimeModule;
 pub use startup_entry_point::StartupEntrypointRuntimeModule;
 pub use system_context::SystemContextRuntimeModule;
+pub use uncaught_error_handler::UncaughtErrorHandlerRuntimeModule;
 pub use utils::*;
@@ -6,7 +6,10 @@ use rspack_core::{
 use rspack_identifier::Identifier;
 
 use super::utils::{chunk_has_js, get_output_dir};
-use crate::runtime_module::utils::{get_initial_chunk_ids, stringify_chunks};
+use crate::runtime_module::utils::{
+  get_initial_chunk_ids, render_condition_map, render_prefetch_cross_origin_loading,
+  render_preload_cross_origin_loading, stringify_chunks,
+};
 
 #[derive(Debug, Default, Eq)]
 pub struct ModuleChunkLoadingRuntimeModule {
@@ -91,6 +94,12 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     let with_on_chunk_load = self
       .runtime_requirements
       .contains(RuntimeGlobals::ON_CHUNKS_LOADED);
+    let with_prefetch = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    let with_preload = self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
 
     if with_loading || with_external_install_chunk {
       source.add(RawSource::from(
@@ -117,6 +126,37 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       ));
     }
 
+    if with_prefetch || with_preload {
+      let condition_map =
+        compilation
+          .chunk_graph
+          .get_chunk_condition_map(&chunk.ukey, compilation, chunk_has_js);
+      let js_matcher = render_condition_map(&condition_map);
+      let cross_origin_loading = &compilation.options.output.cross_origin_loading;
+
+      if with_prefetch {
+        source.add(RawSource::from(
+          include_str!("runtime/chunk_loading_with_prefetch.js")
+            .replace("JS_MATCHER", &js_matcher)
+            .replace(
+              "$CROSS_ORIGIN_LOADING$",
+              &render_prefetch_cross_origin_loading(cross_origin_loading),
+            ),
+        ));
+      }
+
+      if with_preload {
+        source.add(RawSource::from(
+          include_str!("runtime/module_chunk_loading_with_preload.js")
+            .replace("JS_MATCHER", &js_matcher)
+            .replace(
+              "$CROSS_ORIGIN_LOADING$",
+              &render_preload_cross_origin_loading(cross_origin_loading),
+            ),
+        ));
+      }
+    }
+
     if with_external_install_chunk {
       source.add(RawSource::from("__webpack_require__.C = installChunk;\n"));
     }
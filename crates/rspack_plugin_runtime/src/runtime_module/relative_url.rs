@@ -0,0 +1,31 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+#[derive(Debug, Eq)]
+pub struct RelativeUrlRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for RelativeUrlRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/relative_url"),
+    }
+  }
+}
+
+impl RuntimeModule for RelativeUrlRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(include_str!("runtime/relative_url.js")).boxed()
+  }
+}
+
+impl_runtime_module!(RelativeUrlRuntimeModule);
@@ -0,0 +1,5 @@
+var chunkToChildrenMap = $CHUNK_MAP$;
+__webpack_require__.f.preload = function (chunkId) {
+	var chunks = chunkToChildrenMap[chunkId];
+	Array.isArray(chunks) && chunks.map(__webpack_require__.G);
+};
@@ -0,0 +1,19 @@
+__webpack_require__.H.j = function (chunkId) {
+	if (
+		(!__webpack_require__.o(installedChunks, chunkId) ||
+			installedChunks[chunkId] === undefined) &&
+		JS_MATCHER
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		$SCRIPT_TYPE$
+		link.charset = "utf-8";
+		if (__webpack_require__.nc) {
+			link.setAttribute("nonce", __webpack_require__.nc);
+		}
+		$LINK_REL$
+		link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
+		$CROSS_ORIGIN_LOADING$
+		document.head.appendChild(link);
+	}
+};
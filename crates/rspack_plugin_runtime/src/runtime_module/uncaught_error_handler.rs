@@ -0,0 +1,31 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+#[derive(Debug, Eq)]
+pub struct UncaughtErrorHandlerRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for UncaughtErrorHandlerRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/uncaught_error_handler"),
+    }
+  }
+}
+
+impl RuntimeModule for UncaughtErrorHandlerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(include_str!("runtime/uncaught_error_handler.js")).boxed()
+  }
+}
+
+impl_runtime_module!(UncaughtErrorHandlerRuntimeModule);
@@ -2,7 +2,7 @@ use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use rspack_core::{
   get_js_chunk_filename_template, stringify_map, Chunk, ChunkKind, ChunkLoading, ChunkUkey,
-  Compilation, PathData, SourceType,
+  Compilation, CrossOriginLoading, PathData, SourceType,
 };
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
@@ -176,6 +176,34 @@ pub fn is_enabled_for_chunk(
   chunk_loading == expected
 }
 
+/// The `crossOrigin` of the `<link rel="prefetch">` of a chunk.
+pub fn render_prefetch_cross_origin_loading(cross_origin_loading: &CrossOriginLoading) -> String {
+  match cross_origin_loading {
+    CrossOriginLoading::Disable => String::new(),
+    CrossOriginLoading::Enable(value) => format!(
+      "link.crossOrigin = {};",
+      serde_json::to_string(value).expect("invalid json to_string")
+    ),
+  }
+}
+
+/// The `crossOrigin` of the `<link rel="preload">` of a chunk, same as the script tag it's only
+/// needed for the chunks from other origins unless credentials are used.
+pub fn render_preload_cross_origin_loading(cross_origin_loading: &CrossOriginLoading) -> String {
+  match cross_origin_loading {
+    CrossOriginLoading::Disable => String::new(),
+    CrossOriginLoading::Enable(value) if value == "use-credentials" => {
+      r#"link.crossOrigin = "use-credentials";"#.to_string()
+    }
+    CrossOriginLoading::Enable(value) => format!(
+      r#"if (link.href.indexOf(window.location.origin + '/') !== 0) {{
+      link.crossOrigin = {};
+    }}"#,
+      serde_json::to_string(value).expect("invalid json to_string")
+    ),
+  }
+}
+
 pub fn unquoted_stringify(chunk: &Chunk, str: &String) -> String {
   if let Some(chunk_id) = &chunk.id {
     if str.len() >= 5 && str == chunk_id {
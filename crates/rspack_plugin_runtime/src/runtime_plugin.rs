@@ -10,15 +10,16 @@ use rspack_core::{
 };
 
 use crate::runtime_module::{
-  is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule, BaseUriRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule,
-  LoadChunkWithBlockRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
-  NodeModuleDecoratorRuntimeModule, NormalRuntimeModule, OnChunkLoadedRuntimeModule,
-  PublicPathRuntimeModule, SystemContextRuntimeModule,
+  is_enabled_for_chunk, AmdDefineRuntimeModule, AmdOptionsRuntimeModule, AsyncRuntimeModule,
+  AutoPublicPathRuntimeModule, BaseUriRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadChunkWithBlockRuntimeModule, LoadScriptRuntimeModule,
+  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+  SystemContextRuntimeModule, UncaughtErrorHandlerRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
@@ -42,17 +43,23 @@ static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
     RuntimeGlobals::INTERCEPT_MODULE_EXECUTION,
     RuntimeGlobals::PUBLIC_PATH,
     RuntimeGlobals::BASE_URI,
-    // RuntimeGlobals::RELATIVE_URL,
+    RuntimeGlobals::RELATIVE_URL,
     // RuntimeGlobals::SCRIPT_NONCE,
-    // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
+    RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
     // RuntimeGlobals::SHARE_SCOPE_MAP,
     // RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
     // RuntimeGlobals::SYSTEM_CONTEXT,
     RuntimeGlobals::ON_CHUNKS_LOADED,
+    RuntimeGlobals::PREFETCH_CHUNK,
+    RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+    RuntimeGlobals::PRELOAD_CHUNK,
+    RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+    RuntimeGlobals::AMD_DEFINE,
+    RuntimeGlobals::AMD_OPTIONS,
   ]
 });
 
@@ -378,6 +385,17 @@ impl Plugin for RuntimePlugin {
         RuntimeGlobals::SYSTEM_CONTEXT if matches!(&library_type, Some(t) if t == "system") => {
           compilation.add_runtime_module(chunk, SystemContextRuntimeModule::default().boxed())
         }
+        RuntimeGlobals::AMD_DEFINE => {
+          compilation.add_runtime_module(chunk, AmdDefineRuntimeModule::default().boxed())
+        }
+        RuntimeGlobals::AMD_OPTIONS => {
+          compilation.add_runtime_module(chunk, AmdOptionsRuntimeModule::default().boxed())
+        }
+        RuntimeGlobals::UNCAUGHT_ERROR_HANDLER => compilation
+          .add_runtime_module(chunk, UncaughtErrorHandlerRuntimeModule::default().boxed()),
+        RuntimeGlobals::RELATIVE_URL => {
+          compilation.add_runtime_module(chunk, RelativeUrlRuntimeModule::default().boxed())
+        }
         _ => {}
       }
     }
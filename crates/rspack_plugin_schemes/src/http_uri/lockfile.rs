@@ -0,0 +1,154 @@
+use std::{collections::BTreeMap, fs, io::ErrorKind, path::Path};
+
+use rspack_error::{internal_error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const LOCKFILE_VERSION: u64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockfileEntry {
+  /// the url after following redirects
+  pub resolved: String,
+  pub integrity: String,
+  pub content_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawLockfileEntry {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  resolved: Option<String>,
+  integrity: String,
+  content_type: String,
+}
+
+/// The lockfile of remote resources, compatible with the `experiments.buildHttp` lockfile of webpack.
+#[derive(Debug, Default)]
+pub struct Lockfile {
+  entries: BTreeMap<String, LockfileEntry>,
+  changed: bool,
+}
+
+impl Lockfile {
+  /// Returns an empty lockfile when the file doesn't exist.
+  pub fn read(path: &Path) -> Result<Self> {
+    match fs::read_to_string(path) {
+      Ok(content) => Self::parse(&content)
+        .map_err(|e| internal_error!("Failed to parse lockfile {}: {e}", path.display())),
+      Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+      Err(e) => Err(e.into()),
+    }
+  }
+
+  pub fn parse(content: &str) -> Result<Self> {
+    let mut raw: Map<String, Value> = serde_json::from_str(content)?;
+    match raw.remove("version").and_then(|v| v.as_u64()) {
+      Some(LOCKFILE_VERSION) => {}
+      version => {
+        return Err(internal_error!(
+          "Unsupported lockfile version {version:?}, expected {LOCKFILE_VERSION}"
+        ))
+      }
+    }
+    let entries = raw
+      .into_iter()
+      .map(|(url, value)| {
+        let entry: RawLockfileEntry = serde_json::from_value(value)?;
+        Ok((
+          url.clone(),
+          LockfileEntry {
+            resolved: entry.resolved.unwrap_or(url),
+            integrity: entry.integrity,
+            content_type: entry.content_type,
+          },
+        ))
+      })
+      .collect::<Result<_>>()?;
+    Ok(Self {
+      entries,
+      changed: false,
+    })
+  }
+
+  pub fn to_json_string(&self) -> Result<String> {
+    let mut raw = Map::new();
+    for (url, entry) in &self.entries {
+      let entry = RawLockfileEntry {
+        resolved: (&entry.resolved != url).then(|| entry.resolved.clone()),
+        integrity: entry.integrity.clone(),
+        content_type: entry.content_type.clone(),
+      };
+      raw.insert(url.clone(), serde_json::to_value(entry)?);
+    }
+    raw.insert("version".into(), LOCKFILE_VERSION.into());
+    Ok(serde_json::to_string_pretty(&raw)? + "\n")
+  }
+
+  pub fn get(&self, url: &str) -> Option<&LockfileEntry> {
+    self.entries.get(url)
+  }
+
+  pub fn insert(&mut self, url: String, entry: LockfileEntry) {
+    if self.entries.get(&url) != Some(&entry) {
+      self.entries.insert(url, entry);
+      self.changed = true;
+    }
+  }
+
+  /// Write the lockfile if any entry is changed since it's read.
+  pub fn write_if_changed(&mut self, path: &Path) -> Result<()> {
+    if !self.changed {
+      return Ok(());
+    }
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    fs::write(path, self.to_json_string()?)?;
+    self.changed = false;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn lockfile_round_trip() {
+    let content = r#"{
+  "https://example.com/a.js": {
+    "integrity": "sha512-a",
+    "contentType": "application/javascript"
+  },
+  "https://example.com/b": {
+    "resolved": "https://example.com/b.js",
+    "integrity": "sha512-b",
+    "contentType": "application/javascript"
+  },
+  "version": 1
+}
+"#;
+    let lockfile = Lockfile::parse(content).expect("should parse lockfile");
+    assert_eq!(
+      lockfile
+        .get("https://example.com/a.js")
+        .map(|e| e.resolved.as_str()),
+      Some("https://example.com/a.js")
+    );
+    assert_eq!(
+      lockfile
+        .get("https://example.com/b")
+        .map(|e| e.resolved.as_str()),
+      Some("https://example.com/b.js")
+    );
+    assert_eq!(
+      lockfile
+        .to_json_string()
+        .expect("should serialize lockfile"),
+      content
+    );
+
+    assert!(Lockfile::parse(r#"{ "version": 2 }"#).is_err());
+  }
+}
@@ -0,0 +1,473 @@
+mod lockfile;
+
+use std::{
+  collections::HashMap,
+  fs,
+  hash::Hash,
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use rspack_core::{
+  get_scheme, Compilation, Content, Context, Plugin, PluginContext,
+  PluginNormalModuleFactoryResolveForSchemeOutput, PluginReadResourceOutput, ResourceData,
+};
+use rspack_error::{internal_error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_regex::RspackRegex;
+use url::Url;
+
+pub use self::lockfile::{Lockfile, LockfileEntry};
+
+const MAX_REDIRECTS: usize = 5;
+
+#[derive(Debug)]
+pub struct HttpResponse {
+  pub status: u16,
+  /// header names are in lower case
+  pub headers: HashMap<String, String>,
+  pub body: Vec<u8>,
+}
+
+/// Sends a GET request to the url, redirects should not be followed by the client.
+pub type HttpClientFn =
+  Box<dyn Fn(String) -> BoxFuture<'static, Result<HttpResponse>> + Sync + Send>;
+
+#[derive(Debug)]
+pub enum AllowedUri {
+  /// the uri should start with the prefix
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl AllowedUri {
+  fn try_match(&self, uri: &str) -> bool {
+    match self {
+      Self::String(prefix) => uri.starts_with(prefix),
+      Self::Regexp(regexp) => regexp.test(uri),
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct HttpUriPluginOptions {
+  pub allowed_uris: Vec<AllowedUri>,
+  pub lockfile_location: PathBuf,
+  /// Where the fetched resources are stored, `None` to fetch them in every build.
+  pub cache_location: Option<PathBuf>,
+  /// Never update the lockfile and never fetch from network, the build only reads the resources
+  /// stored in the cache.
+  pub frozen: bool,
+  /// Fetch the resources again and update the lockfile if the content changes.
+  pub upgrade: bool,
+  #[derivative(Debug = "ignore")]
+  pub http_client: HttpClientFn,
+}
+
+/// Build `http:` and `https:` imports, like `experiments.buildHttp` of webpack.
+#[derive(Debug)]
+pub struct HttpUriPlugin {
+  options: HttpUriPluginOptions,
+  /// read on first use
+  lockfile: Mutex<Option<Lockfile>>,
+}
+
+impl HttpUriPlugin {
+  pub fn new(options: HttpUriPluginOptions) -> Self {
+    Self {
+      options,
+      lockfile: Default::default(),
+    }
+  }
+
+  fn with_lockfile<R>(&self, f: impl FnOnce(&mut Lockfile) -> Result<R>) -> Result<R> {
+    let mut lockfile = self
+      .lockfile
+      .lock()
+      .map_err(|_| internal_error!("Failed to lock the lockfile of HttpUriPlugin"))?;
+    let current = match lockfile.take() {
+      Some(current) => current,
+      None => Lockfile::read(&self.options.lockfile_location)?,
+    };
+    f(lockfile.insert(current))
+  }
+
+  fn check_allowed(&self, url: &str) -> Result<()> {
+    if self
+      .options
+      .allowed_uris
+      .iter()
+      .any(|allowed| allowed.try_match(url))
+    {
+      return Ok(());
+    }
+    let allowed_uris = self
+      .options
+      .allowed_uris
+      .iter()
+      .map(|allowed| match allowed {
+        AllowedUri::String(prefix) => format!(" - {prefix}"),
+        AllowedUri::Regexp(regexp) => format!(" - {}", rspack_regex::regexp_as_str(regexp)),
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    Err(internal_error!(
+      "{url} doesn't match the allowedUris policy. These URIs are allowed:\n{allowed_uris}"
+    ))
+  }
+
+  fn resource_data(&self, url: Url) -> Result<ResourceData> {
+    self.check_allowed(url.as_str())?;
+    let query = url.query().map(|q| format!("?{q}"));
+    let fragment = url.fragment().map(|f| format!("#{f}"));
+    let mut path = url.clone();
+    path.set_query(None);
+    path.set_fragment(None);
+    Ok(
+      ResourceData::new(url.to_string(), PathBuf::from(path.as_str()))
+        .query_optional(query)
+        .fragment_optional(fragment),
+    )
+  }
+
+  async fn fetch(&self, url: &str) -> Result<(String, HttpResponse)> {
+    let mut current = url.to_string();
+    for _ in 0..=MAX_REDIRECTS {
+      let response = (self.options.http_client)(current.clone()).await?;
+      if !matches!(response.status, 301 | 302 | 303 | 307 | 308) {
+        if !(200..300).contains(&response.status) {
+          return Err(internal_error!(
+            "{current} responded with status {}",
+            response.status
+          ));
+        }
+        return Ok((current, response));
+      }
+      let location = response
+        .headers
+        .get("location")
+        .ok_or_else(|| internal_error!("{current} is redirected without a location header"))?;
+      let next = Url::parse(&current)
+        .and_then(|base| base.join(location))
+        .map_err(|e| internal_error!("Failed to resolve redirect of {current}: {e}"))?;
+      self.check_allowed(next.as_str())?;
+      current = next.to_string();
+    }
+    Err(internal_error!("{url} is redirected too many times"))
+  }
+
+  fn cache_file(&self, entry: &LockfileEntry) -> Option<PathBuf> {
+    let cache_location = self.options.cache_location.as_ref()?;
+    let url = Url::parse(&entry.resolved).ok()?;
+    let host = match url.port() {
+      Some(port) => format!("{}_{port}", url.host_str()?),
+      None => url.host_str()?.to_string(),
+    };
+    let name = url
+      .path_segments()
+      .and_then(|mut segments| segments.next_back())
+      .filter(|name| !name.is_empty())
+      .unwrap_or("index")
+      .chars()
+      .take(64)
+      .map(|c| {
+        if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+          c
+        } else {
+          '_'
+        }
+      })
+      .collect::<String>();
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    entry.integrity.hash(&mut hasher);
+    let hash = hasher.digest(&HashDigest::Hex);
+    Some(
+      cache_location
+        .join(host)
+        .join(format!("{name}_{}", hash.rendered(10))),
+    )
+  }
+
+  /// Returns `None` when the cache file is missing or its content doesn't match the integrity.
+  fn read_cache(&self, entry: &LockfileEntry) -> Option<Vec<u8>> {
+    let content = fs::read(self.cache_file(entry)?).ok()?;
+    (compute_integrity(&content) == entry.integrity).then_some(content)
+  }
+
+  fn write_cache(&self, entry: &LockfileEntry, content: &[u8]) -> Result<()> {
+    let Some(file) = self.cache_file(entry) else {
+      return Ok(());
+    };
+    if let Some(dir) = file.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    fs::write(file, content)?;
+    Ok(())
+  }
+
+  async fn fetch_content(&self, url: &str) -> Result<Vec<u8>> {
+    let entry = self.with_lockfile(|lockfile| Ok(lockfile.get(url).cloned()))?;
+    if let Some(entry) = &entry
+      && (!self.options.upgrade || self.options.frozen)
+      && let Some(content) = self.read_cache(entry)
+    {
+      return Ok(content);
+    }
+    if self.options.frozen {
+      return Err(match entry {
+        Some(_) => internal_error!(
+          "{url} is not found in the cache {}, it can't be fetched when the lockfile is frozen",
+          self
+            .options
+            .cache_location
+            .as_deref()
+            .unwrap_or(Path::new(""))
+            .display()
+        ),
+        None => internal_error!(
+          "{url} has no lockfile entry and lockfile is frozen, build without frozen to update {}",
+          self.options.lockfile_location.display()
+        ),
+      });
+    }
+
+    let (resolved, response) = self.fetch(url).await?;
+    let new_entry = LockfileEntry {
+      resolved,
+      integrity: compute_integrity(&response.body),
+      content_type: response
+        .headers
+        .get("content-type")
+        .cloned()
+        .unwrap_or_default(),
+    };
+    if let Some(entry) = &entry
+      && !self.options.upgrade
+      && entry.integrity != new_entry.integrity
+    {
+      return Err(internal_error!(
+        "Content of {url} doesn't match the integrity in lockfile, it's expected to be {} but got {}. Build with upgrade to update the lockfile",
+        entry.integrity,
+        new_entry.integrity
+      ));
+    }
+    self.write_cache(&new_entry, &response.body)?;
+    self.with_lockfile(|lockfile| {
+      lockfile.insert(url.to_string(), new_entry);
+      Ok(())
+    })?;
+    Ok(response.body)
+  }
+}
+
+fn compute_integrity(content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Sha512);
+  std::hash::Hasher::write(&mut hasher, content);
+  format!(
+    "sha512-{}",
+    rspack_base64::encode_to_string(hasher.finalize())
+  )
+}
+
+fn is_relative_request(request: &str) -> bool {
+  request.starts_with("./") || request.starts_with("../") || request.starts_with('/')
+}
+
+#[async_trait::async_trait]
+impl Plugin for HttpUriPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HttpUriPlugin"
+  }
+
+  async fn normal_module_factory_resolve_for_scheme(
+    &self,
+    _ctx: PluginContext,
+    resource_data: ResourceData,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok((resource_data, false));
+    }
+    let url = Url::parse(&resource_data.resource).map_err(|e| internal_error!(e.to_string()))?;
+    Ok((self.resource_data(url)?, true))
+  }
+
+  async fn normal_module_factory_resolve_in_scheme(
+    &self,
+    _ctx: PluginContext,
+    resource_data: ResourceData,
+    context: &Context,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    if !get_scheme(context.as_str()).is_http() || !is_relative_request(&resource_data.resource) {
+      return Ok((resource_data, false));
+    }
+    let url = Url::parse(&format!("{}/", context.as_str()))
+      .and_then(|base| base.join(&resource_data.resource))
+      .map_err(|e| internal_error!(e.to_string()))?;
+    Ok((self.resource_data(url)?, true))
+  }
+
+  async fn read_resource(&self, resource_data: &ResourceData) -> PluginReadResourceOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok(None);
+    }
+    let url = match &resource_data.resource_fragment {
+      Some(fragment) => resource_data
+        .resource
+        .strip_suffix(fragment.as_str())
+        .unwrap_or(&resource_data.resource),
+      None => &resource_data.resource,
+    };
+    Ok(Some(Content::Buffer(self.fetch_content(url).await?)))
+  }
+
+  async fn finish_modules(&self, _compilation: &mut Compilation) -> Result<()> {
+    let mut lockfile = self
+      .lockfile
+      .lock()
+      .map_err(|_| internal_error!("Failed to lock the lockfile of HttpUriPlugin"))?;
+    if let Some(lockfile) = lockfile.as_mut() {
+      lockfile.write_if_changed(&self.options.lockfile_location)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  };
+
+  use futures::executor::block_on;
+
+  use super::*;
+
+  /// A local stand-in of the remote server, `content` is served at every url.
+  fn create_plugin(
+    name: &str,
+    content: &'static str,
+    frozen: bool,
+    upgrade: bool,
+  ) -> (HttpUriPlugin, Arc<AtomicUsize>) {
+    let dir = std::env::temp_dir().join(format!("rspack_http_uri_test_{name}"));
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let plugin = HttpUriPlugin::new(HttpUriPluginOptions {
+      allowed_uris: vec![AllowedUri::String("https://example.com/".into())],
+      lockfile_location: dir.join("rspack.lock"),
+      cache_location: Some(dir.join("rspack.lock.data")),
+      frozen,
+      upgrade,
+      http_client: Box::new(move |url| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Box::pin(async move {
+          if url == "https://example.com/redirect" {
+            return Ok(HttpResponse {
+              status: 302,
+              headers: HashMap::from([("location".into(), "/lib/a.js".into())]),
+              body: vec![],
+            });
+          }
+          Ok(HttpResponse {
+            status: 200,
+            headers: HashMap::from([("content-type".into(), "text/javascript".into())]),
+            body: content.as_bytes().to_vec(),
+          })
+        })
+      }),
+    });
+    (plugin, requests)
+  }
+
+  fn read(plugin: &HttpUriPlugin, url: &str) -> Result<Vec<u8>> {
+    let resource_data = ResourceData::new(url.to_string(), PathBuf::from(url));
+    match block_on(plugin.read_resource(&resource_data))? {
+      Some(Content::Buffer(buffer)) => Ok(buffer),
+      content => panic!("unexpected content {content:?}"),
+    }
+  }
+
+  fn store_lockfile(plugin: &HttpUriPlugin) {
+    plugin
+      .with_lockfile(|lockfile| lockfile.write_if_changed(&plugin.options.lockfile_location))
+      .expect("should write lockfile");
+  }
+
+  #[test]
+  fn build_from_lockfile_cache() {
+    let _ = fs::remove_dir_all(std::env::temp_dir().join("rspack_http_uri_test_cache"));
+    let (plugin, requests) = create_plugin("cache", "export default 1", false, false);
+    assert_eq!(
+      read(&plugin, "https://example.com/redirect").expect("should fetch"),
+      b"export default 1"
+    );
+    assert_eq!(requests.load(Ordering::Relaxed), 2);
+    store_lockfile(&plugin);
+    let entry = Lockfile::read(&plugin.options.lockfile_location)
+      .expect("should read lockfile")
+      .get("https://example.com/redirect")
+      .cloned()
+      .expect("should have lockfile entry");
+    assert_eq!(entry.resolved, "https://example.com/lib/a.js");
+    assert_eq!(entry.content_type, "text/javascript");
+
+    // frozen builds only read the cache
+    let (plugin, requests) = create_plugin("cache", "export default 2", true, false);
+    assert_eq!(
+      read(&plugin, "https://example.com/redirect").expect("should read cache"),
+      b"export default 1"
+    );
+    assert!(read(&plugin, "https://example.com/b.js").is_err());
+    assert_eq!(requests.load(Ordering::Relaxed), 0);
+
+    // changed content is rejected unless upgrade
+    let (plugin, _) = create_plugin("cache", "export default 2", false, false);
+    fs::remove_dir_all(
+      plugin
+        .options
+        .cache_location
+        .as_ref()
+        .expect("should have cache"),
+    )
+    .expect("should remove cache");
+    assert!(read(&plugin, "https://example.com/redirect").is_err());
+    let (plugin, _) = create_plugin("cache", "export default 2", false, true);
+    assert_eq!(
+      read(&plugin, "https://example.com/redirect").expect("should upgrade"),
+      b"export default 2"
+    );
+  }
+
+  #[test]
+  fn resolve_allowed_uris() {
+    let (plugin, _) = create_plugin("resolve", "", false, false);
+    let resolve = |request: &str| {
+      block_on(plugin.normal_module_factory_resolve_for_scheme(
+        PluginContext::new(),
+        ResourceData::new(request.to_string(), "".into()),
+      ))
+    };
+    let (resource_data, stop) = resolve("https://example.com/a.js?x=1#y").expect("should resolve");
+    assert!(stop);
+    assert_eq!(
+      resource_data.resource_path,
+      PathBuf::from("https://example.com/a.js")
+    );
+    assert_eq!(resource_data.resource_query.as_deref(), Some("?x=1"));
+    assert_eq!(resource_data.resource_fragment.as_deref(), Some("#y"));
+    assert!(resolve("https://example.org/a.js").is_err());
+
+    let (resource_data, stop) = block_on(plugin.normal_module_factory_resolve_in_scheme(
+      PluginContext::new(),
+      ResourceData::new("../b.js".into(), "".into()),
+      &Context::from("https://example.com/lib/dir".to_string()),
+    ))
+    .expect("should resolve");
+    assert!(stop);
+    assert_eq!(resource_data.resource, "https://example.com/lib/b.js");
+  }
+}
@@ -1,11 +1,98 @@
+#![feature(let_chains)]
+
+use std::hash::Hash;
+use std::path::Path;
+
 use async_trait::async_trait;
 use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
-use rspack_core::{rspack_sources::MapOptions, Plugin};
+use rspack_core::{
+  rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt},
+  AssetInfo, CompilationAsset, CssChunkHashArgs, Plugin, PluginContext,
+  PluginCssChunkHashHookOutput,
+};
 use rspack_error::Result;
-use rspack_plugin_css::swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig};
+use rspack_plugin_css::swc_css_compiler::{
+  SwcCssCompiler, SwcCssMinifyOptions, SwcCssSourceMapGenConfig,
+};
+use rspack_regex::RspackRegex;
+use rspack_util::try_any_sync;
+
+#[derive(Debug, Default, Hash)]
+pub struct SwcCssMinimizerRspackPluginOptions {
+  pub test: Option<SwcCssMinimizerRules>,
+  pub include: Option<SwcCssMinimizerRules>,
+  pub exclude: Option<SwcCssMinimizerRules>,
+  /// Comments matching the condition are kept at the top of the minimized asset,
+  /// other comments are removed.
+  pub preserve_comments: Option<RspackRegex>,
+  /// Comments matching the condition are extracted to `[file].LICENSE.txt`,
+  /// takes precedence over `preserve_comments`.
+  pub extract_comments: Option<RspackRegex>,
+  /// Browserslist queries of the target browsers.
+  pub targets: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum SwcCssMinimizerRule {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SwcCssMinimizerRule {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum SwcCssMinimizerRules {
+  String(String),
+  Regexp(RspackRegex),
+  Array(Vec<SwcCssMinimizerRule>),
+}
+
+impl SwcCssMinimizerRules {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+      Self::Array(l) => try_any_sync(l, |i| i.try_match(data)),
+    }
+  }
+}
+
+fn match_object(obj: &SwcCssMinimizerRspackPluginOptions, str: &str) -> Result<bool> {
+  if let Some(condition) = &obj.test {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.include {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.exclude {
+    if condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+#[derive(Debug, Default)]
+pub struct SwcCssMinimizerRspackPlugin {
+  options: SwcCssMinimizerRspackPluginOptions,
+}
 
-#[derive(Debug)]
-pub struct SwcCssMinimizerRspackPlugin;
+impl SwcCssMinimizerRspackPlugin {
+  pub fn new(options: SwcCssMinimizerRspackPluginOptions) -> Self {
+    Self { options }
+  }
+}
 
 #[async_trait]
 impl Plugin for SwcCssMinimizerRspackPlugin {
@@ -13,7 +100,15 @@ impl Plugin for SwcCssMinimizerRspackPlugin {
     "rspack.SwcCssMinimizerRspackPlugin"
   }
 
-  // TODO: chunk hash
+  fn css_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut CssChunkHashArgs,
+  ) -> PluginCssChunkHashHookOutput {
+    self.name().hash(&mut args.hasher);
+    self.options.hash(&mut args.hasher);
+    Ok(())
+  }
 
   async fn process_assets_stage_optimize_size(
     &self,
@@ -21,36 +116,96 @@ impl Plugin for SwcCssMinimizerRspackPlugin {
     args: rspack_core::ProcessAssetsArgs<'_>,
   ) -> rspack_core::PluginProcessAssetsOutput {
     let compilation = args.compilation;
+    let minify_options = &self.options;
 
     let gen_source_map_config = SwcCssSourceMapGenConfig {
       enable: compilation.options.devtool.source_map(),
       inline_sources_content: !compilation.options.devtool.no_sources(),
       emit_columns: !compilation.options.devtool.cheap(),
     };
+    let swc_css_minify_options = SwcCssMinifyOptions {
+      targets: minify_options.targets.clone(),
+    };
 
-    compilation
+    let extracted_comments = compilation
       .assets_mut()
       .par_iter_mut()
-      .filter(|(filename, _)| filename.ends_with(".css"))
-      .try_for_each(|(filename, original)| -> Result<()> {
-        if original.get_info().minimized {
-          return Ok(());
-        }
-
+      .filter(|(filename, original)| {
+        filename.ends_with(".css")
+          && !original.get_info().minimized
+          && match_object(minify_options, filename).unwrap_or(false)
+      })
+      .map(|(filename, original)| -> Result<Option<(String, String)>> {
+        let mut extracted = None;
         if let Some(original_source) = original.get_source() {
           let input = original_source.source().to_string();
           let input_source_map = original_source.map(&MapOptions::default());
-          let minimized_source = SwcCssCompiler::default().minify(
+          let output = SwcCssCompiler::default().minify(
             filename,
             input,
             input_source_map,
             gen_source_map_config.clone(),
+            &swc_css_minify_options,
           )?;
-          original.set_source(Some(minimized_source));
+
+          let mut extracted_comments = vec![];
+          let mut preserved_comments = vec![];
+          for comment in output.comments {
+            if let Some(condition) = &minify_options.extract_comments
+              && condition.test(&comment)
+            {
+              extracted_comments.push(comment);
+            } else if let Some(condition) = &minify_options.preserve_comments
+              && condition.test(&comment)
+            {
+              preserved_comments.push(comment);
+            }
+          }
+
+          let mut header = preserved_comments;
+          if !extracted_comments.is_empty() {
+            let comments_filename = format!("{filename}.LICENSE.txt");
+            let relative = Path::new(&comments_filename)
+              .file_name()
+              .expect("should have file name")
+              .to_string_lossy()
+              .to_string();
+            header.insert(
+              0,
+              format!("/*! For license information please see {relative} */"),
+            );
+            original.get_info_mut().related.license = Some(comments_filename.clone());
+            extracted = Some((comments_filename, extracted_comments.join("\n\n")));
+          }
+          let source = if header.is_empty() {
+            output.source
+          } else {
+            ConcatSource::new([
+              RawSource::from(header.join("\n") + "\n").boxed(),
+              output.source,
+            ])
+            .boxed()
+          };
+          original.set_source(Some(source));
         }
         original.get_info_mut().minimized = true;
-        Ok(())
-      })?;
+        Ok(extracted)
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    // write all extracted comments to assets
+    for (comments_filename, comments) in extracted_comments.into_iter().flatten() {
+      compilation.emit_asset(
+        comments_filename,
+        CompilationAsset::new(
+          Some(RawSource::from(comments).boxed()),
+          AssetInfo {
+            minimized: true,
+            ..Default::default()
+          },
+        ),
+      );
+    }
 
     Ok(())
   }
@@ -6,21 +6,25 @@ use std::collections::HashMap;
 use std::hash::Hash;
 use std::path::Path;
 use std::sync::{mpsc, Mutex};
+use std::time::Instant;
 
 use async_trait::async_trait;
 use minify::{match_object, minify};
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt, SourceMap};
 use rspack_core::rspack_sources::{Source, SourceMapSource, SourceMapSourceOptions};
 use rspack_core::{
-  AssetInfo, CompilationAsset, JsChunkHashArgs, Plugin, PluginContext, PluginJsChunkHashHookOutput,
-  PluginProcessAssetsOutput, ProcessAssetsArgs,
+  cache::MinifyResult, AssetInfo, CompilationAsset, Filename, JsChunkHashArgs, Logger, PathData,
+  Plugin, PluginContext, PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, ProcessAssetsArgs,
 };
 use rspack_error::{internal_error, Diagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_plugin_javascript::ExtractedCommentsInfo;
 use rspack_regex::RspackRegex;
 use rspack_util::try_any_sync;
+use sugar_path::SugarPath;
 use swc_config::config_types::BoolOrDataConfig;
 use swc_core::base::config::JsMinifyFormatOptions;
 pub use swc_ecma_minifier::option::{
@@ -29,7 +33,7 @@ pub use swc_ecma_minifier::option::{
 };
 #[derive(Debug, Clone, Default)]
 pub struct SwcJsMinimizerRspackPluginOptions {
-  pub extract_comments: Option<String>,
+  pub extract_comments: Option<ExtractComments>,
   pub compress: BoolOrDataConfig<TerserCompressorOptions>,
   pub mangle: BoolOrDataConfig<MangleOptions>,
   pub format: JsMinifyFormatOptions,
@@ -109,10 +113,58 @@ impl SwcJsMinimizerRules {
   }
 }
 
-struct ExtractComments<'a> {
+// copied from terser-webpack-plugin
+static SOME_COMMENTS_CONDITION: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"@preserve|@lic|@cc_on|^\**!").expect("Invalid regex"));
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Hash)]
+pub enum ExtractCommentsCondition {
+  /// Legal comments, e.g. `/*! ... */`, `@license` and `@preserve`.
+  Some,
+  All,
+  Regexp(RspackRegex),
+}
+
+impl ExtractCommentsCondition {
+  pub fn test(&self, comment: &str) -> bool {
+    match self {
+      Self::Some => SOME_COMMENTS_CONDITION.is_match(comment),
+      Self::All => true,
+      Self::Regexp(r) => r.test(comment),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ExtractCommentsBanner {
+  Disabled,
+  String(String),
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ExtractComments {
+  pub condition: ExtractCommentsCondition,
+  /// Template of the file the comments are extracted to, `[file].LICENSE.txt[query]` by default.
+  pub filename: Option<Filename>,
+  /// Comment added to the minimized asset, it refers to the extracted file by default.
+  pub banner: Option<ExtractCommentsBanner>,
+}
+
+impl Default for ExtractComments {
+  fn default() -> Self {
+    Self {
+      condition: ExtractCommentsCondition::Some,
+      filename: None,
+      banner: None,
+    }
+  }
+}
+
+struct NormalizedExtractComments<'a> {
   filename: String,
-  condition: &'a Regex,
-  banner: String,
+  condition: &'a ExtractCommentsCondition,
+  banner: Option<String>,
 }
 
 #[derive(Debug)]
@@ -140,18 +192,16 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
     let compilation = args.compilation;
     let minify_options = &self.options;
 
+    let logger = compilation.get_logger(self.name());
+    let cache = compilation.cache.clone();
+    let compiler_options = compilation.options.clone();
+
     let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
     // collect all extracted comments info
     let all_extracted_comments = Mutex::new(HashMap::new());
-    let extract_comments_condition = minify_options.extract_comments.as_ref().map(|condition| {
-      let regexp = if condition.eq("true") {
-        // copied from terser-webpack-plugin
-        Regex::new(r"@preserve|@lic|@cc_on|^\**!")
-      } else {
-        Regex::new(&condition[1..condition.len() - 2])
-      };
-      regexp.expect("Invalid extractComments")
-    });
+    // collect minify time of each asset and whether it's from the cache
+    let all_minify_stats = Mutex::new(Vec::new());
+    let default_extract_comments_filename = Filename::from("[file].LICENSE.txt[query]".to_string());
     let emit_source_map_columns = !compilation.options.devtool.cheap();
 
     compilation
@@ -194,24 +244,60 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
             module: is_module,
             ..Default::default()
           };
-          let extract_comments_option = extract_comments_condition.as_ref().map(|condition| {
-            let comments_filename = filename.to_string() + ".LICENSE.txt";
-            let dir = Path::new(filename).parent().expect("should has parent");
-            let relative = Path::new(&comments_filename).strip_prefix(dir).expect("should has common prefix").to_string_lossy().to_string().replace('\\', "/");
-            let banner = format!("/*! For license information please see {relative} */");
-            ExtractComments {
+          let extract_comments_option = minify_options.extract_comments.as_ref().map(|extract_comments| {
+            let comments_filename = extract_comments
+              .filename
+              .as_ref()
+              .unwrap_or(&default_extract_comments_filename)
+              .render(PathData::default().filename(filename), None);
+            let banner = match &extract_comments.banner {
+              Some(ExtractCommentsBanner::Disabled) => None,
+              Some(ExtractCommentsBanner::String(banner)) => Some(format!("/*! {banner} */")),
+              None => {
+                let dir = Path::new(filename).parent().expect("should has parent");
+                let relative = Path::new(&comments_filename).relative(dir).to_string_lossy().replace('\\', "/");
+                Some(format!("/*! For license information please see {relative} */"))
+              }
+            };
+            NormalizedExtractComments {
               filename: comments_filename,
-              condition,
+              condition: &extract_comments.condition,
               banner
             }
           });
-          let output = match minify(
-            &js_minify_options,
-            input,
-            filename,
-            &all_extracted_comments,
-            &extract_comments_option,
-          ) {
+
+          let mut hasher = RspackHash::from(&compiler_options.output);
+          self.name().hash(&mut hasher);
+          filename.hash(&mut hasher);
+          minify_options.hash(&mut hasher);
+          is_module.hash(&mut hasher);
+          emit_source_map_columns.hash(&mut hasher);
+          original_source.hash(&mut hasher);
+          let hash = hasher.digest(&compiler_options.output.hash_digest);
+
+          let start = Instant::now();
+          let (result, from_cache) = match cache.minify_occasion.use_cache(hash.encoded(), || {
+            let (output, extracted_comments) = minify(
+              &js_minify_options,
+              input,
+              filename,
+              &extract_comments_option,
+            )?;
+            let source = if let Some(map) = &output.map {
+              SourceMapSource::new(SourceMapSourceOptions {
+                value: output.code,
+                name: filename,
+                source_map: SourceMap::from_json(map).map_err(|e| internal_error!(e.to_string()))?,
+                original_source: None,
+                inner_source_map: input_source_map,
+                remove_original_source: true,
+              })
+              .boxed()
+            } else {
+              RawSource::from(output.code).boxed()
+            };
+            Ok(MinifyResult { source, extracted_comments })
+          }) {
             Ok(r) => r,
             Err(e) => {
               tx.send(e.into())
@@ -219,24 +305,29 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
               return Ok(())
             }
           };
-          let source = if let Some(map) = &output.map {
-            SourceMapSource::new(SourceMapSourceOptions {
-              value: output.code,
-              name: filename,
-              source_map: SourceMap::from_json(map).map_err(|e| internal_error!(e.to_string()))?,
-              original_source: None,
-              inner_source_map: input_source_map,
-              remove_original_source: true,
-            })
-            .boxed()
-          } else {
-            RawSource::from(output.code).boxed()
-          };
-          let source = if let Some(banner) = extract_comments_option.map(|option| option.banner)
-            && all_extracted_comments
+          all_minify_stats
             .lock()
-            .expect("all_extract_comments lock failed")
-            .contains_key(filename)
+            .expect("all_minify_stats lock failed")
+            .push((filename.clone(), start.elapsed(), from_cache));
+
+          let MinifyResult { source, extracted_comments } = result;
+          if let Some(option) = &extract_comments_option
+            && let Some(comments) = extracted_comments
+          {
+            original.get_info_mut().related.license = Some(option.filename.clone());
+            all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .insert(
+                filename.clone(),
+                ExtractedCommentsInfo {
+                  source: RawSource::from(comments).boxed(),
+                  comments_file_name: option.filename.clone(),
+                },
+              );
+          }
+          let source = if let Some(banner) = extract_comments_option.and_then(|option| option.banner)
+            && original.get_info().related.license.is_some()
           {
             ConcatSource::new([
               RawSource::Source(banner).boxed(),
@@ -255,6 +346,22 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
 
     compilation.push_batch_diagnostic(rx.into_iter().flatten().collect::<Vec<_>>());
 
+    let mut all_minify_stats = all_minify_stats
+      .into_inner()
+      .expect("all_minify_stats lock failed");
+    all_minify_stats.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    let mut cache_count = logger.cache("minify cache");
+    for (filename, elapsed, from_cache) in all_minify_stats {
+      if from_cache {
+        cache_count.hit();
+        logger.debug(format!("{filename}: restored from cache in {elapsed:?}"));
+      } else {
+        cache_count.miss();
+        logger.log(format!("{filename}: minified in {elapsed:?}"));
+      }
+    }
+    logger.cache_end(cache_count);
+
     // write all extracted comments to assets
     all_extracted_comments
       .lock()
@@ -1,18 +1,11 @@
-use std::{
-  collections::HashMap,
-  sync::{mpsc, Arc, Mutex},
-};
+use std::sync::{mpsc, Arc};
 
-use rspack_core::{
-  rspack_sources::{RawSource, SourceExt},
-  ModuleType,
-};
+use rspack_core::ModuleType;
 use rspack_error::{internal_error, DiagnosticKind, Error, Result, TraceableError};
 use rspack_plugin_javascript::ast::parse_js;
 use rspack_plugin_javascript::ast::{print, SourceMapConfig};
 use rspack_plugin_javascript::{
-  utils::ecma_parse_error_to_rspack_error, ExtractedCommentsInfo, IsModule, SourceMapsConfig,
-  TransformOutput,
+  utils::ecma_parse_error_to_rspack_error, IsModule, SourceMapsConfig, TransformOutput,
 };
 use swc_config::config_types::BoolOr;
 use swc_core::{
@@ -41,7 +34,7 @@ use swc_ecma_minifier::{
   option::{MinifyOptions, TopLevelOptions},
 };
 
-use crate::{ExtractComments, JsMinifyOptions, SwcJsMinimizerRspackPluginOptions};
+use crate::{JsMinifyOptions, NormalizedExtractComments, SwcJsMinimizerRspackPluginOptions};
 
 pub fn match_object(obj: &SwcJsMinimizerRspackPluginOptions, str: &str) -> Result<bool> {
   if let Some(condition) = &obj.test {
@@ -96,15 +89,16 @@ pub(crate) fn minify_file_comments(
   }
 }
 
+/// Returns the minified output and the content of the extracted comments file if any comment is extracted.
 pub fn minify(
   opts: &JsMinifyOptions,
   input: String,
   filename: &str,
-  all_extract_comments: &Mutex<HashMap<String, ExtractedCommentsInfo>>,
-  extract_comments: &Option<ExtractComments<'_>>,
-) -> Result<TransformOutput> {
+  extract_comments: &Option<NormalizedExtractComments<'_>>,
+) -> Result<(TransformOutput, Option<String>)> {
   let cm: Arc<SourceMap> = Default::default();
-  GLOBALS.set(&Default::default(), || -> Result<TransformOutput> {
+  let mut all_extracted_comments = None;
+  let output = GLOBALS.set(&Default::default(), || -> Result<TransformOutput> {
     with_rspack_error_handler(
       "Minify Error".to_string(),
       DiagnosticKind::JavaScript,
@@ -230,7 +224,7 @@ pub fn minify(
 
           leading_trivial.iter().for_each(|(_, comments)| {
             comments.iter().for_each(|c| {
-              if extract_comments.condition.is_match(&c.text) {
+              if extract_comments.condition.test(&c.text) {
                 extracted_comments.push(match c.kind {
                   CommentKind::Line => {
                     format!("// {}", c.text)
@@ -244,7 +238,7 @@ pub fn minify(
           });
           trailing_trivial.iter().for_each(|(_, comments)| {
             comments.iter().for_each(|c| {
-              if extract_comments.condition.is_match(&c.text) {
+              if extract_comments.condition.test(&c.text) {
                 extracted_comments.push(match c.kind {
                   CommentKind::Line => {
                     format!("// {}", c.text)
@@ -259,16 +253,7 @@ pub fn minify(
 
           // if not matched comments, we don't need to emit .License.txt file
           if !extracted_comments.is_empty() {
-            all_extract_comments
-              .lock()
-              .expect("all_extract_comments lock failed")
-              .insert(
-                filename.to_string(),
-                ExtractedCommentsInfo {
-                  source: RawSource::Source(extracted_comments.join("\n\n")).boxed(),
-                  comments_file_name: extract_comments.filename.to_string(),
-                },
-              );
+            all_extracted_comments = Some(extracted_comments.join("\n\n"));
           }
         }
 
@@ -298,7 +283,8 @@ pub fn minify(
         )
       },
     )
-  })
+  })?;
+  Ok((output, all_extracted_comments))
 }
 
 pub struct IdentCollector {
@@ -11,9 +11,10 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: JsWord,
   request: String,
-  // only_direct_import: bool,
   /// the WASM AST node
   pub desc: WasmNode,
+  /// count of the parameters when a function is imported
+  func_params: Option<usize>,
 
   span: Option<ErrorSpan>,
 }
@@ -25,13 +26,29 @@ impl WasmImportDependency {
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      func_params: None,
       span: None,
     }
   }
+
+  pub fn with_func_params(mut self, func_params: usize) -> Self {
+    self.func_params = Some(func_params);
+    self
+  }
+
   pub fn name(&self) -> &str {
     &self.name
   }
+
+  pub fn func_params(&self) -> Option<usize> {
+    self.func_params
+  }
+
+  /// Non-function imports like globals can't be wrapped, so they can only be imported from
+  /// another WebAssembly module directly.
+  pub fn only_direct_import(&self) -> bool {
+    !matches!(self.desc, WasmNode::Func(_))
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -1,19 +1,49 @@
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, BoxPlugin, Plugin,
+  AdditionalChunkRuntimeRequirementsArgs, BoxPlugin, ChunkUkey, Compilation, ModuleType, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginExt, RuntimeGlobals,
   RuntimeModuleExt, WasmLoadingType,
 };
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new(false).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileWasmPlugin::new(true).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
   }
 }
 
+fn has_sync_wasm_module_in_graph(chunk: &ChunkUkey, compilation: &Compilation) -> bool {
+  let chunk = compilation
+    .chunk_by_ukey
+    .get(chunk)
+    .expect("chunk not found");
+  chunk
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .into_iter()
+    .chain(std::iter::once(chunk.ukey))
+    .any(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk, &compilation.module_graph)
+        .into_iter()
+        .any(|m| *m.module_type() == ModuleType::WasmSync)
+    })
+}
+
 #[derive(Debug)]
 pub struct FetchCompileAsyncWasmPlugin;
 
@@ -91,3 +121,86 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_module_in_graph(args.chunk, args.compilation)
+    {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+      runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+          true,
+          *args.chunk,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self { import }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_module_in_graph(args.chunk, args.compilation)
+    {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          if self.import {
+            include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+          } else {
+            include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+          },
+          false,
+          *args.chunk,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
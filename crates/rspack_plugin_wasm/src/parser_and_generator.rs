@@ -12,7 +12,7 @@ use rspack_core::{
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
 use swc_core::atoms::Atom;
-use wasmparser::{Import, Parser, Payload};
+use wasmparser::{Import, Parser, Payload, Type, TypeRef};
 
 use crate::dependency::{StaticExportsDependency, WasmImportDependency};
 use crate::ModuleIdToFileName;
@@ -286,7 +286,194 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut func_types = vec![];
+    let mut exports = Vec::with_capacity(1);
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for ty in s {
+              match ty {
+                Ok(Type::Func(func_type)) => func_types.push(func_type.params().len()),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                  0,
+                  0,
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => exports.push(export.name.to_string()),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                  0,
+                  0,
+                )),
+              };
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let dep = WasmImportDependency::new(module.into(), name.into(), ty);
+                  let dep = match ty {
+                    TypeRef::Func(index) => dep.with_func_params(
+                      func_types.get(index as usize).copied().unwrap_or_default(),
+                    ),
+                    _ => dep,
+                  };
+                  dependencies.push(Box::new(dep));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                  0,
+                  0,
+                )),
+              }
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+            0,
+            0,
+          ));
+        }
+      }
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      exports.iter().cloned().map(Atom::from).collect::<Vec<_>>(),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+
+        let module_graph = &compilation.module_graph;
+        let chunk_graph = &compilation.chunk_graph;
+
+        // the imported modules are executed after the exports are assigned,
+        // so that circular dependencies can access the exports of WebAssembly
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id).expect("should be ok"))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+          .filter_map(|dep| module_graph.module_graph_module_by_dependency_id(dep.id()))
+          .for_each(|mgm| {
+            if !dep_modules.contains_key(&mgm.module_identifier) {
+              let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+              dep_modules.insert(mgm.module_identifier, (import_var, mgm.id(chunk_graph)));
+            }
+          });
+
+        let imports_code = dep_modules
+          .iter()
+          .map(|(_, val)| render_import_stmt(&val.0, val.1))
+          .collect::<Vec<_>>()
+          .join("");
+
+        let source = format!(
+          r#"// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+{}(exports);
+// export exports from WebAssembly module
+for (var name in wasmExports) if (name) exports[name] = wasmExports[name];
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+          RuntimeGlobals::WASM_INSTANCES,
+          RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+        );
+
+        Ok(RawSource::from(source).boxed())
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+}
+
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
@@ -306,7 +493,7 @@ fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,9 +1,15 @@
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, stringify_map, ChunkUkey, Compilation,
+  DependencyType, Module, ModuleDependency, ModuleType, PathData, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage, RuntimeSpec, SourceType, UsedName,
 };
 use rspack_identifier::Identifier;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::dependency::WasmImportDependency;
+use crate::{hash_for_source, render_wasm_name};
 
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -104,3 +110,328 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     "#
   )
 }
+
+/// Instantiates the `webassembly/sync` modules of a chunk while the chunk is loading,
+/// so the modules can access the exports synchronously by `__webpack_require__.w`.
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk)
+      .expect("chunk not found");
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut wasm_module_map = HashMap::default();
+    let mut wasm_modules = IndexMap::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let mut modules = chunk_graph
+        .get_chunk_modules(&chunk_ukey, module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .map(|m| (chunk_graph.get_module_id(m.identifier()).as_deref(), m))
+        .filter_map(|(id, m)| id.map(|id| (id, m)))
+        .collect::<Vec<_>>();
+      if modules.is_empty() {
+        continue;
+      }
+      modules.sort_unstable_by_key(|(id, _)| *id);
+      let chunk_id = compilation
+        .chunk_by_ukey
+        .get(&chunk_ukey)
+        .expect("chunk not found")
+        .expect_id()
+        .to_string();
+      wasm_module_map.insert(
+        chunk_id,
+        serde_json::to_string(&modules.iter().map(|(id, _)| id).collect::<Vec<_>>())
+          .expect("should be valid json"),
+      );
+      wasm_modules.extend(modules);
+    }
+    wasm_modules.sort_unstable_keys();
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut wasm_module_filenames = HashMap::default();
+    for (id, module) in wasm_modules {
+      import_objects.push(generate_import_object(
+        compilation,
+        module.as_ref(),
+        id,
+        &chunk.runtime,
+        &mut declarations,
+      ));
+
+      let source = compilation
+        .code_generation_results
+        .get(&module.identifier(), Some(&chunk.runtime))
+        .ok()
+        .and_then(|result| result.get(&SourceType::Wasm));
+      if let Some(source) = source
+        && let Some(normal_module) = module.as_normal_module()
+      {
+        let (filename, _) = render_wasm_name(
+          compilation,
+          normal_module,
+          &compilation.options.output.webassembly_module_filename,
+          &hash_for_source(source),
+        );
+        wasm_module_filenames.insert(
+          id.to_string(),
+          serde_json::to_string(&filename).expect("should be valid json"),
+        );
+      }
+    }
+
+    RawSource::from(get_wasm_chunk_loading(
+      &declarations.join("\n"),
+      &import_objects.join("\n"),
+      &stringify_map(&wasm_module_map),
+      &stringify_map(&wasm_module_filenames),
+      &self
+        .generate_load_binary_code
+        .replace("$PATH", "wasmModuleFilenames[wasmModuleId]"),
+      self.supports_streaming,
+    ))
+    .boxed()
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+impl_runtime_module!(WasmChunkLoadingRuntimeModule);
+
+/// Renders the factory of the import object of a WebAssembly module. Imported functions
+/// are wrapped to access the exports lazily, the other imports wait for the instance of the
+/// imported WebAssembly module.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &dyn Module,
+  module_id: &str,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = &compilation.module_graph;
+  let chunk_graph = &compilation.chunk_graph;
+
+  let mut wait_for_instances = IndexMap::<&str, String>::new();
+  let mut properties = IndexMap::<&str, Vec<String>>::new();
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter(|dep| dep.dependency_type() == &DependencyType::WasmImport)
+  {
+    let Some(imported_module) = module_graph.get_module(dep.id()) else {
+      continue;
+    };
+    let Some(dep) = dep.as_any().downcast_ref::<WasmImportDependency>() else {
+      continue;
+    };
+    let used_name = module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      );
+    let Some(UsedName::Str(used_name)) = used_name else {
+      continue;
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be valid json");
+    let imported_module_id = chunk_graph
+      .get_module_id(imported_module.identifier())
+      .as_deref()
+      .unwrap_or_default();
+
+    let value = if dep.only_direct_import() {
+      let instances = wait_for_instances.len();
+      let instance_var = wait_for_instances
+        .entry(imported_module_id)
+        .or_insert_with(|| format!("m{instances}"));
+      format!("{instance_var}[{used_name}]")
+    } else {
+      let params = (0..dep.func_params().unwrap_or_default())
+        .map(|i| format!("p{i}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let module = format!(
+        "{}[{}]",
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be valid json")
+      );
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let prefix = if imported_module.module_type().is_wasm_like() {
+        format!("{module} ? {module}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        "{prefix}function({params}) {{\n  if({cache} === undefined) {cache} = {module}.exports;\n  return {cache}[{used_name}]({params});\n}}"
+      )
+    };
+    properties.entry(dep.request()).or_default().push(format!(
+      "{}: {value}",
+      serde_json::to_string(dep.name()).expect("should be valid json")
+    ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties
+      .into_iter()
+      .map(|(request, values)| format!(
+        "{}: {{\n{}\n}}",
+        serde_json::to_string(request).expect("should be valid json"),
+        values.join(",\n")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+  let module_id = serde_json::to_string(module_id).expect("should be valid json");
+  let installed_module = |id: &str| {
+    format!(
+      "installedWasmModules[{}]",
+      serde_json::to_string(id).expect("should be valid json")
+    )
+  };
+
+  match wait_for_instances.len() {
+    0 => format!("{module_id}: function() {{\n{import_object}\n}},"),
+    1 => {
+      let (id, instance_var) = wait_for_instances
+        .first()
+        .expect("should have one instance");
+      format!(
+        "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return {}; }}).then(function({instance_var}) {{\n{import_object}\n}});\n}},",
+        installed_module(id)
+      )
+    }
+    _ => {
+      let promises = wait_for_instances
+        .keys()
+        .map(|id| installed_module(id))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .values()
+        .enumerate()
+        .map(|(i, instance_var)| format!("{instance_var} = array[{i}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{\nvar {variables};\n{import_object}\n}});\n}},"
+      )
+    }
+  }
+}
+
+fn get_wasm_chunk_loading(
+  declarations: &str,
+  import_objects: &str,
+  wasm_module_map: &str,
+  wasm_module_filenames: &str,
+  req: &str,
+  supports_streaming: bool,
+) -> String {
+  let instantiate_code = if supports_streaming {
+    r#"
+        if (importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+          promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else if (typeof WebAssembly.instantiateStreaming === 'function') {
+          promise = WebAssembly.instantiateStreaming(req, importObject);
+        } else {
+          var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }
+    "#
+  } else {
+    r#"
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        if (importObject && typeof importObject.then === 'function') {
+          promise = Promise.all([
+            bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+            importObject
+          ]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else {
+          promise = bytesPromise.then(function(bytes) {
+            return WebAssembly.instantiate(bytes, importObject);
+          });
+        }
+    "#
+  };
+  format!(
+    r#"
+    // object to store loaded and loading wasm modules
+    var installedWasmModules = {{}};
+    function promiseResolve() {{ return Promise.resolve(); }}
+    {declarations}
+    var wasmImportObjects = {{
+    {import_objects}
+    }};
+    var wasmModuleMap = {wasm_module_map};
+    var wasmModuleFilenames = {wasm_module_filenames};
+    // object with all WebAssembly.instance exports
+    {wasm_instances} = {{}};
+    // Fetch + compile chunk loading for webassembly
+    {ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+      var wasmModules = wasmModuleMap[chunkId] || [];
+      wasmModules.forEach(function(wasmModuleId, idx) {{
+        var installedWasmModuleData = installedWasmModules[wasmModuleId];
+        // a Promise means "currently loading" or "already loaded".
+        if (installedWasmModuleData) {{
+          promises.push(installedWasmModuleData);
+          return;
+        }}
+        var importObject = wasmImportObjects[wasmModuleId]();
+        var req = {req};
+        var promise;
+        {instantiate_code}
+        promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+          return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+        }}));
+      }});
+    }};
+    "#,
+    wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+    ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+  )
+}
@@ -2,13 +2,16 @@ use std::fmt::Debug;
 
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, CompilationArgs, CompilationParams, CompilerOptions, DependencyType, ModuleType,
-  ParserAndGenerator, Plugin, PluginCompilationHookOutput, PluginContext,
+  ApplyContext, CompilationArgs, CompilationParams, CompilerOptions, Dependency, DependencyType,
+  ModuleDependency, ModuleType, OptimizeChunksArgs, ParserAndGenerator, Plugin,
+  PluginCompilationHookOutput, PluginContext, PluginOptimizeChunksOutput,
   PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, SourceType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
+use rspack_identifier::IdentifierSet;
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::dependency::WasmImportDependency;
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -78,38 +81,189 @@ impl Plugin for AsyncWasmPlugin {
     _ctx: PluginContext,
     args: RenderManifestArgs<'_>,
   ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(
+      &args,
+      ModuleType::WasmAsync,
+      &self.module_id_to_filename_without_ext,
+    )
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self {
+      module_id_to_filename_without_ext: Default::default(),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmImport,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    let builder = move || {
+      Box::new({
+        SyncWasmParserAndGenerator {
+          module_id_to_filename: module_id_to_filename_without_ext.clone(),
+        }
+      }) as Box<dyn ParserAndGenerator>
+    };
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  async fn optimize_chunks(
+    &self,
+    _ctx: PluginContext,
+    args: OptimizeChunksArgs<'_>,
+  ) -> PluginOptimizeChunksOutput {
     let compilation = args.compilation;
-    let chunk = args.chunk();
     let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+    let mut diagnostics = vec![];
+
+    // the wasm instances are created while loading the chunk, which can't happen for initial chunks
+    let mut initial_wasm_modules = IdentifierSet::default();
+    for chunk in compilation.chunk_by_ukey.values() {
+      if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        continue;
+      }
+      initial_wasm_modules.extend(
+        compilation
+          .chunk_graph
+          .get_chunk_modules(&chunk.ukey, module_graph)
+          .into_iter()
+          .filter(|m| *m.module_type() == ModuleType::WasmSync)
+          .map(|m| m.identifier()),
+      );
+    }
+    for module in initial_wasm_modules
+      .iter()
+      .filter_map(|id| module_graph.module_by_identifier(id))
+    {
+      diagnostics.push(Diagnostic::error(
+        "WebAssemblyInInitialChunkError".into(),
+        format!(
+          "WebAssembly module {} is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module.",
+          module.readable_identifier(context)
+        ),
+        0,
+        0,
+      ));
+    }
 
-    let ordered_modules = compilation
-      .chunk_graph
-      .get_chunk_modules(&args.chunk_ukey, module_graph);
-
-    let files = ordered_modules
-      .par_iter()
-      .filter(|m| *m.module_type() == ModuleType::WasmAsync)
-      .map(|m| {
-        let code_gen_result = compilation
-          .code_generation_results
-          .get(&m.identifier(), Some(&chunk.runtime))?;
-
-        let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-          let (output_path, asset_info) = self
-            .module_id_to_filename_without_ext
-            .get(&m.identifier())
-            .map(|s| s.clone())
-            .expect("should have wasm_filename");
-          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
-        });
-
-        Ok(result)
-      })
-      .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
-      .into_iter()
-      .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
-
-    Ok(files)
+    for module in module_graph
+      .modules()
+      .values()
+      .filter(|m| *m.module_type() == ModuleType::WasmSync)
+    {
+      for dep in module
+        .get_dependencies()
+        .iter()
+        .filter_map(|id| module_graph.dependency_by_id(id))
+        .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+      {
+        if dep.only_direct_import()
+          && let Some(imported_module) = module_graph.get_module(dep.id())
+          && !imported_module.module_type().is_wasm_like()
+        {
+          diagnostics.push(Diagnostic::error(
+            "UnsupportedWebAssemblyFeatureError".into(),
+            format!(
+              "Import \"{}\" from \"{}\" in {} can only be used for direct wasm to wasm dependencies",
+              dep.name(),
+              dep.request(),
+              module.readable_identifier(context)
+            ),
+            0,
+            0,
+          ));
+        }
+      }
+    }
+
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(
+      &args,
+      ModuleType::WasmSync,
+      &self.module_id_to_filename_without_ext,
+    )
   }
 }
+
+fn render_wasm_manifest(
+  args: &RenderManifestArgs<'_>,
+  module_type: ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> PluginRenderManifestHookOutput {
+  let compilation = args.compilation;
+  let chunk = args.chunk();
+  let module_graph = &compilation.module_graph;
+
+  let ordered_modules = compilation
+    .chunk_graph
+    .get_chunk_modules(&args.chunk_ukey, module_graph);
+
+  let files = ordered_modules
+    .par_iter()
+    .filter(|m| *m.module_type() == module_type)
+    .map(|m| {
+      let code_gen_result = compilation
+        .code_generation_results
+        .get(&m.identifier(), Some(&chunk.runtime))?;
+
+      let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
+        let (output_path, asset_info) = module_id_to_filename
+          .get(&m.identifier())
+          .map(|s| s.clone())
+          .expect("should have wasm_filename");
+        RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+      });
+
+      Ok(result)
+    })
+    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<RenderManifestEntry>>();
+
+  Ok(files)
+}
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::Range;
 
 use regex_syntax::hir::literal::ExtractKind;
 use regex_syntax::hir::{Hir, HirKind, Look};
@@ -97,6 +98,21 @@ impl Algo {
     }
   }
 
+  pub(crate) fn find_group(&self, str: &str, group: usize) -> Option<Range<usize>> {
+    match self {
+      Algo::Regress(regex) => regex.find(str)?.group(group),
+      Algo::EndWith { pats } => {
+        if group != 0 {
+          return None;
+        }
+        pats
+          .iter()
+          .find(|pat| str.ends_with(pat.as_str()))
+          .map(|pat| str.len() - pat.len()..str.len())
+      }
+    }
+  }
+
   pub(crate) fn global(&self) -> bool {
     match self {
       Algo::Regress(reg) => reg.flags.contains('g'),
@@ -170,4 +186,15 @@ mod test_algo {
     // wildcard match
     assert!(Algo::new("\\..(svg|png)$", "").unwrap().is_regress());
   }
+
+  #[test]
+  fn find_group() {
+    let algo = Algo::new("^(.+?[\\\\/]node_modules[\\\\/])", "").unwrap();
+    let text = "/a/node_modules/b/node_modules/c/index.js";
+    assert_eq!(algo.find_group(text, 1), Some(0..16));
+    assert_eq!(algo.find_group("/a/b.js", 1), None);
+    let algo = Algo::new("\\.js$", "").unwrap();
+    assert_eq!(algo.find_group("a.js", 0), Some(1..4));
+    assert_eq!(algo.find_group("a.js", 1), None);
+  }
 }
@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 
 use std::fmt::Debug;
+use std::ops::Range;
 
 use rspack_error::Error;
 use swc_core::ecma::ast::Regex as SwcRegex;
@@ -27,6 +28,11 @@ impl RspackRegex {
     self.algo.test(text)
   }
 
+  /// Returns the range of a capture group in the first match, group `0` is the whole match.
+  pub fn find_group(&self, text: &str, group: usize) -> Option<Range<usize>> {
+    self.algo.find_group(text, group)
+  }
+
   pub fn global(&self) -> bool {
     self.algo.global()
   }
@@ -35,6 +41,14 @@ impl RspackRegex {
     self.algo.sticky()
   }
 
+  /// The source text of the regex, without flags.
+  pub fn source(&self) -> &str {
+    self
+      .raw
+      .rsplit_once('|')
+      .map_or(self.raw.as_str(), |(source, _)| source)
+  }
+
   pub fn with_flags(expr: &str, flags: &str) -> Result<Self, Error> {
     let mut chars = flags.chars().collect::<Vec<char>>();
     chars.sort();
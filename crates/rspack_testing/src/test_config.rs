@@ -28,6 +28,7 @@ fn default_entry() -> HashMap<String, EntryItem> {
     EntryItem {
       import: vec!["./index".to_string()],
       runtime: None,
+      layer: None,
     },
   );
   map
@@ -99,14 +100,22 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  // False by default to keep AMD handling out of snapshots, the JS side follows webpack and
+  // enables it unless `amd` is `false`.
+  #[serde(default)]
+  pub amd: bool,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct Experiments {
   // True by default to reduce code in snapshots.
+  #[serde(default)]
+  pub sync_web_assembly: bool,
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub layers: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -131,6 +140,8 @@ pub struct Optimization {
   pub mangle_exports: String,
   #[serde(default = "default_optimization_false_string_lit")]
   pub used_exports: String,
+  #[serde(default)]
+  pub concatenate_modules: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -139,6 +150,8 @@ pub struct EntryItem {
   pub import: Vec<String>,
   #[serde(default = "enable_runtime_by_default")]
   pub runtime: Option<String>,
+  #[serde(default)]
+  pub layer: Option<String>,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -262,6 +275,47 @@ pub struct Rule {
   pub r#use: Vec<ModuleRuleUse>,
   pub side_effect: Option<bool>,
   pub r#type: Option<String>,
+  pub layer: Option<String>,
+  pub issuer_layer: Option<String>,
+  pub parser: Option<JavascriptParser>,
+}
+
+#[derive(Debug, JsonSchema, Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct JavascriptParser {
+  pub url: Option<String>,
+  pub worker: Option<Vec<String>>,
+  pub import_meta: Option<bool>,
+  pub wrapped_context_critical: Option<bool>,
+  pub wrapped_context_reg_exp: Option<String>,
+  pub exports_presence: Option<String>,
+  pub commonjs_magic_comments: Option<bool>,
+}
+
+impl From<JavascriptParser> for rspack_core::JavascriptParserOptions {
+  fn from(value: JavascriptParser) -> Self {
+    let default = Self::default();
+    Self {
+      url: value
+        .url
+        .map(|url| url.as_str().into())
+        .unwrap_or(default.url),
+      worker: value.worker.unwrap_or(default.worker),
+      import_meta: value.import_meta.unwrap_or(default.import_meta),
+      wrapped_context_critical: value
+        .wrapped_context_critical
+        .unwrap_or(default.wrapped_context_critical),
+      wrapped_context_reg_exp: value
+        .wrapped_context_reg_exp
+        .map(|reg_exp| RspackRegex::new(&reg_exp).expect("should be valid regex"))
+        .unwrap_or(default.wrapped_context_reg_exp),
+      exports_presence: value.exports_presence.map(|mode| mode.as_str().into()),
+      commonjs_magic_comments: value
+        .commonjs_magic_comments
+        .unwrap_or(default.commonjs_magic_comments),
+      ..default
+    }
+  }
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -332,7 +386,11 @@ impl TestConfig {
       rule!("\\.ts$", "ts"),
       rule!("\\.tsx$", "tsx"),
       rule!("\\.css$", "css"),
-      rule!("\\.wasm$", "webassembly/async"),
+      if self.experiments.sync_web_assembly {
+        rule!("\\.wasm$", "webassembly/sync")
+      } else {
+        rule!("\\.wasm$", "webassembly/async")
+      },
     ];
     rules.extend(self.module.rules.into_iter().map(|rule| {
       c::ModuleRule {
@@ -348,6 +406,11 @@ impl TestConfig {
         r#type: rule
           .r#type
           .map(|i| ModuleType::try_from(i.as_str()).expect("should give a right module_type")),
+        layer: rule.layer,
+        issuer_layer: rule.issuer_layer.map(c::RuleSetCondition::String),
+        parser: rule
+          .parser
+          .map(|parser| c::ParserOptions::Javascript(parser.into())),
         ..Default::default()
       }
     }));
@@ -359,7 +422,7 @@ impl TestConfig {
     let options = CompilerOptions {
       context: root.clone(),
       output: c::OutputOptions {
-        clean: self.output.clean,
+        clean: self.output.clean.then(Default::default),
         filename: c::Filename::from_str(&self.output.filename).expect("Should exist"),
         chunk_filename: c::Filename::from_str(&self.output.chunk_filename).expect("Should exist"),
         cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
@@ -372,7 +435,7 @@ impl TestConfig {
           .expect("Should exist"),
         hot_update_global: "rspack_testing".to_string(),
         asset_module_filename: c::Filename::from_str("[hash][ext][query]").expect("Should exist"),
-        wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
+        wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::Fetch),
         webassembly_module_filename: c::Filename::from_str("[hash].module.wasm")
           .expect("Should exist"),
         public_path: c::PublicPath::String("/".to_string()),
@@ -403,7 +466,7 @@ impl TestConfig {
         hash_salt: c::HashSalt::None,
         async_chunks: true,
         worker_chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::ImportScripts),
-        worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
+        worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::Fetch),
         worker_public_path: String::new(),
         script_type: String::from("false"),
       },
@@ -436,13 +499,17 @@ impl TestConfig {
       stats: Default::default(),
       snapshot: Default::default(),
       cache: c::CacheOptions::Disabled,
-      experiments: Default::default(),
+      experiments: c::Experiments {
+        layers: self.experiments.layers,
+        ..Default::default()
+      },
       dev_server: Default::default(),
       node: Some(c::NodeOption {
         dirname: "mock".to_string(),
         filename: "mock".to_string(),
         global: "warn".to_string(),
       }),
+      amd: self.amd.then(|| "{}".to_string()),
       optimization: c::Optimization {
         remove_available_modules: self.optimization.remove_available_modules,
         remove_empty_chunks: self.optimization.remove_empty_chunks,
@@ -451,6 +518,7 @@ impl TestConfig {
         inner_graph: self.optimization.inner_graph,
         used_exports: c::UsedExportsOption::from(self.optimization.used_exports.as_str()),
         mangle_exports: c::MangleExportsOption::from(self.optimization.mangle_exports.as_str()),
+        concatenate_modules: self.optimization.concatenate_modules,
       },
       profile: false,
     };
@@ -470,6 +538,7 @@ impl TestConfig {
               base_uri: None,
               filename: None,
               library: None,
+              layer: desc.layer.clone(),
             },
           )
           .boxed(),
@@ -511,6 +580,7 @@ impl TestConfig {
     plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
     plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
     plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
     if options.dev_server.hot {
       plugins.push(rspack_plugin_hmr::HotModuleReplacementPlugin.boxed());
     }
@@ -521,17 +591,26 @@ impl TestConfig {
     plugins.push(rspack_plugin_javascript::JsPlugin::new().boxed());
 
     if options.devtool.source_map() {
-      plugins.push(
-        rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
-          inline: options.devtool.inline(),
-          append: !options.devtool.hidden(),
-          namespace: options.output.unique_name.clone(),
-          columns: !options.devtool.cheap(),
-          no_sources: options.devtool.no_sources(),
-          public_path: None,
-        })
-        .boxed(),
-      );
+      plugins.push(rspack_plugin_devtool::DevtoolPlugin.boxed());
+      if !options.devtool.eval() {
+        plugins.push(
+          rspack_plugin_devtool::SourceMapDevToolPlugin::new(
+            rspack_plugin_devtool::SourceMapDevToolPluginOptions {
+              filename: (!options.devtool.inline())
+                .then(|| options.output.source_map_filename.template().to_string()),
+              append: options
+                .devtool
+                .hidden()
+                .then_some(rspack_plugin_devtool::Append::Disabled),
+              namespace: options.output.unique_name.clone(),
+              columns: !options.devtool.cheap(),
+              no_sources: options.devtool.no_sources(),
+              ..Default::default()
+            },
+          )
+          .boxed(),
+        );
+      }
     }
 
     if self.optimization.module_ids == "named" {
@@ -550,6 +629,10 @@ impl TestConfig {
     plugins.push(rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if self.experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::FetchCompileWasmPlugin {}.boxed());
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     if self.experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
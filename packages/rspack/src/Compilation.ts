@@ -336,6 +336,10 @@ export class Compilation {
 			!context.forToString
 		);
 		options.source = optionOrLocalFallback(options.source, false);
+		options.optimizationBailout = optionOrLocalFallback(
+			options.optimizationBailout,
+			false
+		);
 		options.logging = optionOrLocalFallback(
 			options.logging,
 			context.forToString ? "info" : true
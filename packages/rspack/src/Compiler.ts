@@ -1005,7 +1005,11 @@ class Compiler {
 			if (error) {
 				return callback && callback(error);
 			}
-			const unsafe_build = instance?.unsafe_build;
+			// Outside watch mode the persistent cache is stored before the build finishes,
+			// as the process may exit before the compiler becomes idle.
+			const unsafe_build = this.watchMode
+				? instance?.unsafe_build
+				: instance?.unsafe_run;
 			const build_cb = unsafe_build?.bind(instance) as typeof unsafe_build;
 			build_cb?.(error => {
 				if (error) {
@@ -0,0 +1,52 @@
+import {
+	RawContextReplacementData,
+	RawContextReplacementPluginOptions
+} from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+/**
+ * `resource` is only available after resolving.
+ */
+export type ContextReplacementData = RawContextReplacementData;
+
+export const ContextReplacementPlugin = create(
+	BuiltinPluginName.ContextReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newContentResource?:
+			| string
+			| boolean
+			| RegExp
+			| ((data: ContextReplacementData) => void),
+		newContentRecursive?: boolean | RegExp,
+		newContentRegExp?: RegExp
+	): RawContextReplacementPluginOptions => {
+		if (typeof newContentResource === "function") {
+			const newContentCallback = newContentResource;
+			return {
+				resourceRegExp,
+				newContentCallback: data => {
+					// Same as webpack, the data is modified in place
+					newContentCallback(data);
+					return data;
+				}
+			};
+		}
+		// Same as webpack, the leading arguments are optional
+		if (typeof newContentResource !== "string") {
+			newContentRegExp = newContentRecursive as RegExp | undefined;
+			newContentRecursive = newContentResource;
+			newContentResource = undefined;
+		}
+		if (typeof newContentRecursive !== "boolean") {
+			newContentRegExp = newContentRecursive;
+			newContentRecursive = undefined;
+		}
+		return {
+			resourceRegExp,
+			newContentResource,
+			newContentRecursive,
+			newContentRegExp
+		};
+	}
+);
@@ -21,6 +21,7 @@ export type EntryOptions = {
 	baseUri?: string;
 	filename?: Filename;
 	library?: LibraryOptions;
+	layer?: string;
 };
 export const EntryPlugin = create(
 	BuiltinPluginName.EntryPlugin,
@@ -52,6 +53,7 @@ function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 			: undefined,
 		asyncChunks: entry.asyncChunks,
 		filename: entry.filename,
-		library: entry.library && getRawLibrary(entry.library)
+		library: entry.library && getRawLibrary(entry.library),
+		layer: entry.layer
 	};
 }
@@ -0,0 +1,61 @@
+import http from "http";
+import https from "https";
+import path from "path";
+import { BuiltinPlugin, RawHttpResponse } from "@rspack/binding";
+import { BuiltinPluginName, RspackBuiltinPlugin } from "./base";
+import { HttpUriOptions } from "../config";
+import { Compiler } from "../Compiler";
+
+// redirects are followed by the plugin, to check each url with allowedUris
+function fetch(url: string): Promise<RawHttpResponse> {
+	return new Promise((resolve, reject) => {
+		const request = (url.startsWith("https:") ? https : http).get(url, res => {
+			const chunks: Buffer[] = [];
+			res.on("data", chunk => chunks.push(chunk));
+			res.on("end", () => {
+				const headers: Record<string, string> = {};
+				for (const [key, value] of Object.entries(res.headers)) {
+					if (value !== undefined) {
+						headers[key] = Array.isArray(value) ? value.join(", ") : value;
+					}
+				}
+				resolve({
+					status: res.statusCode ?? 0,
+					headers,
+					body: Buffer.concat(chunks)
+				});
+			});
+			res.on("error", reject);
+		});
+		request.on("error", reject);
+	});
+}
+
+export class HttpUriPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.HttpUriPlugin;
+
+	constructor(private options: HttpUriOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		const lockfileLocation =
+			this.options.lockfileLocation ??
+			path.resolve(compiler.context, "rspack.lock");
+		const cacheLocation =
+			this.options.cacheLocation === false
+				? undefined
+				: this.options.cacheLocation ?? `${lockfileLocation}.data`;
+		return {
+			name: this.name as any,
+			options: {
+				allowedUris: this.options.allowedUris,
+				lockfileLocation,
+				cacheLocation,
+				frozen: this.options.frozen ?? false,
+				upgrade: this.options.upgrade ?? false,
+				httpClient: fetch
+			}
+		};
+	}
+}
@@ -0,0 +1,30 @@
+import {
+	RawNormalModuleReplacementData,
+	RawNormalModuleReplacementPluginOptions
+} from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+/**
+ * `resource` is only available after resolving.
+ */
+export type NormalModuleReplacementData = RawNormalModuleReplacementData;
+
+export const NormalModuleReplacementPlugin = create(
+	BuiltinPluginName.NormalModuleReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newResource: string | ((data: NormalModuleReplacementData) => void)
+	): RawNormalModuleReplacementPluginOptions => {
+		return {
+			resourceRegExp,
+			newResource:
+				typeof newResource === "function"
+					? data => {
+							// Same as webpack, the data is modified in place
+							newResource(data);
+							return data;
+					  }
+					: newResource
+		};
+	}
+);
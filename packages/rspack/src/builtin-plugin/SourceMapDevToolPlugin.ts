@@ -0,0 +1,73 @@
+import {
+	RawSourceMapDevToolAppendFnCtx,
+	RawSourceMapDevToolPluginOptions
+} from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+type SourceMapDevToolRule = string | RegExp;
+type SourceMapDevToolRules = SourceMapDevToolRule | SourceMapDevToolRule[];
+export type SourceMapDevToolPluginOptions = {
+	/**
+	 * Template of the source map filename, inline source maps if not set.
+	 */
+	filename?: string | false;
+	/**
+	 * Comment appended to the asset, `[url]` is replaced with the source map url.
+	 * `false` to disable it.
+	 */
+	append?: boolean | string | ((ctx: RawSourceMapDevToolAppendFnCtx) => string);
+	/**
+	 * Value of `[namespace]` in the module filename templates.
+	 */
+	namespace?: string;
+	/**
+	 * If false, the source maps only contain line mappings.
+	 */
+	columns?: boolean;
+	/**
+	 * If true, `sourcesContent` is emptied.
+	 */
+	noSources?: boolean;
+	/**
+	 * Prefix of the source map url in the comment.
+	 */
+	publicPath?: string;
+	/**
+	 * Template of the `sources` entries, `[resourcePath]` by default.
+	 */
+	moduleFilenameTemplate?: string;
+	/**
+	 * Template used when `moduleFilenameTemplate` produces duplicated entries.
+	 */
+	fallbackModuleFilenameTemplate?: string;
+	/**
+	 * Set as `sourceRoot` of the source maps.
+	 */
+	sourceRoot?: string;
+
+	test?: SourceMapDevToolRules;
+	include?: SourceMapDevToolRules;
+	exclude?: SourceMapDevToolRules;
+};
+
+export const SourceMapDevToolPlugin = create(
+	BuiltinPluginName.SourceMapDevToolPlugin,
+	(
+		options: SourceMapDevToolPluginOptions = {}
+	): RawSourceMapDevToolPluginOptions => {
+		return {
+			filename: options.filename || undefined,
+			append: options.append,
+			namespace: options.namespace,
+			columns: options.columns,
+			noSources: options.noSources,
+			publicPath: options.publicPath,
+			moduleFilenameTemplate: options.moduleFilenameTemplate,
+			fallbackModuleFilenameTemplate: options.fallbackModuleFilenameTemplate,
+			sourceRoot: options.sourceRoot,
+			test: options.test,
+			include: options.include,
+			exclude: options.exclude
+		};
+	}
+);
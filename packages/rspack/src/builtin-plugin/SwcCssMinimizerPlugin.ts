@@ -1,6 +1,39 @@
+import { RawSwcCssMinimizerRspackPluginOptions } from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 
+type MinifyCondition = string | RegExp;
+type MinifyConditions = MinifyCondition | MinifyCondition[];
+export type SwcCssMinimizerRspackPluginOptions = {
+	/**
+	 * Comments matching the condition are kept at the top of the minimized asset.
+	 */
+	preserveComments?: RegExp;
+	/**
+	 * Comments matching the condition are extracted to `[file].LICENSE.txt`.
+	 */
+	extractComments?: RegExp;
+	/**
+	 * Browserslist queries used to add the vendor prefixes required by the targets.
+	 */
+	targets?: string[];
+
+	test?: MinifyConditions;
+	exclude?: MinifyConditions;
+	include?: MinifyConditions;
+};
+
 export const SwcCssMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcCssMinimizerRspackPlugin,
-	(options?: any /* TODO: extend more options */) => undefined
+	(
+		options?: SwcCssMinimizerRspackPluginOptions
+	): RawSwcCssMinimizerRspackPluginOptions => {
+		return {
+			preserveComments: options?.preserveComments,
+			extractComments: options?.extractComments,
+			targets: options?.targets,
+			test: options?.test,
+			include: options?.include,
+			exclude: options?.exclude
+		};
+	}
 );
@@ -1,8 +1,23 @@
-import { RawSwcJsMinimizerRspackPluginOptions } from "@rspack/binding";
+import {
+	RawExtractComments,
+	RawSwcJsMinimizerRspackPluginOptions
+} from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 
 type MinifyCondition = string | RegExp;
 type MinifyConditions = MinifyCondition | MinifyCondition[];
+type ExtractCommentsCondition = boolean | "some" | "all" | RegExp;
+type ExtractCommentsObject = {
+	condition?: ExtractCommentsCondition;
+	/**
+	 * Template of the file the comments are extracted to, `[file].LICENSE.txt[query]` by default
+	 */
+	filename?: string;
+	/**
+	 * Comment added to the minimized asset, `false` to disable it
+	 */
+	banner?: boolean | string;
+};
 export type SwcJsMinimizerRspackPluginOptions = {
 	/**
 	 * @deprecated Deprecated, move to `compress.passes`
@@ -32,7 +47,7 @@ export type SwcJsMinimizerRspackPluginOptions = {
 	 * @deprecated Deprecated, move to `format.ascii_only`
 	 */
 	asciiOnly?: boolean;
-	extractComments?: boolean | RegExp;
+	extractComments?: ExtractCommentsCondition | ExtractCommentsObject;
 	compress?: TerserCompressOptions | boolean;
 	mangle?: TerserMangleOptions | boolean;
 	format?: JsFormatOptions & ToSnakeCaseProperties<JsFormatOptions>;
@@ -313,15 +328,47 @@ function getRawFormatOptions(options?: SwcJsMinimizerRspackPluginOptions) {
 	return JSON.stringify(_inner());
 }
 
+function getRawExtractCommentsCondition(
+	condition: ExtractCommentsCondition | undefined
+): RawExtractComments["condition"] {
+	if (condition === undefined || typeof condition === "boolean") {
+		return "some";
+	}
+	return condition;
+}
+
+function getRawExtractComments(
+	options?: SwcJsMinimizerRspackPluginOptions
+): RawExtractComments | undefined {
+	const extractComments = options?.extractComments;
+	if (!extractComments) {
+		return undefined;
+	}
+	if (
+		typeof extractComments === "object" &&
+		!(extractComments instanceof RegExp)
+	) {
+		if (extractComments.condition === false) {
+			return undefined;
+		}
+		return {
+			condition: getRawExtractCommentsCondition(extractComments.condition),
+			filename: extractComments.filename,
+			banner: extractComments.banner
+		};
+	}
+	return {
+		condition: getRawExtractCommentsCondition(extractComments)
+	};
+}
+
 export const SwcJsMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcJsMinimizerRspackPlugin,
 	(
 		options?: SwcJsMinimizerRspackPluginOptions
 	): RawSwcJsMinimizerRspackPluginOptions => {
 		return {
-			extractComments: options?.extractComments
-				? String(options.extractComments)
-				: undefined,
+			extractComments: getRawExtractComments(options),
 			compress: getRawCompressOptions(options),
 			mangle: getRawMangleOptions(options),
 			format: getRawFormatOptions(options),
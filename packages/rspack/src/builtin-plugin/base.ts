@@ -6,6 +6,9 @@ export enum BuiltinPluginName {
 	DefinePlugin = "DefinePlugin",
 	ProvidePlugin = "ProvidePlugin",
 	BannerPlugin = "BannerPlugin",
+	IgnorePlugin = "IgnorePlugin",
+	NormalModuleReplacementPlugin = "NormalModuleReplacementPlugin",
+	ContextReplacementPlugin = "ContextReplacementPlugin",
 	ProgressPlugin = "ProgressPlugin",
 	EntryPlugin = "EntryPlugin",
 	ExternalsPlugin = "ExternalsPlugin",
@@ -33,7 +36,9 @@ export enum BuiltinPluginName {
 	ContainerReferencePlugin = "ContainerReferencePlugin",
 	ModuleFederationRuntimePlugin = "ModuleFederationRuntimePlugin",
 	ProvideSharedPlugin = "ProvideSharedPlugin",
-	ConsumeSharedPlugin = "ConsumeSharedPlugin"
+	ConsumeSharedPlugin = "ConsumeSharedPlugin",
+	HttpUriPlugin = "HttpUriPlugin",
+	SourceMapDevToolPlugin = "SourceMapDevToolPlugin"
 }
 
 export abstract class RspackBuiltinPlugin implements RspackPluginInstance {
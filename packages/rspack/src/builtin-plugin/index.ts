@@ -3,12 +3,16 @@ export { RspackBuiltinPlugin } from "./base";
 export * from "./DefinePlugin";
 export * from "./ProvidePlugin";
 export * from "./BannerPlugin";
+export * from "./IgnorePlugin";
+export * from "./NormalModuleReplacementPlugin";
+export * from "./ContextReplacementPlugin";
 export * from "./ProgressPlugin";
 export * from "./EntryPlugin";
 export * from "./ExternalsPlugin";
 export * from "./NodeTargetPlugin";
 export * from "./ElectronTargetPlugin";
 export * from "./HttpExternalsRspackPlugin";
+export * from "./HttpUriPlugin";
 export * from "./EnableChunkLoadingPlugin";
 export * from "./EnableLibraryPlugin";
 export * from "./EnableWasmLoadingPlugin";
@@ -20,6 +24,7 @@ export * from "./WebWorkerTemplatePlugin";
 export * from "./LimitChunkCountPlugin";
 export * from "./MergeDuplicateChunksPlugin";
 export * from "./SplitChunksPlugin";
+export * from "./SourceMapDevToolPlugin";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
@@ -8,6 +8,7 @@ import type {
 	RawAssetGeneratorOptions,
 	RawParserOptions,
 	RawAssetParserOptions,
+	RawJavascriptParserOptions,
 	RawAssetParserDataUrl,
 	RawAssetGeneratorDataUrl,
 	RawAssetInlineGeneratorOptions,
@@ -26,6 +27,7 @@ import { Compiler } from "../Compiler";
 import { normalizeStatsPreset } from "../Stats";
 import { deprecatedWarn, isNil } from "../util";
 import { parseResource } from "../util/identifier";
+import { isConcatenateModulesExplicit } from "./defaults";
 import {
 	ComposeJsUseOptions,
 	LoaderContext,
@@ -59,7 +61,9 @@ import {
 	JavascriptParserOptions,
 	LibraryName,
 	EntryRuntime,
-	ChunkLoading
+	ChunkLoading,
+	Clean,
+	CacheOptions
 } from "./zod";
 import {
 	ExperimentsNormalized,
@@ -108,20 +112,11 @@ export const getRawOptions = (
 			hot: options.devServer?.hot ?? false
 		},
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		cache: getRawCacheOptions(options.cache),
 		experiments,
 		node: getRawNode(options.node),
+		// same as webpack, AMD is enabled unless `amd` is `false`
+		amd: options.amd === false ? undefined : JSON.stringify(options.amd || {}),
 		profile: options.profile!,
 		// TODO: remove this
 		builtins: options.builtins as any
@@ -192,6 +187,20 @@ function getRawCrossOriginLoading(
 	return { type: "string", stringPayload: crossOriginLoading };
 }
 
+function getRawClean(clean: Clean): RawOptions["output"]["clean"] {
+	if (!clean) {
+		return undefined;
+	}
+	if (clean === true) {
+		return { dry: false };
+	}
+	return {
+		dry: clean.dry ?? false,
+		keep:
+			clean.keep === undefined ? undefined : getRawRuleSetCondition(clean.keep)
+	};
+}
+
 function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	const chunkLoading = output.chunkLoading!;
 	const wasmLoading = output.wasmLoading!;
@@ -200,7 +209,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	return {
 		path: output.path!,
 		publicPath: output.publicPath!,
-		clean: output.clean!,
+		clean: getRawClean(output.clean!),
 		assetModuleFilename: output.assetModuleFilename!,
 		filename: output.filename!,
 		chunkFilename: output.chunkFilename!,
@@ -400,6 +409,9 @@ const getRawModuleRule = (
 		include: rule.include ? getRawRuleSetCondition(rule.include) : undefined,
 		exclude: rule.exclude ? getRawRuleSetCondition(rule.exclude) : undefined,
 		issuer: rule.issuer ? getRawRuleSetCondition(rule.issuer) : undefined,
+		issuerLayer: rule.issuerLayer
+			? getRawRuleSetCondition(rule.issuerLayer)
+			: undefined,
 		dependency: rule.dependency
 			? getRawRuleSetCondition(rule.dependency)
 			: undefined,
@@ -433,6 +445,7 @@ const getRawModuleRule = (
 						)
 				  },
 		type: rule.type,
+		layer: rule.layer,
 		parser: rule.parser
 			? getRawParserOptions(rule.parser, rule.type ?? "javascript/auto")
 			: undefined,
@@ -573,7 +586,7 @@ function getRawParserOptions(
 			type: "asset",
 			asset: getRawAssetParserOptions(parser)
 		};
-	} else if (type === "javascript") {
+	} else if (type === "javascript" || type.startsWith("javascript/")) {
 		return {
 			type: "javascript",
 			javascript: getRawJavascriptParserOptions(parser)
@@ -584,9 +597,46 @@ function getRawParserOptions(
 	};
 }
 
-function getRawJavascriptParserOptions(parser: JavascriptParserOptions) {
+function getRawJavascriptParserOptions(
+	parser: JavascriptParserOptions
+): RawJavascriptParserOptions {
+	type ExportsPresence = RawJavascriptParserOptions["exportsPresence"];
+	const getRawExportsPresence = (
+		value: JavascriptParserOptions["exportsPresence"]
+	): ExportsPresence =>
+		value === undefined ? undefined : (String(value) as ExportsPresence);
+	const { worker, url, wrappedContextRegExp } = parser;
 	return {
-		dynamicImportMode: parser.dynamicImportMode ?? "lazy"
+		dynamicImportMode: parser.dynamicImportMode ?? "lazy",
+		url:
+			url === undefined
+				? undefined
+				: (String(url) as RawJavascriptParserOptions["url"]),
+		worker:
+			worker === undefined
+				? undefined
+				: worker === true
+				? ["..."]
+				: worker === false
+				? []
+				: worker,
+		importMeta: parser.importMeta,
+		exprContextCritical: parser.exprContextCritical,
+		unknownContextCritical: parser.unknownContextCritical,
+		wrappedContextCritical: parser.wrappedContextCritical,
+		wrappedContextRegExp: wrappedContextRegExp
+			? {
+					source: wrappedContextRegExp.source,
+					flags: wrappedContextRegExp.flags
+			  }
+			: undefined,
+		strictExportPresence: parser.strictExportPresence,
+		exportsPresence: getRawExportsPresence(parser.exportsPresence),
+		importExportsPresence: getRawExportsPresence(parser.importExportsPresence),
+		reexportExportsPresence: getRawExportsPresence(
+			parser.reexportExportsPresence
+		),
+		commonjsMagicComments: parser.commonjsMagicComments
 	};
 }
 
@@ -718,7 +768,9 @@ function getRawOptimization(
 		usedExports: String(optimization.usedExports),
 		providedExports: optimization.providedExports,
 		innerGraph: optimization.innerGraph,
-		mangleExports: String(optimization.mangleExports)
+		mangleExports: String(optimization.mangleExports),
+		concatenateModules: !!optimization.concatenateModules,
+		concatenateModulesExplicit: isConcatenateModulesExplicit(optimization)
 	};
 }
 
@@ -790,8 +842,13 @@ export function toRawSplitChunksOptions(
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
-	assert(!isNil(resolve) && !isNil(module));
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
+	assert(
+		!isNil(resolve) &&
+			!isNil(module) &&
+			!isNil(immutablePaths) &&
+			!isNil(managedPaths)
+	);
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
 	assert(
@@ -808,7 +865,99 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths: immutablePaths.map(getRawSnapshotPathMatcher),
+		managedPaths: managedPaths.map(getRawSnapshotPathMatcher)
+	};
+}
+
+function getRawCacheOptions(cache: CacheOptions): RawOptions["cache"] {
+	const raw: RawOptions["cache"] = {
+		type: cache ? "memory" : "disable",
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		name: "",
+		version: "",
+		hashAlgorithm: "md4",
+		compression: "false",
+		idleTimeout: 60000,
+		idleTimeoutForInitialStore: 5000,
+		idleTimeoutAfterLargeChanges: 1000
+	};
+	if (typeof cache !== "object") {
+		return raw;
+	}
+	if (cache.type === "memory") {
+		return {
+			...raw,
+			maxGenerations: cache.maxGenerations ?? 0
+		};
+	}
+	const {
+		cacheDirectory,
+		cacheLocation,
+		name,
+		version,
+		hashAlgorithm,
+		compression,
+		profile,
+		idleTimeout,
+		idleTimeoutForInitialStore,
+		idleTimeoutAfterLargeChanges,
+		maxAge,
+		buildDependencies
+	} = cache;
+	assert(
+		!isNil(cacheDirectory) &&
+			!isNil(cacheLocation) &&
+			!isNil(name) &&
+			!isNil(version) &&
+			!isNil(hashAlgorithm) &&
+			!isNil(compression) &&
+			!isNil(profile) &&
+			!isNil(idleTimeout) &&
+			!isNil(idleTimeoutForInitialStore) &&
+			!isNil(idleTimeoutAfterLargeChanges) &&
+			!isNil(maxAge) &&
+			!isNil(buildDependencies)
+	);
+	return {
+		...raw,
+		type: "filesystem",
+		maxAge,
+		profile,
+		buildDependencies: Object.values(buildDependencies).flat(),
+		cacheDirectory,
+		cacheLocation,
+		name,
+		version,
+		hashAlgorithm,
+		compression: String(compression),
+		idleTimeout,
+		idleTimeoutForInitialStore,
+		idleTimeoutAfterLargeChanges
+	};
+}
+
+function getRawSnapshotPathMatcher(
+	path: string | RegExp
+): RawOptions["snapshot"]["managedPaths"][number] {
+	if (path instanceof RegExp) {
+		return {
+			type: "regexp",
+			regexpMatcher: {
+				source: path.source,
+				flags: path.flags
+			}
+		};
+	}
+	return {
+		type: "string",
+		stringMatcher: path
 	};
 }
 
@@ -818,18 +967,22 @@ function getRawExperiments(
 	const {
 		lazyCompilation,
 		incrementalRebuild,
+		syncWebAssembly,
 		asyncWebAssembly,
 		newSplitChunks,
 		topLevelAwait,
+		layers,
 		css,
 		rspackFuture
 	} = experiments;
 	assert(
 		!isNil(lazyCompilation) &&
 			!isNil(incrementalRebuild) &&
+			!isNil(syncWebAssembly) &&
 			!isNil(asyncWebAssembly) &&
 			!isNil(newSplitChunks) &&
 			!isNil(topLevelAwait) &&
+			!isNil(layers) &&
 			!isNil(css) &&
 			!isNil(rspackFuture)
 	);
@@ -837,9 +990,11 @@ function getRawExperiments(
 	return {
 		lazyCompilation,
 		incrementalRebuild: getRawIncrementalRebuild(incrementalRebuild),
+		syncWebAssembly,
 		asyncWebAssembly,
 		newSplitChunks,
 		topLevelAwait,
+		layers,
 		css,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
 	};
@@ -21,6 +21,7 @@ import {
 import type {
 	Context,
 	ExternalsPresets,
+	FileCacheOptions,
 	InfrastructureLogging,
 	JavascriptParserOptions,
 	Library,
@@ -77,15 +78,25 @@ export const applyRspackOptionsDefaults = (
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
+	if (
+		typeof options.cache === "object" &&
+		options.cache.type === "filesystem"
+	) {
+		applyFileCacheDefaults(options.cache, {
+			name: options.name,
+			mode,
+			context: options.context!
+		});
+	}
 
 	applyExperimentsDefaults(options.experiments, {
-		cache: options.cache!
+		cache: !!options.cache
 	});
 
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		disableTransformByDefault:
 			options.experiments.rspackFuture!.disableTransformByDefault!,
@@ -155,15 +166,39 @@ const applyInfrastructureLoggingDefaults = (
 	D(infrastructureLogging, "appendOnly", !tty);
 };
 
+const applyFileCacheDefaults = (
+	cache: FileCacheOptions,
+	{ name, mode, context }: { name?: string; mode?: Mode; context: Context }
+) => {
+	F(cache, "name", () => `${name ? `${name}-` : ""}${mode}`);
+	D(cache, "version", "");
+	F(cache, "cacheDirectory", () =>
+		path.resolve(context, "node_modules/.cache/rspack")
+	);
+	F(cache, "cacheLocation", () =>
+		path.resolve(cache.cacheDirectory!, cache.name!)
+	);
+	D(cache, "hashAlgorithm", "md4");
+	D(cache, "compression", false);
+	D(cache, "profile", false);
+	D(cache, "idleTimeout", 60000);
+	D(cache, "idleTimeoutForInitialStore", 5000);
+	D(cache, "idleTimeoutAfterLargeChanges", 1000);
+	D(cache, "maxAge", 1000 * 60 * 60 * 24 * 30); // 1 month
+	D(cache, "buildDependencies", {});
+};
+
 const applyExperimentsDefaults = (
 	experiments: ExperimentsNormalized,
 	{ cache }: { cache: boolean }
 ) => {
 	D(experiments, "lazyCompilation", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "asyncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
+	D(experiments, "layers", false);
 
 	D(experiments, "incrementalRebuild", {});
 	if (typeof experiments.incrementalRebuild === "object") {
@@ -179,6 +214,11 @@ const applyExperimentsDefaults = (
 		// TODO: use logger to warn user enable cache for incrementalRebuild.make
 	}
 
+	if (typeof experiments.buildHttp === "object") {
+		D(experiments.buildHttp, "frozen", false);
+		D(experiments.buildHttp, "upgrade", false);
+	}
+
 	D(experiments, "rspackFuture", {});
 	if (typeof experiments.rspackFuture === "object") {
 		D(experiments.rspackFuture, "newResolver", true);
@@ -201,6 +241,8 @@ const applySnapshotDefaults = (
 			? { timestamp: true, hash: true }
 			: { timestamp: true, hash: false }
 	);
+	F(snapshot, "immutablePaths", () => []);
+	F(snapshot, "managedPaths", () => []);
 };
 
 const applyJavascriptParserOptionsDefaults = (
@@ -212,10 +254,12 @@ const applyJavascriptParserOptionsDefaults = (
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
+		syncWebAssembly,
 		asyncWebAssembly,
 		css,
 		disableTransformByDefault
 	}: {
+		syncWebAssembly: boolean;
 		asyncWebAssembly: boolean;
 		css: boolean;
 		disableTransformByDefault: boolean;
@@ -332,6 +376,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -733,6 +799,13 @@ const applyNodeDefaults = (
 	});
 };
 
+// the `optimization` objects whose `concatenateModules` is defaulted by the mode, it's
+// only a warning to ignore `concatenateModules` when it's set explicitly
+const defaultedConcatenateModules = new WeakSet<Optimization>();
+
+export const isConcatenateModulesExplicit = (optimization: Optimization) =>
+	!defaultedConcatenateModules.has(optimization);
+
 const applyOptimizationDefaults = (
 	optimization: Optimization,
 	{ production, development }: { production: boolean; development: boolean }
@@ -750,6 +823,10 @@ const applyOptimizationDefaults = (
 	D(optimization, "providedExports", true);
 	D(optimization, "usedExports", production);
 	D(optimization, "innerGraph", production);
+	if (optimization.concatenateModules === undefined) {
+		defaultedConcatenateModules.add(optimization);
+	}
+	D(optimization, "concatenateModules", production);
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "realContentHash", production);
 	D(optimization, "minimize", production);
@@ -36,11 +36,13 @@ import type {
 	WatchOptions,
 	DevServer,
 	Profile,
+	Amd,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
 	PublicPath,
 	EntryFilename,
+	Layer,
 	Path,
 	Clean,
 	Filename,
@@ -73,6 +75,7 @@ import type {
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	IncrementalRebuildOptions,
+	HttpUriOptions,
 	RspackFutureOptions,
 	HotUpdateGlobal,
 	ScriptType
@@ -259,9 +262,13 @@ export const getNormalizedRspackOptions = (
 			module: optionalNestedConfig(snapshot.module, module => ({
 				timestamp: module.timestamp,
 				hash: module.hash
-			}))
+			})),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p])
 		})),
-		cache: optionalNestedConfig(config.cache, cache => cache),
+		cache: optionalNestedConfig(config.cache, cache =>
+			typeof cache === "object" ? { ...cache } : cache
+		),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
 				return {
@@ -304,11 +311,15 @@ export const getNormalizedRspackOptions = (
 			incrementalRebuild: optionalNestedConfig(
 				experiments.incrementalRebuild,
 				options => (options === true ? {} : options)
+			),
+			buildHttp: optionalNestedConfig(experiments.buildHttp, options =>
+				Array.isArray(options) ? { allowedUris: options } : options
 			)
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
+		amd: config.amd,
 		profile: config.profile,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
@@ -351,7 +362,8 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 				chunkLoading: value.chunkLoading,
 				asyncChunks: value.asyncChunks,
 				filename: value.filename,
-				library: value.library
+				library: value.library,
+				layer: value.layer
 			};
 		}
 	}
@@ -438,6 +450,7 @@ export interface EntryDescriptionNormalized {
 	baseUri?: string;
 	filename?: EntryFilename;
 	library?: LibraryOptions;
+	layer?: Layer;
 }
 
 export interface OutputNormalized {
@@ -491,11 +504,14 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: boolean;
 	incrementalRebuild?: false | IncrementalRebuildOptions;
+	syncWebAssembly?: boolean;
 	asyncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
+	layers?: boolean;
 	css?: boolean;
+	buildHttp?: HttpUriOptions;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
 }
@@ -538,6 +554,7 @@ export interface RspackOptionsNormalized {
 	watchOptions: WatchOptions;
 	devServer?: DevServer;
 	ignoreWarnings?: IgnoreWarningsNormalized;
+	amd?: Amd;
 	profile?: Profile;
 	builtins: Builtins;
 }
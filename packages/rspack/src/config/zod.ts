@@ -145,6 +145,9 @@ export type EntryRuntime = z.infer<typeof entryRuntime>;
 const entryItem = z.string().or(z.array(z.string()));
 export type EntryItem = z.infer<typeof entryItem>;
 
+const layer = z.string();
+export type Layer = z.infer<typeof layer>;
+
 const entryDescription = z.strictObject({
 	import: entryItem,
 	runtime: entryRuntime.optional(),
@@ -154,7 +157,8 @@ const entryDescription = z.strictObject({
 	asyncChunks: asyncChunks.optional(),
 	wasmLoading: wasmLoading.optional(),
 	filename: entryFilename.optional(),
-	library: libraryOptions.optional()
+	library: libraryOptions.optional(),
+	layer: layer.optional()
 });
 export type EntryDescription = z.infer<typeof entryDescription>;
 
@@ -215,7 +219,16 @@ export type ChunkLoadingGlobal = z.infer<typeof chunkLoadingGlobal>;
 const enabledLibraryTypes = z.array(libraryType);
 export type EnabledLibraryTypes = z.infer<typeof enabledLibraryTypes>;
 
-const clean = z.boolean();
+const clean = z.boolean().or(
+	z.strictObject({
+		dry: z.boolean().optional(),
+		keep: z
+			.instanceof(RegExp)
+			.or(z.string())
+			.or(z.function().args(z.string()).returns(z.boolean()))
+			.optional()
+	})
+);
 export type Clean = z.infer<typeof clean>;
 
 const outputModule = z.boolean();
@@ -431,6 +444,7 @@ const baseRuleSetRule = z.strictObject({
 	exclude: ruleSetCondition.optional(),
 	include: ruleSetCondition.optional(),
 	issuer: ruleSetCondition.optional(),
+	issuerLayer: ruleSetCondition.optional(),
 	dependency: ruleSetCondition.optional(),
 	resource: ruleSetCondition.optional(),
 	resourceFragment: ruleSetCondition.optional(),
@@ -439,6 +453,7 @@ const baseRuleSetRule = z.strictObject({
 	mimetype: ruleSetCondition.optional(),
 	descriptionData: z.record(ruleSetCondition).optional(),
 	type: z.string().optional(),
+	layer: layer.optional(),
 	loader: ruleSetLoader.optional(),
 	options: ruleSetLoaderOptions.optional(),
 	use: ruleSetUse.optional(),
@@ -477,11 +492,24 @@ const assetParserOptions = z.strictObject({
 });
 export type AssetParserOptions = z.infer<typeof assetParserOptions>;
 
-//TODO: "weak", "lazy-once"
-const dynamicImportMode = z.enum(["eager", "lazy"]);
+const dynamicImportMode = z.enum(["eager", "lazy", "weak", "lazy-once"]);
+
+const exportsPresence = z.enum(["error", "warn", "auto"]).or(z.literal(false));
 
 const javascriptParserOptions = z.strictObject({
-	dynamicImportMode: dynamicImportMode.optional()
+	dynamicImportMode: dynamicImportMode.optional(),
+	url: z.literal("relative").or(z.boolean()).optional(),
+	worker: z.array(z.string()).or(z.boolean()).optional(),
+	importMeta: z.boolean().optional(),
+	exprContextCritical: z.boolean().optional(),
+	unknownContextCritical: z.boolean().optional(),
+	wrappedContextCritical: z.boolean().optional(),
+	wrappedContextRegExp: z.instanceof(RegExp).optional(),
+	strictExportPresence: z.boolean().optional(),
+	exportsPresence: exportsPresence.optional(),
+	importExportsPresence: exportsPresence.optional(),
+	reexportExportsPresence: exportsPresence.optional(),
+	commonjsMagicComments: z.boolean().optional()
 });
 export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
 
@@ -822,13 +850,38 @@ const snapshotOptions = z.strictObject({
 			hash: z.boolean().optional(),
 			timestamp: z.boolean().optional()
 		})
-		.optional()
+		.optional(),
+	immutablePaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+	managedPaths: z.string().or(z.instanceof(RegExp)).array().optional()
 });
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
 
 //#region Cache
-const cacheOptions = z.boolean();
+const memoryCacheOptions = z.strictObject({
+	type: z.literal("memory"),
+	maxGenerations: z.number().optional()
+});
+export type MemoryCacheOptions = z.infer<typeof memoryCacheOptions>;
+
+const fileCacheOptions = z.strictObject({
+	type: z.literal("filesystem"),
+	buildDependencies: z.record(z.array(z.string())).optional(),
+	cacheDirectory: z.string().optional(),
+	cacheLocation: z.string().optional(),
+	compression: z.literal(false).or(z.enum(["gzip", "brotli"])).optional(),
+	hashAlgorithm: z.string().optional(),
+	idleTimeout: z.number().optional(),
+	idleTimeoutAfterLargeChanges: z.number().optional(),
+	idleTimeoutForInitialStore: z.number().optional(),
+	maxAge: z.number().optional(),
+	name: z.string().optional(),
+	profile: z.boolean().optional(),
+	version: z.string().optional()
+});
+export type FileCacheOptions = z.infer<typeof fileCacheOptions>;
+
+const cacheOptions = z.boolean().or(memoryCacheOptions).or(fileCacheOptions);
 export type CacheOptions = z.infer<typeof cacheOptions>;
 //#endregion
 
@@ -862,6 +915,7 @@ const statsOptions = z.strictObject({
 	modulesSpace: z.number().optional(),
 	nestedModules: z.boolean().optional(),
 	source: z.boolean().optional(),
+	optimizationBailout: z.boolean().optional(),
 	logging: z
 		.enum(["none", "error", "warn", "info", "log", "verbose"])
 		.or(z.boolean())
@@ -951,6 +1005,7 @@ const optimizationSplitChunksCacheGroup = z.strictObject({
 	filename: z.string().optional(),
 	reuseExistingChunk: z.boolean().optional(),
 	type: z.string().or(z.instanceof(RegExp)).optional(),
+	layer: z.string().or(z.instanceof(RegExp)).optional(),
 	idHint: z.string().optional(),
 	...sharedOptimizationSplitChunksCacheGroup
 });
@@ -996,6 +1051,7 @@ const optimization = z.strictObject({
 	innerGraph: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
+	concatenateModules: z.boolean().optional(),
 	nodeEnv: z.union([z.string(), z.literal(false)]).optional()
 });
 export type Optimization = z.infer<typeof optimization>;
@@ -1032,6 +1088,15 @@ const rspackFutureOptions = z.strictObject({
 });
 export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 
+const httpUriOptions = z.strictObject({
+	allowedUris: z.array(z.string().or(z.instanceof(RegExp))),
+	cacheLocation: z.string().or(z.literal(false)).optional(),
+	frozen: z.boolean().optional(),
+	lockfileLocation: z.string().optional(),
+	upgrade: z.boolean().optional()
+});
+export type HttpUriOptions = z.infer<typeof httpUriOptions>;
+
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional(),
 	incrementalRebuild: z
@@ -1049,9 +1114,11 @@ const experiments = z.strictObject({
 			}
 			return true;
 		}),
+	syncWebAssembly: z.boolean().optional(),
 	asyncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
+	layers: z.boolean().optional(),
 	newSplitChunks: z
 		.boolean()
 		.optional()
@@ -1070,6 +1137,7 @@ const experiments = z.strictObject({
 			return true;
 		}),
 	css: z.boolean().optional(),
+	buildHttp: httpUriOptions.or(httpUriOptions.shape.allowedUris).optional(),
 	futureDefaults: z.boolean().optional(),
 	rspackFuture: rspackFutureOptions.optional()
 });
@@ -1117,6 +1185,11 @@ const ignoreWarnings = z
 export type IgnoreWarnings = z.infer<typeof ignoreWarnings>;
 //#endregion
 
+//#region Amd
+const amd = z.literal(false).or(z.record(z.any()));
+export type Amd = z.infer<typeof amd>;
+//#endregion
+
 //#region Profile
 const profile = z.boolean();
 export type Profile = z.infer<typeof profile>;
@@ -1155,6 +1228,7 @@ export const rspackOptions = z.strictObject({
 	devServer: devServer.optional(),
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
+	amd: amd.optional(),
 	profile: profile.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
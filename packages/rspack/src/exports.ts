@@ -81,6 +81,15 @@ export type { ProvidePluginOptions } from "./builtin-plugin";
 export { DefinePlugin } from "./builtin-plugin";
 export type { DefinePluginOptions } from "./builtin-plugin";
 
+export { IgnorePlugin } from "./builtin-plugin";
+export type { IgnorePluginOptions } from "./builtin-plugin";
+
+export { NormalModuleReplacementPlugin } from "./builtin-plugin";
+export type { NormalModuleReplacementData } from "./builtin-plugin";
+
+export { ContextReplacementPlugin } from "./builtin-plugin";
+export type { ContextReplacementData } from "./builtin-plugin";
+
 export { ProgressPlugin } from "./builtin-plugin";
 export type { ProgressPluginArgument } from "./builtin-plugin";
 
@@ -91,6 +100,9 @@ export { ExternalsPlugin } from "./builtin-plugin";
 
 export { HotModuleReplacementPlugin } from "./builtin-plugin";
 
+export { SourceMapDevToolPlugin } from "./builtin-plugin";
+export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
+
 export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
@@ -119,6 +131,9 @@ export const webworker = { WebWorkerTemplatePlugin };
 import { LimitChunkCountPlugin } from "./builtin-plugin";
 export const optimize = { LimitChunkCountPlugin };
 
+import { HttpUriPlugin } from "./builtin-plugin";
+export const experiments = { schemes: { HttpUriPlugin } };
+
 import { ContainerPlugin } from "./container/ContainerPlugin";
 import { ContainerReferencePlugin } from "./container/ContainerReferencePlugin";
 import { ModuleFederationPlugin } from "./container/ModuleFederationPlugin";
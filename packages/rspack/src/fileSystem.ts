@@ -1,3 +1,4 @@
+import type { NodeFsStats } from "@rspack/binding";
 import { join } from "path";
 
 export interface ThreadsafeWritableNodeFS {
@@ -6,6 +7,11 @@ export interface ThreadsafeWritableNodeFS {
 	mkdir: (...args: any[]) => any;
 	mkdirp: (...args: any[]) => any;
 	removeDirAll: (...args: any[]) => any;
+	removeDir: (...args: any[]) => any;
+	readDir: (...args: any[]) => any;
+	stat: (...args: any[]) => any;
+	lstat: (...args: any[]) => any;
+	rename: (...args: any[]) => any;
 }
 
 function createThreadsafeNodeFSFromRaw(
@@ -22,10 +28,23 @@ function createThreadsafeNodeFSFromRaw(
 		removeDirAll: dir => {
 			// memfs don't support rmSync
 			rmrfBuild(fs)(dir);
-		}
+		},
+		removeDir: dir => fs.rmdirSync(dir),
+		readDir: dir => fs.readdirSync(dir),
+		stat: file => toNodeFsStats(fs.statSync(file)),
+		lstat: file => toNodeFsStats(fs.lstatSync(file)),
+		rename: (from, to) => fs.renameSync(from, to)
 	};
 }
 
+const toNodeFsStats = (stats: import("fs").Stats): NodeFsStats => ({
+	isFile: stats.isFile(),
+	isDirectory: stats.isDirectory(),
+	isSymbolicLink: stats.isSymbolicLink(),
+	size: stats.size,
+	mtimeMs: stats.mtimeMs
+});
+
 const rmrfBuild = (fs: typeof import("fs")) => {
 	const rmrf = (dir: string) => {
 		if (fs.existsSync(dir)) {
@@ -31,6 +31,7 @@ import {
 	EnableWasmLoadingPlugin,
 	ExternalsPlugin,
 	HttpExternalsRspackPlugin,
+	HttpUriPlugin,
 	ModuleChunkFormatPlugin,
 	NodeTargetPlugin,
 	DefinePlugin,
@@ -223,6 +224,10 @@ export class RspackOptionsApply {
 			options.output.workerPublicPath!
 		).apply(compiler);
 
+		if (options.experiments.buildHttp) {
+			new HttpUriPlugin(options.experiments.buildHttp).apply(compiler);
+		}
+
 		new DefaultStatsFactoryPlugin().apply(compiler);
 		new DefaultStatsPrinterPlugin().apply(compiler);
 
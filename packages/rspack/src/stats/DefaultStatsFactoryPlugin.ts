@@ -55,6 +55,7 @@ const ITEM_NAMES: Record<string, string> = {
 	"compilation.warnings[]": "warning",
 	"chunk.modules[]": "module",
 	"chunk.rootModules[]": "module",
+	"module.modules[]": "module",
 	"chunk.origins[]": "chunkOrigin",
 	"compilation.chunks[]": "chunk",
 	"compilation.assets[]": "asset",
@@ -780,6 +781,19 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		},
 		source: (object, module) => {
 			object.source = module.source;
+		},
+		nestedModules: (object, module, context, options, factory) => {
+			const { type } = context;
+			if (module.modules) {
+				object.modules = factory.create(
+					`${type.slice(0, -8)}.modules`,
+					module.modules,
+					context
+				);
+			}
+		},
+		optimizationBailout: (object, module) => {
+			object.optimizationBailout = module.optimizationBailout;
 		}
 	},
 	profile: {
@@ -108,8 +108,10 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
@@ -154,8 +156,10 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
@@ -299,6 +303,46 @@ describe("snapshots", () => {
 	`)
 	);
 
+	test("sync wasm", { experiments: { syncWebAssembly: true } }, e =>
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-     "syncWebAssembly": false,
+		+     "syncWebAssembly": true,
+		@@ ... @@
+		+       },
+		+       Object {
+		+         "rules": Array [
+		+           Object {
+		+             "descriptionData": Object {
+		+               "type": "module",
+		+             },
+		+             "resolve": Object {
+		+               "fullySpecified": true,
+		+             },
+		+           },
+		+         ],
+		+         "test": /\\.wasm$/i,
+		+         "type": "webassembly/sync",
+		+       },
+		+       Object {
+		+         "mimetype": "application/wasm",
+		+         "rules": Array [
+		+           Object {
+		+             "descriptionData": Object {
+		+               "type": "module",
+		+             },
+		+             "resolve": Object {
+		+               "fullySpecified": true,
+		+             },
+		+           },
+		+         ],
+		+         "type": "webassembly/sync",
+	`)
+	);
+
 	test(
 		"both wasm",
 		{ experiments: { syncWebAssembly: true, asyncWebAssembly: true } },
@@ -311,6 +355,7 @@ describe("snapshots", () => {
 			-     "asyncWebAssembly": false,
 			+     "asyncWebAssembly": true,
 			@@ ... @@
+			-     "syncWebAssembly": false,
 			+     "syncWebAssembly": true,
 			@@ ... @@
 			+       },
@@ -930,7 +975,19 @@ describe("snapshots", () => {
 		@@ ... @@
 		-   "cache": false,
 		+   "cache": Object {
+		+     "buildDependencies": Object {},
+		+     "cacheDirectory": "<cwd>/node_modules/.cache/rspack",
+		+     "cacheLocation": "<cwd>/node_modules/.cache/rspack/none",
+		+     "compression": false,
+		+     "hashAlgorithm": "md4",
+		+     "idleTimeout": 60000,
+		+     "idleTimeoutAfterLargeChanges": 1000,
+		+     "idleTimeoutForInitialStore": 5000,
+		+     "maxAge": 2592000000,
+		+     "name": "none",
+		+     "profile": false,
 		+     "type": "filesystem",
+		+     "version": "",
 		+   },
 		@@ ... @@
 		-       "make": false,
@@ -948,7 +1005,19 @@ describe("snapshots", () => {
 			@@ ... @@
 			-   "cache": false,
 			+   "cache": Object {
+			+     "buildDependencies": Object {},
+			+     "cacheDirectory": "<cwd>/node_modules/.cache/rspack",
+			+     "cacheLocation": "<cwd>/node_modules/.cache/rspack/development",
+			+     "compression": false,
+			+     "hashAlgorithm": "md4",
+			+     "idleTimeout": 60000,
+			+     "idleTimeoutAfterLargeChanges": 1000,
+			+     "idleTimeoutForInitialStore": 5000,
+			+     "maxAge": 2592000000,
+			+     "name": "development",
+			+     "profile": false,
 			+     "type": "filesystem",
+			+     "version": "",
 			+   },
 			@@ ... @@
 			-       "make": false,
@@ -0,0 +1,35 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		clean: {
+			dry: true
+		}
+	},
+	plugins: [
+		compiler => {
+			let once = true;
+			compiler.hooks.thisCompilation.tap("Test", compilation => {
+				compilation.hooks.processAssets.tap("Test", () => {
+					if (once) {
+						const outputPath = compiler.outputPath;
+						const customDir = path.join(outputPath, "this/dir/should/not/be/removed");
+						fs.mkdirSync(customDir, { recursive: true });
+						fs.writeFileSync(path.join(customDir, "file.ext"), "");
+						once = false;
+					}
+				});
+			});
+			compiler.hooks.afterEmit.tap("Test", compilation => {
+				const outputPath = compiler.outputPath;
+				expect(
+					fs.existsSync(
+						path.join(outputPath, "this/dir/should/not/be/removed/file.ext")
+					)
+				).toBe(true);
+			});
+		}
+	]
+};
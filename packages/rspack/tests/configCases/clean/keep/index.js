@@ -0,0 +1 @@
+it("should compile and run the test", function () {});
@@ -0,0 +1,56 @@
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		clean: {
+			keep: /ignored\/dir/
+		}
+	},
+	plugins: [
+		compiler => {
+			let once = true;
+			compiler.hooks.thisCompilation.tap("Test", compilation => {
+				compilation.hooks.processAssets.tap("Test", () => {
+					if (once) {
+						const outputPath = compiler.outputPath;
+						const customDir = path.join(outputPath, "this/dir/should/be/removed");
+						const ignoredDir = path.join(
+							outputPath,
+							"this/is/ignored/dir/that/should/not/be/removed"
+						);
+						fs.mkdirSync(customDir, { recursive: true });
+						fs.writeFileSync(path.join(customDir, "file.ext"), "");
+						fs.mkdirSync(ignoredDir, { recursive: true });
+						fs.writeFileSync(path.join(ignoredDir, "file.ext"), "");
+						once = false;
+					}
+					compilation.emitAsset(
+						"this/dir/should/not/be/removed/file.ext",
+						new compiler.webpack.sources.RawSource("")
+					);
+				});
+			});
+			compiler.hooks.afterEmit.tap("Test", compilation => {
+				const outputPath = compiler.outputPath;
+				expect(
+					fs.existsSync(path.join(outputPath, "this/dir/should/be/removed"))
+				).toBe(false);
+				expect(
+					fs.existsSync(
+						path.join(
+							outputPath,
+							"this/is/ignored/dir/that/should/not/be/removed/file.ext"
+						)
+					)
+				).toBe(true);
+				expect(
+					fs.existsSync(
+						path.join(outputPath, "this/dir/should/not/be/removed/file.ext")
+					)
+				).toBe(true);
+			});
+		}
+	]
+};
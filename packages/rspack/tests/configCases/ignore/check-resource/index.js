@@ -0,0 +1,8 @@
+it("should ignore the resources checkResource returns true for", () => {
+	expect(() => require("./folder-b")).toThrow();
+});
+
+it("should not ignore other resources", () => {
+	expect(require("./folder-a")).toBe("a");
+	expect(require("./normal-module")).toBe("normal");
+});
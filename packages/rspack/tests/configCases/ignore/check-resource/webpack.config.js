@@ -0,0 +1,18 @@
+const path = require("path");
+const { IgnorePlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	plugins: [
+		new IgnorePlugin({
+			checkResource(resource, context) {
+				return (
+					resource === "./ignored-module" &&
+					context === path.resolve(__dirname, "folder-b")
+				);
+			}
+		})
+	]
+};
@@ -0,0 +1,5 @@
+it("should ignore the context modules matching resourceRegExp", () => {
+	const load = name => require("./locale/" + name);
+	expect(() => load("de")).toThrow();
+	expect(() => load("en")).toThrow();
+});
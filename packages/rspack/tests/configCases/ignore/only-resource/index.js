@@ -0,0 +1,7 @@
+it("should ignore the resources matching resourceRegExp", () => {
+	expect(() => require("./ignored-module")).toThrow();
+});
+
+it("should not ignore the resources not matching resourceRegExp", () => {
+	expect(require("./normal-module")).toBe("normal");
+});
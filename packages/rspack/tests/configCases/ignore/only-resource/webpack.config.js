@@ -0,0 +1,8 @@
+const { IgnorePlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	plugins: [new IgnorePlugin({ resourceRegExp: /ignored-module/ })]
+};
@@ -0,0 +1,7 @@
+it("should ignore the resources in the contexts matching contextRegExp", () => {
+	expect(() => require("./folder-b")).toThrow();
+});
+
+it("should not ignore the resources in other contexts", () => {
+	expect(require("./folder-a")).toBe("a");
+});
@@ -0,0 +1,9 @@
+const fs = require("fs");
+
+it("lazy-once should put all modules of a context into one chunk", async () => {
+	const load = name => import(`./dir/${name}`);
+	expect((await load("a")).default).toBe("a");
+	expect((await load("b")).default).toBe("b");
+	const chunks = fs.readdirSync(__dirname).filter(file => file !== "main.js");
+	expect(chunks).toHaveLength(1);
+});
@@ -0,0 +1,9 @@
+module.exports = {
+	module: {
+		parser: {
+			javascript: {
+				dynamicImportMode: "lazy-once"
+			}
+		}
+	}
+};
@@ -0,0 +1,3 @@
+export default import(
+	/* webpackChunkName: "chunk2", webpackPreload: true */ "./chunk2"
+);
@@ -0,0 +1,4 @@
+it("should emit the prefetch and preload runtime for module chunk loading", () => {
+	// checked by the plugin in webpack.config.js, `prefetch.js` needs a document to run
+	expect(true).toBe(true);
+});
@@ -0,0 +1,3 @@
+export default import(
+	/* webpackChunkName: "chunk1", webpackPrefetch: true */ "./chunk1"
+);
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function () {
+		return ["./main.js"];
+	}
+};
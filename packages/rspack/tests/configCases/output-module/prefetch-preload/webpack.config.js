@@ -0,0 +1,36 @@
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, assets => {
+				const source = assets["prefetch.js"].source();
+				// prefetch of chunk1 from the entry chunk
+				assert(source.includes('link.rel = "prefetch";'));
+				// preload of chunk2 when chunk1 is loaded
+				assert(source.includes('link.rel = "modulepreload";'));
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		prefetch: "./prefetch.js"
+	},
+	output: {
+		filename: "[name].js",
+		chunkFormat: "module",
+		chunkLoading: "import",
+		library: {
+			type: "module"
+		}
+	},
+	experiments: {
+		outputModule: true
+	},
+	plugins: [new Plugin()]
+};
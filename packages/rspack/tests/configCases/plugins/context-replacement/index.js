@@ -0,0 +1,17 @@
+it("should replace the regExp of the context", () => {
+	const load = name => require("./locale/" + name);
+	expect(load("de")).toBe("de");
+	expect(load("fr")).toBe("fr");
+	expect(() => load("en")).toThrow();
+});
+
+it("should replace the directory of the context", () => {
+	const load = name => require("./folder/" + name);
+	expect(load("a")).toBe("new-context");
+});
+
+it("should replace the context with a function", () => {
+	const load = name => require("./callback/" + name);
+	expect(load("a")).toBe("callback");
+	expect(() => load("nested/b")).toThrow();
+});
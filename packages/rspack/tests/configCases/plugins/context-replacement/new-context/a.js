@@ -0,0 +1 @@
+module.exports = "new-context";
@@ -0,0 +1,18 @@
+const path = require("path");
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	plugins: [
+		new ContextReplacementPlugin(/locale$/, /^\.\/(de|fr)/),
+		new ContextReplacementPlugin(
+			/folder$/,
+			path.resolve(__dirname, "new-context")
+		),
+		new ContextReplacementPlugin(/callback$/, data => {
+			data.recursive = false;
+		})
+	]
+};
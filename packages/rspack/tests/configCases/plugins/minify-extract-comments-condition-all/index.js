@@ -0,0 +1,36 @@
+const fs = require("fs");
+const path = require("path");
+/*! Legal Comment */
+
+/**
+ * @preserve Copyright 2009 SomeThirdParty.
+ */
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+/*
+ Foo Bar
+ */
+
+it("should extract all comments", () => {
+	const comments = fs.readFileSync(
+		path.resolve(__dirname, "main.js.LICENSE.txt"),
+		"utf-8"
+	);
+	expect(comments).toMatch(/Legal Comment/);
+	expect(comments).toMatch(/@preserve/);
+	expect(comments).toMatch(/@license/);
+	expect(comments).toMatch(/Foo Bar/);
+});
+
+it("should refer to the extracted file in the banner", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(
+		content.startsWith(
+			"/*! For license information please see main.js.LICENSE.txt */\n"
+		)
+	).toBe(true);
+});
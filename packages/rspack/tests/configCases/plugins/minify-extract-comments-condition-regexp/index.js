@@ -0,0 +1,32 @@
+const fs = require("fs");
+const path = require("path");
+/*! Legal Comment */
+
+/**
+ * @preserve Copyright 2009 SomeThirdParty.
+ */
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+/*
+ Foo Bar
+ */
+
+it("should only extract the comments matching the condition", () => {
+	const comments = fs.readFileSync(
+		path.resolve(__dirname, "main.js.LICENSE.txt"),
+		"utf-8"
+	);
+	expect(comments).toMatch(/@preserve/);
+	expect(comments).not.toMatch(/Legal Comment/);
+	expect(comments).not.toMatch(/@license/);
+	expect(comments).not.toMatch(/Foo Bar/);
+});
+
+it("should not add the banner when it's disabled", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content.startsWith("/*!")).toBe(false);
+});
@@ -0,0 +1,37 @@
+const fs = require("fs");
+const path = require("path");
+/*! Legal Comment */
+
+/**
+ * @preserve Copyright 2009 SomeThirdParty.
+ */
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+/*
+ Foo Bar
+ */
+
+it("should extract the comments to the file of the filename template", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "main.js.LICENSE.txt"))).toBe(
+		false
+	);
+	const comments = fs.readFileSync(
+		path.resolve(__dirname, "licenses/main.js.txt"),
+		"utf-8"
+	);
+	expect(comments).toMatch(/@preserve/);
+	expect(comments).toMatch(/@license/);
+});
+
+it("should add the string banner", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(
+		content.startsWith(
+			"/*! Licenses are extracted to the licenses directory */\n"
+		)
+	).toBe(true);
+});
@@ -0,0 +1,26 @@
+const fs = require("fs");
+const path = require("path");
+/*! Legal Comment */
+
+/**
+ * @preserve Copyright 2009 SomeThirdParty.
+ */
+
+/**
+ * Utility functions for the foo package.
+ * @license Apache-2.0
+ */
+
+/*
+ Foo Bar
+ */
+
+it("should delete the extracted comments together with the asset", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "main.js.LICENSE.txt"))).toBe(
+		true
+	);
+	expect(fs.existsSync(path.resolve(__dirname, "other.js"))).toBe(false);
+	expect(fs.existsSync(path.resolve(__dirname, "other.js.LICENSE.txt"))).toBe(
+		false
+	);
+});
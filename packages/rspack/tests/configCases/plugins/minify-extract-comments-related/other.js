@@ -0,0 +1,2 @@
+/*! Other Legal Comment */
+module.exports = "other";
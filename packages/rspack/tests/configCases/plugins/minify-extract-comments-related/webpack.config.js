@@ -0,0 +1,47 @@
+const assert = require("assert").strict;
+const rspack = require("@rspack/core");
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: pluginName,
+					stage: compiler.webpack.Compilation.PROCESS_ASSETS_STAGE_SUMMARIZE
+				},
+				() => {
+					const main = compilation.getAsset("main.js");
+					assert(main.info.related.license === "main.js.LICENSE.txt");
+					const other = compilation.getAsset("other.js");
+					assert(other.info.related.license === "other.js.LICENSE.txt");
+					assert(compilation.getAsset("other.js.LICENSE.txt"));
+					compilation.deleteAsset("other.js");
+					assert(!compilation.getAsset("other.js.LICENSE.txt"));
+				}
+			);
+		});
+	}
+}
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			extractComments: true
+		}),
+		new Plugin()
+	]
+};
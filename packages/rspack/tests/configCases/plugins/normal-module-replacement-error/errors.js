@@ -0,0 +1 @@
+module.exports = [[/Failed to replace \.\/a/]];
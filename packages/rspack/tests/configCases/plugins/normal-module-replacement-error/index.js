@@ -0,0 +1,3 @@
+it("should fail the build when the replacement callback throws", () => {
+	require("./a");
+});
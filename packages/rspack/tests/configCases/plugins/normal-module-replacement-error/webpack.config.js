@@ -0,0 +1,12 @@
+const { NormalModuleReplacementPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	plugins: [
+		new NormalModuleReplacementPlugin(/^\.\/a$/, () => {
+			throw new Error("Failed to replace ./a");
+		})
+	]
+};
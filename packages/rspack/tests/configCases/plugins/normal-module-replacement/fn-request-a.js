@@ -0,0 +1 @@
+module.exports = "fn-request-a";
@@ -0,0 +1 @@
+module.exports = "fn-resource-b";
@@ -0,0 +1,15 @@
+it("should replace the request before resolving", () => {
+	expect(require("./request-a")).toBe("request-b");
+});
+
+it("should replace the resource after resolving", () => {
+	expect(require("./resource-a")).toBe("resource-b");
+});
+
+it("should replace the request with a function", () => {
+	expect(require("./fn-request-a")).toBe("fn-request-b");
+});
+
+it("should replace the resource with a function", () => {
+	expect(require("./fn-resource-a")).toBe("fn-resource-b");
+});
@@ -0,0 +1,22 @@
+const { NormalModuleReplacementPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	plugins: [
+		new NormalModuleReplacementPlugin(/^\.\/request-a$/, "./request-b"),
+		new NormalModuleReplacementPlugin(
+			/[\\/]resource-a\.js$/,
+			"./resource-b.js"
+		),
+		new NormalModuleReplacementPlugin(/^\.\/fn-request-a$/, data => {
+			data.request = "./fn-request-b";
+		}),
+		new NormalModuleReplacementPlugin(/fn-resource-a\.js$/, data => {
+			if (data.resource) {
+				data.resource = data.resource.replace("fn-resource-a", "fn-resource-b");
+			}
+		})
+	]
+};
@@ -0,0 +1,20 @@
+import "./a.css";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should preserve and extract the matched comments", () => {
+	const css = fs.readFileSync(path.join(__dirname, "main.css"), "utf-8");
+	const [banner, preserved] = css.split("\n");
+	expect(banner).toBe(
+		"/*! For license information please see main.css.LICENSE.txt */"
+	);
+	expect(preserved).toBe("/*! preserved */");
+	expect(css).not.toContain("@license");
+	expect(css).not.toContain("removed");
+	const license = fs.readFileSync(
+		path.join(__dirname, "main.css.LICENSE.txt"),
+		"utf-8"
+	);
+	expect(license).toBe("/* @license extracted */");
+});
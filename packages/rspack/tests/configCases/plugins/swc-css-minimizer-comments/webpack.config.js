@@ -0,0 +1,16 @@
+const { SwcCssMinimizerRspackPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new SwcCssMinimizerRspackPlugin({
+				preserveComments: /preserved|@license/,
+				extractComments: /@license/
+			})
+		]
+	}
+};
@@ -0,0 +1,14 @@
+import "./a.css";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should only minimize the matched assets", () => {
+	const main = fs.readFileSync(path.join(__dirname, "main.css"), "utf-8");
+	expect(main).toContain(".a{color:red}");
+	const excluded = fs.readFileSync(
+		path.join(__dirname, "excluded.css"),
+		"utf-8"
+	);
+	expect(excluded).toContain(".b {");
+});
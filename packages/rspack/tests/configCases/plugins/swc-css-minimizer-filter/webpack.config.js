@@ -0,0 +1,24 @@
+const { SwcCssMinimizerRspackPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		excluded: "./excluded.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new SwcCssMinimizerRspackPlugin({
+				test: /\.css$/,
+				include: [/main/, "excluded"],
+				exclude: /excluded/
+			})
+		]
+	}
+};
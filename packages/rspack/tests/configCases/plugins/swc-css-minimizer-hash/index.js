@@ -0,0 +1,7 @@
+import "./a.css";
+
+it("should change the content hash when the options change", () => {
+	const fs = require("fs");
+	const files = fs.readdirSync(__dirname).filter(file => file.endsWith(".css"));
+	expect(files).toHaveLength(2);
+});
@@ -0,0 +1,18 @@
+const { SwcCssMinimizerRspackPlugin } = require("@rspack/core");
+
+const config = (index, options) => ({
+	output: {
+		filename: `bundle${index}.js`,
+		cssFilename: "[name].[contenthash].css"
+	},
+	optimization: {
+		minimize: true,
+		realContentHash: false,
+		minimizer: [new SwcCssMinimizerRspackPlugin(options)]
+	}
+});
+
+/**
+ * @type {import("@rspack/core").Configuration[]}
+ */
+module.exports = [config(0, {}), config(1, { preserveComments: /comment/ })];
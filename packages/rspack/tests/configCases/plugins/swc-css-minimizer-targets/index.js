@@ -0,0 +1,8 @@
+import "./a.css";
+
+it("should add the vendor prefixes required by the targets", () => {
+	const fs = require("fs");
+	const path = require("path");
+	const css = fs.readFileSync(path.join(__dirname, "main.css"), "utf-8");
+	expect(css).toContain("-webkit-user-select:none");
+});
@@ -0,0 +1,11 @@
+const { SwcCssMinimizerRspackPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	optimization: {
+		minimize: true,
+		minimizer: [new SwcCssMinimizerRspackPlugin({ targets: ["safari 10"] })]
+	}
+};
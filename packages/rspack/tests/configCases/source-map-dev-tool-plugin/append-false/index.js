@@ -0,0 +1,8 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should not append the comment if append is false", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(/sourceMappingURL\s*=\s*(.*)/.test(source)).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "main.js.map"))).toBe(true);
+});
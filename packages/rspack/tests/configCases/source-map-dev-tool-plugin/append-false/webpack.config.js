@@ -0,0 +1,14 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "[file].map",
+			append: false
+		})
+	]
+};
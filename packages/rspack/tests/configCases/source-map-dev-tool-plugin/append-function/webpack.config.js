@@ -0,0 +1,15 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "[file].map",
+			append: ({ filename, url }) =>
+				`\n//# sourceMappingURL=${url}?file=${filename}`
+		})
+	]
+};
@@ -0,0 +1,6 @@
+it("should append the comment with the url", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const match = /sourceMappingURL\s*=\s*(.*)/.exec(source);
+	expect(match[1]).toBe("https://example.com/main.js.map");
+});
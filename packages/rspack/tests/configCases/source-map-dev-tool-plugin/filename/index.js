@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the source map to the rendered filename", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	const match = /sourceMappingURL\s*=\s*(.*)/.exec(source);
+	expect(match[1]).toBe("maps/main.js.map");
+	expect(fs.existsSync(path.join(__dirname, "maps/main.js.map"))).toBe(true);
+});
+
+it("should set the sourceRoot and render the module filename template", () => {
+	const map = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "maps/main.js.map"), "utf-8")
+	);
+	expect(map.sourceRoot).toBe("/source-root");
+	const source = map.sources.find(source => source.includes("index.js"));
+	expect(source).toMatch(/^webpack:\/\/my-lib\/\.\/index\.js\?[0-9a-f]{4}$/);
+});
@@ -0,0 +1,16 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "maps/[name].js.map",
+			sourceRoot: "/source-root",
+			namespace: "my-lib",
+			moduleFilenameTemplate: "webpack://[namespace]/[resource-path]?[hash]"
+		})
+	]
+};
@@ -0,0 +1,8 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should only emit the source maps of the matched assets", () => {
+	expect(fs.existsSync(path.join(__dirname, "main.js.map"))).toBe(true);
+	expect(fs.existsSync(path.join(__dirname, "excluded.js.map"))).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "other.js.map"))).toBe(false);
+});
@@ -0,0 +1,24 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	devtool: false,
+	entry: {
+		main: "./index.js",
+		excluded: "./excluded.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "[file].map",
+			test: /\.js$/,
+			include: [/main/, "excluded"],
+			exclude: /excluded/
+		})
+	]
+};
@@ -0,0 +1,11 @@
+import { value } from "./module";
+
+it("should not warn that the defaulted concatenateModules is ignored", () => {
+	expect(value).toBe(42);
+	const messages = __STATS__.warnings.map(warning => warning.message);
+	expect(
+		messages.some(message =>
+			message.includes("optimization.concatenateModules is ignored")
+		)
+	).toBe(false);
+});
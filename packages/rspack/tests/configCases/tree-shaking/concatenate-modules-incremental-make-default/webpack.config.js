@@ -0,0 +1,38 @@
+const assert = require("assert").strict;
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules } = stats.toJson({
+				all: false,
+				modules: true,
+				optimizationBailout: true
+			});
+			const module = modules.find(module => module.name === "./module.js");
+			assert(
+				module.optimizationBailout.includes(
+					"ModuleConcatenation bailout: experiments.incrementalRebuild.make is enabled"
+				)
+			);
+		});
+	}
+}
+
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	mode: "production",
+	cache: true,
+	experiments: {
+		incrementalRebuild: {
+			make: true
+		},
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	plugins: [new Plugin()]
+};
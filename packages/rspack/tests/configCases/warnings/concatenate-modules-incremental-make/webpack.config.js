@@ -0,0 +1,19 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	mode: "production",
+	cache: true,
+	experiments: {
+		incrementalRebuild: {
+			make: true
+		},
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	optimization: {
+		concatenateModules: true
+	}
+};